@@ -0,0 +1,439 @@
+//! Derive macros for the `FromRedisValue` and `ToRedisArgs` traits of the
+//! [`redis`](https://docs.rs/redis) crate.
+//!
+//! These macros are re-exported by `redis` behind its `derive` feature, and shouldn't be used
+//! directly.
+//!
+//! * Structs with named fields are converted from the replies of hash commands, like `HGETALL`,
+//!   which are arrays of fields and values in RESP2 and maps in RESP3, and into the field/value
+//!   arguments of `HSET`.
+//! * Newtypes are converted like the type they wrap.
+//! * Enums with unit variants only are converted from and into the names of their variants.
+//!
+//! The fields and variants accept these `#[redis(...)]` attributes:
+//!
+//! * `rename = "name"`: uses `name` instead of the name of the field or the variant.
+//! * `skip`: ignores the field, which is set to its default value when parsing.
+//! * `default`: uses the default value of the field when it is missing. `default = "path"`
+//!   calls the function `path` instead. `Option` fields are `None` when missing.
+//! * `flatten`: parses the field from the fields that don't belong to other fields, and
+//!   writes its arguments along with the other fields.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    Data, DeriveInput, Error, Field, Fields, GenericParam, Generics, LitStr, Result, Type,
+    parse_macro_input, parse_quote, parse_quote_spanned,
+};
+
+/// Derives `FromRedisValue`.
+#[proc_macro_derive(FromRedisValue, attributes(redis))]
+pub fn derive_from_redis_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_redis_value(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `ToRedisArgs`, and `ToSingleRedisArg` for enums and newtypes of single arguments.
+///
+/// The fields of structs are written after their names, so apart from flattened fields, each
+/// field must be a single argument. Fields holding several values fail to compile:
+///
+/// ```compile_fail
+/// #[derive(redis::ToRedisArgs)]
+/// struct User {
+///     name: String,
+///     tags: Vec<String>,
+/// }
+/// ```
+#[proc_macro_derive(ToRedisArgs, attributes(redis))]
+pub fn derive_to_redis_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_redis_args(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum DefaultValue {
+    None,
+    Trait,
+    Function(syn::Path),
+}
+
+struct Attributes {
+    rename: Option<String>,
+    skip: bool,
+    default: DefaultValue,
+    flatten: bool,
+}
+
+impl Attributes {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut parsed = Attributes {
+            rename: None,
+            skip: false,
+            default: DefaultValue::None,
+            flatten: false,
+        };
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("redis")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    parsed.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                } else if meta.path.is_ident("default") {
+                    parsed.default = if meta.input.peek(syn::Token![=]) {
+                        DefaultValue::Function(meta.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        DefaultValue::Trait
+                    };
+                } else if meta.path.is_ident("flatten") {
+                    parsed.flatten = true;
+                } else {
+                    return Err(meta.error("unsupported redis attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+struct NamedField<'a> {
+    field: &'a Field,
+    ident: &'a syn::Ident,
+    name: String,
+    attrs: Attributes,
+}
+
+fn named_fields(fields: &syn::FieldsNamed) -> Result<Vec<NamedField<'_>>> {
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let attrs = Attributes::parse(&field.attrs)?;
+            let ident = field.ident.as_ref().expect("named fields have identifiers");
+            let name = attrs
+                .rename
+                .clone()
+                .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+            Ok(NamedField {
+                field,
+                ident,
+                name,
+                attrs,
+            })
+        })
+        .collect()
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+// Returns `T` for a field of type `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ty) if segment.ident == "Option" => Some(ty),
+        _ => None,
+    }
+}
+
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+fn unit_variants(data: &syn::DataEnum) -> Result<Vec<(&syn::Ident, String)>> {
+    data.variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new_spanned(
+                    variant,
+                    "only enums with unit variants are supported",
+                ));
+            }
+            let attrs = Attributes::parse(&variant.attrs)?;
+            let name = attrs.rename.unwrap_or_else(|| variant.ident.to_string());
+            Ok((&variant.ident, name))
+        })
+        .collect()
+}
+
+fn newtype_field(fields: &Fields) -> Option<&Field> {
+    match fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+        _ => None,
+    }
+}
+
+fn from_redis_value(input: &DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::redis::FromRedisValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => from_map(ident, &named_fields(fields)?),
+            fields => match newtype_field(fields) {
+                Some(_) => quote! {
+                    ::std::result::Result::Ok(#ident(::redis::FromRedisValue::from_redis_value(v)?))
+                },
+                None => {
+                    return Err(Error::new_spanned(
+                        fields,
+                        "only structs with named fields and newtypes are supported",
+                    ));
+                }
+            },
+        },
+        Data::Enum(data) => {
+            let arms = unit_variants(data)?.into_iter().map(
+                |(variant, name)| quote!(#name => ::std::result::Result::Ok(#ident::#variant),),
+            );
+            let type_name = ident.to_string();
+            quote! {
+                let name: ::std::string::String = ::redis::FromRedisValue::from_redis_value(v)?;
+                match name.as_str() {
+                    #(#arms)*
+                    other => ::std::result::Result::Err(::redis::ParsingError::from(
+                        ::std::format!("unknown {} variant `{}`", #type_name, other),
+                    )),
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "unions are not supported",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::redis::FromRedisValue for #ident #ty_generics #where_clause {
+            fn from_redis_value(
+                v: ::redis::Value,
+            ) -> ::std::result::Result<Self, ::redis::ParsingError> {
+                #body
+            }
+        }
+    })
+}
+
+fn from_map(ident: &syn::Ident, fields: &[NamedField]) -> TokenStream2 {
+    let type_name = ident.to_string();
+    let mut lets = Vec::new();
+    let mut flattened = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let var = format_ident!("__field{}", index);
+        let name = &field.name;
+        if field.attrs.skip {
+            lets.push(quote!(let #var = ::std::default::Default::default();));
+        } else if field.attrs.flatten {
+            flattened.push(quote! {
+                let #var = ::redis::FromRedisValue::from_redis_value(::redis::Value::Map(
+                    map.iter()
+                        .map(|(key, value)| {
+                            (::redis::Value::BulkString(key.clone().into_bytes()), value.clone())
+                        })
+                        .collect(),
+                ))?;
+            });
+        } else {
+            let missing = match &field.attrs.default {
+                DefaultValue::Trait => quote!(::std::default::Default::default()),
+                DefaultValue::Function(path) => quote!(#path()),
+                DefaultValue::None if is_option(&field.field.ty) => {
+                    quote!(::std::option::Option::None)
+                }
+                DefaultValue::None => quote! {
+                    return ::std::result::Result::Err(::redis::ParsingError::from(
+                        ::std::format!("missing field `{}` of {}", #name, #type_name),
+                    ))
+                },
+            };
+            lets.push(quote! {
+                let #var = match map.remove(#name) {
+                    ::std::option::Option::Some(value) => {
+                        ::redis::FromRedisValue::from_redis_value(value)?
+                    }
+                    ::std::option::Option::None => #missing,
+                };
+            });
+        }
+    }
+    let inits = fields.iter().enumerate().map(|(index, field)| {
+        let ident = field.ident;
+        let var = format_ident!("__field{}", index);
+        quote!(#ident: #var)
+    });
+
+    quote! {
+        #[allow(unused_mut, unused_variables)]
+        let mut map: ::std::collections::HashMap<::std::string::String, ::redis::Value> =
+            ::redis::FromRedisValue::from_redis_value(v)?;
+        #(#lets)*
+        #(#flattened)*
+        ::std::result::Result::Ok(#ident { #(#inits),* })
+    }
+}
+
+fn to_redis_args(input: &DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let mut generics = add_bounds(&input.generics, quote!(::redis::ToRedisArgs));
+
+    let (write, num_of_args, single) = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let fields = named_fields(fields)?;
+                let (write, num_of_args) = to_pairs(&fields);
+                // Each field value follows its name, so it must be a single argument.
+                let predicates = &mut generics.make_where_clause().predicates;
+                for ty in fields.iter().filter_map(single_value_type) {
+                    predicates
+                        .push(parse_quote_spanned!(ty.span()=> #ty: ::redis::ToSingleRedisArg));
+                }
+                (write, num_of_args, None)
+            }
+            fields => match newtype_field(fields) {
+                Some(field) => {
+                    let ty = &field.ty;
+                    (
+                        quote!(::redis::ToRedisArgs::write_redis_args(&self.0, out);),
+                        quote!(::redis::ToRedisArgs::num_of_args(&self.0)),
+                        // The higher-ranked bound keeps the impl from failing to compile when
+                        // the wrapped type isn't a single argument.
+                        Some(quote!(for<'__redis> #ty: ::redis::ToSingleRedisArg)),
+                    )
+                }
+                None => {
+                    return Err(Error::new_spanned(
+                        fields,
+                        "only structs with named fields and newtypes are supported",
+                    ));
+                }
+            },
+        },
+        Data::Enum(data) => {
+            let arms = unit_variants(data)?.into_iter().map(
+                |(variant, name)| quote!(#ident::#variant => out.write_arg(#name.as_bytes()),),
+            );
+            (
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                },
+                quote!(1),
+                Some(TokenStream2::new()),
+            )
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "unions are not supported",
+            ));
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let single = single.map(|bound| {
+        let mut generics = add_bounds(&input.generics, quote!(::redis::ToSingleRedisArg));
+        if !bound.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#bound));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::redis::ToSingleRedisArg for #ident #ty_generics #where_clause {}
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::redis::ToRedisArgs for #ident #ty_generics #where_clause {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?Sized + ::redis::RedisWrite,
+            {
+                #write
+            }
+
+            fn num_of_args(&self) -> usize {
+                #num_of_args
+            }
+        }
+
+        #single
+    })
+}
+
+// Returns the type written after the name of the field, if the field is written that way.
+fn single_value_type<'a>(field: &NamedField<'a>) -> Option<&'a Type> {
+    if field.attrs.skip || field.attrs.flatten {
+        return None;
+    }
+    let ty = &field.field.ty;
+    if is_option(ty) {
+        return option_inner(ty);
+    }
+    Some(ty)
+}
+
+fn to_pairs(fields: &[NamedField]) -> (TokenStream2, TokenStream2) {
+    let mut writes = Vec::new();
+    let mut counts = Vec::new();
+    for field in fields.iter().filter(|field| !field.attrs.skip) {
+        let ident = field.ident;
+        let name = &field.name;
+        if field.attrs.flatten {
+            writes.push(quote!(::redis::ToRedisArgs::write_redis_args(&self.#ident, out);));
+            counts.push(quote!(::redis::ToRedisArgs::num_of_args(&self.#ident)));
+        } else if is_option(&field.field.ty) {
+            // Missing values are left out rather than written as a field without a value.
+            writes.push(quote! {
+                if let ::std::option::Option::Some(value) = &self.#ident {
+                    out.write_arg(#name.as_bytes());
+                    ::redis::ToRedisArgs::write_redis_args(value, out);
+                }
+            });
+            counts.push(quote! {
+                self.#ident
+                    .as_ref()
+                    .map_or(0, |value| 1 + ::redis::ToRedisArgs::num_of_args(value))
+            });
+        } else {
+            writes.push(quote! {
+                out.write_arg(#name.as_bytes());
+                ::redis::ToRedisArgs::write_redis_args(&self.#ident, out);
+            });
+            counts.push(quote!(1 + ::redis::ToRedisArgs::num_of_args(&self.#ident)));
+        }
+    }
+    (quote!(#(#writes)*), quote!(0 #(+ #counts)*))
+}
@@ -34,6 +34,8 @@ enum ServerType {
 pub enum Module {
     Bloom,
     Json,
+    Search,
+    TimeSeries,
 }
 
 /// A standalone Redis server instance for testing.
@@ -215,6 +217,19 @@ impl RedisServer {
 
                     redis_cmd.arg("--loadmodule").arg(path);
                 }
+                Module::Search => {
+                    // Redis 8 ships with the query engine built in, so the module only has to be
+                    // loaded for older servers
+                    if let Ok(path) = env::var("REDISRS_REDIS_SEARCH_PATH") {
+                        redis_cmd.arg("--loadmodule").arg(path);
+                    }
+                }
+                Module::TimeSeries => {
+                    // Like the query engine, RedisTimeSeries is built into Redis 8
+                    if let Ok(path) = env::var("REDISRS_REDIS_TIMESERIES_PATH") {
+                        redis_cmd.arg("--loadmodule").arg(path);
+                    }
+                }
             };
         }
 
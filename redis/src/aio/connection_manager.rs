@@ -3,7 +3,7 @@ use super::{AsyncPushSender, HandleContainer, RedisFuture};
 use crate::caching::CacheManager;
 use crate::{
     AsyncConnectionConfig, Client, Cmd, Pipeline, PushInfo, PushKind, ToRedisArgs,
-    aio::{ConnectionLike, MultiplexedConnection, Runtime},
+    aio::{BulkReader, ConnectionLike, MultiplexedConnection, Runtime},
     check_resp3,
     client::{DEFAULT_CONNECTION_TIMEOUT, DEFAULT_RESPONSE_TIMEOUT},
     cmd,
@@ -17,6 +17,7 @@ use futures_channel::oneshot;
 use futures_util::future::{BoxFuture, FutureExt, Shared};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
+use tokio::io::AsyncRead;
 use tokio::sync::Mutex;
 use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
 
@@ -48,6 +49,8 @@ pub struct ConnectionManagerConfig {
     concurrency_limit: Option<usize>,
     /// Flush threshold for the outbound write buffer; see [`AsyncConnectionConfig::set_write_backpressure_boundary`].
     write_backpressure_boundary: Option<usize>,
+    /// Minimum length of the replies kept in the read buffer; see [`AsyncConnectionConfig::set_bulk_bytes_threshold`].
+    bulk_bytes_threshold: Option<usize>,
     /// Optional credentials provider for dynamic authentication (e.g., token-based authentication)
     #[cfg(feature = "token-based-authentication")]
     credentials_provider: Option<std::sync::Arc<dyn crate::auth::StreamingCredentialsProvider>>,
@@ -69,6 +72,7 @@ impl std::fmt::Debug for ConnectionManagerConfig {
             pipeline_buffer_size,
             concurrency_limit,
             write_backpressure_boundary,
+            bulk_bytes_threshold,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider,
         } = &self;
@@ -83,6 +87,7 @@ impl std::fmt::Debug for ConnectionManagerConfig {
             .field("pipeline_buffer_size", &pipeline_buffer_size)
             .field("concurrency_limit", &concurrency_limit)
             .field("write_backpressure_boundary", &write_backpressure_boundary)
+            .field("bulk_bytes_threshold", &bulk_bytes_threshold)
             .field(
                 "push_sender",
                 if push_sender.is_some() {
@@ -286,6 +291,15 @@ impl ConnectionManagerConfig {
         self
     }
 
+    /// Sets the minimum length of the bulk string replies that are kept in the read buffer
+    /// instead of being copied out of it.
+    ///
+    /// See [`AsyncConnectionConfig::set_bulk_bytes_threshold`] for full semantics.
+    pub fn set_bulk_bytes_threshold(mut self, threshold: usize) -> Self {
+        self.bulk_bytes_threshold = Some(threshold);
+        self
+    }
+
     /// Sets a credentials provider for dynamic authentication.
     ///
     /// This is useful for token-based authentication where credentials need to be
@@ -333,6 +347,7 @@ impl Default for ConnectionManagerConfig {
             pipeline_buffer_size: None,
             concurrency_limit: None,
             write_backpressure_boundary: None,
+            bulk_bytes_threshold: None,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider: None,
         }
@@ -491,6 +506,7 @@ impl ConnectionManager {
         connection_config.pipeline_buffer_size = config.pipeline_buffer_size;
         connection_config.concurrency_limit = config.concurrency_limit;
         connection_config.write_backpressure_boundary = config.write_backpressure_boundary;
+        connection_config.bulk_bytes_threshold = config.bulk_bytes_threshold;
 
         #[cfg(feature = "cache-aio")]
         let cache_manager = config
@@ -720,6 +736,43 @@ impl ConnectionManager {
         result
     }
 
+    /// Sends `cmd` with an extra last argument of `len` bytes, streamed from `reader`.
+    ///
+    /// See [`MultiplexedConnection::send_command_with_reader`].
+    pub async fn send_command_with_reader<R>(
+        &mut self,
+        cmd: &Cmd,
+        len: u64,
+        reader: R,
+    ) -> RedisResult<Value>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        // Clone shared connection future to avoid having to lock the ArcSwap in write mode
+        let guard = self.0.connection.load();
+        let connection_result = (**guard).clone().await;
+        reconnect_if_io_error!(self, connection_result, guard);
+        let result = connection_result?
+            .send_command_with_reader(cmd, len, reader)
+            .await;
+        reconnect_if_dropped!(self, &result, guard);
+        result
+    }
+
+    /// Sends `cmd`, and returns its bulk string reply as a [`BulkReader`] streaming it from the
+    /// socket, or `None` if the reply is nil.
+    ///
+    /// See [`MultiplexedConnection::send_command_for_reader`].
+    pub async fn send_command_for_reader(&mut self, cmd: &Cmd) -> RedisResult<Option<BulkReader>> {
+        // Clone shared connection future to avoid having to lock the ArcSwap in write mode
+        let guard = self.0.connection.load();
+        let connection_result = (**guard).clone().await;
+        reconnect_if_io_error!(self, connection_result, guard);
+        let result = connection_result?.send_command_for_reader(cmd).await;
+        reconnect_if_dropped!(self, &result, guard);
+        result
+    }
+
     async fn update_subscription_tracker(
         &self,
         action: SubscriptionAction,
@@ -847,6 +900,10 @@ impl ConnectionLike for ConnectionManager {
     fn get_db(&self) -> i64 {
         self.0.client.connection_info().redis.db
     }
+
+    fn response_timeout(&self) -> Option<Duration> {
+        self.0.connection_config.response_timeout
+    }
 }
 
 #[cfg(test)]
@@ -890,6 +947,14 @@ mod tests {
         assert_eq!(config.write_backpressure_boundary, Some(16 * 1024 * 1024));
     }
 
+    #[test]
+    fn test_connection_manager_config_bulk_bytes_threshold() {
+        let config = ConnectionManagerConfig::new();
+        assert_eq!(config.bulk_bytes_threshold, None);
+        let config = config.set_bulk_bytes_threshold(64 * 1024);
+        assert_eq!(config.bulk_bytes_threshold, Some(64 * 1024));
+    }
+
     #[tokio::test]
     async fn test_lazy_connection_manager_with_config() {
         // Test that lazy connection manager can be created with custom config
@@ -18,6 +18,7 @@ use std::net::SocketAddr;
 #[cfg(unix)]
 use std::path::Path;
 use std::pin::Pin;
+use std::time::Duration;
 
 mod monitor;
 
@@ -95,6 +96,13 @@ pub trait ConnectionLike {
     /// also might be incorrect if the connection like object is not
     /// actually connected.
     fn get_db(&self) -> i64;
+
+    /// Returns how long the connection waits for a response before failing a request, or
+    /// `None` if responses don't time out. Blocking commands issued by the crate's helpers,
+    /// such as the stream consumers, block for less than this.
+    fn response_timeout(&self) -> Option<Duration> {
+        None
+    }
 }
 
 async fn execute_connection_pipeline<T>(
@@ -8,13 +8,14 @@ use crate::{
     check_resp3, cmd,
     cmd::Cmd,
     errors::{ErrorKind, RedisError, closed_connection_error},
-    parser::ValueCodec,
+    parser::{ReplyFrame, StreamingValueCodec, ValueCodec},
     types::{RedisFuture, RedisResult, Value},
 };
 use ::tokio::{
-    io::{AsyncRead, AsyncWrite},
+    io::{AsyncRead, AsyncWrite, ReadBuf},
     sync::{mpsc, oneshot},
 };
+use bytes::Bytes;
 #[cfg(feature = "token-based-authentication")]
 use {
     arcstr::ArcStr,
@@ -31,11 +32,13 @@ use pin_project_lite::pin_project;
 use std::collections::VecDeque;
 use std::fmt;
 use std::fmt::Debug;
+use std::io;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{self, Poll};
 use std::time::Duration;
-use tokio_util::codec::Decoder;
+use tokio_util::codec::{Decoder, Framed};
+use tokio_util::sync::PollSender;
 
 // Senders which the result of a single request are sent through
 type PipelineOutput = oneshot::Sender<RedisResult<Value>>;
@@ -81,9 +84,47 @@ impl ResponseAggregate {
 
 struct InFlight {
     output: Option<PipelineOutput>,
+    // Set instead of `output` for requests whose reply is streamed.
+    download: Option<Download>,
     response_aggregate: ResponseAggregate,
 }
 
+impl InFlight {
+    fn is_closed(&self) -> bool {
+        self.output
+            .as_ref()
+            .is_some_and(|output| output.is_closed())
+            || self
+                .download
+                .as_ref()
+                .is_some_and(|download| download.head.is_closed())
+    }
+
+    fn send_error(mut self, err: RedisError) {
+        if let Some(output) = self.output.take() {
+            _ = output.send(Err(err));
+        } else if let Some(download) = self.download.take() {
+            _ = download.head.send(Err(err));
+        }
+    }
+}
+
+// The last argument of a request, streamed from a reader after the rest of the request.
+struct Upload {
+    reader: Pin<Box<dyn AsyncRead + Send>>,
+    remaining: u64,
+    buffer: Box<[u8]>,
+}
+
+// The receiving end of a request whose bulk string reply is streamed.
+struct Download {
+    // Receives the length of the bulk string once its header is read, or `None` if the reply is nil.
+    head: oneshot::Sender<RedisResult<Option<usize>>>,
+    chunks: PollSender<Bytes>,
+    // Whether the transport was asked to stream the reply.
+    requested: bool,
+}
+
 // A single message sent through the pipeline
 struct PipelineMessage {
     input: Vec<u8>,
@@ -93,6 +134,25 @@ struct PipelineMessage {
     // If `Some`, the first value is the number of responses to skip,
     // the second is the number of responses to keep, and the third is whether the pipeline is a transaction.
     expectation: Option<PipelineResponseExpectation>,
+    upload: Option<Upload>,
+    download: Option<Download>,
+}
+
+// The transport under a `PipelineSink`, which can stream the body of a bulk string reply.
+trait ReplyTransport:
+    Sink<Vec<u8>, Error = RedisError> + Stream<Item = RedisResult<ReplyFrame>>
+{
+    // Streams the next reply if it's a bulk string. Must only be called between replies.
+    fn stream_next_reply(self: Pin<&mut Self>);
+}
+
+impl<C> ReplyTransport for Framed<C, StreamingValueCodec>
+where
+    C: AsyncRead + AsyncWrite,
+{
+    fn stream_next_reply(self: Pin<&mut Self>) {
+        self.codec_pin_mut().stream_next_reply();
+    }
 }
 
 /// Wrapper around a `Stream + Sink` where each item sent through the `Sink` results in one or more
@@ -119,6 +179,10 @@ pin_project! {
         error: Option<RedisError>,
         push_sender: Option<Arc<dyn AsyncPushSender>>,
         cache_manager: Option<CacheManager>,
+        // The streamed argument being written, during which no other request is sent.
+        upload: Option<Upload>,
+        // Where the chunks of the bulk string reply being read are sent.
+        download: Option<PollSender<Bytes>>,
     }
 }
 
@@ -130,6 +194,10 @@ pin_project! {
         in_flight: VecDeque<InFlight>,
         error: Option<RedisError>,
         push_sender: Option<Arc<dyn AsyncPushSender>>,
+        // The streamed argument being written, during which no other request is sent.
+        upload: Option<Upload>,
+        // Where the chunks of the bulk string reply being read are sent.
+        download: Option<PollSender<Bytes>>,
     }
 }
 
@@ -145,16 +213,13 @@ pub(crate) fn send_disconnect(push_sender: &Option<Arc<dyn AsyncPushSender>>) {
 
 impl<T> PipelineSink<T>
 where
-    T: Stream<Item = RedisResult<Value>> + 'static,
+    T: ReplyTransport + 'static,
 {
     fn new(
         sink_stream: T,
         push_sender: Option<Arc<dyn AsyncPushSender>>,
         #[cfg(feature = "cache-aio")] cache_manager: Option<CacheManager>,
-    ) -> Self
-    where
-        T: Sink<Vec<u8>, Error = RedisError> + Stream<Item = RedisResult<Value>> + 'static,
-    {
+    ) -> Self {
         PipelineSink {
             sink_stream,
             in_flight: VecDeque::new(),
@@ -162,15 +227,51 @@ where
             push_sender,
             #[cfg(feature = "cache-aio")]
             cache_manager,
+            upload: None,
+            download: None,
         }
     }
 
     // Read messages from the stream and send them back to the caller
     fn poll_read(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Result<(), ()>> {
         loop {
-            let item = ready!(self.as_mut().project().sink_stream.poll_next(cx));
+            let mut self_ = self.as_mut().project();
+            if let Some(chunks) = self_.download {
+                // Stop reading until the reader can take the next chunk. Once the reader is
+                // dropped, the rest of the reply is discarded.
+                _ = ready!(chunks.poll_reserve(cx));
+            } else if let Some(InFlight {
+                download: Some(download),
+                ..
+            }) = self_.in_flight.front_mut()
+                && !download.requested
+            {
+                download.requested = true;
+                self_.sink_stream.as_mut().stream_next_reply();
+            }
+
+            let item = ready!(self_.sink_stream.poll_next(cx));
             let item = match item {
-                Some(result) => result,
+                Some(Ok(ReplyFrame::Value(value))) => Ok(value),
+                Some(Ok(ReplyFrame::BulkStart(len))) => {
+                    self.as_mut().start_download(len);
+                    continue;
+                }
+                Some(Ok(ReplyFrame::BulkChunk(chunk))) => {
+                    if let Some(chunks) = self_.download {
+                        _ = chunks.send_item(chunk);
+                    }
+                    continue;
+                }
+                Some(Ok(ReplyFrame::BulkEnd)) => {
+                    *self_.download = None;
+                    continue;
+                }
+                Some(Err(err)) => {
+                    // The reader reports the reply as truncated.
+                    *self_.download = None;
+                    Err(err)
+                }
                 // The redis response stream is not going to produce any more items so we simulate a disconnection error to break out of the loop.
                 None => Err(closed_connection_error()),
             };
@@ -185,6 +286,65 @@ where
         }
     }
 
+    fn start_download(self: Pin<&mut Self>, len: usize) {
+        let self_ = self.project();
+        if let Some(InFlight {
+            download: Some(Download { head, chunks, .. }),
+            ..
+        }) = self_.in_flight.pop_front()
+        {
+            // If the caller is gone, the chunks are discarded as they are read.
+            _ = head.send(Ok(Some(len)));
+            *self_.download = Some(chunks);
+        }
+    }
+
+    // Write the streamed argument of the last request, if any, until it's fully written
+    fn poll_upload(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Result<(), ()>> {
+        loop {
+            let mut self_ = self.as_mut().project();
+            let Some(upload) = self_.upload else {
+                return Poll::Ready(Ok(()));
+            };
+            let result = match ready!(self_.sink_stream.as_mut().poll_ready(cx)) {
+                Ok(()) if upload.remaining == 0 => {
+                    *self_.upload = None;
+                    self_.sink_stream.start_send(b"\r\n".to_vec())
+                }
+                Ok(()) => {
+                    let len = usize::try_from(upload.remaining)
+                        .map_or(upload.buffer.len(), |remaining| {
+                            remaining.min(upload.buffer.len())
+                        });
+                    let mut buf = ReadBuf::new(&mut upload.buffer[..len]);
+                    match ready!(upload.reader.as_mut().poll_read(cx, &mut buf)) {
+                        Ok(()) if buf.filled().is_empty() => Err(RedisError::from(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "streamed argument ended before its length",
+                        ))),
+                        Ok(()) => {
+                            upload.remaining -= buf.filled().len() as u64;
+                            let chunk = buf.filled().to_vec();
+                            self_.sink_stream.start_send(chunk)
+                        }
+                        Err(err) => Err(err.into()),
+                    }
+                }
+                Err(err) => Err(err),
+            };
+
+            if let Err(err) = result {
+                // The request can't be completed, so the connection can't be used anymore.
+                *self_.upload = None;
+                if let Some(entry) = self_.in_flight.pop_back() {
+                    entry.send_error(err);
+                }
+                send_disconnect(self_.push_sender);
+                return Poll::Ready(Err(()));
+            }
+        }
+    }
+
     fn send_result(self: Pin<&mut Self>, result: RedisResult<Value>) {
         let self_ = self.project();
         let result = match result {
@@ -221,6 +381,19 @@ where
             ResponseAggregate::SingleCommand => {
                 if let Some(output) = entry.output.take() {
                     _ = output.send(result);
+                } else if let Some(download) = entry.download.take() {
+                    // Replies other than bulk strings aren't streamed.
+                    let result = result.and_then(|value| match value {
+                        Value::Nil => Ok(None),
+                        Value::ServerError(err) => Err(err.into()),
+                        value => Err((
+                            ErrorKind::UnexpectedReturnType,
+                            "Expected a bulk string reply",
+                            format!("{value:?}"),
+                        )
+                            .into()),
+                    });
+                    _ = download.head.send(result);
                 }
             }
             ResponseAggregate::Pipeline {
@@ -295,7 +468,7 @@ where
 
 impl<T> Sink<PipelineMessage> for PipelineSink<T>
 where
-    T: Sink<Vec<u8>, Error = RedisError> + Stream<Item = RedisResult<Value>> + 'static,
+    T: ReplyTransport + 'static,
 {
     type Error = ();
 
@@ -313,6 +486,7 @@ where
         if matches!(self.as_mut().poll_read(cx), Poll::Ready(Err(()))) {
             return Poll::Ready(Err(()));
         }
+        ready!(self.as_mut().poll_upload(cx))?;
         match ready!(self.as_mut().project().sink_stream.poll_ready(cx)) {
             Ok(()) => Ok(()).into(),
             Err(err) => {
@@ -326,41 +500,39 @@ where
         mut self: Pin<&mut Self>,
         PipelineMessage {
             input,
-            mut output,
+            output,
             expectation,
+            upload,
+            download,
         }: PipelineMessage,
     ) -> Result<(), Self::Error> {
+        let entry = InFlight {
+            output,
+            download,
+            response_aggregate: ResponseAggregate::new(expectation),
+        };
         // If initially a receiver was created, but then dropped, there is nothing to receive our output we do not need to send the message as it is
         // ambiguous whether the message will be sent anyway. Helps shed some load on the
         // connection.
-        if output.as_ref().is_some_and(|output| output.is_closed()) {
+        if entry.is_closed() {
             return Ok(());
         }
 
         let self_ = self.as_mut().project();
 
         if let Some(err) = self_.error.take() {
-            if let Some(output) = output.take() {
-                _ = output.send(Err(err));
-            }
+            entry.send_error(err);
             return Err(());
         }
 
         match self_.sink_stream.start_send(input) {
             Ok(()) => {
-                let response_aggregate = ResponseAggregate::new(expectation);
-                let entry = InFlight {
-                    output,
-                    response_aggregate,
-                };
-
                 self_.in_flight.push_back(entry);
+                *self_.upload = upload;
                 Ok(())
             }
             Err(err) => {
-                if let Some(output) = output.take() {
-                    _ = output.send(Err(err));
-                }
+                entry.send_error(err);
                 Err(())
             }
         }
@@ -379,6 +551,7 @@ where
         if matches!(self.as_mut().poll_read(cx), Poll::Ready(Err(()))) {
             return Poll::Ready(Err(()));
         }
+        ready!(self.as_mut().poll_upload(cx))?;
         self.as_mut()
             .project()
             .sink_stream
@@ -406,6 +579,9 @@ where
 
 impl Pipeline {
     const DEFAULT_BUFFER_SIZE: usize = 50;
+    // The number of chunks of a streamed reply buffered before reading from the socket stops.
+    const DOWNLOAD_BUFFER_SIZE: usize = 16;
+    const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
 
     fn resolve_buffer_size(size: Option<usize>) -> usize {
         size.unwrap_or(Self::DEFAULT_BUFFER_SIZE)
@@ -418,9 +594,7 @@ impl Pipeline {
         buffer_size: usize,
     ) -> (Self, impl Future<Output = ()>)
     where
-        T: Sink<Vec<u8>, Error = RedisError>,
-        T: Stream<Item = RedisResult<Value>>,
-        T: Unpin + Send + 'static,
+        T: ReplyTransport + Unpin + Send + 'static,
     {
         let (sender, mut receiver) = mpsc::channel(buffer_size);
 
@@ -457,6 +631,8 @@ impl Pipeline {
                         input,
                         expectation,
                         output: None,
+                        upload: None,
+                        download: None,
                     })
                     .await
                     .map_err(|_| None)?;
@@ -471,6 +647,8 @@ impl Pipeline {
                     input,
                     expectation,
                     output: Some(sender),
+                    upload: None,
+                    download: None,
                 })
                 .await
                 .map_err(|_| None)?;
@@ -491,6 +669,168 @@ impl Pipeline {
         }
         .map_err(|err| err.unwrap_or_else(closed_connection_error))
     }
+
+    async fn send_upload(&mut self, input: Vec<u8>, upload: Upload) -> RedisResult<Value> {
+        let (sender, receiver) = oneshot::channel();
+        self.sender
+            .send(PipelineMessage {
+                input,
+                expectation: None,
+                output: Some(sender),
+                upload: Some(upload),
+                download: None,
+            })
+            .await
+            .map_err(|_| closed_connection_error())?;
+
+        receiver
+            .await
+            .unwrap_or_else(|_| Err(closed_connection_error()))
+    }
+
+    async fn send_download(
+        &mut self,
+        input: Vec<u8>,
+        timeout: Option<Duration>,
+        permit: Option<async_lock::SemaphoreGuardArc>,
+    ) -> RedisResult<Option<BulkReader>> {
+        let (head, head_receiver) = oneshot::channel();
+        let (chunks, chunks_receiver) = mpsc::channel(Self::DOWNLOAD_BUFFER_SIZE);
+
+        let request = async {
+            self.sender
+                .send(PipelineMessage {
+                    input,
+                    expectation: None,
+                    output: None,
+                    upload: None,
+                    download: Some(Download {
+                        head,
+                        chunks: PollSender::new(chunks),
+                        requested: false,
+                    }),
+                })
+                .await
+                .map_err(|_| closed_connection_error())?;
+
+            head_receiver
+                .await
+                .unwrap_or_else(|_| Err(closed_connection_error()))
+        };
+
+        let len = match timeout {
+            Some(timeout) => Runtime::locate()
+                .timeout(timeout, request)
+                .await
+                .unwrap_or_else(|elapsed| Err(elapsed.into())),
+            None => request.await,
+        }?;
+        Ok(len.map(|len| BulkReader::new(len, chunks_receiver, permit)))
+    }
+}
+
+/// The body of a bulk string reply, read from the connection as it's consumed.
+///
+/// Returned by [`MultiplexedConnection::send_command_for_reader`] and
+/// `ConnectionManager::send_command_for_reader`. Cluster connections don't support streamed replies. The reply is read in chunks sliced out of
+/// the connection's read buffer, which can be taken as they are with the [`Stream`]
+/// implementation, or copied out with the [`AsyncRead`] one.
+///
+/// While the reader isn't consumed, replies to the other requests on the connection are not read
+/// either, and the request counts against the connection's concurrency limit. Dropping the reader
+/// discards the rest of the reply. If the connection fails before the whole reply is read, reading
+/// fails with [`io::ErrorKind::UnexpectedEof`].
+pub struct BulkReader {
+    len: usize,
+    // The number of bytes which weren't received yet.
+    remaining: usize,
+    chunks: mpsc::Receiver<Bytes>,
+    chunk: Bytes,
+    // Released once the whole reply is received.
+    permit: Option<async_lock::SemaphoreGuardArc>,
+}
+
+impl BulkReader {
+    fn new(
+        len: usize,
+        chunks: mpsc::Receiver<Bytes>,
+        permit: Option<async_lock::SemaphoreGuardArc>,
+    ) -> Self {
+        Self {
+            len,
+            remaining: len,
+            chunks,
+            chunk: Bytes::new(),
+            permit: if len == 0 { None } else { permit },
+        }
+    }
+
+    /// Returns the length of the whole bulk string, in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the bulk string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn poll_chunk(&mut self, cx: &mut task::Context) -> Poll<Option<io::Result<Bytes>>> {
+        if !self.chunk.is_empty() {
+            return Poll::Ready(Some(Ok(std::mem::take(&mut self.chunk))));
+        }
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        match ready!(self.chunks.poll_recv(cx)) {
+            Some(chunk) => {
+                self.remaining = self.remaining.saturating_sub(chunk.len());
+                if self.remaining == 0 {
+                    self.permit = None;
+                }
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            None => Poll::Ready(Some(Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "connection closed before the whole reply was read",
+            )))),
+        }
+    }
+}
+
+impl Debug for BulkReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BulkReader")
+            .field("len", &self.len)
+            .field("remaining", &(self.remaining + self.chunk.len()))
+            .finish()
+    }
+}
+
+impl Stream for BulkReader {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_chunk(cx)
+    }
+}
+
+impl AsyncRead for BulkReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut task::Context,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let mut chunk = match ready!(this.poll_chunk(cx)) {
+            Some(chunk) => chunk?,
+            None => return Poll::Ready(Ok(())),
+        };
+        let len = chunk.len().min(buf.remaining());
+        buf.put_slice(&chunk.split_to(len));
+        this.chunk = chunk;
+        Poll::Ready(Ok(()))
+    }
 }
 
 /// Semaphore plus the `limit` it was created with, so the limit stays readable.
@@ -599,7 +939,9 @@ impl MultiplexedConnection {
     where
         C: Unpin + AsyncRead + AsyncWrite + Send + 'static,
     {
-        let mut codec = ValueCodec::default().framed(stream);
+        let mut codec = ValueCodec::default()
+            .set_bulk_bytes_threshold(config.bulk_bytes_threshold)
+            .framed(stream);
         if let Some(boundary) = config.write_backpressure_boundary {
             codec.set_backpressure_boundary(boundary);
         }
@@ -677,7 +1019,7 @@ impl MultiplexedConnection {
         }
 
         let (pipeline, driver) = Pipeline::new(
-            codec,
+            codec.map_codec(StreamingValueCodec::new),
             config.push_sender,
             #[cfg(feature = "cache-aio")]
             cache_manager_opt.clone(),
@@ -836,7 +1178,7 @@ impl MultiplexedConnection {
         if let Some(cache_manager) = &self.cache_manager {
             let (cacheable_pipeline, pipeline, (skipped_response_count, expected_response_count)) =
                 cache_manager.get_cached_pipeline(cmd);
-            if pipeline.is_empty() {
+            if pipeline.is_empty() && pipeline.durability.is_none() {
                 return cacheable_pipeline.resolve(cache_manager, Value::Array(Vec::new()));
             }
             let result = self
@@ -876,6 +1218,94 @@ impl MultiplexedConnection {
         }
     }
 
+    /// Sends `cmd` with an extra last argument of `len` bytes, streamed from `reader`, and reads
+    /// the single response.
+    ///
+    /// The argument is written to the socket as it's read, so it is never held in memory as a
+    /// whole, and other requests on the connection are only sent once it's written. If `reader`
+    /// fails or ends before `len` bytes, the request fails and the connection is closed, as the
+    /// command can't be completed. The response timeout doesn't apply to this request.
+    ///
+    /// ```rust,no_run
+    /// # async fn func() -> redis::RedisResult<()> {
+    /// let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+    /// let mut con = client.get_multiplexed_async_connection().await?;
+    /// let file = tokio::fs::File::open("blob.bin").await?;
+    /// let len = file.metadata().await?.len();
+    /// con.send_command_with_reader(redis::cmd("SET").arg("blob"), len, file)
+    ///     .await?
+    ///     .extract_error()?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn send_command_with_reader<R>(
+        &mut self,
+        cmd: &Cmd,
+        len: u64,
+        reader: R,
+    ) -> RedisResult<Value>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        if cmd.is_empty() {
+            return Err(RedisError::make_empty_command());
+        }
+        let _permit = if cmd.skip_concurrency_limit {
+            None
+        } else if let Some(limiter) = &self.concurrency_limiter {
+            Some(limiter.semaphore.acquire().await)
+        } else {
+            None
+        };
+        let upload = Upload {
+            reader: Box::pin(reader),
+            remaining: len,
+            buffer: vec![
+                0;
+                usize::try_from(len).map_or(Pipeline::UPLOAD_CHUNK_SIZE, |len| len
+                    .min(Pipeline::UPLOAD_CHUNK_SIZE))
+            ]
+            .into(),
+        };
+        self.pipeline
+            .send_upload(cmd.get_packed_command_with_streamed_arg(len), upload)
+            .await
+    }
+
+    /// Sends `cmd`, and returns its bulk string reply as a [`BulkReader`] streaming it from the
+    /// socket, or `None` if the reply is nil. Other replies are returned as errors.
+    ///
+    /// The response timeout only applies until the reply starts being read.
+    ///
+    /// ```rust,no_run
+    /// # async fn func() -> redis::RedisResult<()> {
+    /// let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+    /// let mut con = client.get_multiplexed_async_connection().await?;
+    /// let mut file = tokio::fs::File::create("blob.bin").await?;
+    /// if let Some(mut reader) = con.send_command_for_reader(redis::cmd("GET").arg("blob")).await? {
+    ///     tokio::io::copy(&mut reader, &mut file).await?;
+    /// }
+    /// let header = con
+    ///     .send_command_for_reader(redis::cmd("GETRANGE").arg("blob").arg(0).arg(1023))
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn send_command_for_reader(&mut self, cmd: &Cmd) -> RedisResult<Option<BulkReader>> {
+        if cmd.is_empty() {
+            return Err(RedisError::make_empty_command());
+        }
+        // The permit is held by the reader, as the reply is only read as the reader is consumed.
+        let permit = if cmd.skip_concurrency_limit {
+            None
+        } else if let Some(limiter) = &self.concurrency_limiter {
+            Some(limiter.semaphore.acquire_arc().await)
+        } else {
+            None
+        };
+        self.pipeline
+            .send_download(cmd.get_packed_command(), self.response_timeout, permit)
+            .await
+    }
+
     /// Gets [`CacheStatistics`] for current connection if caching is enabled.
     #[cfg(feature = "cache-aio")]
     #[cfg_attr(docsrs, doc(cfg(feature = "cache-aio")))]
@@ -901,6 +1331,10 @@ impl ConnectionLike for MultiplexedConnection {
     fn get_db(&self) -> i64 {
         self.db
     }
+
+    fn response_timeout(&self) -> Option<Duration> {
+        self.response_timeout
+    }
 }
 
 impl MultiplexedConnection {
@@ -1469,4 +1903,230 @@ mod tests {
         assert!(err.is_io_error(), "expected IO error from timeout");
         cmd_received_rx.recv().await.unwrap();
     }
+
+    type ServerWriter = tokio::io::WriteHalf<tokio::io::DuplexStream>;
+
+    // Returns a connection, the commands its server receives, and the server's writer.
+    async fn create_scripted_connection() -> (
+        MultiplexedConnection,
+        tokio::sync::mpsc::Receiver<Value>,
+        ServerWriter,
+    ) {
+        create_scripted_connection_with_config(AsyncConnectionConfig::new()).await
+    }
+
+    async fn create_scripted_connection_with_config(
+        config: AsyncConnectionConfig,
+    ) -> (
+        MultiplexedConnection,
+        tokio::sync::mpsc::Receiver<Value>,
+        ServerWriter,
+    ) {
+        use futures_util::StreamExt;
+        use tokio_util::codec::FramedRead;
+
+        let (client_half, server_half) = tokio::io::duplex(4096);
+        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::channel(10);
+        let (server_read, server_write) = tokio::io::split(server_half);
+
+        tokio::spawn(async move {
+            let mut reader = FramedRead::new(server_read, ValueCodec::default());
+            while let Some(Ok(cmd)) = reader.next().await {
+                let _ = cmd_tx.send(cmd).await;
+            }
+        });
+
+        let config = config
+            .set_response_timeout(None)
+            .set_connection_timeout(None);
+        let (conn, driver) =
+            MultiplexedConnection::new_with_config(&mock_conn_info(), client_half, config)
+                .await
+                .unwrap();
+        tokio::spawn(driver);
+
+        (conn, cmd_rx, server_write)
+    }
+
+    fn blob(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[tokio::test]
+    async fn test_streamed_reply_is_read_in_chunks() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut conn, mut cmd_rx, mut server_write) = create_scripted_connection().await;
+        let payload = blob(100_000);
+
+        let server = tokio::spawn({
+            let payload = payload.clone();
+            async move {
+                cmd_rx.recv().await.unwrap();
+                server_write.write_all(b"$100000\r\n").await.unwrap();
+                // The reply is larger than the socket buffer, so it's only written as it's read.
+                server_write.write_all(&payload).await.unwrap();
+                server_write.write_all(b"\r\n").await.unwrap();
+                cmd_rx.recv().await.unwrap();
+                server_write.write_all(b"+PONG\r\n").await.unwrap();
+            }
+        });
+
+        let mut reader = conn
+            .send_command_for_reader(cmd("GET").arg("key"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reader.len(), payload.len());
+        let mut received = Vec::new();
+        reader.read_to_end(&mut received).await.unwrap();
+        assert_eq!(received, payload);
+
+        assert_eq!(
+            conn.send_packed_command(&cmd("PING")).await.unwrap(),
+            Value::SimpleString("PONG".to_string())
+        );
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_streamed_reply_returns_other_replies() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut conn, mut cmd_rx, mut server_write) = create_scripted_connection().await;
+
+        tokio::spawn(async move {
+            for reply in [&b"$-1\r\n"[..], b"-ERR no\r\n", b":1\r\n"] {
+                cmd_rx.recv().await.unwrap();
+                server_write.write_all(reply).await.unwrap();
+            }
+        });
+
+        let get = cmd("GET").arg("key").clone();
+        assert!(conn.send_command_for_reader(&get).await.unwrap().is_none());
+        let err = conn.send_command_for_reader(&get).await.unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Server(crate::ServerErrorKind::ResponseError)
+        );
+        let err = conn.send_command_for_reader(&get).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedReturnType);
+    }
+
+    #[tokio::test]
+    async fn test_reader_holds_a_permit_until_the_reply_is_read() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let config = AsyncConnectionConfig::new().set_concurrency_limit(1);
+        let (mut conn, mut cmd_rx, mut server_write) =
+            create_scripted_connection_with_config(config).await;
+
+        let get = cmd("GET").arg("key").clone();
+        let (reader, _) = tokio::join!(conn.send_command_for_reader(&get), async {
+            cmd_rx.recv().await.unwrap();
+            server_write.write_all(b"$5\r\nhel").await.unwrap();
+        });
+        let mut reader = reader.unwrap().unwrap();
+        let ping = tokio::spawn({
+            let mut conn = conn.clone();
+            async move { conn.send_packed_command(&cmd("PING")).await }
+        });
+        // The rest of the reply wasn't received, so the other request waits for the reader.
+        let blocked = tokio::time::timeout(Duration::from_millis(100), cmd_rx.recv()).await;
+        assert!(blocked.is_err());
+
+        server_write.write_all(b"lo\r\n").await.unwrap();
+        let mut received = Vec::new();
+        reader.read_to_end(&mut received).await.unwrap();
+        assert_eq!(received, b"hello");
+
+        cmd_rx.recv().await.unwrap();
+        server_write.write_all(b"+PONG\r\n").await.unwrap();
+        assert_eq!(
+            ping.await.unwrap().unwrap(),
+            Value::SimpleString("PONG".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dropped_reader_discards_the_reply() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut conn, mut cmd_rx, mut server_write) = create_scripted_connection().await;
+
+        tokio::spawn(async move {
+            cmd_rx.recv().await.unwrap();
+            server_write.write_all(b"$100000\r\n").await.unwrap();
+            server_write.write_all(&blob(100_000)).await.unwrap();
+            server_write.write_all(b"\r\n").await.unwrap();
+            cmd_rx.recv().await.unwrap();
+            server_write.write_all(b"+PONG\r\n").await.unwrap();
+        });
+
+        let reader = conn
+            .send_command_for_reader(cmd("GET").arg("key"))
+            .await
+            .unwrap();
+        drop(reader);
+
+        assert_eq!(
+            conn.send_packed_command(&cmd("PING")).await.unwrap(),
+            Value::SimpleString("PONG".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streamed_argument_is_sent() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut conn, mut cmd_rx, mut server_write) = create_scripted_connection().await;
+        let payload = blob(200_000);
+
+        let server = tokio::spawn(async move {
+            let received = cmd_rx.recv().await.unwrap();
+            server_write.write_all(b"+OK\r\n").await.unwrap();
+            let ping = cmd_rx.recv().await.unwrap();
+            server_write.write_all(b"+PONG\r\n").await.unwrap();
+            (received, ping)
+        });
+
+        let reader = std::io::Cursor::new(payload.clone());
+        let result = conn
+            .send_command_with_reader(cmd("SET").arg("key"), payload.len() as u64, reader)
+            .await;
+        assert_eq!(result, Ok(Value::Okay));
+        assert_eq!(
+            conn.send_packed_command(&cmd("PING")).await.unwrap(),
+            Value::SimpleString("PONG".to_string())
+        );
+
+        let (received, ping) = server.await.unwrap();
+        assert_eq!(
+            received,
+            Value::Array(vec![
+                Value::BulkString(b"SET".to_vec()),
+                Value::BulkString(b"key".to_vec()),
+                Value::BulkString(payload),
+            ])
+        );
+        assert_eq!(
+            ping,
+            Value::Array(vec![Value::BulkString(b"PING".to_vec())])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_short_streamed_argument_closes_the_connection() {
+        let (mut conn, _cmd_rx, _server_write) = create_scripted_connection().await;
+
+        let reader = std::io::Cursor::new(b"short".to_vec());
+        let err = conn
+            .send_command_with_reader(cmd("SET").arg("key"), 10, reader)
+            .await
+            .unwrap_err();
+        assert!(err.is_io_error());
+
+        let err = conn.send_packed_command(&cmd("PING")).await.unwrap_err();
+        assert!(err.is_unrecoverable_error());
+    }
 }
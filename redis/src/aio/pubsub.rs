@@ -484,6 +484,14 @@ impl PubSub {
         self.sink.ping_message(message).await
     }
 
+    /// Sends a command that isn't a (un)subscription, e.g. CONFIG SET before subscribing.
+    pub(crate) async fn req_command(&mut self, cmd: &crate::Cmd) -> RedisResult<Value> {
+        self.sink
+            .send_recv(cmd.get_packed_command())
+            .await?
+            .extract_error()
+    }
+
     /// Returns [`Stream`] of [`Msg`]s from this [`PubSub`]s subscriptions.
     ///
     /// The message itself is still generic and can be converted into an appropriate type through
@@ -1,10 +1,12 @@
-//! Defines types to use with Bloom filter commands.
+//! Defines types to use with Bloom filter and Cuckoo filter commands.
 
 use crate::errors::invalid_type_error;
 use crate::{
-    Commands, Connection, FromRedisValue, ParsingError, RedisResult, RedisWrite, ToRedisArgs,
+    Cmd, Connection, FromRedisValue, ParsingError, RedisResult, RedisWrite, ToRedisArgs,
     ToSingleRedisArg, Value,
 };
+use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::ops::Deref;
 
 /// Specific type of information to query for Bloom filters
@@ -227,7 +229,7 @@ impl ToRedisArgs for BloomFilterInsertOptions {
     }
 }
 
-/// Single chunk of a Bloom filter scan dump
+/// Single chunk of a Bloom filter or Cuckoo filter scan dump
 #[derive(Debug, Clone, PartialEq)]
 pub struct BloomFilterDumpChunk {
     /// The iterator associated to the [`data`](Self::data)
@@ -238,7 +240,8 @@ pub struct BloomFilterDumpChunk {
 
 impl FromRedisValue for BloomFilterDumpChunk {
     fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
-        // `v` should be an array holding an Int (iterator) followed by BulkString (data).
+        // `v` should be an array holding an Int (iterator) followed by BulkString (data). The
+        // final chunk of a Cuckoo filter holds a `0` iterator and `Nil` instead of data.
 
         let Value::Array(items) = v else {
             invalid_type_error!(v, "expected array response");
@@ -253,9 +256,10 @@ impl FromRedisValue for BloomFilterDumpChunk {
         };
 
         // Extract the data
-        let item = items_iter.next();
-        let Some(Value::BulkString(data)) = item else {
-            invalid_type_error!(item, "expected second element to be a Bulk string");
+        let data = match items_iter.next().map(Value::into_bulk_string) {
+            Some(Ok(data)) => data,
+            Some(Err(Value::Nil)) => Vec::new(),
+            item => invalid_type_error!(item, "expected second element to be a Bulk string"),
         };
 
         // `items_iter` should be empty now, so we guard against upstream additions
@@ -284,27 +288,59 @@ impl FromRedisValue for BloomFilterDumpChunk {
 /// # Ok(())
 /// # }
 /// ```
-pub struct BloomFilterDumpIterator<'a> {
+pub type BloomFilterDumpIterator<'a> = FilterDumpIterator<'a, BloomFilter>;
+
+/// A filter whose scan dump can be iterated over with [`FilterDumpIterator`]
+pub trait ScanDumpFilter {
+    /// Builds the command returning the chunk following `iterator`
+    fn scandump(key: &str, iterator: i64) -> Cmd;
+}
+
+/// Bloom filters, dumped with `BF.SCANDUMP`
+#[derive(Debug)]
+pub enum BloomFilter {}
+
+impl ScanDumpFilter for BloomFilter {
+    fn scandump(key: &str, iterator: i64) -> Cmd {
+        Cmd::bf_scandump(key, iterator)
+    }
+}
+
+/// Cuckoo filters, dumped with `CF.SCANDUMP`
+#[derive(Debug)]
+pub enum CuckooFilter {}
+
+impl ScanDumpFilter for CuckooFilter {
+    fn scandump(key: &str, iterator: i64) -> Cmd {
+        Cmd::cf_scandump(key, iterator)
+    }
+}
+
+/// An iterator performing the scan dump of a filter, see [`BloomFilterDumpIterator`] and
+/// [`CuckooFilterDumpIterator`]
+pub struct FilterDumpIterator<'a, F> {
     con: &'a mut Connection,
     key: &'a str,
     iterator: i64,
     /// `true`, iff the iterator cannot produce more elements.
     dry: bool,
+    filter: PhantomData<F>,
 }
 
-impl<'a> BloomFilterDumpIterator<'a> {
+impl<'a, F: ScanDumpFilter> FilterDumpIterator<'a, F> {
     /// Create a new iterator for the given key
     pub fn new(con: &'a mut Connection, key: &'a str) -> Self {
-        BloomFilterDumpIterator {
+        FilterDumpIterator {
             con,
             key,
             iterator: 0,
             dry: false,
+            filter: PhantomData,
         }
     }
 }
 
-impl Iterator for BloomFilterDumpIterator<'_> {
+impl<F: ScanDumpFilter> Iterator for FilterDumpIterator<'_, F> {
     type Item = RedisResult<BloomFilterDumpChunk>;
 
     #[inline]
@@ -312,7 +348,7 @@ impl Iterator for BloomFilterDumpIterator<'_> {
         if self.dry {
             return None;
         }
-        let dump_result: Self::Item = self.con.bf_scandump(self.key, self.iterator);
+        let dump_result: Self::Item = F::scandump(self.key, self.iterator).query(self.con);
         match dump_result {
             Ok(ref dump) => {
                 self.iterator = dump.iterator;
@@ -331,11 +367,436 @@ impl Iterator for BloomFilterDumpIterator<'_> {
     }
 }
 
+/// Options for creating a Cuckoo filter
+#[derive(Default, Debug)]
+#[non_exhaustive]
+pub struct CuckooFilterReserveOptions {
+    bucket_size: Option<usize>,
+    max_iterations: Option<usize>,
+    expansion: Option<usize>,
+}
+
+impl CuckooFilterReserveOptions {
+    /// Specifies the number of items in each bucket.
+    pub fn bucket_size(mut self, bucket_size: usize) -> Self {
+        self.bucket_size = Some(bucket_size);
+        self
+    }
+
+    /// Specifies the number of attempts to swap items between buckets before declaring the
+    /// filter as full and creating an additional filter.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Specifies the expansion rate of additional filters. `0` prevents additional filters.
+    pub fn expansion(mut self, expansion: usize) -> Self {
+        self.expansion = Some(expansion);
+        self
+    }
+}
+
+impl ToRedisArgs for CuckooFilterReserveOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref bucket_size) = self.bucket_size {
+            out.write_arg(b"BUCKETSIZE");
+            bucket_size.write_redis_args(out);
+        }
+
+        if let Some(ref max_iterations) = self.max_iterations {
+            out.write_arg(b"MAXITERATIONS");
+            max_iterations.write_redis_args(out);
+        }
+
+        if let Some(ref expansion) = self.expansion {
+            out.write_arg(b"EXPANSION");
+            expansion.write_redis_args(out);
+        }
+    }
+}
+
+/// Options for inserting items to a Cuckoo filter
+#[derive(Default, Debug)]
+#[non_exhaustive]
+pub struct CuckooFilterInsertOptions {
+    create: Option<bool>,
+    capacity: Option<usize>,
+}
+
+impl CuckooFilterInsertOptions {
+    /// Indicates that the filter should not be created if it does not already exist.
+    pub fn nocreate(mut self) -> Self {
+        self.create = Some(false);
+        self
+    }
+
+    /// Specifies the desired capacity for the filter to be created.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+}
+
+impl ToRedisArgs for CuckooFilterInsertOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref capacity) = self.capacity {
+            out.write_arg(b"CAPACITY");
+            capacity.write_redis_args(out);
+        }
+
+        if Some(false) == self.create {
+            out.write_arg(b"NOCREATE");
+        }
+    }
+}
+
+/// Outcome of inserting a single item into a Cuckoo filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CuckooFilterInsertResult {
+    /// The item was added.
+    Added,
+    /// The item may already exist, so it was not added.
+    ///
+    /// Only reported by the `NX` variants of the insert commands.
+    Exists,
+    /// The filter is full, so the item was not added.
+    Full,
+}
+
+impl FromRedisValue for CuckooFilterInsertResult {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::Int(1) | Value::Boolean(true) => Ok(CuckooFilterInsertResult::Added),
+            Value::Int(0) | Value::Boolean(false) => Ok(CuckooFilterInsertResult::Exists),
+            Value::Int(-1) => Ok(CuckooFilterInsertResult::Full),
+            _ => invalid_type_error!(v, "expected 1, 0 or -1 as insert result"),
+        }
+    }
+}
+
+/// Information about a Cuckoo filter, as returned by `CF.INFO`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CuckooFilterInfo {
+    /// Number of bytes allocated for the filter
+    pub size: u64,
+    /// Number of buckets
+    pub number_of_buckets: u64,
+    /// Number of sub-filters
+    pub number_of_filters: u64,
+    /// Number of items inserted into the filter
+    pub number_of_items_inserted: u64,
+    /// Number of items deleted from the filter
+    pub number_of_items_deleted: u64,
+    /// Number of items in each bucket
+    pub bucket_size: u64,
+    /// Expansion rate of additional filters
+    pub expansion_rate: u64,
+    /// Maximal number of swap attempts before creating an additional filter
+    pub max_iterations: u64,
+}
+
+impl FromRedisValue for CuckooFilterInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields = InfoFields::new(v)?;
+
+        Ok(CuckooFilterInfo {
+            size: fields.take("Size")?,
+            number_of_buckets: fields.take("Number of buckets")?,
+            number_of_filters: fields.take("Number of filters")?,
+            number_of_items_inserted: fields.take("Number of items inserted")?,
+            number_of_items_deleted: fields.take("Number of items deleted")?,
+            bucket_size: fields.take("Bucket size")?,
+            expansion_rate: fields.take("Expansion rate")?,
+            max_iterations: fields.take("Max iterations")?,
+        })
+    }
+}
+
+/// Fields of an `INFO` response of the probabilistic data structures
+///
+/// RESP2 responds with an array of name-value pairs, while RESP3 responds with a map. Both are
+/// converted into a map to look the fields up by name.
+struct InfoFields(HashMap<String, Value>);
+
+impl InfoFields {
+    fn new(v: Value) -> Result<Self, ParsingError> {
+        Ok(InfoFields(crate::from_redis_value(v)?))
+    }
+
+    fn take<T: FromRedisValue>(&mut self, name: &str) -> Result<T, ParsingError> {
+        match self.0.remove(name) {
+            Some(value) => T::from_redis_value(value),
+            None => invalid_type_error!(format!("missing field `{name}`")),
+        }
+    }
+}
+
+/// Single chunk of a Cuckoo filter scan dump
+pub type CuckooFilterDumpChunk = BloomFilterDumpChunk;
+
+/// An iterator performing a Cuckoo filter scan dump
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn dump() -> redis::RedisResult<()> {
+/// # use redis::Commands;
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let mut con = client.get_connection()?;
+///
+/// // Copy the Cuckoo filter at key `foo` to key `bar`
+/// let full_dump = redis::bloom::CuckooFilterDumpIterator::new(&mut con, "foo")
+///     .collect::<redis::RedisResult<Vec<_>>>()?;
+/// for chunk in full_dump {
+///     let _: () = con.cf_loadchunk("bar", chunk)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub type CuckooFilterDumpIterator<'a> = FilterDumpIterator<'a, CuckooFilter>;
+
+/// Information about a Count-Min Sketch, as returned by `CMS.INFO`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CountMinSketchInfo {
+    /// Number of counters in each array
+    pub width: u64,
+    /// Number of counter arrays
+    pub depth: u64,
+    /// Total count of all items
+    pub count: u64,
+}
+
+impl FromRedisValue for CountMinSketchInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields = InfoFields::new(v)?;
+
+        Ok(CountMinSketchInfo {
+            width: fields.take("width")?,
+            depth: fields.take("depth")?,
+            count: fields.take("count")?,
+        })
+    }
+}
+
+/// Options for merging Count-Min Sketches
+#[derive(Default, Debug)]
+#[non_exhaustive]
+pub struct CountMinSketchMergeOptions {
+    weights: Vec<u64>,
+}
+
+impl CountMinSketchMergeOptions {
+    /// Specifies the multiplier of each source sketch, in the order of the sources.
+    pub fn weights<I: IntoIterator<Item = u64>>(mut self, weights: I) -> Self {
+        self.weights = weights.into_iter().collect();
+        self
+    }
+}
+
+impl ToRedisArgs for CountMinSketchMergeOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if !self.weights.is_empty() {
+            out.write_arg(b"WEIGHTS");
+            for weight in &self.weights {
+                weight.write_redis_args(out);
+            }
+        }
+    }
+}
+
+/// Dimensions of a Top-K sketch
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TopKReserveOptions {
+    width: usize,
+    depth: usize,
+    decay: f64,
+}
+
+impl TopKReserveOptions {
+    /// Specifies the number of counters in each array, the number of arrays and the probability
+    /// of reducing a counter in an occupied bucket.
+    ///
+    /// The server requires all three to be set together.
+    pub fn new(width: usize, depth: usize, decay: f64) -> Self {
+        TopKReserveOptions {
+            width,
+            depth,
+            decay,
+        }
+    }
+}
+
+impl ToRedisArgs for TopKReserveOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.width.write_redis_args(out);
+        self.depth.write_redis_args(out);
+        self.decay.write_redis_args(out);
+    }
+}
+
+/// Information about a Top-K sketch, as returned by `TOPK.INFO`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TopKInfo {
+    /// Number of top items to keep
+    pub k: u64,
+    /// Number of counters in each array
+    pub width: u64,
+    /// Number of counter arrays
+    pub depth: u64,
+    /// Probability of reducing a counter in an occupied bucket
+    pub decay: f64,
+}
+
+impl FromRedisValue for TopKInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields = InfoFields::new(v)?;
+
+        Ok(TopKInfo {
+            k: fields.take("k")?,
+            width: fields.take("width")?,
+            depth: fields.take("depth")?,
+            decay: fields.take("decay")?,
+        })
+    }
+}
+
+/// Options for creating a T-Digest sketch
+#[derive(Default, Debug)]
+#[non_exhaustive]
+pub struct TDigestCreateOptions {
+    compression: Option<usize>,
+}
+
+impl TDigestCreateOptions {
+    /// Specifies the tradeoff between accuracy and memory consumption. Defaults to 100.
+    pub fn compression(mut self, compression: usize) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+}
+
+impl ToRedisArgs for TDigestCreateOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref compression) = self.compression {
+            out.write_arg(b"COMPRESSION");
+            compression.write_redis_args(out);
+        }
+    }
+}
+
+/// Options for merging T-Digest sketches
+#[derive(Default, Debug)]
+#[non_exhaustive]
+pub struct TDigestMergeOptions {
+    compression: Option<usize>,
+    override_destination: bool,
+}
+
+impl TDigestMergeOptions {
+    /// Specifies the compression of the destination sketch. Defaults to the largest compression
+    /// of the sources, or of the existing destination.
+    pub fn compression(mut self, compression: usize) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Indicates that an existing destination sketch should be replaced instead of being merged
+    /// with the sources.
+    pub fn override_destination(mut self) -> Self {
+        self.override_destination = true;
+        self
+    }
+}
+
+impl ToRedisArgs for TDigestMergeOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref compression) = self.compression {
+            out.write_arg(b"COMPRESSION");
+            compression.write_redis_args(out);
+        }
+
+        if self.override_destination {
+            out.write_arg(b"OVERRIDE");
+        }
+    }
+}
+
+/// Information about a T-Digest sketch, as returned by `TDIGEST.INFO`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TDigestInfo {
+    /// Tradeoff between accuracy and memory consumption
+    pub compression: u64,
+    /// Size of the buffer for merged observations
+    pub capacity: u64,
+    /// Number of merged observations
+    pub merged_nodes: u64,
+    /// Number of buffered, not yet merged observations
+    pub unmerged_nodes: u64,
+    /// Weight of the merged observations
+    pub merged_weight: f64,
+    /// Weight of the buffered, not yet merged observations
+    pub unmerged_weight: f64,
+    /// Number of observations added to the sketch
+    pub observations: u64,
+    /// Number of times the buffered observations were merged
+    pub total_compressions: u64,
+    /// Number of bytes allocated for the sketch
+    pub memory_usage: u64,
+}
+
+impl FromRedisValue for TDigestInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields = InfoFields::new(v)?;
+
+        Ok(TDigestInfo {
+            compression: fields.take("Compression")?,
+            capacity: fields.take("Capacity")?,
+            merged_nodes: fields.take("Merged nodes")?,
+            unmerged_nodes: fields.take("Unmerged nodes")?,
+            merged_weight: fields.take("Merged weight")?,
+            unmerged_weight: fields.take("Unmerged weight")?,
+            observations: fields.take("Observations")?,
+            total_compressions: fields.take("Total compressions")?,
+            memory_usage: fields.take("Memory usage")?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BloomFilterDumpChunk, BloomFilterInfoTypeResponse};
-    use crate::FromRedisValue;
+    use super::{
+        BloomFilterDumpChunk, BloomFilterInfoTypeResponse, CountMinSketchInfo,
+        CountMinSketchMergeOptions, CuckooFilterDumpChunk, CuckooFilterInfo,
+        CuckooFilterInsertOptions, CuckooFilterInsertResult, CuckooFilterReserveOptions,
+        TDigestInfo, TDigestMergeOptions, TopKInfo, TopKReserveOptions,
+    };
     use crate::types::Value;
+    use crate::{FromRedisValue, ToRedisArgs};
 
     /// Tries to assure that [`BloomFilterInfoTypeResponse`] conversion from wrong type gives a useful error
     #[test]
@@ -624,4 +1085,214 @@ mod tests {
         };
         assert_eq!(chunk, expected);
     }
+
+    /// Tries to assure that the Cuckoo filter options are written in the expected order
+    #[test]
+    fn cuckoo_options_to_args() {
+        let reserve = CuckooFilterReserveOptions::default()
+            .bucket_size(4)
+            .max_iterations(20)
+            .expansion(2);
+        assert_eq!(
+            reserve.to_redis_args(),
+            vec![
+                b"BUCKETSIZE".to_vec(),
+                b"4".to_vec(),
+                b"MAXITERATIONS".to_vec(),
+                b"20".to_vec(),
+                b"EXPANSION".to_vec(),
+                b"2".to_vec(),
+            ]
+        );
+
+        let insert = CuckooFilterInsertOptions::default()
+            .nocreate()
+            .capacity(100);
+        assert_eq!(
+            insert.to_redis_args(),
+            vec![b"CAPACITY".to_vec(), b"100".to_vec(), b"NOCREATE".to_vec()]
+        );
+    }
+
+    /// Tries to assure that [`CuckooFilterInsertResult`] conversion covers all insert outcomes
+    #[test]
+    fn cuckoo_insert_result_from_value() {
+        let results: Vec<CuckooFilterInsertResult> = crate::from_redis_value(Value::Array(vec![
+            Value::Int(1),
+            Value::Int(0),
+            Value::Int(-1),
+        ]))
+        .unwrap();
+        assert_eq!(
+            results,
+            vec![
+                CuckooFilterInsertResult::Added,
+                CuckooFilterInsertResult::Exists,
+                CuckooFilterInsertResult::Full,
+            ]
+        );
+        assert!(CuckooFilterInsertResult::from_redis_value(Value::Int(2)).is_err());
+    }
+
+    /// Tries to assure that [`CuckooFilterInfo`] conversion works for RESP2 and RESP3
+    #[test]
+    fn cuckoo_info_from_value() {
+        let fields = [
+            ("Size", 1080),
+            ("Number of buckets", 512),
+            ("Number of filters", 1),
+            ("Number of items inserted", 3),
+            ("Number of items deleted", 1),
+            ("Bucket size", 2),
+            ("Expansion rate", 1),
+            ("Max iterations", 20),
+        ];
+        let expected = CuckooFilterInfo {
+            size: 1080,
+            number_of_buckets: 512,
+            number_of_filters: 1,
+            number_of_items_inserted: 3,
+            number_of_items_deleted: 1,
+            bucket_size: 2,
+            expansion_rate: 1,
+            max_iterations: 20,
+        };
+
+        let resp2 = Value::Array(
+            fields
+                .iter()
+                .flat_map(|(name, value)| {
+                    [Value::SimpleString(name.to_string()), Value::Int(*value)]
+                })
+                .collect(),
+        );
+        assert_eq!(CuckooFilterInfo::from_redis_value(resp2).unwrap(), expected);
+
+        let resp3 = Value::Map(
+            fields
+                .iter()
+                .map(|(name, value)| (Value::SimpleString(name.to_string()), Value::Int(*value)))
+                .collect(),
+        );
+        assert_eq!(CuckooFilterInfo::from_redis_value(resp3).unwrap(), expected);
+
+        let err = CuckooFilterInfo::from_redis_value(Value::Map(vec![])).unwrap_err();
+        assert!(err.to_string().contains("Size"));
+    }
+
+    /// Tries to assure that [`CuckooFilterDumpChunk`] conversion accepts the final `Nil` chunk
+    #[test]
+    fn cuckoo_dump_chunk_from_value() {
+        let chunk = CuckooFilterDumpChunk::from_redis_value(Value::Array(vec![
+            Value::Int(42),
+            Value::BulkString(b"foo".to_vec()),
+        ]))
+        .unwrap();
+        assert_eq!(chunk.iterator, 42);
+        assert_eq!(chunk.data, b"foo".to_vec());
+
+        let chunk =
+            CuckooFilterDumpChunk::from_redis_value(Value::Array(vec![Value::Int(0), Value::Nil]))
+                .unwrap();
+        assert_eq!(chunk.iterator, 0);
+        assert!(chunk.data.is_empty());
+
+        let err = CuckooFilterDumpChunk::from_redis_value(Value::Array(vec![
+            Value::Int(42),
+            Value::Okay,
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("expected second"));
+    }
+
+    /// Tries to assure that the sketch options are written as expected
+    #[test]
+    fn sketch_options_to_args() {
+        let merge = CountMinSketchMergeOptions::default().weights([1, 3]);
+        assert_eq!(
+            merge.to_redis_args(),
+            vec![b"WEIGHTS".to_vec(), b"1".to_vec(), b"3".to_vec()]
+        );
+        assert!(
+            CountMinSketchMergeOptions::default()
+                .to_redis_args()
+                .is_empty()
+        );
+
+        assert_eq!(
+            TopKReserveOptions::new(8, 7, 0.9).to_redis_args(),
+            vec![b"8".to_vec(), b"7".to_vec(), b"0.9".to_vec()]
+        );
+
+        let merge = TDigestMergeOptions::default()
+            .override_destination()
+            .compression(200);
+        assert_eq!(
+            merge.to_redis_args(),
+            vec![
+                b"COMPRESSION".to_vec(),
+                b"200".to_vec(),
+                b"OVERRIDE".to_vec()
+            ]
+        );
+    }
+
+    /// Tries to assure that the sketch info conversions work for RESP2 and RESP3
+    #[test]
+    fn sketch_infos_from_value() {
+        let resp2 = Value::Array(vec![
+            Value::SimpleString("width".to_string()),
+            Value::Int(2000),
+            Value::SimpleString("depth".to_string()),
+            Value::Int(7),
+            Value::SimpleString("count".to_string()),
+            Value::Int(42),
+        ]);
+        assert_eq!(
+            CountMinSketchInfo::from_redis_value(resp2).unwrap(),
+            CountMinSketchInfo {
+                width: 2000,
+                depth: 7,
+                count: 42
+            }
+        );
+
+        let resp3 = Value::Map(vec![
+            (Value::SimpleString("k".to_string()), Value::Int(10)),
+            (Value::SimpleString("width".to_string()), Value::Int(8)),
+            (Value::SimpleString("depth".to_string()), Value::Int(7)),
+            (Value::SimpleString("decay".to_string()), Value::Double(0.9)),
+        ]);
+        assert_eq!(
+            TopKInfo::from_redis_value(resp3).unwrap(),
+            TopKInfo {
+                k: 10,
+                width: 8,
+                depth: 7,
+                decay: 0.9
+            }
+        );
+
+        let resp2 = Value::Array(
+            [
+                ("Compression", Value::Int(100)),
+                ("Capacity", Value::Int(610)),
+                ("Merged nodes", Value::Int(3)),
+                ("Unmerged nodes", Value::Int(2)),
+                ("Merged weight", Value::BulkString(b"3".to_vec())),
+                ("Unmerged weight", Value::BulkString(b"2".to_vec())),
+                ("Observations", Value::Int(5)),
+                ("Total compressions", Value::Int(1)),
+                ("Memory usage", Value::Int(9768)),
+            ]
+            .into_iter()
+            .flat_map(|(name, value)| [Value::SimpleString(name.to_string()), value])
+            .collect(),
+        );
+        let info = TDigestInfo::from_redis_value(resp2).unwrap();
+        assert_eq!(info.compression, 100);
+        assert_eq!(info.merged_weight, 3.);
+        assert_eq!(info.observations, 5);
+        assert_eq!(info.memory_usage, 9768);
+    }
 }
@@ -303,15 +303,15 @@ impl CacheManager {
             commands.push(CachingResult::Item(cacheable_command));
         }
 
-        let pipeline_response_counts = if transaction_mode {
+        if transaction_mode {
             packed_pipeline.atomic();
-            (packed_pipeline.commands.len() + 1, 1)
-        } else {
-            (0, packed_pipeline.commands.len())
-        };
+        }
+        packed_pipeline.durability = requested_pipeline.durability;
+        let pipeline_response_counts = packed_pipeline.response_counts();
         let cp = CacheablePipeline {
             commands,
             transaction_mode,
+            durable: requested_pipeline.durability.is_some(),
         };
         (cp, packed_pipeline, pipeline_response_counts)
     }
@@ -9,6 +9,7 @@ use super::{CacheManager, CacheMode, CachingResult};
 pub(crate) struct CacheablePipeline<'a> {
     pub(crate) commands: Vec<CachingResult<'a>>,
     pub(crate) transaction_mode: bool,
+    pub(crate) durable: bool,
 }
 
 impl CacheablePipeline<'_> {
@@ -17,12 +18,15 @@ impl CacheablePipeline<'_> {
         cache_manager: &CacheManager,
         result: Value,
     ) -> RedisResult<Vec<Value>> {
+        let mut replies: Vec<Value> = crate::types::from_redis_value(result)?;
+        // The WAIT/WAITAOF reply comes last, it is handed back as-is after the results.
+        let ack = if self.durable { replies.pop() } else { None };
         let replies: Vec<Value> = if self.transaction_mode {
             // With transaction mode, result is contained in an array, so it requires special care.
-            let (replies,): (Vec<Value>,) = crate::types::from_redis_value(result)?;
+            let (replies,): (Vec<Value>,) = crate::types::from_redis_value(Value::Array(replies))?;
             replies
         } else {
-            crate::types::from_redis_value(result)?
+            replies
         };
         let mut replies = replies.into_iter();
 
@@ -40,11 +44,13 @@ impl CacheablePipeline<'_> {
                 }
             }
         }
-        if self.transaction_mode {
-            Ok(vec![Value::Array(response)])
+        let mut response = if self.transaction_mode {
+            vec![Value::Array(response)]
         } else {
-            Ok(response)
-        }
+            response
+        };
+        response.extend(ack);
+        Ok(response)
     }
 }
 
@@ -197,6 +197,8 @@ pub struct AsyncConnectionConfig {
     pub(crate) concurrency_limit: Option<usize>,
     /// Flush threshold for the outbound write buffer; see [`AsyncConnectionConfig::set_write_backpressure_boundary`].
     pub(crate) write_backpressure_boundary: Option<usize>,
+    /// Minimum length of the replies kept in the read buffer; see [`AsyncConnectionConfig::set_bulk_bytes_threshold`].
+    pub(crate) bulk_bytes_threshold: Option<usize>,
     /// Optional credentials provider for dynamic authentication (e.g., token-based authentication)
     #[cfg(feature = "token-based-authentication")]
     pub(crate) credentials_provider: Option<std::sync::Arc<dyn StreamingCredentialsProvider>>,
@@ -215,6 +217,7 @@ impl Default for AsyncConnectionConfig {
             pipeline_buffer_size: None,
             concurrency_limit: None,
             write_backpressure_boundary: None,
+            bulk_bytes_threshold: None,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider: None,
         }
@@ -374,6 +377,24 @@ impl AsyncConnectionConfig {
         self
     }
 
+    /// Sets the minimum length of the bulk string replies that are kept in the read buffer
+    /// instead of being copied out of it.
+    ///
+    /// A top-level bulk string reply of at least `threshold` bytes, like the reply of a GET,
+    /// is returned as a [`Value::BulkBytes`](crate::Value::BulkBytes) that shares the read
+    /// buffer of the connection, and converts into [`bytes::Bytes`] without copying. The buffer
+    /// is also grown once to fit the whole reply, instead of repeatedly as the reply arrives.
+    /// Bulk strings nested in other replies are always copied.
+    ///
+    /// Keeping such a value alive keeps the part of the read buffer it was read into alive, so
+    /// this is meant for large values, e.g. a threshold of 64 KiB.
+    ///
+    /// When left unset, all bulk strings are returned as [`Value::BulkString`](crate::Value::BulkString).
+    pub fn set_bulk_bytes_threshold(mut self, threshold: usize) -> Self {
+        self.bulk_bytes_threshold = Some(threshold);
+        self
+    }
+
     /// Sets a credentials provider for dynamic authentication (e.g., token-based authentication).
     ///
     /// This is useful for authentication mechanisms that require periodic credential refresh,
@@ -731,4 +752,13 @@ mod test {
         let config = AsyncConnectionConfig::new().set_write_backpressure_boundary(16 * 1024 * 1024);
         assert_eq!(config.write_backpressure_boundary, Some(16 * 1024 * 1024));
     }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn test_async_connection_config_bulk_bytes_threshold() {
+        let config = AsyncConnectionConfig::new();
+        assert_eq!(config.bulk_bytes_threshold, None);
+        let config = config.set_bulk_bytes_threshold(64 * 1024);
+        assert_eq!(config.bulk_bytes_threshold, Some(64 * 1024));
+    }
 }
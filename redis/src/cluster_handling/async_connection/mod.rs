@@ -118,6 +118,7 @@ use crate::{
         read_routing::ReadRoutingStrategy,
         routing::{
             MultipleNodeRoutingInfo, Redirect, ResponsePolicy, RoutingInfo, SingleNodeRoutingInfo,
+            check_sort_patterns,
         },
         slot_cmd,
         slot_map::{SlotMap, SlotRange},
@@ -148,6 +149,8 @@ struct ClientSideState {
     protocol: ProtocolVersion,
     _task_handle: HandleContainer,
     overall_response_timeout: Option<Duration>,
+    // The shorter of the node connections' and the overall response timeouts.
+    response_timeout: Option<Duration>,
     runtime: Runtime,
     #[cfg(feature = "cache-aio")]
     cache_manager: Option<CacheManager>,
@@ -192,6 +195,10 @@ where
     ) -> (ClusterConnection<C>, oneshot::Receiver<RedisResult<()>>) {
         let protocol = cluster_params.protocol.unwrap_or_default();
         let overall_response_timeout = cluster_params.overall_response_timeout;
+        let response_timeout = [cluster_params.response_timeout, overall_response_timeout]
+            .into_iter()
+            .flatten()
+            .min();
         #[cfg(feature = "cache-aio")]
         let cache_manager = cluster_params.cache_manager.clone();
         let runtime = Runtime::locate();
@@ -251,6 +258,7 @@ where
                     protocol,
                     _task_handle,
                     overall_response_timeout,
+                    response_timeout,
                     runtime,
                     #[cfg(feature = "cache-aio")]
                     cache_manager,
@@ -410,6 +418,19 @@ where
         Ok(())
     }
 
+    /// Subscribes to channel pattern(s) on the nodes of `routing`, for node-local channels.
+    pub(crate) async fn psubscribe_on(
+        &mut self,
+        channel_pattern: impl ToRedisArgs,
+        routing: RoutingInfo,
+    ) -> RedisResult<()> {
+        check_resp3!(self.state.protocol);
+        let mut cmd = cmd("PSUBSCRIBE");
+        cmd.arg(channel_pattern);
+        self.route_command(cmd, routing).await?;
+        Ok(())
+    }
+
     /// Unsubscribes from channel pattern(s).
     ///
     /// This method is only available when the connection is using RESP3 protocol, and will return an error otherwise.
@@ -846,11 +867,13 @@ where
                         let Some(tracker) = &self.subscription_tracker else {
                             return;
                         };
+                        // A transaction's results are in its EXEC reply, which may be followed
+                        // by a durability acknowledgement.
                         let res = if pipeline.is_transaction() {
-                            debug_assert_eq!(res.len(), 1);
-                            match res[0] {
-                                Value::Array(ref arr) => arr,
-                                _ => res,
+                            match res.first() {
+                                Some(Value::Array(arr)) => arr,
+                                // The transaction was aborted, so none of its commands ran.
+                                _ => return,
                             }
                         } else {
                             res
@@ -1023,6 +1046,11 @@ where
     async fn refresh_slots(self) -> RedisResult<()> {
         let mut write_guard = self.conn_lock.write().await;
         let (connections, slots) = &mut *write_guard;
+        let previous_primaries: HashSet<NodeAddress> = slots
+            .addresses_for_all_primaries()
+            .into_iter()
+            .cloned()
+            .collect();
 
         let mut found = false;
         let mut last_err = None;
@@ -1081,6 +1109,16 @@ where
 
         self.refresh_connections_locked(connections, nodes).await;
 
+        // A replica promoted by a failover may have stayed connected, in which case it isn't
+        // resubscribed on reconnection.
+        let promoted: Vec<NodeAddress> = slots
+            .addresses_for_all_primaries()
+            .into_iter()
+            .filter(|addr| !previous_primaries.contains(*addr))
+            .cloned()
+            .collect();
+        self.subscribe_node_local(&promoted);
+
         Ok(())
     }
 
@@ -1130,9 +1168,16 @@ where
 
         // we send request per cmd, instead of sending the pipe together, in order to send each command to the relevant node, instead of all together to a single node.
         let requests = subscription_pipe.into_cmd_iter().map(|cmd| {
-            let routing = RoutingInfo::for_routable(&cmd)
-                .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
-                .into();
+            // Keyspace notifications are only published by the node owning the key. Replicas
+            // publish them too when applying writes, so only primaries are subscribed to avoid
+            // receiving each event more than once.
+            let routing = if SubscriptionTracker::is_node_local(&cmd) {
+                RoutingInfo::MultiNode((MultipleNodeRoutingInfo::AllMasters, None))
+            } else {
+                RoutingInfo::for_routable(&cmd)
+                    .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
+            }
+            .into();
             PendingRequest {
                 retry: 0,
                 sender: request::ResultExpectation::Internal,
@@ -1151,27 +1196,74 @@ where
         }
     }
 
-    fn resubscribe_node(&self, reconnected_addr: &NodeAddress) {
+    // Subscribes `primaries` to the keyspace notification channels, which are only published by
+    // the node owning the key, so every primary must be subscribed.
+    fn subscribe_node_local(&self, primaries: &[NodeAddress]) {
         let Some(subscription_tracker) = self.subscription_tracker.as_ref() else {
             return;
         };
+        if primaries.is_empty() {
+            return;
+        }
 
-        // Determine if there are other connected nodes
-        let guard = self.conn_lock.try_read().ok();
-        let other_nodes_connected = if let Some(guard) = &guard {
-            guard.0.iter().any(|(addr, state)| {
-                addr != reconnected_addr && matches!(state, ConnState::Connected(_))
-            })
-        } else {
-            false
+        let subscription_pipe = subscription_tracker
+            .lock()
+            .unwrap()
+            .get_subscription_pipeline();
+
+        for cmd in subscription_pipe
+            .into_cmd_iter()
+            .filter(SubscriptionTracker::is_node_local)
+        {
+            let cmd = Arc::new(cmd);
+            for addr in primaries {
+                let routing = RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress {
+                    host: addr.host().to_string(),
+                    port: addr.port(),
+                });
+                let _ = self
+                    .pending_requests_tx
+                    .send(PendingRequest {
+                        retry: 0,
+                        sender: request::ResultExpectation::Internal,
+                        cmd: CmdArg::Cmd {
+                            cmd: cmd.clone(),
+                            routing: routing.into(),
+                        },
+                    })
+                    .inspect_err(|e| debug!("Failed internal send {e:?}"));
+            }
+        }
+    }
+
+    async fn resubscribe_node(&self, reconnected_addr: &NodeAddress) {
+        let Some(subscription_tracker) = self.subscription_tracker.as_ref() else {
+            return;
         };
 
+        // Determine if there are other connected nodes
+        let guard = self.conn_lock.read().await;
+        let other_nodes_connected = guard.0.iter().any(|(addr, state)| {
+            addr != reconnected_addr && matches!(state, ConnState::Connected(_))
+        });
+
+        if guard
+            .1
+            .addresses_for_all_primaries()
+            .contains(reconnected_addr)
+        {
+            self.subscribe_node_local(std::slice::from_ref(reconnected_addr));
+        }
+
         let subscription_pipe = subscription_tracker
             .lock()
             .unwrap()
             .get_subscription_pipeline();
 
         let requests = subscription_pipe.into_cmd_iter().filter_map(|cmd| {
+            if SubscriptionTracker::is_node_local(&cmd) {
+                return None;
+            }
             let routing = RoutingInfo::for_routable(&cmd)
                 .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random));
 
@@ -1179,9 +1271,9 @@ where
                 RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(route)) => {
                     // For specific node routing (like SSUBSCRIBE/shard pubsub),
                     // only send if it routes to the reconnected_addr.
-                    let target_addr = guard.as_ref().and_then(|g| {
-                        g.1.slot_addr_for_route(route, self.routing_strategy.as_deref())
-                    });
+                    let target_addr = guard
+                        .1
+                        .slot_addr_for_route(route, self.routing_strategy.as_deref());
                     if target_addr != Some(reconnected_addr) {
                         return None;
                     }
@@ -1293,7 +1385,7 @@ where
                     // TODO: make the resubscribe semantic more granular.
                     // Right now resubscribe() re-sends every subscription across all nodes.
                     // We should re-subscribe for the newly repaired node.
-                    core.resubscribe_node(&addr);
+                    core.resubscribe_node(&addr).await;
                 }
                 break;
             }
@@ -1808,9 +1900,13 @@ where
     C: ConnectionLike + Send + Clone + Unpin + Sync + Connect + 'static,
 {
     fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
-        let routing = RoutingInfo::for_routable(cmd)
-            .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random));
-        self.route_command(cmd.clone(), routing).boxed()
+        async move {
+            check_sort_patterns(cmd)?;
+            let routing = RoutingInfo::for_routable(cmd)
+                .unwrap_or(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random));
+            self.route_command(cmd.clone(), routing).await
+        }
+        .boxed()
     }
 
     fn req_packed_commands<'a>(
@@ -1830,6 +1926,10 @@ where
     fn get_db(&self) -> i64 {
         0
     }
+
+    fn response_timeout(&self) -> Option<Duration> {
+        self.state.response_timeout
+    }
 }
 /// Implements the process of connecting to a Redis server
 /// and obtaining a connection handle.
@@ -3,7 +3,7 @@ use crate::{
     cluster_handling::NodeAddress,
     cluster_routing::{
         MultipleNodeRoutingInfo, Redirect, ResponsePolicy, Route, RoutingInfo,
-        SingleNodeRoutingInfo, SlotAddr,
+        SingleNodeRoutingInfo, SlotAddr, check_sort_patterns,
     },
     errors::ServerErrorKind,
 };
@@ -114,6 +114,10 @@ pub(super) fn route_for_pipeline(pipeline: &crate::Pipeline) -> RedisResult<Opti
 
     // Find first specific slot and send to it. There's no need to check If later commands
     // should be routed to a different slot, since the server will return an error indicating this.
+    for cmd in pipeline.cmd_iter() {
+        check_sort_patterns(cmd)?;
+    }
+
     pipeline.cmd_iter().map(route_for_command).try_fold(
         None,
         |chosen_route, next_cmd_route| match (chosen_route, next_cmd_route) {
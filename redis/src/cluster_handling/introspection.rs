@@ -0,0 +1,428 @@
+//! Typed replies of the cluster introspection commands, such as
+//! [`cluster_shards`](crate::Commands::cluster_shards) and
+//! [`cluster_nodes`](crate::Commands::cluster_nodes).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::NodeAddress;
+use super::read_routing::{ClusterTopology, Shard};
+use crate::errors::{ParsingError, invalid_type_error};
+use crate::types::{FromRedisValue, Value, from_redis_value};
+
+/// The role of a node in its shard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NodeRole {
+    /// The node serves the slots of the shard.
+    Primary,
+    /// The node replicates the primary of the shard.
+    Replica,
+}
+
+/// A node of a shard, as returned by [`cluster_shards`](crate::Commands::cluster_shards).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ShardNode {
+    /// The unique node ID.
+    pub id: String,
+    /// The preferred endpoint to reach the node.
+    pub endpoint: String,
+    /// The IP address of the node.
+    pub ip: String,
+    /// The announced hostname of the node, if any.
+    pub hostname: Option<String>,
+    /// The plain TCP port of the node, if any.
+    pub port: Option<u16>,
+    /// The TLS port of the node, if any.
+    pub tls_port: Option<u16>,
+    /// The role of the node.
+    pub role: NodeRole,
+    /// The replication offset of the node.
+    pub replication_offset: u64,
+    /// The health of the node: `online`, `failed` or `loading`.
+    pub health: String,
+}
+
+impl ShardNode {
+    /// The address of the node, preferring its endpoint over its IP address and its plain
+    /// port over its TLS port. Returns `None` if the node announces no usable address.
+    pub fn address(&self) -> Option<NodeAddress> {
+        let host = match self.endpoint.as_str() {
+            "" | "?" => self.ip.as_str(),
+            endpoint => endpoint,
+        };
+        let port = self.port.or(self.tls_port)?;
+        (!host.is_empty()).then(|| NodeAddress::new(host, port))
+    }
+}
+
+impl FromRedisValue for ShardNode {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields: HashMap<String, Value> = from_redis_value(v)?;
+        let mut take = |name: &str| fields.remove(name).unwrap_or(Value::Nil);
+
+        let role = match from_redis_value::<String>(take("role"))?.as_str() {
+            "master" | "primary" => NodeRole::Primary,
+            "replica" | "slave" => NodeRole::Replica,
+            role => invalid_type_error!(format!("unknown node role `{role}`")),
+        };
+        Ok(ShardNode {
+            id: from_redis_value(take("id"))?,
+            endpoint: from_redis_value::<Option<String>>(take("endpoint"))?.unwrap_or_default(),
+            ip: from_redis_value::<Option<String>>(take("ip"))?.unwrap_or_default(),
+            hostname: from_redis_value::<Option<String>>(take("hostname"))?
+                .filter(|hostname| !hostname.is_empty()),
+            port: from_redis_value(take("port"))?,
+            tls_port: from_redis_value(take("tls-port"))?,
+            role,
+            replication_offset: from_redis_value(take("replication-offset"))?,
+            health: from_redis_value(take("health"))?,
+        })
+    }
+}
+
+/// A shard of the cluster, as returned by [`cluster_shards`](crate::Commands::cluster_shards).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ShardInfo {
+    /// The slot ranges served by the shard. Each tuple is `(start, end)` inclusive.
+    pub slot_ranges: Vec<(u16, u16)>,
+    /// The nodes of the shard.
+    pub nodes: Vec<ShardNode>,
+}
+
+impl ShardInfo {
+    /// Converts the shard to the [`Shard`] used by read routing strategies. Returns `None` if
+    /// the shard has no reachable primary.
+    pub fn to_shard(&self) -> Option<Shard> {
+        let primary = self
+            .nodes
+            .iter()
+            .find(|node| node.role == NodeRole::Primary)?
+            .address()?;
+        let replicas: Arc<[NodeAddress]> = self
+            .nodes
+            .iter()
+            .filter(|node| node.role == NodeRole::Replica)
+            .filter_map(ShardNode::address)
+            .collect();
+        Some(Shard::new(self.slot_ranges.as_slice(), primary, replicas))
+    }
+
+    /// Builds a [`ClusterTopology`] from the reply of
+    /// [`cluster_shards`](crate::Commands::cluster_shards). Shards without a reachable
+    /// primary are left out.
+    pub fn topology(shards: &[ShardInfo]) -> ClusterTopology {
+        ClusterTopology::from_shards(shards.iter().filter_map(ShardInfo::to_shard).collect())
+    }
+}
+
+impl FromRedisValue for ShardInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut fields: HashMap<String, Value> = from_redis_value(v)?;
+        let slots: Vec<u16> = from_redis_value(fields.remove("slots").unwrap_or(Value::Nil))?;
+        if !slots.len().is_multiple_of(2) {
+            invalid_type_error!("expected pairs of slots");
+        }
+        Ok(ShardInfo {
+            slot_ranges: slots.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+            nodes: from_redis_value(fields.remove("nodes").unwrap_or(Value::Nil))?,
+        })
+    }
+}
+
+/// A node of the cluster, as returned by [`cluster_nodes`](crate::Commands::cluster_nodes).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClusterNode {
+    /// The unique node ID.
+    pub id: String,
+    /// The `ip:port` address clients use to reach the node.
+    pub addr: String,
+    /// The port of the cluster bus, if announced.
+    pub bus_port: Option<u16>,
+    /// The announced hostname of the node, if any.
+    pub hostname: Option<String>,
+    /// The node flags, e.g. `myself`, `master`, `slave` or `fail`.
+    pub flags: Vec<String>,
+    /// The ID of the primary, if the node is a replica.
+    pub primary_id: Option<String>,
+    /// Unix time, in milliseconds, at which the pending ping was sent, or 0.
+    pub ping_sent: u64,
+    /// Unix time, in milliseconds, at which the last pong was received.
+    pub pong_recv: u64,
+    /// The configuration epoch of the node.
+    pub config_epoch: u64,
+    /// Whether the cluster bus link to the node is connected.
+    pub connected: bool,
+    /// The slot ranges served by the node. Each tuple is `(start, end)` inclusive.
+    pub slot_ranges: Vec<(u16, u16)>,
+}
+
+impl ClusterNode {
+    /// Returns whether the node has the given flag.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+
+    /// Returns whether the node is the one which answered the command.
+    pub fn is_myself(&self) -> bool {
+        self.has_flag("myself")
+    }
+
+    /// Returns whether the node is a primary.
+    pub fn is_primary(&self) -> bool {
+        self.has_flag("master")
+    }
+
+    fn parse(line: &str) -> Result<Self, ParsingError> {
+        let invalid = || ParsingError::from(format!("invalid cluster node `{line}`"));
+        let mut parts = line.split_ascii_whitespace();
+        let mut next = || parts.next().ok_or_else(invalid);
+        let number = |s: &str| s.parse::<u64>().map_err(|_| invalid());
+
+        let id = next()?.to_string();
+        // ip:port@cport[,hostname]
+        let address = next()?;
+        let (address, hostname) = match address.split_once(',') {
+            Some((address, hostname)) => (address, Some(hostname)),
+            None => (address, None),
+        };
+        let (addr, bus_port) = match address.split_once('@') {
+            Some((addr, bus_port)) => (addr, Some(bus_port.parse().map_err(|_| invalid())?)),
+            None => (address, None),
+        };
+        let flags = next()?.split(',').map(str::to_string).collect();
+        let primary_id = Some(next()?).filter(|id| *id != "-").map(str::to_string);
+        let ping_sent = number(next()?)?;
+        let pong_recv = number(next()?)?;
+        let config_epoch = number(next()?)?;
+        let connected = next()? == "connected";
+
+        let mut slot_ranges = Vec::new();
+        for slot in parts {
+            // Slots being migrated or imported are shown as `[slot-<-node]` or `[slot->-node]`.
+            if slot.starts_with('[') {
+                continue;
+            }
+            let range = match slot.split_once('-') {
+                Some((start, end)) => (start.parse(), end.parse()),
+                None => (slot.parse(), slot.parse()),
+            };
+            match range {
+                (Ok(start), Ok(end)) => slot_ranges.push((start, end)),
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(ClusterNode {
+            id,
+            addr: addr.to_string(),
+            bus_port,
+            hostname: hostname.filter(|h| !h.is_empty()).map(str::to_string),
+            flags,
+            primary_id,
+            ping_sent,
+            pong_recv,
+            config_epoch,
+            connected,
+            slot_ranges,
+        })
+    }
+}
+
+/// The nodes of the cluster, as returned by [`cluster_nodes`](crate::Commands::cluster_nodes).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClusterNodes {
+    /// One entry per known node.
+    pub nodes: Vec<ClusterNode>,
+}
+
+impl FromRedisValue for ClusterNodes {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let lines: String = from_redis_value(v)?;
+        let nodes = lines
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(ClusterNode::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(ClusterNodes { nodes })
+    }
+}
+
+/// The state of the cluster, as returned by [`cluster_info`](crate::Commands::cluster_info).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClusterInfo {
+    /// Whether the cluster can serve queries (`cluster_state:ok`).
+    pub state_ok: bool,
+    /// Number of slots assigned to a node.
+    pub slots_assigned: u16,
+    /// Number of slots served by a node in a healthy state.
+    pub slots_ok: u16,
+    /// Number of slots served by a node flagged as possibly failing.
+    pub slots_pfail: u16,
+    /// Number of slots served by a node flagged as failing.
+    pub slots_fail: u16,
+    /// Number of known nodes, including the ones in handshake state.
+    pub known_nodes: usize,
+    /// Number of primaries serving at least one slot.
+    pub size: usize,
+    /// The local current epoch.
+    pub current_epoch: u64,
+    /// The configuration epoch of the node answering the command.
+    pub my_epoch: u64,
+    /// All the fields reported by the server, including the ones above.
+    pub fields: HashMap<String, String>,
+}
+
+impl FromRedisValue for ClusterInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let lines: String = from_redis_value(v)?;
+        let fields: HashMap<String, String> = lines
+            .lines()
+            .filter_map(|line| line.trim().split_once(':'))
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        fn number<T: std::str::FromStr>(
+            fields: &HashMap<String, String>,
+            name: &str,
+        ) -> Result<T, ParsingError> {
+            match fields.get(name).map(|value| value.parse()) {
+                Some(Ok(number)) => Ok(number),
+                _ => Err(format!("missing or invalid cluster info field `{name}`").into()),
+            }
+        }
+
+        Ok(ClusterInfo {
+            state_ok: fields.get("cluster_state").map(String::as_str) == Some("ok"),
+            slots_assigned: number(&fields, "cluster_slots_assigned")?,
+            slots_ok: number(&fields, "cluster_slots_ok")?,
+            slots_pfail: number(&fields, "cluster_slots_pfail")?,
+            slots_fail: number(&fields, "cluster_slots_fail")?,
+            known_nodes: number(&fields, "cluster_known_nodes")?,
+            size: number(&fields, "cluster_size")?,
+            current_epoch: number(&fields, "cluster_current_epoch")?,
+            my_epoch: number(&fields, "cluster_my_epoch")?,
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::bulk;
+
+    fn shard_node(id: &str, port: i64, role: &str) -> Value {
+        Value::Map(vec![
+            (bulk("id"), bulk(id)),
+            (bulk("port"), Value::Int(port)),
+            (bulk("ip"), bulk("127.0.0.1")),
+            (bulk("endpoint"), bulk("127.0.0.1")),
+            (bulk("hostname"), bulk("")),
+            (bulk("role"), bulk(role)),
+            (bulk("replication-offset"), Value::Int(72156)),
+            (bulk("health"), bulk("online")),
+        ])
+    }
+
+    #[test]
+    fn parse_cluster_shards() {
+        let reply = Value::Array(vec![Value::Map(vec![
+            (
+                bulk("slots"),
+                Value::Array(vec![
+                    Value::Int(0),
+                    Value::Int(5460),
+                    Value::Int(10923),
+                    Value::Int(10930),
+                ]),
+            ),
+            (
+                bulk("nodes"),
+                Value::Array(vec![
+                    shard_node("a", 30001, "master"),
+                    shard_node("b", 30004, "replica"),
+                ]),
+            ),
+        ])]);
+
+        let shards: Vec<ShardInfo> = from_redis_value(reply).unwrap();
+        assert_eq!(shards.len(), 1);
+        assert_eq!(shards[0].slot_ranges, vec![(0, 5460), (10923, 10930)]);
+        assert_eq!(shards[0].nodes[0].role, NodeRole::Primary);
+        assert_eq!(shards[0].nodes[1].role, NodeRole::Replica);
+        assert_eq!(shards[0].nodes[0].hostname, None);
+        assert_eq!(shards[0].nodes[1].tls_port, None);
+
+        let shard = shards[0].to_shard().unwrap();
+        assert_eq!(shard.primary(), &NodeAddress::new("127.0.0.1", 30001));
+        assert_eq!(shard.replicas(), &[NodeAddress::new("127.0.0.1", 30004)]);
+
+        let topology = ShardInfo::topology(&shards);
+        assert_eq!(topology.shards().count(), 1);
+    }
+
+    #[test]
+    fn parse_cluster_nodes() {
+        let reply = bulk(
+            "07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004,host-4 slave e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 0 1426238317239 4 connected\n\
+             e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 127.0.0.1:30001@31001 myself,master - 0 0 1 connected 0-5460 6000 [5461->-67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1]\n",
+        );
+
+        let ClusterNodes { nodes } = from_redis_value(reply).unwrap();
+        assert_eq!(nodes.len(), 2);
+
+        let replica = &nodes[0];
+        assert_eq!(replica.addr, "127.0.0.1:30004");
+        assert_eq!(replica.bus_port, Some(31004));
+        assert_eq!(replica.hostname.as_deref(), Some("host-4"));
+        assert_eq!(
+            replica.primary_id.as_deref(),
+            Some("e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca")
+        );
+        assert_eq!(replica.pong_recv, 1426238317239);
+        assert!(!replica.is_primary());
+        assert!(replica.slot_ranges.is_empty());
+
+        let primary = &nodes[1];
+        assert!(primary.is_myself());
+        assert!(primary.is_primary());
+        assert!(primary.connected);
+        assert_eq!(primary.primary_id, None);
+        assert_eq!(primary.config_epoch, 1);
+        assert_eq!(primary.slot_ranges, vec![(0, 5460), (6000, 6000)]);
+    }
+
+    #[test]
+    fn parse_invalid_cluster_node() {
+        let reply = bulk("07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004 slave");
+        assert!(from_redis_value::<ClusterNodes>(reply).is_err());
+    }
+
+    #[test]
+    fn parse_cluster_info() {
+        let reply = Value::VerbatimString {
+            format: crate::types::VerbatimFormat::Text,
+            text: "cluster_state:ok\r\ncluster_slots_assigned:16384\r\ncluster_slots_ok:16384\r\n\
+                   cluster_slots_pfail:0\r\ncluster_slots_fail:0\r\ncluster_known_nodes:6\r\n\
+                   cluster_size:3\r\ncluster_current_epoch:6\r\ncluster_my_epoch:2\r\n\
+                   total_cluster_links_buffer_limit_exceeded:0\r\n"
+                .to_string(),
+        };
+
+        let info: ClusterInfo = from_redis_value(reply).unwrap();
+        assert!(info.state_ok);
+        assert_eq!(info.slots_assigned, 16384);
+        assert_eq!(info.known_nodes, 6);
+        assert_eq!(info.size, 3);
+        assert_eq!(info.my_epoch, 2);
+        assert_eq!(
+            info.fields["total_cluster_links_buffer_limit_exceeded"],
+            "0"
+        );
+    }
+}
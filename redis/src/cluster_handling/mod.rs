@@ -11,6 +11,8 @@ use std::str::FromStr;
 #[cfg(feature = "cluster-async")]
 pub mod async_connection;
 pub mod client;
+/// Typed replies of the cluster introspection commands.
+pub mod introspection;
 /// Pluggable read routing strategies for cluster connections.
 pub mod read_routing;
 /// Routing information for cluster commands.
@@ -51,6 +51,7 @@ pub enum ResponsePolicy {
     /// Aggregate success results according to a numeric operator. Return error on any failed request or on a response that isn't an integer.
     Aggregate(AggregateOp),
     /// Aggregate array responses into a single array. Return error on any failed request or on a response that isn't an array.
+    /// If all responses are maps, as for RESP3 replies of `TS.MRANGE` and `TS.MGET`, they are combined into a single map instead.
     CombineArrays,
     /// Handling is not defined by the Redis standard. Will receive a special case
     Special,
@@ -130,8 +131,33 @@ where
     new_cmd
 }
 
-/// Aggreagte numeric responses.
+/// Aggreagte numeric responses. Arrays of integers, such as WAITAOF replies, are aggregated
+/// element-wise.
 pub(crate) fn aggregate(values: Vec<Value>, op: AggregateOp) -> RedisResult<Value> {
+    if let Some(Value::Array(first)) = values.first() {
+        let mut columns = vec![Vec::with_capacity(values.len()); first.len()];
+        for value in values {
+            match value {
+                Value::Array(items) if items.len() == columns.len() => {
+                    for (column, item) in columns.iter_mut().zip(items) {
+                        column.push(item);
+                    }
+                }
+                _ => {
+                    return Err((
+                        ErrorKind::UnexpectedReturnType,
+                        "expected arrays of integers of the same length as response",
+                    )
+                        .into());
+                }
+            }
+        }
+        return columns
+            .into_iter()
+            .map(|column| aggregate(column, op))
+            .collect::<RedisResult<_>>()
+            .map(Value::Array);
+    }
     let initial_value = match op {
         AggregateOp::Min => i64::MAX,
         AggregateOp::Sum => 0,
@@ -215,7 +241,7 @@ pub(crate) fn combine_map_results(values: Vec<Value>) -> RedisResult<Value> {
                 let mut iter = elements.into_iter();
 
                 while let Some(key) = iter.next() {
-                    if let Value::BulkString(key_bytes) = key {
+                    if let Ok(key_bytes) = key.into_bulk_string() {
                         if let Some(Value::Int(value)) = iter.next() {
                             *map.entry(key_bytes).or_insert(0) += value;
                         } else {
@@ -249,7 +275,20 @@ pub(crate) fn combine_map_results(values: Vec<Value>) -> RedisResult<Value> {
 }
 
 /// Aggregate array responses into a single array.
+///
+/// Commands that report one entry per key, like `TS.MRANGE` and `TS.MGET`, reply with a map in
+/// RESP3. If all responses are maps, they are combined into a single map.
 pub(crate) fn combine_array_results(values: Vec<Value>) -> RedisResult<Value> {
+    if !values.is_empty() && values.iter().all(|value| matches!(value, Value::Map(_))) {
+        let mut entries = Vec::new();
+        for value in values {
+            if let Value::Map(map) = value {
+                entries.extend(map);
+            }
+        }
+        return Ok(Value::Map(entries));
+    }
+
     let mut results = Vec::new();
 
     for value in values {
@@ -557,7 +596,7 @@ impl ResponsePolicy {
                 Some(ResponsePolicy::Aggregate(AggregateOp::Sum))
             }
 
-            b"WAIT" => Some(ResponsePolicy::Aggregate(AggregateOp::Min)),
+            b"WAIT" | b"WAITAOF" => Some(ResponsePolicy::Aggregate(AggregateOp::Min)),
 
             b"ACL SETUSER" | b"ACL DELUSER" | b"ACL SAVE" | b"CLIENT SETNAME"
             | b"CLIENT SETINFO" | b"CONFIG SET" | b"CONFIG RESETSTAT" | b"CONFIG REWRITE"
@@ -573,7 +612,12 @@ impl ResponsePolicy {
             | b"JSON.MGET"
             | b"SLOWLOG GET"
             | b"PUBSUB CHANNELS"
-            | b"PUBSUB SHARDCHANNELS" => Some(ResponsePolicy::CombineArrays),
+            | b"PUBSUB SHARDCHANNELS"
+            | b"TS.MADD"
+            | b"TS.MGET"
+            | b"TS.MRANGE"
+            | b"TS.MREVRANGE"
+            | b"TS.QUERYINDEX" => Some(ResponsePolicy::CombineArrays),
 
             b"PUBSUB NUMSUB" | b"PUBSUB SHARDNUMSUB" => Some(ResponsePolicy::CombineMaps),
 
@@ -601,7 +645,11 @@ impl ResponsePolicy {
 enum RouteBy {
     AllNodes,
     AllPrimaries,
+    // The index of `FT.AGGREGATE`, on the primary when a cursor is created, since the cursor can
+    // only be read from the node that created it.
+    AggregateIndex,
     FirstKey,
+    Migrate,
     MultiShard(MultiSlotArgPattern),
     Random,
     SecondArg,
@@ -663,6 +711,10 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"UNWATCH"
         | b"WAIT"
         | b"RANDOMKEY"
+        | b"TS.MGET"
+        | b"TS.MRANGE"
+        | b"TS.MREVRANGE"
+        | b"TS.QUERYINDEX"
         | b"WAITAOF" => RouteBy::AllPrimaries,
 
         b"MGET" | b"DEL" | b"EXISTS" | b"UNLINK" | b"TOUCH" | b"WATCH" => {
@@ -671,7 +723,7 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
 
         b"MSET" => RouteBy::MultiShard(MultiSlotArgPattern::KeyValuePairs),
         b"JSON.MGET" => RouteBy::MultiShard(MultiSlotArgPattern::KeysAndLastArg),
-        b"JSON.MSET" => RouteBy::MultiShard(MultiSlotArgPattern::KeyWithTwoArgTriples),
+        b"JSON.MSET" | b"TS.MADD" => RouteBy::MultiShard(MultiSlotArgPattern::KeyWithTwoArgTriples),
         // TODO - special handling - b"SCAN"
         b"SCAN" | b"SHUTDOWN" | b"SLAVEOF" | b"REPLICAOF" => RouteBy::Undefined,
 
@@ -693,7 +745,9 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"OBJECT FREQ"
         | b"OBJECT IDLETIME"
         | b"OBJECT REFCOUNT"
-        | b"JSON.DEBUG" => RouteBy::SecondArg,
+        | b"JSON.DEBUG"
+        | b"FT.CURSOR READ"
+        | b"FT.CURSOR DEL" => RouteBy::SecondArg,
 
         b"LMPOP" | b"SINTERCARD" | b"ZDIFF" | b"ZINTER" | b"ZINTERCARD" | b"ZMPOP" | b"ZUNION" => {
             RouteBy::SecondArgAfterKeyCount
@@ -740,6 +794,7 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"COMMAND"
         | b"CONFIG GET"
         | b"ECHO"
+        | b"FUNCTION DUMP"
         | b"FUNCTION LIST"
         | b"LASTSAVE"
         | b"LOLWUT"
@@ -765,6 +820,10 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"CLUSTER GETKEYSINSLOT"
         | b"CLUSTER SETSLOT" => RouteBy::SecondArgSlot,
 
+        b"MIGRATE" => RouteBy::Migrate,
+
+        b"FT.AGGREGATE" => RouteBy::AggregateIndex,
+
         _ => RouteBy::FirstKey,
     }
 }
@@ -787,6 +846,20 @@ impl RoutingInfo {
                 ResponsePolicy::for_command(cmd),
             ))),
 
+            RouteBy::AggregateIndex => {
+                let index = r.arg_idx(1)?;
+                if r.position(b"WITHCURSOR").is_some() {
+                    Some(RoutingInfo::SingleNode(
+                        SingleNodeRoutingInfo::SpecificNode(Route::with_key(
+                            index,
+                            SlotAddr::Master,
+                        )),
+                    ))
+                } else {
+                    Some(RoutingInfo::for_key(cmd, index))
+                }
+            }
+
             RouteBy::MultiShard(arg_pattern) => multi_shard(r, cmd, 1, arg_pattern),
 
             RouteBy::Random => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
@@ -835,6 +908,14 @@ impl RoutingInfo {
                     })
             }
 
+            // MIGRATE host port key|"" destination-db timeout [...] [KEYS key [key ...]]
+            RouteBy::Migrate => match r.arg_idx(3) {
+                Some(b"") => r
+                    .arg_idx(r.position(b"KEYS")? + 1)
+                    .map(|key| RoutingInfo::for_key(cmd, key)),
+                key => key.map(|key| RoutingInfo::for_key(cmd, key)),
+            },
+
             RouteBy::FirstKey => match r.arg_idx(1) {
                 Some(key) => Some(RoutingInfo::for_key(cmd, key)),
                 None => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
@@ -852,6 +933,77 @@ impl RoutingInfo {
     }
 }
 
+/// Returns the slot of the keys formed by a `SORT` pattern, or `None` if they may be in
+/// different slots. This mirrors the server, which only accepts patterns whose hash tag
+/// comes before any glob-style character.
+fn pattern_slot(pattern: &[u8]) -> Option<Slot> {
+    let mut open = None;
+    for (i, c) in pattern.iter().enumerate() {
+        match (c, open) {
+            (b'*' | b'?' | b'[' | b'\\', _) => return None,
+            (b'{', None) => open = Some(i),
+            (b'}', Some(open)) if i == open + 1 => return None,
+            (b'}', Some(open)) => return Some(Slot::for_key(&pattern[open + 1..i])),
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Checks that the keys formed by the `BY` and `GET` patterns of a `SORT` or `SORT_RO`
+/// command are in the same slot as the sorted key, since the server can't fetch them
+/// otherwise. Other commands are always accepted.
+pub(crate) fn check_sort_patterns<R>(r: &R) -> RedisResult<()>
+where
+    R: Routable + ?Sized,
+{
+    if !matches!(r.command().as_deref(), Some(b"SORT" | b"SORT_RO")) {
+        return Ok(());
+    }
+    let Some(key) = r.arg_idx(1) else {
+        return Ok(());
+    };
+    let slot = Slot::for_key(key);
+
+    let mut idx = 2;
+    while let Some(arg) = r.arg_idx(idx) {
+        let option = if arg.eq_ignore_ascii_case(b"BY") {
+            "BY"
+        } else if arg.eq_ignore_ascii_case(b"GET") {
+            "GET"
+        } else {
+            idx += if arg.eq_ignore_ascii_case(b"LIMIT") {
+                3
+            } else {
+                1
+            };
+            continue;
+        };
+        let Some(pattern) = r.arg_idx(idx + 1) else {
+            break;
+        };
+        idx += 2;
+
+        // `GET #` returns the element itself, and a `BY` pattern without `*` skips sorting.
+        let is_key_pattern = match option {
+            "GET" => pattern != b"#",
+            _ => pattern.contains(&b'*'),
+        };
+        if is_key_pattern && pattern_slot(pattern) != Some(slot) {
+            return Err(RedisError::from((
+                ErrorKind::Client,
+                "SORT pattern keys may be in a different slot than the sorted key",
+                format!(
+                    "The {option} pattern `{}` must contain a hash tag mapping to the slot of `{}`",
+                    String::from_utf8_lossy(pattern),
+                    String::from_utf8_lossy(key),
+                ),
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Objects that implement this trait define a request that can be routed by a cluster client to different nodes in the cluster.
 pub(crate) trait Routable {
     /// Convenience function to return ascii uppercase version of the
@@ -861,7 +1013,7 @@ pub(crate) trait Routable {
         let mut primary_command = match primary_command.as_slice() {
             b"XGROUP" | b"OBJECT" | b"SLOWLOG" | b"FUNCTION" | b"MODULE" | b"COMMAND"
             | b"PUBSUB" | b"CONFIG" | b"MEMORY" | b"XINFO" | b"CLIENT" | b"ACL" | b"SCRIPT"
-            | b"CLUSTER" | b"LATENCY" => primary_command,
+            | b"CLUSTER" | b"LATENCY" | b"FT.CURSOR" => primary_command,
             _ => {
                 return Some(primary_command);
             }
@@ -905,8 +1057,8 @@ impl Routable for Value {
     fn arg_idx(&self, idx: usize) -> Option<&[u8]> {
         match self {
             Value::Array(args) => match args.get(idx) {
-                Some(Value::BulkString(data)) => Some(&data[..]),
-                _ => None,
+                Some(arg) => arg.as_bulk_string(),
+                None => None,
             },
             _ => None,
         }
@@ -914,9 +1066,9 @@ impl Routable for Value {
 
     fn position(&self, candidate: &[u8]) -> Option<usize> {
         match self {
-            Value::Array(args) => args.iter().position(|a| match a {
-                Value::BulkString(d) => d.eq_ignore_ascii_case(candidate),
-                _ => false,
+            Value::Array(args) => args.iter().position(|a| {
+                a.as_bulk_string()
+                    .is_some_and(|d| d.eq_ignore_ascii_case(candidate))
             }),
             _ => None,
         }
@@ -1030,9 +1182,10 @@ fn get_hashtag(key: &[u8]) -> Option<&[u8]> {
 mod tests_routing {
     use super::{
         AggregateOp, MultiSlotArgPattern, MultipleNodeRoutingInfo, ResponsePolicy, Route,
-        RoutingInfo, SingleNodeRoutingInfo, SlotAddr, command_for_multi_slot_indices,
+        RoutingInfo, SingleNodeRoutingInfo, SlotAddr, aggregate, check_sort_patterns,
+        combine_array_results, command_for_multi_slot_indices,
     };
-    use crate::{Value, cmd, parser::parse_redis_value};
+    use crate::{ErrorKind, SortOptions, Value, cmd, parser::parse_redis_value};
     use assert_matches::assert_matches;
     use core::panic;
 
@@ -1058,6 +1211,36 @@ mod tests_routing {
         );
     }
 
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_routing_info_for_bulk_bytes() {
+        let bulk_bytes =
+            |s: &'static str| Value::BulkBytes(bytes::Bytes::from_static(s.as_bytes()));
+        let value = Value::Array(vec![
+            bulk_bytes("xread"),
+            bulk_bytes("streams"),
+            bulk_bytes("foo"),
+            bulk_bytes("0"),
+        ]);
+        let mut command = cmd("XREAD");
+        command.arg("STREAMS").arg("foo").arg(0);
+
+        assert_eq!(
+            RoutingInfo::for_routable(&value).unwrap(),
+            RoutingInfo::for_routable(&command).unwrap()
+        );
+
+        let result = super::combine_map_results(vec![
+            Value::Array(vec![bulk_bytes("key1"), Value::Int(5)]),
+            Value::Array(vec![Value::BulkString(b"key1".to_vec()), Value::Int(3)]),
+        ])
+        .unwrap();
+        assert_eq!(
+            result,
+            Value::Map(vec![(Value::BulkString(b"key1".to_vec()), Value::Int(8))])
+        );
+    }
+
     #[test]
     fn test_routing_info() {
         let mut test_cmds = vec![];
@@ -1186,6 +1369,104 @@ mod tests_routing {
             ))
         );
 
+        // And FCALL_RO may be served by a replica
+        assert_eq!(
+            RoutingInfo::for_routable(cmd("FCALL_RO").arg("foo").arg(1).arg("mykey")),
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::SpecificNode(Route::with_key(
+                    b"mykey",
+                    SlotAddr::ReplicaOptional
+                ))
+            ))
+        );
+
+        // Function libraries are loaded on every primary
+        assert_eq!(
+            RoutingInfo::for_routable(cmd("FUNCTION").arg("LOAD").arg("#!lua name=mylib")),
+            Some(RoutingInfo::MultiNode((
+                MultipleNodeRoutingInfo::AllMasters,
+                Some(ResponsePolicy::AllSucceeded)
+            )))
+        );
+        assert_eq!(
+            RoutingInfo::for_routable(cmd("FUNCTION").arg("DUMP")),
+            Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random))
+        );
+
+        // Aggregation cursors are read from the node the aggregation was sent to, so aggregations
+        // creating a cursor aren't sent to replicas
+        let primary_route = Some(RoutingInfo::SingleNode(
+            SingleNodeRoutingInfo::SpecificNode(Route::with_key(b"myindex", SlotAddr::Master)),
+        ));
+        assert_eq!(
+            RoutingInfo::for_routable(
+                cmd("FT.AGGREGATE")
+                    .arg("myindex")
+                    .arg("*")
+                    .arg("WITHCURSOR")
+                    .arg("COUNT")
+                    .arg(10)
+            ),
+            primary_route
+        );
+        for subcommand in ["READ", "DEL"] {
+            assert_eq!(
+                RoutingInfo::for_routable(cmd("FT.CURSOR").arg(subcommand).arg("myindex").arg(42)),
+                primary_route
+            );
+        }
+        assert_eq!(
+            RoutingInfo::for_routable(cmd("FT.AGGREGATE").arg("myindex").arg("*")),
+            Some(RoutingInfo::SingleNode(
+                SingleNodeRoutingInfo::SpecificNode(Route::with_key(
+                    b"myindex",
+                    SlotAddr::ReplicaOptional
+                ))
+            ))
+        );
+
+        // Time series selected by labels can live on any shard
+        for name in ["TS.MRANGE", "TS.MREVRANGE", "TS.MGET", "TS.QUERYINDEX"] {
+            let mut cmd = cmd(name);
+            if name.contains("RANGE") {
+                cmd.arg("-").arg("+");
+            }
+            if name != "TS.QUERYINDEX" {
+                cmd.arg("FILTER");
+            }
+            cmd.arg("sensor=temperature");
+            assert_eq!(
+                RoutingInfo::for_routable(&cmd),
+                Some(RoutingInfo::MultiNode((
+                    MultipleNodeRoutingInfo::AllMasters,
+                    Some(ResponsePolicy::CombineArrays)
+                ))),
+                "{name}"
+            );
+        }
+
+        // A GROUPBY reduction isn't routed differently, so each primary reduces its own series
+        for name in ["TS.MRANGE", "TS.MREVRANGE"] {
+            assert_eq!(
+                RoutingInfo::for_routable(
+                    cmd(name)
+                        .arg("-")
+                        .arg("+")
+                        .arg("FILTER")
+                        .arg("sensor=temperature")
+                        .arg("GROUPBY")
+                        .arg("room")
+                        .arg("REDUCE")
+                        .arg("avg")
+                ),
+                Some(RoutingInfo::MultiNode((
+                    MultipleNodeRoutingInfo::AllMasters,
+                    Some(ResponsePolicy::CombineArrays)
+                ))),
+                "{name}"
+            );
+        }
+
         for (cmd, expected) in [
             (
                 cmd("EVAL")
@@ -1249,6 +1530,35 @@ mod tests_routing {
                     )),
                 )),
             ),
+            (
+                cmd("MIGRATE")
+                    .arg("10.0.0.2")
+                    .arg(6379)
+                    .arg("foo")
+                    .arg(0)
+                    .arg(5000),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::with_key(b"foo", SlotAddr::Master)),
+                )),
+            ),
+            (
+                cmd("MIGRATE")
+                    .arg("10.0.0.2")
+                    .arg(6379)
+                    .arg("")
+                    .arg(0)
+                    .arg(5000)
+                    .arg("REPLACE")
+                    .arg("KEYS")
+                    .arg("{bar}1")
+                    .arg("{bar}2"),
+                Some(RoutingInfo::SingleNode(
+                    SingleNodeRoutingInfo::SpecificNode(Route::with_key(
+                        b"{bar}1",
+                        SlotAddr::Master,
+                    )),
+                )),
+            ),
         ] {
             assert_eq!(
                 RoutingInfo::for_routable(cmd),
@@ -1389,6 +1699,98 @@ mod tests_routing {
         );
     }
 
+    #[test]
+    fn test_multi_shard_ts_madd() {
+        let mut cmd = cmd("TS.MADD");
+        cmd.arg("foo") // key slot 12182
+            .arg(1)
+            .arg(1.5)
+            .arg("foo2") // key slot 1044
+            .arg(1)
+            .arg(2.5)
+            .arg("{foo}foo3") // key slot 12182
+            .arg(2)
+            .arg(3.5);
+        let routing = RoutingInfo::for_routable(&cmd);
+        let mut expected = std::collections::HashMap::new();
+        expected.insert(Route(1044, SlotAddr::Master), vec![3, 4, 5]);
+        expected.insert(Route(12182, SlotAddr::Master), vec![0, 1, 2, 6, 7, 8]);
+
+        assert!(
+            matches!(routing.clone(), Some(RoutingInfo::MultiNode((MultipleNodeRoutingInfo::MultiSlot((vec, args_pattern)), Some(ResponsePolicy::CombineArrays)))) if {
+                let routes = vec.clone().into_iter().collect();
+                expected == routes && args_pattern == MultiSlotArgPattern::KeyWithTwoArgTriples
+            }),
+            "expected={expected:?}\nrouting={routing:?}"
+        );
+    }
+
+    #[test]
+    fn test_check_sort_patterns() {
+        let sort = |key: &str, options: SortOptions| cmd("SORT").arg(key).arg(&options).clone();
+
+        // Patterns whose hash tag maps to the slot of the key are accepted
+        let options = SortOptions::default()
+            .limit(0, 10)
+            .by("{user}:weight:*")
+            .get("#")
+            .get("{user}:name:*");
+        assert!(check_sort_patterns(&sort("{user}:ids", options.clone())).is_ok());
+        assert!(check_sort_patterns(&sort("user", options.clone())).is_ok());
+        let packed = parse_redis_value(&sort("user", options).get_packed_command()).unwrap();
+        assert!(check_sort_patterns(&packed).is_ok());
+
+        // BY patterns without `*` don't fetch keys
+        let options = SortOptions::default().by("nosort");
+        assert!(check_sort_patterns(&sort("ids", options)).is_ok());
+
+        for options in [
+            SortOptions::default().by("weight_*"),
+            SortOptions::default().get("{other}:name:*"),
+            SortOptions::default().get("name"),
+            SortOptions::default().by("*{user}"),
+            SortOptions::default().by("{}:weight:*"),
+        ] {
+            let err = check_sort_patterns(&sort("{user}:ids", options)).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Client);
+        }
+
+        // SORT_RO is checked as well, while other commands are ignored
+        let err = check_sort_patterns(cmd("SORT_RO").arg("ids").arg("GET").arg("name_*"));
+        assert!(err.is_err());
+        assert!(check_sort_patterns(cmd("GET").arg("BY").arg("weight_*")).is_ok());
+    }
+
+    #[test]
+    fn test_combine_array_results_of_maps() {
+        let combined = combine_array_results(vec![
+            Value::Map(vec![(Value::SimpleString("a".into()), Value::Int(1))]),
+            Value::Map(vec![]),
+            Value::Map(vec![(Value::SimpleString("b".into()), Value::Int(2))]),
+        ])
+        .unwrap();
+        assert_eq!(
+            combined,
+            Value::Map(vec![
+                (Value::SimpleString("a".into()), Value::Int(1)),
+                (Value::SimpleString("b".into()), Value::Int(2)),
+            ])
+        );
+
+        assert!(
+            combine_array_results(vec![Value::Map(vec![]), Value::Array(vec![Value::Int(1)]),])
+                .is_err()
+        );
+        assert_eq!(
+            combine_array_results(vec![
+                Value::Array(vec![Value::Int(1)]),
+                Value::Array(vec![])
+            ])
+            .unwrap(),
+            Value::Array(vec![Value::Int(1)])
+        );
+    }
+
     #[test]
     fn test_command_creation_for_multi_shard() {
         let mut original_cmd = cmd("DEL");
@@ -1541,6 +1943,21 @@ mod tests_routing {
         );
     }
 
+    #[test]
+    fn test_aggregate_arrays_element_wise() {
+        let values = vec![
+            Value::Array(vec![Value::Int(1), Value::Int(2)]),
+            Value::Array(vec![Value::Int(0), Value::Int(3)]),
+        ];
+        assert_eq!(
+            aggregate(values, AggregateOp::Min).unwrap(),
+            Value::Array(vec![Value::Int(0), Value::Int(2)])
+        );
+
+        let values = vec![Value::Array(vec![Value::Int(1)]), Value::Int(1)];
+        assert!(aggregate(values, AggregateOp::Min).is_err());
+    }
+
     #[test]
     fn test_combine_map_results() {
         let input = vec![];
@@ -75,14 +75,15 @@ use super::topology::parse_slots;
 use super::{
     client::ClusterParams,
     read_routing::ReadRoutingStrategy,
-    routing::{Redirect, Route, RoutingInfo},
+    routing::{Redirect, Route, RoutingInfo, check_sort_patterns},
     slot_map::SlotMap,
 };
 use crate::IntoConnectionInfo;
 pub use crate::TlsMode; // Pub for backwards compatibility
 use crate::cluster_handling::{get_connection_info, slot_cmd};
 use crate::cluster_routing::{
-    MultipleNodeRoutingInfo, ResponsePolicy, Routable, SingleNodeRoutingInfo, Slot, SlotAddr,
+    AggregateOp, MultipleNodeRoutingInfo, ResponsePolicy, Routable, SingleNodeRoutingInfo, Slot,
+    SlotAddr,
 };
 use crate::cmd::{Cmd, cmd};
 use crate::connection::{Connection, ConnectionInfo, ConnectionLike, connect};
@@ -398,7 +399,7 @@ where
     }
 
     pub(crate) fn execute_pipeline(&mut self, pipe: &ClusterPipeline) -> RedisResult<Vec<Value>> {
-        self.send_recv_and_retry_cmds(pipe.commands())
+        self.send_recv_and_retry_cmds(pipe.commands(), pipe.durability_cmd().as_ref())
     }
 
     /// Returns the connection status.
@@ -944,17 +945,27 @@ where
         }
     }
 
-    fn send_recv_and_retry_cmds(&self, cmds: &[Cmd]) -> RedisResult<Vec<Value>> {
+    // `wait` is the WAIT/WAITAOF command of a pipeline with durability, it is sent after the
+    // commands of each node and the aggregated acknowledgement is appended to the results.
+    fn send_recv_and_retry_cmds(
+        &self,
+        cmds: &[Cmd],
+        wait: Option<&Cmd>,
+    ) -> RedisResult<Vec<Value>> {
         // Vector to hold the results, pre-populated with `Nil` values. This allows the original
         // cmd ordering to be re-established by inserting the response directly into the result
         // vector (e.g., results[10] = response).
         let mut results = vec![Value::Nil; cmds.len()];
+        let mut acks = Vec::new();
 
         let to_retry = self
-            .send_all_commands(cmds)
-            .and_then(|node_cmds| self.recv_all_commands(&mut results, &node_cmds))?;
+            .send_all_commands(cmds, wait)
+            .and_then(|node_cmds| self.recv_all_commands(&mut results, &mut acks, &node_cmds))?;
 
         if to_retry.is_empty() {
+            if wait.is_some() {
+                results.push(aggregate_acks(acks)?);
+            }
             return Ok(results);
         }
 
@@ -969,15 +980,25 @@ where
             let routing = RoutingInfo::for_routable(cmd);
             results[retry_idx] = self.request(Input::Cmd(cmd), routing)?.into();
         }
+        if let Some(wait) = wait {
+            // The retried commands may have been written to any primary, so wait on all of them.
+            let routing = RoutingInfo::for_routable(wait);
+            acks.push(self.request(Input::Cmd(wait), routing)?.into());
+            results.push(aggregate_acks(acks)?);
+        }
         Ok(results)
     }
 
     // Build up a pipeline per node, then send it
-    fn send_all_commands(&self, cmds: &[Cmd]) -> RedisResult<Vec<NodeCmd>> {
+    fn send_all_commands(&self, cmds: &[Cmd], wait: Option<&Cmd>) -> RedisResult<Vec<NodeCmd>> {
         let mut connections = self.connections.borrow_mut();
 
-        let node_cmds = self.map_cmds_to_nodes(cmds)?;
-        for nc in &node_cmds {
+        let mut node_cmds = self.map_cmds_to_nodes(cmds)?;
+        for nc in &mut node_cmds {
+            if let Some(wait) = wait {
+                wait.write_packed_command(&mut nc.pipe);
+                nc.waits = true;
+            }
             self.get_connection_by_addr(&mut connections, &nc.addr)?
                 .send_packed_command(&nc.pipe)?;
         }
@@ -988,6 +1009,7 @@ where
     fn recv_all_commands(
         &self,
         results: &mut [Value],
+        acks: &mut Vec<Value>,
         node_cmds: &[NodeCmd],
     ) -> RedisResult<Vec<usize>> {
         let mut to_retry = Vec::new();
@@ -1005,6 +1027,15 @@ where
                     Err(err) => first_err = first_err.or(Some(err)),
                 }
             }
+            if nc.waits {
+                match self
+                    .get_connection_by_addr(&mut connections, &nc.addr)?
+                    .recv_response()
+                {
+                    Ok(ack) => acks.push(ack),
+                    Err(err) => first_err = first_err.or(Some(err)),
+                }
+            }
         }
         match first_err {
             Some(err) => Err(err),
@@ -1029,6 +1060,7 @@ impl<C: Connect + ConnectionLike> ConnectionLike for ClusterConnection<C> {
         if cmd.is_empty() {
             return Err(RedisError::make_empty_command());
         }
+        check_sort_patterns(cmd)?;
         let routing = RoutingInfo::for_routable(cmd);
         self.request(Input::Cmd(cmd), routing).map(|res| res.into())
     }
@@ -1043,6 +1075,7 @@ impl<C: Connect + ConnectionLike> ConnectionLike for ClusterConnection<C> {
             cmd
         };
         let value = parse_redis_value(actual_cmd)?;
+        check_sort_patterns(&value)?;
         let routing = RoutingInfo::for_routable(&value);
         self.request(
             Input::Slice {
@@ -1113,6 +1146,8 @@ struct NodeCmd {
     indexes: Vec<usize>,
     pipe: Vec<u8>,
     addr: NodeAddress,
+    // Whether the pipe ends with a WAIT/WAITAOF command
+    waits: bool,
 }
 
 impl NodeCmd {
@@ -1121,10 +1156,19 @@ impl NodeCmd {
             indexes: vec![],
             pipe: vec![],
             addr: a,
+            waits: false,
         }
     }
 }
 
+// Reports the lowest acknowledgement of the nodes, or the first error.
+fn aggregate_acks(acks: Vec<Value>) -> RedisResult<Value> {
+    match acks.iter().find(|ack| matches!(ack, Value::ServerError(_))) {
+        Some(error) => Ok(error.clone()),
+        None => crate::cluster_routing::aggregate(acks, AggregateOp::Min),
+    }
+}
+
 fn get_random_connection<C: ConnectionLike + Connect + Sized>(
     connections: &mut HashMap<NodeAddress, C>,
 ) -> Option<(NodeAddress, &mut C)> {
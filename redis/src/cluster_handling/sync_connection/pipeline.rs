@@ -2,6 +2,7 @@ use super::ClusterConnection;
 use crate::RedisError;
 use crate::cmd::{Cmd, cmd};
 use crate::errors::ErrorKind;
+use crate::pipeline::Durability;
 use crate::types::{FromRedisValue, HashSet, RedisResult, ToRedisArgs, Value, from_redis_value};
 
 pub(crate) const UNROUTABLE_ERROR: (ErrorKind, &str) = (
@@ -47,6 +48,7 @@ pub struct ClusterPipeline {
     commands: Vec<Cmd>,
     ignored_commands: HashSet<usize>,
     ignore_errors: bool,
+    durability: Option<Durability>,
 }
 
 /// A cluster pipeline is almost identical to a normal [Pipeline](crate::pipeline::Pipeline), with two exceptions:
@@ -83,6 +85,7 @@ impl ClusterPipeline {
             commands: Vec::with_capacity(capacity),
             ignored_commands: HashSet::new(),
             ignore_errors: false,
+            durability: None,
         }
     }
 
@@ -90,6 +93,10 @@ impl ClusterPipeline {
         &self.commands
     }
 
+    pub(crate) fn durability_cmd(&self) -> Option<Cmd> {
+        self.durability.map(|durability| durability.command())
+    }
+
     /// Executes the pipeline and fetches the return values:
     ///
     /// ```rust,no_run
@@ -625,6 +625,25 @@ impl Cmd {
         write_command(cmd, self.args_iter(), self.cursor.unwrap_or(0))
     }
 
+    /// Packs the command with an extra last argument of `len` bytes, up to the argument's data,
+    /// which is sent separately and followed by `\r\n`.
+    #[cfg(feature = "aio")]
+    pub(crate) fn get_packed_command_with_streamed_arg(&self, len: u64) -> Vec<u8> {
+        let args = self.args_iter();
+        let count = args.len();
+        let mut cmd = Vec::new();
+        write_command_to_vec(&mut cmd, args, self.cursor.unwrap_or(0));
+
+        let mut buf = ::itoa::Buffer::new();
+        let header_len = 1 + countdigits(count) + 2;
+        let header = [b"*", buf.format(count + 1).as_bytes(), b"\r\n"].concat();
+        cmd.splice(..header_len, header);
+        cmd.extend_from_slice(b"$");
+        cmd.extend_from_slice(buf.format(len).as_bytes());
+        cmd.extend_from_slice(b"\r\n");
+        cmd
+    }
+
     /// Returns true if the command is in scan mode.
     #[inline]
     pub fn in_scan_mode(&self) -> bool {
@@ -946,6 +965,18 @@ mod tests {
         assert_eq!(cmd.args_iter().collect::<Vec<_>>(), args_vec);
     }
 
+    #[cfg(feature = "aio")]
+    #[test]
+    fn test_cmd_packed_command_with_streamed_arg() {
+        let mut cmd = cmd("SETRANGE");
+        cmd.arg("key").arg(10);
+
+        assert_eq!(
+            cmd.get_packed_command_with_streamed_arg(1234),
+            b"*4\r\n$8\r\nSETRANGE\r\n$3\r\nkey\r\n$2\r\n10\r\n$1234\r\n"
+        );
+    }
+
     #[test]
     fn test_cmd_clean() {
         let mut cmd = cmd("key");
@@ -0,0 +1,288 @@
+//! Defines wrappers that store values in an encoded form.
+//!
+//! Each wrapper holds a value along with its encoding. It is created from a value with `new`,
+//! which reports the values the format can't represent as errors of kind
+//! [`ErrorKind::Serialize`], and is written as a single bulk string argument. It is decoded back
+//! from a bulk string when used as a return type, so it can be used with any command:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "json")]
+//! # fn do_something() -> redis::RedisResult<()> {
+//! use redis::Commands;
+//! use redis::codec::Json;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! let client = redis::Client::open("redis://127.0.0.1/")?;
+//! let mut con = client.get_connection()?;
+//! let user = User { name: "alice".to_string() };
+//! let _: () = con.set("user", Json::new(user)?)?;
+//! let user: User = con.get::<_, Json<User>>("user")?.into_inner();
+//! let users: Vec<Option<Json<User>>> = con.mget(&["user", "missing"])?;
+//! # Ok(()) }
+//! ```
+//!
+//! Decoding failures are reported as [`ParsingError`]s.
+//!
+//! The available wrappers are:
+//! * [`Json`], with the `json` feature
+//! * [`MsgPack`], with the `msgpack` feature
+//! * [`Bincode`], with the `bincode` feature, using the standard configuration of bincode
+
+use std::ops::Deref;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::errors::invalid_type_error;
+use crate::{
+    ErrorKind, FromRedisValue, ParsingError, RedisResult, RedisWrite, ToRedisArgs,
+    ToSingleRedisArg, Value,
+};
+
+macro_rules! implement_codec {
+    (
+        $(#[$attr:meta])*
+        $feature:literal, $name:ident, $format:literal,
+        encode: $encode:expr,
+        decode: $decode:expr,
+    ) => {
+        $(#[$attr])*
+        #[cfg(feature = $feature)]
+        #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name<T> {
+            value: T,
+            // The encoding of `value`.
+            bytes: Vec<u8>,
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: Serialize> $name<T> {
+            #[doc = concat!("Wraps `value`, encoding it as ", $format, ".")]
+            pub fn new(value: T) -> RedisResult<Self> {
+                let encode: fn(&T) -> Result<Vec<u8>, String> = $encode;
+                match encode(&value) {
+                    Ok(bytes) => Ok($name { value, bytes }),
+                    Err(err) => Err((
+                        ErrorKind::Serialize,
+                        concat!("failed to encode value as ", $format),
+                        err,
+                    )
+                        .into()),
+                }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> $name<T> {
+            /// Returns the wrapped value.
+            pub fn into_inner(self) -> T {
+                self.value
+            }
+
+            /// Returns the encoding of the value.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.bytes
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.value
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> ToRedisArgs for $name<T> {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?Sized + RedisWrite,
+            {
+                out.write_arg(&self.bytes);
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T> ToSingleRedisArg for $name<T> {}
+
+        #[cfg(feature = $feature)]
+        impl<T: DeserializeOwned> FromRedisValue for $name<T> {
+            fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+                let bytes: &[u8] = match v {
+                    Value::BulkString(bytes) => bytes,
+                    #[cfg(feature = "bytes")]
+                    Value::BulkBytes(bytes) => bytes,
+                    Value::Attribute { data, .. } => return Self::from_redis_value_ref(data),
+                    _ => invalid_type_error!(v, concat!("Response type not ", $format, " compatible.")),
+                };
+                Self::decode(bytes.to_vec())
+            }
+
+            fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+                match v {
+                    Value::BulkString(bytes) => Self::decode(bytes),
+                    v => Self::from_redis_value_ref(&v),
+                }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: DeserializeOwned> $name<T> {
+            fn decode(bytes: Vec<u8>) -> Result<Self, ParsingError> {
+                let decode: fn(&[u8]) -> Result<T, String> = $decode;
+                let value = decode(&bytes).map_err(|err| {
+                    ParsingError::from(format!(concat!("failed to decode ", $format, ": {}"), err))
+                })?;
+                Ok($name { value, bytes })
+            }
+        }
+    };
+}
+
+implement_codec! {
+    /// A value stored as JSON
+    ///
+    /// Values that can't be represented in JSON, like maps with non-string keys, fail to encode.
+    "json", Json, "JSON",
+    encode: |value| serde_json::to_vec(value).map_err(|err| err.to_string()),
+    decode: |bytes| serde_json::from_slice(bytes).map_err(|err| err.to_string()),
+}
+
+implement_codec! {
+    /// A value stored as MessagePack
+    ///
+    /// Structs are encoded as arrays of their fields, so the field order must not change
+    /// between the writer and the reader.
+    "msgpack", MsgPack, "MessagePack",
+    encode: |value| rmp_serde::to_vec(value).map_err(|err| err.to_string()),
+    decode: |bytes| rmp_serde::from_slice(bytes).map_err(|err| err.to_string()),
+}
+
+implement_codec! {
+    /// A value stored with bincode, using its standard configuration
+    ///
+    /// The encoding isn't self-describing, so the writer and the reader must use the same type.
+    /// Trailing bytes after the value are reported as a decoding failure. Sequences of unknown
+    /// length fail to encode.
+    "bincode", Bincode, "bincode",
+    encode: |value| {
+        bincode::serde::encode_to_vec(value, bincode::config::standard())
+            .map_err(|err| err.to_string())
+    },
+    decode: |bytes| {
+        let (value, read) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map_err(|err| err.to_string())?;
+        if read != bytes.len() {
+            return Err(format!("{} trailing bytes", bytes.len() - read));
+        }
+        Ok(value)
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct User {
+        name: String,
+        tags: Vec<String>,
+        age: Option<u8>,
+    }
+
+    fn user() -> User {
+        User {
+            name: "alice".to_string(),
+            tags: vec!["admin".to_string()],
+            age: Some(30),
+        }
+    }
+
+    fn roundtrip<C>(value: RedisResult<C>) -> C
+    where
+        C: ToSingleRedisArg + FromRedisValue,
+    {
+        let mut args = value.unwrap().to_redis_args();
+        assert_eq!(args.len(), 1);
+        C::from_redis_value(Value::BulkString(args.pop().unwrap())).unwrap()
+    }
+
+    fn assert_parsing_error<C: FromRedisValue>(bytes: &[u8]) {
+        let err = C::from_redis_value(Value::BulkString(bytes.to_vec()))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("failed to decode"), "{err}");
+        assert!(C::from_redis_value(Value::Int(1)).is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json() {
+        assert_eq!(roundtrip(Json::new(user())).into_inner(), user());
+        assert_eq!(
+            Json::new(user()).unwrap().to_redis_args(),
+            vec![br#"{"name":"alice","tags":["admin"],"age":30}"#.to_vec()]
+        );
+        assert_parsing_error::<Json<User>>(b"{\"name\":");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_encoding_error() {
+        let map = HashMap::from([((1, 2), "value")]);
+        let err = Json::new(map).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Serialize);
+        assert!(
+            err.to_string().contains("failed to encode value as JSON"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_collections() {
+        let users: Vec<Option<Json<User>>> = crate::from_redis_value(Value::Array(vec![
+            Value::BulkString(serde_json::to_vec(&user()).unwrap()),
+            Value::Nil,
+        ]))
+        .unwrap();
+        assert_eq!(users, vec![Some(Json::new(user()).unwrap()), None]);
+
+        let map: HashMap<String, Json<u32>> = crate::from_redis_value(Value::Map(vec![(
+            Value::SimpleString("a".to_string()),
+            Value::BulkString(b"1".to_vec()),
+        )]))
+        .unwrap();
+        assert_eq!(*map["a"], 1);
+        assert_eq!(map["a"].as_bytes(), b"1");
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack() {
+        assert_eq!(roundtrip(MsgPack::new(user())).into_inner(), user());
+        assert_eq!(roundtrip(MsgPack::new(42u64)).into_inner(), 42);
+        assert_parsing_error::<MsgPack<User>>(&[0xc1]);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode() {
+        assert_eq!(roundtrip(Bincode::new(user())).into_inner(), user());
+        assert_eq!(roundtrip(Bincode::new(-7i32)).into_inner(), -7);
+        let mut bytes = Bincode::new(1u8).unwrap().as_bytes().to_vec();
+        bytes.push(0);
+        assert_parsing_error::<Bincode<u8>>(&bytes);
+        assert_parsing_error::<Bincode<String>>(&[10, b'a']);
+    }
+}
@@ -167,8 +167,8 @@ pub struct AclInfo {
 impl AclInfo {
     fn handle_pair(&mut self, name: &Value, value: &Value) -> Result<(), ParsingError> {
         // Expect name to be a bulk string
-        let key = match name {
-            Value::BulkString(bs) => {
+        let key = match name.as_bulk_string() {
+            Some(bs) => {
                 // convert to owned String and trim optional surrounding quotes
                 let mut s = std::str::from_utf8(bs)?.trim().to_owned();
                 if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
@@ -176,7 +176,7 @@ impl AclInfo {
                 }
                 s
             }
-            _ => {
+            None => {
                 return Err(not_convertible_error!(
                     name,
                     "Expect a bulk string key name"
@@ -191,8 +191,8 @@ impl AclInfo {
                         not_convertible_error!(value, "Expect an array response of ACL flags")
                     })?
                     .iter()
-                    .map(|flag| match flag {
-                        Value::BulkString(flag) => match flag.as_slice() {
+                    .map(|flag| match flag.as_bulk_string() {
+                        Some(flag) => match flag {
                             b"on" => Ok(Rule::On),
                             b"off" => Ok(Rule::Off),
                             b"allkeys" => Ok(Rule::AllKeys),
@@ -200,7 +200,7 @@ impl AclInfo {
                             b"nopass" => Ok(Rule::NoPass),
                             other => Ok(Rule::Other(String::from_utf8_lossy(other).into_owned())),
                         },
-                        _ => Err(not_convertible_error!(
+                        None => Err(not_convertible_error!(
                             flag,
                             "Expect an arbitrary binary data"
                         )),
@@ -223,9 +223,9 @@ impl AclInfo {
                 self.passwords = p;
             }
             "commands" => {
-                let cmds = match value {
-                    Value::BulkString(cmd) => std::str::from_utf8(cmd)?,
-                    _ => {
+                let cmds = match value.as_bulk_string() {
+                    Some(cmd) => std::str::from_utf8(cmd)?,
+                    None => {
                         return Err(not_convertible_error!(
                             value,
                             "Expect a valid UTF8 string for commands"
@@ -247,8 +247,8 @@ impl AclInfo {
                 self.commands = cmds;
             }
             "keys" => {
-                let parsed = match value {
-                    Value::Array(arr) => arr
+                let parsed = match (value, value.as_bulk_string()) {
+                    (Value::Array(arr), _) => arr
                         .iter()
                         .map(|pat| {
                             let s = String::from_redis_value_ref(pat)?;
@@ -258,7 +258,7 @@ impl AclInfo {
                             }
                         })
                         .collect::<Result<_, ParsingError>>()?,
-                    Value::BulkString(bs) => {
+                    (_, Some(bs)) => {
                         let mut s = std::str::from_utf8(bs)?;
                         s = s.trim();
                         if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
@@ -278,7 +278,7 @@ impl AclInfo {
                             })
                             .collect::<Result<_, ParsingError>>()?
                     }
-                    other => {
+                    (other, None) => {
                         return Err(not_convertible_error!(
                             other,
                             "Expect an array or bulk-string of keys"
@@ -288,8 +288,8 @@ impl AclInfo {
                 self.keys = parsed;
             }
             "channels" => {
-                let parsed = match value {
-                    Value::Array(arr) | Value::Set(arr) => arr
+                let parsed = match (value, value.as_bulk_string()) {
+                    (Value::Array(arr) | Value::Set(arr), _) => arr
                         .iter()
                         .map(|pat| {
                             let s = String::from_redis_value_ref(pat)?;
@@ -301,7 +301,7 @@ impl AclInfo {
                             Ok(Rule::Channel(s))
                         })
                         .collect::<Result<_, ParsingError>>()?,
-                    Value::BulkString(bs) => {
+                    (_, Some(bs)) => {
                         let mut s = std::str::from_utf8(bs)?;
                         s = s.trim();
                         if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
@@ -318,7 +318,7 @@ impl AclInfo {
                             })
                             .collect::<Result<_, ParsingError>>()?
                     }
-                    other => {
+                    (other, None) => {
                         return Err(not_convertible_error!(
                             other,
                             "Expect an array or bulk-string of channels"
@@ -0,0 +1,774 @@
+//! Defines types to use with the Redis Functions commands.
+//!
+//! Redis 7 introduced [Functions](https://redis.io/docs/latest/develop/interact/programmability/functions-intro/)
+//! as the successor of `EVAL` scripts. Instead of being sent along with every invocation, functions
+//! are grouped in libraries that are loaded once with `FUNCTION LOAD` and then called by name
+//! with `FCALL` or `FCALL_RO`.
+//!
+//! [`Library`] holds the source code of a library and [`Function`] names a single function that
+//! can be invoked with keys and arguments:
+//!
+//! ```rust,no_run
+//! # fn do_something() -> redis::RedisResult<()> {
+//! # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+//! # let mut con = client.get_connection().unwrap();
+//! let library = redis::Library::new(r"#!lua name=mylib
+//! redis.register_function('add', function(keys, args)
+//!     return tonumber(args[1]) + tonumber(args[2])
+//! end)
+//! ");
+//! library.load_replace(&mut con)?;
+//!
+//! let result: isize = library.function("add").arg(1).arg(2).invoke(&mut con)?;
+//! assert_eq!(result, 3);
+//! # Ok(()) }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::cmd::{Cmd, cmd};
+use crate::connection::ConnectionLike;
+use crate::errors::{ErrorKind, ParsingError, RedisError};
+use crate::types::{
+    FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value, from_redis_value,
+};
+
+/// The source code of a function library.
+///
+/// The library name is taken from the shebang line (`#!<engine> name=<library name>`) that every
+/// library has to start with.
+///
+/// Loading a library through a cluster connection loads it on every primary node.
+#[derive(Debug, Clone)]
+pub struct Library {
+    code: String,
+    name: Option<String>,
+}
+
+impl Library {
+    /// Creates a new library object from its source code.
+    pub fn new(code: &str) -> Library {
+        Library {
+            code: code.to_string(),
+            name: parse_library_name(code),
+        }
+    }
+
+    /// Returns the library name declared in the shebang line, if there is one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the source code of the library.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns a command to load the library.
+    pub(crate) fn load_cmd(&self, replace: bool) -> Cmd {
+        let mut cmd = cmd("FUNCTION");
+        cmd.arg("LOAD");
+        if replace {
+            cmd.arg("REPLACE");
+        }
+        cmd.arg(self.code.as_bytes());
+        cmd
+    }
+
+    /// Loads the library and returns its name.
+    ///
+    /// Fails if a library with the same name is already loaded.
+    #[inline]
+    pub fn load(&self, con: &mut dyn ConnectionLike) -> RedisResult<String> {
+        self.load_cmd(false).query(con)
+    }
+
+    /// Loads the library, replacing an already loaded library with the same name, and returns
+    /// its name.
+    #[inline]
+    pub fn load_replace(&self, con: &mut dyn ConnectionLike) -> RedisResult<String> {
+        self.load_cmd(true).query(con)
+    }
+
+    /// Asynchronously loads the library and returns its name.
+    ///
+    /// Fails if a library with the same name is already loaded.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn load_async<C>(&self, con: &mut C) -> RedisResult<String>
+    where
+        C: crate::aio::ConnectionLike,
+    {
+        self.load_cmd(false).query_async(con).await
+    }
+
+    /// Asynchronously loads the library, replacing an already loaded library with the same name,
+    /// and returns its name.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn load_replace_async<C>(&self, con: &mut C) -> RedisResult<String>
+    where
+        C: crate::aio::ConnectionLike,
+    {
+        self.load_cmd(true).query_async(con).await
+    }
+
+    /// Creates a function object for a function registered by this library.
+    ///
+    /// Invoking the returned function loads the library automatically if the server does not
+    /// know the function yet.
+    #[inline]
+    pub fn function(&self, name: &str) -> Function<'_> {
+        Function {
+            name: name.to_string(),
+            library: Some(self),
+        }
+    }
+}
+
+fn parse_library_name(code: &str) -> Option<String> {
+    let shebang = code.trim_start().lines().next()?.strip_prefix("#!")?;
+    shebang
+        .split_whitespace()
+        .skip(1)
+        .find_map(|param| param.strip_prefix("name="))
+        .map(str::to_string)
+}
+
+/// A function that can be called with `FCALL` or `FCALL_RO`.
+///
+/// A function is either created from its [`Library`] with [`Library::function`], in which case
+/// the library gets loaded on demand, or on its own with [`Function::new`] for functions that
+/// are known to be loaded already.
+#[derive(Debug, Clone)]
+pub struct Function<'a> {
+    name: String,
+    library: Option<&'a Library>,
+}
+
+impl Function<'static> {
+    /// Creates a function object for a function that is already loaded on the server.
+    pub fn new(name: &str) -> Function<'static> {
+        Function {
+            name: name.to_string(),
+            library: None,
+        }
+    }
+}
+
+impl Function<'_> {
+    /// Returns the name of the function.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates a function invocation object with a key filled in.
+    #[inline]
+    pub fn key<T: ToRedisArgs>(&self, key: T) -> FunctionInvocation<'_> {
+        FunctionInvocation {
+            name: &self.name,
+            library: self.library,
+            keys: key.to_redis_args(),
+            args: vec![],
+        }
+    }
+
+    /// Creates a function invocation object with an argument filled in.
+    #[inline]
+    pub fn arg<T: ToRedisArgs>(&self, arg: T) -> FunctionInvocation<'_> {
+        FunctionInvocation {
+            name: &self.name,
+            library: self.library,
+            keys: vec![],
+            args: arg.to_redis_args(),
+        }
+    }
+
+    /// Returns an empty function invocation object.  This is primarily useful
+    /// for programmatically adding arguments and keys because the type will
+    /// not change.  Normally you can use `arg` and `key` directly.
+    #[inline]
+    pub fn prepare_invoke(&self) -> FunctionInvocation<'_> {
+        FunctionInvocation {
+            name: &self.name,
+            library: self.library,
+            keys: vec![],
+            args: vec![],
+        }
+    }
+
+    /// Invokes the function with `FCALL` without keys or arguments.
+    #[inline]
+    pub fn invoke<T: FromRedisValue>(&self, con: &mut dyn ConnectionLike) -> RedisResult<T> {
+        self.prepare_invoke().invoke(con)
+    }
+
+    /// Invokes the function with `FCALL_RO` without keys or arguments.
+    #[inline]
+    pub fn invoke_ro<T: FromRedisValue>(&self, con: &mut dyn ConnectionLike) -> RedisResult<T> {
+        self.prepare_invoke().invoke_ro(con)
+    }
+
+    /// Asynchronously invokes the function with `FCALL` without keys or arguments.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn invoke_async<C, T>(&self, con: &mut C) -> RedisResult<T>
+    where
+        C: crate::aio::ConnectionLike,
+        T: FromRedisValue,
+    {
+        self.prepare_invoke().invoke_async(con).await
+    }
+
+    /// Asynchronously invokes the function with `FCALL_RO` without keys or arguments.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn invoke_ro_async<C, T>(&self, con: &mut C) -> RedisResult<T>
+    where
+        C: crate::aio::ConnectionLike,
+        T: FromRedisValue,
+    {
+        self.prepare_invoke().invoke_ro_async(con).await
+    }
+}
+
+/// Represents a prepared function call.
+///
+/// This type collects keys and other arguments for the function so that it
+/// can be then invoked.
+pub struct FunctionInvocation<'a> {
+    name: &'a str,
+    library: Option<&'a Library>,
+    keys: Vec<Vec<u8>>,
+    args: Vec<Vec<u8>>,
+}
+
+impl<'a> FunctionInvocation<'a> {
+    /// Adds a regular argument to the invocation.  This ends up in the
+    /// `args` table of the function.
+    #[inline]
+    pub fn arg<'b, T: ToRedisArgs>(&'b mut self, arg: T) -> &'b mut FunctionInvocation<'a>
+    where
+        'a: 'b,
+    {
+        arg.write_redis_args(&mut self.args);
+        self
+    }
+
+    /// Adds a key argument to the invocation.  This ends up in the `keys`
+    /// table of the function.
+    #[inline]
+    pub fn key<'b, T: ToRedisArgs>(&'b mut self, key: T) -> &'b mut FunctionInvocation<'a>
+    where
+        'a: 'b,
+    {
+        key.write_redis_args(&mut self.keys);
+        self
+    }
+
+    /// Invokes the function with `FCALL` and returns the result.
+    #[inline]
+    pub fn invoke<T: FromRedisValue>(&self, con: &mut dyn ConnectionLike) -> RedisResult<T> {
+        self.invoke_cmd(self.fcall_cmd(false), con)
+    }
+
+    /// Invokes the function with `FCALL_RO` and returns the result.
+    ///
+    /// The function has to be registered with the `no-writes` flag. In cluster mode, read-only
+    /// calls may be routed to replicas.
+    #[inline]
+    pub fn invoke_ro<T: FromRedisValue>(&self, con: &mut dyn ConnectionLike) -> RedisResult<T> {
+        self.invoke_cmd(self.fcall_cmd(true), con)
+    }
+
+    fn invoke_cmd<T: FromRedisValue>(
+        &self,
+        fcall_cmd: Cmd,
+        con: &mut dyn ConnectionLike,
+    ) -> RedisResult<T> {
+        match fcall_cmd.query(con) {
+            Ok(val) => Ok(val),
+            Err(err) => match self.library {
+                Some(library) if is_function_not_found(&err) => {
+                    library.load_replace(con)?;
+                    fcall_cmd.query(con)
+                }
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Asynchronously invokes the function with `FCALL` and returns the result.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn invoke_async<T: FromRedisValue>(
+        &self,
+        con: &mut impl crate::aio::ConnectionLike,
+    ) -> RedisResult<T> {
+        self.invoke_cmd_async(self.fcall_cmd(false), con).await
+    }
+
+    /// Asynchronously invokes the function with `FCALL_RO` and returns the result.
+    ///
+    /// The function has to be registered with the `no-writes` flag. In cluster mode, read-only
+    /// calls may be routed to replicas.
+    #[inline]
+    #[cfg(feature = "aio")]
+    pub async fn invoke_ro_async<T: FromRedisValue>(
+        &self,
+        con: &mut impl crate::aio::ConnectionLike,
+    ) -> RedisResult<T> {
+        self.invoke_cmd_async(self.fcall_cmd(true), con).await
+    }
+
+    #[cfg(feature = "aio")]
+    async fn invoke_cmd_async<T: FromRedisValue>(
+        &self,
+        fcall_cmd: Cmd,
+        con: &mut impl crate::aio::ConnectionLike,
+    ) -> RedisResult<T> {
+        match fcall_cmd.query_async(con).await {
+            Ok(val) => Ok(val),
+            Err(err) => match self.library {
+                // Load the library if the function wasn't there already
+                Some(library) if is_function_not_found(&err) => {
+                    library.load_replace_async(con).await?;
+                    fcall_cmd.query_async(con).await
+                }
+                _ => Err(err),
+            },
+        }
+    }
+
+    /// Returns a command to call the function.
+    pub(crate) fn fcall_cmd(&self, read_only: bool) -> Cmd {
+        let mut cmd = cmd(if read_only { "FCALL_RO" } else { "FCALL" });
+        cmd.arg(self.name)
+            .arg(self.keys.len())
+            .arg(&*self.keys)
+            .arg(&*self.args);
+        cmd
+    }
+}
+
+fn is_function_not_found(err: &RedisError) -> bool {
+    err.kind() == ErrorKind::Server(crate::ServerErrorKind::ResponseError)
+        && err
+            .detail()
+            .is_some_and(|detail| detail.starts_with("Function not found"))
+}
+
+/// Options for the [`FUNCTION LIST`](https://redis.io/commands/function-list) command
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult};
+/// use redis::function::{FunctionListOptions, LibraryInfo};
+/// fn list_my_libraries(con: &mut redis::Connection) -> RedisResult<Vec<LibraryInfo>> {
+///     let opts = FunctionListOptions::default()
+///         .library_name("my*")
+///         .with_code();
+///     con.function_list_options(opts)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FunctionListOptions {
+    library_name: Option<String>,
+    with_code: bool,
+}
+
+impl FunctionListOptions {
+    /// Only list libraries whose names match the given pattern.
+    pub fn library_name(mut self, pattern: impl Into<String>) -> Self {
+        self.library_name = Some(pattern.into());
+        self
+    }
+
+    /// Include the source code of each library in the reply.
+    pub fn with_code(mut self) -> Self {
+        self.with_code = true;
+        self
+    }
+}
+
+impl ToRedisArgs for FunctionListOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(pattern) = &self.library_name {
+            out.write_arg(b"LIBRARYNAME");
+            out.write_arg(pattern.as_bytes());
+        }
+        if self.with_code {
+            out.write_arg(b"WITHCODE");
+        }
+    }
+
+    fn num_of_args(&self) -> usize {
+        let mut len = 0;
+        if self.library_name.is_some() {
+            len += 2;
+        }
+        if self.with_code {
+            len += 1;
+        }
+        len
+    }
+}
+
+/// How [`function_restore`][1] handles libraries that already exist on the server.
+///
+/// [1]: ../trait.Commands.html#method.function_restore
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FunctionRestorePolicy {
+    /// Append the restored libraries, failing if a library already exists. This is the server default.
+    #[default]
+    Append,
+    /// Append the restored libraries, replacing existing libraries with the same name.
+    Replace,
+    /// Delete all existing libraries before restoring the payload.
+    Flush,
+}
+
+impl ToRedisArgs for FunctionRestorePolicy {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        let policy: &[u8] = match self {
+            FunctionRestorePolicy::Append => b"APPEND",
+            FunctionRestorePolicy::Replace => b"REPLACE",
+            FunctionRestorePolicy::Flush => b"FLUSH",
+        };
+        out.write_arg(policy);
+    }
+}
+
+impl ToSingleRedisArg for FunctionRestorePolicy {}
+
+/// A library returned by [`function_list`][1].
+///
+/// [1]: ../trait.Commands.html#method.function_list
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LibraryInfo {
+    /// The name of the library.
+    pub name: String,
+    /// The engine of the library, e.g. `LUA`.
+    pub engine: String,
+    /// The functions registered by the library.
+    pub functions: Vec<FunctionInfo>,
+    /// The source code of the library, if it was requested with
+    /// [`FunctionListOptions::with_code`].
+    pub code: Option<String>,
+}
+
+/// A function of a [`LibraryInfo`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FunctionInfo {
+    /// The name of the function.
+    pub name: String,
+    /// The description of the function, if one was registered.
+    pub description: Option<String>,
+    /// The flags of the function, e.g. `no-writes`.
+    pub flags: Vec<String>,
+}
+
+impl FromRedisValue for LibraryInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut info = LibraryInfo::default();
+        if let Some(v) = map.remove("library_name") {
+            info.name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("engine") {
+            info.engine = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("functions") {
+            info.functions = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("library_code") {
+            info.code = from_redis_value(v)?;
+        }
+        Ok(info)
+    }
+}
+
+impl FromRedisValue for FunctionInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut info = FunctionInfo::default();
+        if let Some(v) = map.remove("name") {
+            info.name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("description") {
+            info.description = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("flags") {
+            info.flags = from_redis_value(v)?;
+        }
+        Ok(info)
+    }
+}
+
+/// Reply of [`function_stats`][1].
+///
+/// On cluster connections `FUNCTION STATS` is sent to every node, so the reply has to be parsed as
+/// a map from node address to `FunctionStats` instead.
+///
+/// [1]: ../trait.Commands.html#method.function_stats
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FunctionStats {
+    /// The function that is currently running, if any.
+    pub running_script: Option<RunningFunction>,
+    /// Statistics per engine, keyed by the engine name.
+    pub engines: HashMap<String, EngineStats>,
+}
+
+/// A function that is running while [`FunctionStats`] are collected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RunningFunction {
+    /// The name of the function.
+    pub name: String,
+    /// The command and arguments used to invoke the function.
+    pub command: Vec<String>,
+    /// The run time of the function so far, in milliseconds.
+    pub duration_ms: u64,
+}
+
+/// Statistics of a single engine in [`FunctionStats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EngineStats {
+    /// Number of loaded libraries.
+    pub libraries_count: usize,
+    /// Number of loaded functions.
+    pub functions_count: usize,
+}
+
+impl FromRedisValue for FunctionStats {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut stats = FunctionStats::default();
+        if let Some(v) = map.remove("running_script") {
+            stats.running_script = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("engines") {
+            stats.engines = from_redis_value(v)?;
+        }
+        Ok(stats)
+    }
+}
+
+impl FromRedisValue for RunningFunction {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut running = RunningFunction::default();
+        if let Some(v) = map.remove("name") {
+            running.name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("command") {
+            running.command = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("duration_ms") {
+            running.duration_ms = from_redis_value(v)?;
+        }
+        Ok(running)
+    }
+}
+
+impl FromRedisValue for EngineStats {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut stats = EngineStats::default();
+        if let Some(v) = map.remove("libraries_count") {
+            stats.libraries_count = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("functions_count") {
+            stats.functions_count = from_redis_value(v)?;
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::bulk;
+
+    #[test]
+    fn test_library_name_from_shebang() {
+        let library =
+            Library::new("#!lua name=mylib\nredis.register_function('f', function() end)");
+        assert_eq!(library.name(), Some("mylib"));
+
+        let library = Library::new("\n  #!lua  name=other  \nreturn");
+        assert_eq!(library.name(), Some("other"));
+
+        let library = Library::new("redis.register_function('f', function() end)");
+        assert_eq!(library.name(), None);
+    }
+
+    #[test]
+    fn test_load_cmd() {
+        let library = Library::new("#!lua name=mylib\n");
+        assert_eq!(
+            library.load_cmd(true).get_packed_command(),
+            cmd("FUNCTION")
+                .arg("LOAD")
+                .arg("REPLACE")
+                .arg("#!lua name=mylib\n")
+                .get_packed_command()
+        );
+        assert_eq!(
+            library.load_cmd(false).get_packed_command(),
+            cmd("FUNCTION")
+                .arg("LOAD")
+                .arg("#!lua name=mylib\n")
+                .get_packed_command()
+        );
+    }
+
+    #[test]
+    fn test_fcall_cmd() {
+        let function = Function::new("myfunc");
+        let mut invocation = function.key("k1");
+        invocation.key("k2").arg(42).arg("foo");
+
+        assert_eq!(
+            invocation.fcall_cmd(false).get_packed_command(),
+            cmd("FCALL")
+                .arg("myfunc")
+                .arg(2)
+                .arg("k1")
+                .arg("k2")
+                .arg(42)
+                .arg("foo")
+                .get_packed_command()
+        );
+        assert_eq!(
+            function
+                .prepare_invoke()
+                .fcall_cmd(true)
+                .get_packed_command(),
+            cmd("FCALL_RO").arg("myfunc").arg(0).get_packed_command()
+        );
+    }
+
+    #[test]
+    fn test_function_list_options() {
+        assert_eq!(FunctionListOptions::default().to_redis_args().len(), 0);
+
+        let opts = FunctionListOptions::default()
+            .library_name("my*")
+            .with_code();
+        assert_eq!(
+            opts.to_redis_args(),
+            vec![
+                b"LIBRARYNAME".to_vec(),
+                b"my*".to_vec(),
+                b"WITHCODE".to_vec()
+            ]
+        );
+        assert_eq!(opts.num_of_args(), 3);
+    }
+
+    #[test]
+    fn test_parse_library_info_resp2() {
+        let value = Value::Array(vec![
+            bulk("library_name"),
+            bulk("mylib"),
+            bulk("engine"),
+            bulk("LUA"),
+            bulk("functions"),
+            Value::Array(vec![Value::Array(vec![
+                bulk("name"),
+                bulk("myfunc"),
+                bulk("description"),
+                Value::Nil,
+                bulk("flags"),
+                Value::Array(vec![bulk("no-writes")]),
+            ])]),
+            bulk("library_code"),
+            bulk("#!lua name=mylib"),
+        ]);
+
+        assert_eq!(
+            LibraryInfo::from_redis_value(value).unwrap(),
+            LibraryInfo {
+                name: "mylib".to_string(),
+                engine: "LUA".to_string(),
+                functions: vec![FunctionInfo {
+                    name: "myfunc".to_string(),
+                    description: None,
+                    flags: vec!["no-writes".to_string()],
+                }],
+                code: Some("#!lua name=mylib".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_function_stats_resp3() {
+        let value = Value::Map(vec![
+            (
+                bulk("running_script"),
+                Value::Map(vec![
+                    (bulk("name"), bulk("myfunc")),
+                    (
+                        bulk("command"),
+                        Value::Array(vec![bulk("fcall"), bulk("myfunc"), bulk("0")]),
+                    ),
+                    (bulk("duration_ms"), Value::Int(1500)),
+                ]),
+            ),
+            (
+                bulk("engines"),
+                Value::Map(vec![(
+                    bulk("LUA"),
+                    Value::Map(vec![
+                        (bulk("libraries_count"), Value::Int(1)),
+                        (bulk("functions_count"), Value::Int(2)),
+                    ]),
+                )]),
+            ),
+        ]);
+
+        let stats = FunctionStats::from_redis_value(value).unwrap();
+        assert_eq!(
+            stats.running_script,
+            Some(RunningFunction {
+                name: "myfunc".to_string(),
+                command: vec!["fcall".to_string(), "myfunc".to_string(), "0".to_string()],
+                duration_ms: 1500,
+            })
+        );
+        assert_eq!(
+            stats.engines.get("LUA"),
+            Some(&EngineStats {
+                libraries_count: 1,
+                functions_count: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_function_stats_without_running_script() {
+        let value = Value::Array(vec![
+            bulk("running_script"),
+            Value::Nil,
+            bulk("engines"),
+            Value::Array(vec![]),
+        ]);
+
+        let stats = FunctionStats::from_redis_value(value).unwrap();
+        assert_eq!(stats.running_script, None);
+        assert!(stats.engines.is_empty());
+    }
+}
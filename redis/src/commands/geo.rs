@@ -3,10 +3,11 @@
 use crate::errors::{ParsingError, invalid_type_error};
 use crate::types::{FromRedisValue, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
 
-/// Units used by [`geo_dist`][1] and [`geo_radius`][2].
+/// Units used by [`geo_dist`][1], [`geo_radius`][2] and [`geo_search`][3].
 ///
 /// [1]: ../trait.Commands.html#method.geo_dist
 /// [2]: ../trait.Commands.html#method.geo_radius
+/// [3]: ../trait.Commands.html#method.geo_search
 #[non_exhaustive]
 pub enum Unit {
     /// Represents meters.
@@ -98,10 +99,13 @@ impl<T: ToRedisArgs> ToRedisArgs for Coord<T> {
     }
 }
 
-/// Options to sort results from [GEORADIUS][1] and [GEORADIUSBYMEMBER][2] commands
+/// Options to sort results from [GEORADIUS][1], [GEORADIUSBYMEMBER][2], [GEOSEARCH][3] and
+/// [GEOSEARCHSTORE][4] commands
 ///
 /// [1]: https://redis.io/commands/georadius
 /// [2]: https://redis.io/commands/georadiusbymember
+/// [3]: https://redis.io/commands/geosearch
+/// [4]: https://redis.io/commands/geosearchstore
 #[derive(Default)]
 #[non_exhaustive]
 pub enum RadiusOrder {
@@ -268,8 +272,8 @@ pub struct RadiusSearchResult {
 
 impl FromRedisValue for RadiusSearchResult {
     fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
-        match v {
-            Value::BulkString(b) => {
+        match v.into_bulk_string() {
+            Ok(b) => {
                 let s = String::from_utf8(b)?;
                 Ok(RadiusSearchResult {
                     name: s,
@@ -277,8 +281,8 @@ impl FromRedisValue for RadiusSearchResult {
                     dist: None,
                 })
             }
-            Value::Array(items) => RadiusSearchResult::parse_multi_values(items),
-            _ => invalid_type_error!(v, "Response type not RadiusSearchResult compatible."),
+            Err(Value::Array(items)) => RadiusSearchResult::parse_multi_values(items),
+            Err(v) => invalid_type_error!(v, "Response type not RadiusSearchResult compatible."),
         }
     }
 }
@@ -315,10 +319,306 @@ impl RadiusSearchResult {
     }
 }
 
+/// The center of the area searched by [`geo_search`][1] and [`geo_search_store`][2].
+///
+/// [1]: ../trait.Commands.html#method.geo_search
+/// [2]: ../trait.Commands.html#method.geo_search_store
+#[non_exhaustive]
+pub enum GeoSearchFrom {
+    /// Use the position of an existing member of the sorted set (`FROMMEMBER`).
+    Member(String),
+    /// Use the given position (`FROMLONLAT`).
+    LonLat(Coord<f64>),
+}
+
+impl ToRedisArgs for GeoSearchFrom {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            GeoSearchFrom::Member(member) => {
+                out.write_arg(b"FROMMEMBER");
+                out.write_arg(member.as_bytes());
+            }
+            GeoSearchFrom::LonLat(coord) => {
+                out.write_arg(b"FROMLONLAT");
+                coord.write_redis_args(out);
+            }
+        }
+    }
+}
+
+/// The shape of the area searched by [`geo_search`][1] and [`geo_search_store`][2].
+///
+/// [1]: ../trait.Commands.html#method.geo_search
+/// [2]: ../trait.Commands.html#method.geo_search_store
+#[non_exhaustive]
+pub enum GeoSearchBy {
+    /// A circle with the given radius (`BYRADIUS`).
+    Radius(f64, Unit),
+    /// An axis-aligned rectangle with the given width and height (`BYBOX`).
+    Box(f64, f64, Unit),
+}
+
+impl ToRedisArgs for GeoSearchBy {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            GeoSearchBy::Radius(radius, unit) => {
+                out.write_arg(b"BYRADIUS");
+                radius.write_redis_args(out);
+                unit.write_redis_args(out);
+            }
+            GeoSearchBy::Box(width, height, unit) => {
+                out.write_arg(b"BYBOX");
+                width.write_redis_args(out);
+                height.write_redis_args(out);
+                unit.write_redis_args(out);
+            }
+        }
+    }
+}
+
+/// Writes `ASC`/`DESC` and `COUNT count [ANY]`, which are shared by both search commands.
+fn write_search_order_and_count<W>(order: &RadiusOrder, count: Option<(usize, bool)>, out: &mut W)
+where
+    W: ?Sized + RedisWrite,
+{
+    match order {
+        RadiusOrder::Asc => out.write_arg(b"ASC"),
+        RadiusOrder::Desc => out.write_arg(b"DESC"),
+        _ => (),
+    };
+
+    if let Some((n, any)) = count {
+        out.write_arg(b"COUNT");
+        out.write_arg_fmt(n);
+        if any {
+            out.write_arg(b"ANY");
+        }
+    }
+}
+
+/// Options for the [GEOSEARCH][1] command
+///
+/// [1]: https://redis.io/commands/geosearch
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult};
+/// use redis::geo::{Coord, GeoSearchBy, GeoSearchFrom, GeoSearchOptions, GeoSearchResult, RadiusOrder, Unit};
+/// fn nearest_in_box(
+///     con: &mut redis::Connection,
+///     key: &str,
+///     longitude: f64,
+///     latitude: f64,
+///     limit: usize,
+/// ) -> RedisResult<Vec<GeoSearchResult>> {
+///     let opts = GeoSearchOptions::default()
+///         .order(RadiusOrder::Asc)
+///         .limit(limit)
+///         .with_dist();
+///     con.geo_search(
+///         key,
+///         GeoSearchFrom::LonLat(Coord::lon_lat(longitude, latitude)),
+///         GeoSearchBy::Box(400.0, 200.0, Unit::Kilometers),
+///         opts,
+///     )
+/// }
+/// ```
+#[derive(Default)]
+#[non_exhaustive]
+pub struct GeoSearchOptions {
+    order: RadiusOrder,
+    count: Option<(usize, bool)>,
+    with_coord: bool,
+    with_dist: bool,
+    with_hash: bool,
+}
+
+impl GeoSearchOptions {
+    /// Sort the returned items
+    pub fn order(mut self, o: RadiusOrder) -> Self {
+        self.order = o;
+        self
+    }
+
+    /// Limit the results to the first N matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.count = Some((n, false));
+        self
+    }
+
+    /// Limit the results to N matching items, returning as soon as enough matches are found.
+    /// The results may not be the ones closest to the center.
+    pub fn limit_any(mut self, n: usize) -> Self {
+        self.count = Some((n, true));
+        self
+    }
+
+    /// Return the `longitude, latitude` coordinates of the matching items.
+    pub fn with_coord(mut self) -> Self {
+        self.with_coord = true;
+        self
+    }
+
+    /// Return the distance of the returned items from the center, in the unit of the
+    /// search shape.
+    pub fn with_dist(mut self) -> Self {
+        self.with_dist = true;
+        self
+    }
+
+    /// Return the raw geohash-encoded sorted set score of the matching items.
+    pub fn with_hash(mut self) -> Self {
+        self.with_hash = true;
+        self
+    }
+}
+
+impl ToRedisArgs for GeoSearchOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        write_search_order_and_count(&self.order, self.count, out);
+
+        if self.with_coord {
+            out.write_arg(b"WITHCOORD");
+        }
+
+        if self.with_dist {
+            out.write_arg(b"WITHDIST");
+        }
+
+        if self.with_hash {
+            out.write_arg(b"WITHHASH");
+        }
+    }
+}
+
+/// Options for the [GEOSEARCHSTORE][1] command
+///
+/// [1]: https://redis.io/commands/geosearchstore
+#[derive(Default)]
+#[non_exhaustive]
+pub struct GeoSearchStoreOptions {
+    order: RadiusOrder,
+    count: Option<(usize, bool)>,
+    store_dist: bool,
+}
+
+impl GeoSearchStoreOptions {
+    /// Sort the stored items, which matters when limiting them.
+    pub fn order(mut self, o: RadiusOrder) -> Self {
+        self.order = o;
+        self
+    }
+
+    /// Limit the stored items to the first N matching items.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.count = Some((n, false));
+        self
+    }
+
+    /// Limit the stored items to N matching items, stopping as soon as enough matches are
+    /// found. The items may not be the ones closest to the center.
+    pub fn limit_any(mut self, n: usize) -> Self {
+        self.count = Some((n, true));
+        self
+    }
+
+    /// Store the distance from the center as score, instead of the geospatial information.
+    pub fn store_dist(mut self) -> Self {
+        self.store_dist = true;
+        self
+    }
+}
+
+impl ToRedisArgs for GeoSearchStoreOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        write_search_order_and_count(&self.order, self.count, out);
+
+        if self.store_dist {
+            out.write_arg(b"STOREDIST");
+        }
+    }
+}
+
+/// Contain an item returned by [`geo_search`][1].
+///
+/// [1]: ../trait.Commands.html#method.geo_search
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct GeoSearchResult {
+    /// The name that was found.
+    pub name: String,
+    /// The coordinate if available.
+    pub coord: Option<Coord<f64>>,
+    /// The distance if available.
+    pub dist: Option<f64>,
+    /// The geohash-encoded score if available.
+    pub hash: Option<u64>,
+}
+
+impl FromRedisValue for GeoSearchResult {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let items = match v {
+            Value::Array(items) => items,
+            v if v.as_bulk_string().is_some() || matches!(v, Value::SimpleString(_)) => {
+                return Ok(GeoSearchResult {
+                    name: FromRedisValue::from_redis_value(v)?,
+                    coord: None,
+                    dist: None,
+                    hash: None,
+                });
+            }
+            _ => invalid_type_error!(v, "Response type not GeoSearchResult compatible."),
+        };
+
+        let mut iter = items.into_iter();
+
+        // First item is always the member name
+        let name: String = match iter.next().map(FromRedisValue::from_redis_value) {
+            Some(Ok(n)) => n,
+            _ => return Err(arcstr::literal!("Missing member name").into()),
+        };
+
+        // The remaining items are ordered as distance, hash and coordinate, each being optional,
+        // so they are told apart by their type.
+        let mut result = GeoSearchResult {
+            name,
+            coord: None,
+            dist: None,
+            hash: None,
+        };
+        for item in iter {
+            match item {
+                Value::Array(_) => result.coord = Some(Coord::from_redis_value(item)?),
+                Value::Int(hash) => result.hash = Some(hash as u64),
+                _ => result.dist = Some(FromRedisValue::from_redis_value(item)?),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Coord, RadiusOptions, RadiusOrder};
-    use crate::types::ToRedisArgs;
+    use super::{
+        Coord, GeoSearchBy, GeoSearchFrom, GeoSearchOptions, GeoSearchResult,
+        GeoSearchStoreOptions, RadiusOptions, RadiusOrder, Unit,
+    };
+    use crate::test_utils::bulk;
+    use crate::types::{FromRedisValue, ToRedisArgs, Value};
     use std::str;
 
     macro_rules! assert_args {
@@ -368,4 +668,105 @@ mod tests {
             "ASC"
         );
     }
+
+    #[test]
+    fn test_geo_search_args() {
+        assert_args!(
+            GeoSearchFrom::Member("Palermo".to_string()),
+            "FROMMEMBER",
+            "Palermo"
+        );
+        assert_args!(
+            GeoSearchFrom::LonLat(Coord::lon_lat(15.0, 37.0)),
+            "FROMLONLAT",
+            "15.0",
+            "37.0"
+        );
+        assert_args!(
+            GeoSearchBy::Radius(200.0, Unit::Kilometers),
+            "BYRADIUS",
+            "200.0",
+            "km"
+        );
+        assert_args!(
+            GeoSearchBy::Box(400.0, 100.5, Unit::Miles),
+            "BYBOX",
+            "400.0",
+            "100.5",
+            "mi"
+        );
+
+        assert_eq!(GeoSearchOptions::default().to_redis_args().len(), 0);
+        assert_args!(
+            GeoSearchOptions::default()
+                .with_hash()
+                .with_dist()
+                .with_coord()
+                .limit_any(3)
+                .order(RadiusOrder::Desc),
+            "DESC",
+            "COUNT",
+            "3",
+            "ANY",
+            "WITHCOORD",
+            "WITHDIST",
+            "WITHHASH"
+        );
+        assert_args!(
+            GeoSearchStoreOptions::default()
+                .store_dist()
+                .limit(5)
+                .order(RadiusOrder::Asc),
+            "ASC",
+            "COUNT",
+            "5",
+            "STOREDIST"
+        );
+    }
+
+    #[test]
+    fn test_geo_search_result() {
+        let result = GeoSearchResult::from_redis_value(bulk("Palermo")).unwrap();
+        assert_eq!(result.name, "Palermo");
+        assert_eq!((result.coord, result.dist, result.hash), (None, None, None));
+
+        // WITHCOORD WITHDIST WITHHASH
+        let result = GeoSearchResult::from_redis_value(Value::Array(vec![
+            bulk("Palermo"),
+            bulk("190.4424"),
+            Value::Int(3479099956230698),
+            Value::Array(vec![bulk("13.5"), bulk("38.1")]),
+        ]))
+        .unwrap();
+        assert_eq!(result.dist, Some(190.4424));
+        assert_eq!(result.hash, Some(3479099956230698));
+        assert_eq!(result.coord, Some(Coord::lon_lat(13.5, 38.1)));
+
+        // WITHHASH alone, and a RESP3 double distance
+        let result =
+            GeoSearchResult::from_redis_value(Value::Array(vec![bulk("Catania"), Value::Int(42)]))
+                .unwrap();
+        assert_eq!((result.dist, result.hash), (None, Some(42)));
+        let result = GeoSearchResult::from_redis_value(Value::Array(vec![
+            bulk("Catania"),
+            Value::Double(56.4413),
+        ]))
+        .unwrap();
+        assert_eq!(result.dist, Some(56.4413));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_geo_results_from_bulk_bytes() {
+        let bulk_bytes =
+            |s: &'static str| Value::BulkBytes(bytes::Bytes::from_static(s.as_bytes()));
+
+        let result = GeoSearchResult::from_redis_value(bulk_bytes("Palermo")).unwrap();
+        assert_eq!(result.name, "Palermo");
+        assert_eq!((result.coord, result.dist, result.hash), (None, None, None));
+
+        let result = super::RadiusSearchResult::from_redis_value(bulk_bytes("Palermo")).unwrap();
+        assert_eq!(result.name, "Palermo");
+        assert_eq!((result.coord, result.dist), (None, None));
+    }
 }
@@ -10,6 +10,7 @@ use crate::types::{
 #[cfg(feature = "cluster")]
 use crate::commands::ClusterPipeline;
 
+use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 
 macro_rules! implement_json_commands {
@@ -57,7 +58,8 @@ macro_rules! implement_json_commands {
         ///
         /// With RedisJSON commands, you have to note that all results will be wrapped
         /// in square brackets (or empty brackets if not found). If you want to deserialize it
-        /// with e.g. `serde_json` you have to use `Vec<T>` for your output type instead of `T`.
+        /// with e.g. `serde_json` you have to use `Vec<T>` for your output type instead of `T`,
+        /// or use [`json_get_as`](Self::json_get_as), which handles both path syntaxes.
         pub trait JsonCommands : ConnectionLike + Sized {
             $(
                 $(#[$attr])*
@@ -67,6 +69,37 @@ macro_rules! implement_json_commands {
                     &mut self $(, $argname: $argty)*) -> RedisResult<RV>
                     { Cmd::$name($($argname),*)?.query(self) }
             )*
+
+            /// Gets the JSON value at `path` in `key`, deserialized as `T`.
+            ///
+            /// Legacy paths, like `.a`, reply with the value itself, while JSONPath paths,
+            /// starting with `$`, reply with an array of the matching values, of which the first
+            /// is returned. `None` is returned when the key doesn't exist or nothing matches.
+            fn json_get_as<K: ToSingleRedisArg, T: DeserializeOwned>(
+                &mut self, key: K, path: &str) -> RedisResult<Option<T>> {
+                let json: Option<String> = Cmd::json_get(key, path)?.query(self)?;
+                json.map_or(Ok(None), |json| first_match(&json, path))
+            }
+
+            /// Gets all the JSON values matching `path` in `key`, deserialized as `T`.
+            ///
+            /// Legacy paths, like `.a`, always match a single value.
+            fn json_get_all_as<K: ToSingleRedisArg, T: DeserializeOwned>(
+                &mut self, key: K, path: &str) -> RedisResult<Vec<T>> {
+                let json: Option<String> = Cmd::json_get(key, path)?.query(self)?;
+                json.map_or(Ok(Vec::new()), |json| all_matches(&json, path))
+            }
+
+            /// Gets the JSON value at `path` in each of `keys`, deserialized as `T` like
+            /// [`json_get_as`](Self::json_get_as).
+            fn json_mget_as<K: ToRedisArgs, T: DeserializeOwned>(
+                &mut self, keys: K, path: &str) -> RedisResult<Vec<Option<T>>> {
+                let jsons: Vec<Option<String>> = Cmd::json_mget(keys, path)?.query(self)?;
+                jsons
+                    .into_iter()
+                    .map(|json| json.map_or(Ok(None), |json| first_match(&json, path)))
+                    .collect()
+            }
         }
 
         impl Cmd {
@@ -113,7 +146,8 @@ macro_rules! implement_json_commands {
         ///
         /// With RedisJSON commands, you have to note that all results will be wrapped
         /// in square brackets (or empty brackets if not found). If you want to deserialize it
-        /// with e.g. `serde_json` you have to use `Vec<T>` for your output type instead of `T`.
+        /// with e.g. `serde_json` you have to use `Vec<T>` for your output type instead of `T`,
+        /// or use [`json_get_as`](Self::json_get_as), which handles both path syntaxes.
         ///
         #[cfg(feature = "aio")]
         pub trait JsonAsyncCommands : crate::aio::ConnectionLike + Send + Sized {
@@ -133,6 +167,43 @@ macro_rules! implement_json_commands {
                     })
                 }
             )*
+
+            /// Gets the JSON value at `path` in `key`, deserialized as `T`.
+            ///
+            /// Legacy paths, like `.a`, reply with the value itself, while JSONPath paths,
+            /// starting with `$`, reply with an array of the matching values, of which the first
+            /// is returned. `None` is returned when the key doesn't exist or nothing matches.
+            fn json_get_as<'a, K: ToSingleRedisArg + Send + Sync + 'a, T: DeserializeOwned + Send + 'a>(
+                &'a mut self, key: K, path: &'a str) -> $crate::types::RedisFuture<'a, Option<T>> {
+                Box::pin(async move {
+                    let json: Option<String> = Cmd::json_get(key, path)?.query_async(self).await?;
+                    json.map_or(Ok(None), |json| first_match(&json, path))
+                })
+            }
+
+            /// Gets all the JSON values matching `path` in `key`, deserialized as `T`.
+            ///
+            /// Legacy paths, like `.a`, always match a single value.
+            fn json_get_all_as<'a, K: ToSingleRedisArg + Send + Sync + 'a, T: DeserializeOwned + Send + 'a>(
+                &'a mut self, key: K, path: &'a str) -> $crate::types::RedisFuture<'a, Vec<T>> {
+                Box::pin(async move {
+                    let json: Option<String> = Cmd::json_get(key, path)?.query_async(self).await?;
+                    json.map_or(Ok(Vec::new()), |json| all_matches(&json, path))
+                })
+            }
+
+            /// Gets the JSON value at `path` in each of `keys`, deserialized as `T` like
+            /// [`json_get_as`](Self::json_get_as).
+            fn json_mget_as<'a, K: ToRedisArgs + Send + Sync + 'a, T: DeserializeOwned + Send + 'a>(
+                &'a mut self, keys: K, path: &'a str) -> $crate::types::RedisFuture<'a, Vec<Option<T>>> {
+                Box::pin(async move {
+                    let jsons: Vec<Option<String>> = Cmd::json_mget(keys, path)?.query_async(self).await?;
+                    jsons
+                        .into_iter()
+                        .map(|json| json.map_or(Ok(None), |json| first_match(&json, path)))
+                        .collect()
+                })
+            }
         }
 
         /// Implements RedisJSON commands for pipelines.  Unlike the regular
@@ -193,6 +264,13 @@ implement_json_commands! {
         cmd("JSON.ARRINDEX").arg(key).arg(path).arg(serde_json::to_string(value)?).arg(start).arg(stop).take()
     }
 
+    /// Same as `json_arr_index` except it only searches from the `start` index to the end of the array.
+    ///
+    /// A negative `start` counts from the end of the array.
+    fn json_arr_index_start<K: ToSingleRedisArg, P: ToSingleRedisArg, V: Serialize>(key: K, path: P, value: &'a V, start: i64) {
+        cmd("JSON.ARRINDEX").arg(key).arg(path).arg(serde_json::to_string(value)?).arg(start).take()
+    }
+
     /// Inserts the JSON `value` in the array at `path` before the `index` (shifts to the right).
     ///
     /// `index` must be within the array's range.
@@ -225,11 +303,21 @@ implement_json_commands! {
         cmd("JSON.CLEAR").arg(key).arg(path).take()
     }
 
+    /// Reports the size in bytes of the JSON value at `path` in `key`.
+    fn json_debug_memory<K: ToSingleRedisArg, P: ToSingleRedisArg>(key: K, path: P) {
+        cmd("JSON.DEBUG").arg("MEMORY").arg(key).arg(path).take()
+    }
+
     /// Deletes a value at `path`.
     fn json_del<K: ToSingleRedisArg, P: ToSingleRedisArg>(key: K, path: P) {
         cmd("JSON.DEL").arg(key).arg(path).take()
     }
 
+    /// Deletes a value at `path`, like [`json_del`](Self::json_del).
+    fn json_forget<K: ToSingleRedisArg, P: ToSingleRedisArg>(key: K, path: P) {
+        cmd("JSON.FORGET").arg(key).arg(path).take()
+    }
+
     /// Gets JSON Value at `path`.
     ///
     /// With RedisJSON commands, you have to note that all results will be wrapped
@@ -248,6 +336,13 @@ implement_json_commands! {
         cmd("JSON.MGET").arg(key).arg(path).take()
     }
 
+    /// Merges the JSON `value` into the value at `path` in `key`, following
+    /// [RFC 7396](https://datatracker.ietf.org/doc/html/rfc7396): `null` members delete the
+    /// existing members, and other members are added or replaced.
+    fn json_merge<K: ToSingleRedisArg, P: ToSingleRedisArg, V: Serialize>(key: K, path: P, value: &'a V) {
+        cmd("JSON.MERGE").arg(key).arg(path).arg(serde_json::to_string(value)?).take()
+    }
+
     /// Increments the number value stored at `path` by `number`.
     fn json_num_incr_by<K: ToSingleRedisArg, P: ToSingleRedisArg>(key: K, path: P, value: i64) {
         cmd("JSON.NUMINCRBY").arg(key).arg(path).arg(value).take()
@@ -263,6 +358,13 @@ implement_json_commands! {
         cmd("JSON.OBJLEN").arg(key).arg(path).take()
     }
 
+    /// Returns the JSON value at `path` in `key` in the Redis serialization protocol: objects
+    /// are arrays starting with `{`, arrays start with `[`, and numbers and booleans are replied
+    /// as is.
+    fn json_resp<K: ToSingleRedisArg, P: ToSingleRedisArg>(key: K, path: P) {
+        cmd("JSON.RESP").arg(key).arg(path).take()
+    }
+
     /// Sets the JSON Value at `path` in `key`.
     fn json_set<K: ToSingleRedisArg, P: ToSingleRedisArg, V: Serialize>(key: K, path: P, value: &'a V) {
         cmd("JSON.SET").arg(key).arg(path).arg(serde_json::to_string(value)?).take()
@@ -314,6 +416,28 @@ impl<T> JsonCommands for T where T: ConnectionLike {}
 #[cfg(feature = "aio")]
 impl<T> JsonAsyncCommands for T where T: crate::aio::ConnectionLike + Send + Sized {}
 
+// JSONPath paths start with `$`, other paths are legacy ones.
+fn is_json_path(path: &str) -> bool {
+    path.starts_with('$')
+}
+
+fn first_match<T: DeserializeOwned>(json: &str, path: &str) -> RedisResult<Option<T>> {
+    if !is_json_path(path) {
+        return Ok(Some(serde_json::from_str(json)?));
+    }
+    // The other matches may not be `T`s, so only the first one is deserialized as a `T`.
+    let matches: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    Ok(matches.into_iter().next().map(T::deserialize).transpose()?)
+}
+
+fn all_matches<T: DeserializeOwned>(json: &str, path: &str) -> RedisResult<Vec<T>> {
+    if is_json_path(path) {
+        Ok(serde_json::from_str(json)?)
+    } else {
+        Ok(vec![serde_json::from_str(json)?])
+    }
+}
+
 /// Storage-precision tag for the `FPHA` form of `JSON.SET`.
 ///
 /// Applied via [`JsonSetOptions::fpha`] instructs the server to pack any floating-point arrays in the payload using the chosen lane precision.
@@ -421,6 +545,30 @@ mod tests {
         simple_args(&c)
     }
 
+    #[test]
+    fn first_match_of_json_path_reply() {
+        assert_eq!(first_match::<i64>("[2,\"x\"]", "$..a").unwrap(), Some(2));
+        assert_eq!(first_match::<i64>("[]", "$.a").unwrap(), None);
+        first_match::<i64>("[\"x\"]", "$.a").unwrap_err();
+    }
+
+    #[test]
+    fn first_match_of_legacy_path_reply() {
+        assert_eq!(
+            first_match::<Vec<i64>>("[1,2]", ".a").unwrap(),
+            Some(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn all_matches_of_path_replies() {
+        assert_eq!(all_matches::<i64>("[1,2]", "$..a").unwrap(), vec![1, 2]);
+        assert_eq!(
+            all_matches::<Vec<i64>>("[1,2]", ".a").unwrap(),
+            vec![vec![1, 2]]
+        );
+    }
+
     #[test]
     fn json_value_with_default_options_writes_serialized_document_only() {
         assert_eq!(
@@ -0,0 +1,269 @@
+//! Defines types and helpers to receive
+//! [keyspace notifications](https://redis.io/docs/latest/develop/use/keyspace-notifications/).
+//!
+//! Keyspace notifications are published on `__keyspace@<db>__:<key>` channels, with the event
+//! as the payload, and on `__keyevent@<db>__:<event>` channels, with the key as the payload.
+//! [`KeyspaceSubscription`] subscribes to the right patterns, and [`KeyspaceEvent::from_msg`]
+//! parses the received messages.
+//!
+//! # Example
+//! ```rust,no_run
+//! use redis::keyspace::{KeyspaceEvent, KeyspaceSubscription};
+//! # fn do_something() -> redis::RedisResult<()> {
+//! let client = redis::Client::open("redis://127.0.0.1/")?;
+//! let mut con = client.get_connection()?;
+//! let mut pubsub = con.as_pubsub();
+//! KeyspaceSubscription::new()
+//!     .db(0)
+//!     .keys("user:*")
+//!     .notify_keyspace_events("K$x")
+//!     .subscribe(&mut pubsub)?;
+//! loop {
+//!     if let Some(event) = KeyspaceEvent::from_msg(&pubsub.get_message()?) {
+//!         println!("{}: {:?}", event.event, event.key);
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::cmd::{Cmd, cmd};
+use crate::connection::{Msg, PubSub};
+use crate::types::RedisResult;
+
+const KEYSPACE_PREFIX: &[u8] = b"__keyspace@";
+const KEYEVENT_PREFIX: &[u8] = b"__keyevent@";
+
+/// Returns whether `channel` is a keyspace notification channel or pattern. These are
+/// published by each node for its own keys only.
+#[cfg(any(feature = "connection-manager", feature = "cluster-async"))]
+pub(crate) fn is_node_local_channel(channel: &[u8]) -> bool {
+    channel.starts_with(KEYSPACE_PREFIX) || channel.starts_with(KEYEVENT_PREFIX)
+}
+
+/// A keyspace notification
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct KeyspaceEvent {
+    /// The database of the key.
+    pub db: i64,
+    /// The key the event happened on.
+    pub key: Vec<u8>,
+    /// The name of the event, e.g. `set`, `del` or `expired`.
+    pub event: String,
+}
+
+impl KeyspaceEvent {
+    /// Parses a message received on a `__keyspace@<db>__:<key>` or
+    /// `__keyevent@<db>__:<event>` channel. Returns `None` for other messages.
+    pub fn from_msg(msg: &Msg) -> Option<Self> {
+        let channel: Vec<u8> = msg.get_channel().ok()?;
+        let payload = msg.get_payload_bytes();
+        let (is_keyspace, rest) = if let Some(rest) = channel.strip_prefix(KEYSPACE_PREFIX) {
+            (true, rest)
+        } else {
+            (false, channel.strip_prefix(KEYEVENT_PREFIX)?)
+        };
+        let separator = rest.windows(3).position(|window| window == b"__:")?;
+        let db = std::str::from_utf8(&rest[..separator]).ok()?.parse().ok()?;
+        let suffix = &rest[separator + 3..];
+
+        let (key, event) = if is_keyspace {
+            (suffix, payload)
+        } else {
+            (payload, suffix)
+        };
+        Some(KeyspaceEvent {
+            db,
+            key: key.to_vec(),
+            event: String::from_utf8(event.to_vec()).ok()?,
+        })
+    }
+}
+
+/// A subscription to keyspace notifications
+///
+/// Without [`keys`](Self::keys) or [`events`](Self::events), all the events of all the keys
+/// are received through the `__keyspace@<db>__:*` pattern.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct KeyspaceSubscription {
+    db: Option<i64>,
+    keys: Option<String>,
+    events: Option<String>,
+    notify_keyspace_events: Option<String>,
+}
+
+impl KeyspaceSubscription {
+    /// Creates a subscription to the events of all the keys of all the databases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only receive the events of database `db`.
+    pub fn db(mut self, db: i64) -> Self {
+        self.db = Some(db);
+        self
+    }
+
+    /// Receive the events of the keys matching `pattern`, from the `__keyspace@<db>__` channels.
+    pub fn keys(mut self, pattern: impl Into<String>) -> Self {
+        self.keys = Some(pattern.into());
+        self
+    }
+
+    /// Receive the events matching `pattern`, e.g. `expired`, from the `__keyevent@<db>__`
+    /// channels.
+    pub fn events(mut self, pattern: impl Into<String>) -> Self {
+        self.events = Some(pattern.into());
+        self
+    }
+
+    /// Set the `notify-keyspace-events` configuration of the server to `flags`, e.g. `KEA`,
+    /// with CONFIG SET before subscribing. Notifications are disabled by default.
+    pub fn notify_keyspace_events(mut self, flags: impl Into<String>) -> Self {
+        self.notify_keyspace_events = Some(flags.into());
+        self
+    }
+
+    /// Returns the channel patterns of the subscription.
+    pub fn patterns(&self) -> Vec<String> {
+        let db = self.db.map_or_else(|| "*".to_string(), |db| db.to_string());
+        let mut patterns = Vec::with_capacity(2);
+        if let Some(keys) = &self.keys {
+            patterns.push(format!("__keyspace@{db}__:{keys}"));
+        }
+        if let Some(events) = &self.events {
+            patterns.push(format!("__keyevent@{db}__:{events}"));
+        }
+        if patterns.is_empty() {
+            patterns.push(format!("__keyspace@{db}__:*"));
+        }
+        patterns
+    }
+
+    fn config_cmd(&self) -> Option<Cmd> {
+        self.notify_keyspace_events.as_ref().map(|flags| {
+            cmd("CONFIG")
+                .arg("SET")
+                .arg("notify-keyspace-events")
+                .arg(flags)
+                .take()
+        })
+    }
+
+    /// Subscribes `pubsub` to the notifications, after configuring the server if requested.
+    pub fn subscribe(&self, pubsub: &mut PubSub<'_>) -> RedisResult<()> {
+        if let Some(mut config) = self.config_cmd() {
+            pubsub.req_command(&mut config)?;
+        }
+        pubsub.psubscribe(self.patterns())
+    }
+
+    /// Subscribes the async `pubsub` to the notifications, after configuring the server if
+    /// requested.
+    #[cfg(feature = "aio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+    pub async fn subscribe_async(&self, pubsub: &mut crate::aio::PubSub) -> RedisResult<()> {
+        if let Some(config) = self.config_cmd() {
+            pubsub.req_command(&config).await?;
+        }
+        pubsub.psubscribe(self.patterns()).await
+    }
+
+    /// Subscribes the cluster connection to the notifications on every primary, because each
+    /// node only notifies about its own keys. Replicas aren't subscribed, as they would notify
+    /// about the same writes again. The server is configured on every primary if requested.
+    ///
+    /// The notifications are sent to the push sender of the connection, and can be converted
+    /// with [`Msg::from_push_info`] and [`KeyspaceEvent::from_msg`]. This requires RESP3, like
+    /// [`psubscribe`](crate::cluster_async::ClusterConnection::psubscribe).
+    #[cfg(feature = "cluster-async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster-async")))]
+    pub async fn subscribe_cluster(
+        &self,
+        con: &mut crate::cluster_async::ClusterConnection,
+    ) -> RedisResult<()> {
+        use crate::cluster_routing::{MultipleNodeRoutingInfo, ResponsePolicy, RoutingInfo};
+
+        let all_primaries = || {
+            RoutingInfo::MultiNode((
+                MultipleNodeRoutingInfo::AllMasters,
+                Some(ResponsePolicy::AllSucceeded),
+            ))
+        };
+        if let Some(config) = self.config_cmd() {
+            con.route_command(config, all_primaries()).await?;
+        }
+        con.psubscribe_on(self.patterns(), all_primaries()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PushInfo, PushKind, Value};
+
+    fn msg(pattern: &str, channel: &str, payload: &str) -> Msg {
+        Msg::from_push_info(PushInfo {
+            kind: PushKind::PMessage,
+            data: vec![
+                Value::BulkString(pattern.into()),
+                Value::BulkString(channel.into()),
+                Value::BulkString(payload.into()),
+            ],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn keyspace_event_from_keyspace_channel() {
+        let event = KeyspaceEvent::from_msg(&msg("__keyspace@*__:*", "__keyspace@3__:a:b", "set"));
+        assert_eq!(
+            event,
+            Some(KeyspaceEvent {
+                db: 3,
+                key: b"a:b".to_vec(),
+                event: "set".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn keyspace_event_from_keyevent_channel() {
+        let event = KeyspaceEvent::from_msg(&msg(
+            "__keyevent@0__:*",
+            "__keyevent@0__:expired",
+            "key__:1",
+        ));
+        assert_eq!(
+            event,
+            Some(KeyspaceEvent {
+                db: 0,
+                key: b"key__:1".to_vec(),
+                event: "expired".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn keyspace_event_from_other_channels() {
+        assert_eq!(KeyspaceEvent::from_msg(&msg("*", "channel", "set")), None);
+        assert_eq!(
+            KeyspaceEvent::from_msg(&msg("*", "__keyspace@x__:key", "set")),
+            None
+        );
+    }
+
+    #[test]
+    fn subscription_patterns() {
+        assert_eq!(KeyspaceSubscription::new().patterns(), ["__keyspace@*__:*"]);
+        assert_eq!(
+            KeyspaceSubscription::new()
+                .db(1)
+                .keys("user:*")
+                .events("expired")
+                .patterns(),
+            ["__keyspace@1__:user:*", "__keyevent@1__:expired"]
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! Defines types to use with the [`dump`](crate::Commands::dump),
+//! [`restore`](crate::Commands::restore) and [`migrate`](crate::Commands::migrate) commands,
+//! and helpers to copy keys between connections.
+
+use crate::cmd::{Cmd, cmd};
+use crate::connection::ConnectionLike;
+use crate::errors::{ParsingError, invalid_type_error};
+use crate::pipeline::Pipeline;
+use crate::types::{FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+/// Options for the [RESTORE](https://redis.io/commands/restore) command
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult, migrate::RestoreOptions};
+/// fn restore_key(con: &mut redis::Connection, payload: Vec<u8>) -> RedisResult<()> {
+///     let opts = RestoreOptions::default().replace(true).idle_time(60);
+///     con.restore_options("key", 0, payload, &opts)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct RestoreOptions {
+    replace: bool,
+    absolute_ttl: bool,
+    idle_time: Option<u64>,
+    frequency: Option<u8>,
+}
+
+impl RestoreOptions {
+    /// Overwrite the key if it already exists (`REPLACE`).
+    pub fn replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+
+    /// Interpret the TTL as an absolute Unix timestamp in milliseconds (`ABSTTL`).
+    pub fn absolute_ttl(mut self, absolute_ttl: bool) -> Self {
+        self.absolute_ttl = absolute_ttl;
+        self
+    }
+
+    /// Set the idle time of the key, in seconds, for the LRU eviction policy (`IDLETIME`).
+    pub fn idle_time(mut self, seconds: u64) -> Self {
+        self.idle_time = Some(seconds);
+        self
+    }
+
+    /// Set the access frequency of the key for the LFU eviction policy (`FREQ`).
+    pub fn frequency(mut self, frequency: u8) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+}
+
+impl ToRedisArgs for RestoreOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.replace {
+            out.write_arg(b"REPLACE");
+        }
+        if self.absolute_ttl {
+            out.write_arg(b"ABSTTL");
+        }
+        if let Some(idle_time) = self.idle_time {
+            out.write_arg(b"IDLETIME");
+            out.write_arg_fmt(idle_time);
+        }
+        if let Some(frequency) = self.frequency {
+            out.write_arg(b"FREQ");
+            out.write_arg_fmt(frequency);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum MigrateAuth {
+    Password(String),
+    UserPassword(String, String),
+}
+
+/// Options for the [MIGRATE](https://redis.io/commands/migrate) command
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult, migrate::{MigrateOptions, MigrateStatus}};
+/// fn move_keys(con: &mut redis::Connection) -> RedisResult<MigrateStatus> {
+///     let opts = MigrateOptions::default().replace(true).auth2("user", "secret");
+///     con.migrate_options("10.0.0.2", 6379, &["key1", "key2"], 0, 5000, &opts)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct MigrateOptions {
+    copy: bool,
+    replace: bool,
+    auth: Option<MigrateAuth>,
+}
+
+impl MigrateOptions {
+    /// Keep the keys on the source instance (`COPY`).
+    pub fn copy(mut self, copy: bool) -> Self {
+        self.copy = copy;
+        self
+    }
+
+    /// Overwrite existing keys on the destination instance (`REPLACE`).
+    pub fn replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+
+    /// Authenticate to the destination instance with a password (`AUTH`).
+    pub fn auth(mut self, password: impl Into<String>) -> Self {
+        self.auth = Some(MigrateAuth::Password(password.into()));
+        self
+    }
+
+    /// Authenticate to the destination instance with an ACL user and password (`AUTH2`).
+    pub fn auth2(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.auth = Some(MigrateAuth::UserPassword(username.into(), password.into()));
+        self
+    }
+}
+
+impl ToRedisArgs for MigrateOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.copy {
+            out.write_arg(b"COPY");
+        }
+        if self.replace {
+            out.write_arg(b"REPLACE");
+        }
+        match &self.auth {
+            Some(MigrateAuth::Password(password)) => {
+                out.write_arg(b"AUTH");
+                out.write_arg(password.as_bytes());
+            }
+            Some(MigrateAuth::UserPassword(username, password)) => {
+                out.write_arg(b"AUTH2");
+                out.write_arg(username.as_bytes());
+                out.write_arg(password.as_bytes());
+            }
+            None => {}
+        }
+    }
+}
+
+/// The reply of the [MIGRATE](https://redis.io/commands/migrate) command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MigrateStatus {
+    /// The keys were transferred.
+    Ok,
+    /// None of the keys exist on the source instance.
+    NoKey,
+}
+
+impl FromRedisValue for MigrateStatus {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::Okay => Ok(MigrateStatus::Ok),
+            Value::SimpleString(ref s) if s == "OK" => Ok(MigrateStatus::Ok),
+            Value::SimpleString(ref s) if s == "NOKEY" => Ok(MigrateStatus::NoKey),
+            _ => invalid_type_error!(v, "expected OK or NOKEY"),
+        }
+    }
+}
+
+/// Reads `key` and its remaining TTL from the source in a transaction, so that both belong to
+/// the same version of the key.
+fn dump_pipeline<K: ToRedisArgs>(key: &K) -> Pipeline {
+    let mut pipe = crate::pipe();
+    pipe.atomic().cmd("DUMP").arg(key).cmd("PTTL").arg(key);
+    pipe
+}
+
+fn restore_cmd<K: ToRedisArgs>(
+    key: &K,
+    payload: Vec<u8>,
+    pttl: i64,
+    options: &RestoreOptions,
+) -> Option<Cmd> {
+    // PTTL replies -2 if the key expired since DUMP, and -1 if it has no TTL.
+    // The TTL read with PTTL is relative, so ABSTTL must not be sent.
+    let ttl = match pttl {
+        -2 => return None,
+        -1 => 0,
+        ttl => ttl,
+    };
+    let options = RestoreOptions {
+        absolute_ttl: false,
+        ..*options
+    };
+    Some(
+        cmd("RESTORE")
+            .arg(key)
+            .arg(ttl)
+            .arg(payload)
+            .arg(options)
+            .take(),
+    )
+}
+
+/// Copies `keys` from `source` to `destination` with DUMP, PTTL and RESTORE, preserving
+/// their remaining time to live. Keys that don't exist on `source` are skipped.
+///
+/// DUMP and PTTL are sent together in a `MULTI`/`EXEC` transaction for each key, so that the
+/// TTL always belongs to the dumped value. Keys are read one by one, since on a cluster they
+/// may be in different slots.
+///
+/// Unlike [`migrate`](crate::Commands::migrate), the two connections can be of any kind,
+/// e.g. from a standalone instance to a cluster. `options` apply to every RESTORE, except
+/// for `ABSTTL` which is always left out. Returns the number of copied keys.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{RedisResult, migrate::{RestoreOptions, copy_keys}};
+/// fn copy(source: &mut redis::Connection, destination: &mut redis::Connection) -> RedisResult<usize> {
+///     copy_keys(source, destination, &["key1", "key2"], &RestoreOptions::default().replace(true))
+/// }
+/// ```
+pub fn copy_keys<S, D, K>(
+    source: &mut S,
+    destination: &mut D,
+    keys: &[K],
+    options: &RestoreOptions,
+) -> RedisResult<usize>
+where
+    S: ConnectionLike,
+    D: ConnectionLike,
+    K: ToRedisArgs,
+{
+    let mut copied = 0;
+    for key in keys {
+        let (payload, pttl): (Option<Vec<u8>>, i64) = dump_pipeline(key).query(source)?;
+        let Some(payload) = payload else {
+            continue;
+        };
+        if let Some(restore) = restore_cmd(key, payload, pttl, options) {
+            restore.query::<()>(destination)?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// The async version of [`copy_keys`].
+#[cfg(feature = "aio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+pub async fn copy_keys_async<S, D, K>(
+    source: &mut S,
+    destination: &mut D,
+    keys: &[K],
+    options: &RestoreOptions,
+) -> RedisResult<usize>
+where
+    S: crate::aio::ConnectionLike,
+    D: crate::aio::ConnectionLike,
+    K: ToRedisArgs,
+{
+    let mut copied = 0;
+    for key in keys {
+        let (payload, pttl): (Option<Vec<u8>>, i64) =
+            dump_pipeline(key).query_async(source).await?;
+        let Some(payload) = payload else {
+            continue;
+        };
+        if let Some(restore) = restore_cmd(key, payload, pttl, options) {
+            restore.query_async::<()>(destination).await?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::cmd_args;
+
+    #[test]
+    fn restore_options() {
+        let opts = RestoreOptions::default()
+            .replace(true)
+            .absolute_ttl(true)
+            .idle_time(10)
+            .frequency(5);
+        assert_eq!(
+            cmd_args(cmd("RESTORE").arg("key").arg(0).arg("payload").arg(opts)),
+            [
+                "RESTORE", "key", "0", "payload", "REPLACE", "ABSTTL", "IDLETIME", "10", "FREQ",
+                "5"
+            ]
+        );
+    }
+
+    #[test]
+    fn migrate_options() {
+        let opts = MigrateOptions::default()
+            .copy(true)
+            .replace(true)
+            .auth2("user", "secret");
+        assert_eq!(
+            cmd_args(cmd("MIGRATE").arg(&opts)),
+            ["MIGRATE", "COPY", "REPLACE", "AUTH2", "user", "secret"]
+        );
+        let opts = MigrateOptions::default().auth("secret");
+        assert_eq!(
+            cmd_args(cmd("MIGRATE").arg(&opts)),
+            ["MIGRATE", "AUTH", "secret"]
+        );
+    }
+
+    #[test]
+    fn migrate_status() {
+        assert_eq!(
+            crate::from_redis_value::<MigrateStatus>(Value::Okay).unwrap(),
+            MigrateStatus::Ok
+        );
+        assert_eq!(
+            crate::from_redis_value::<MigrateStatus>(Value::SimpleString("NOKEY".into())).unwrap(),
+            MigrateStatus::NoKey
+        );
+        assert!(crate::from_redis_value::<MigrateStatus>(Value::Nil).is_err());
+    }
+
+    #[test]
+    fn dump_pipeline_is_atomic() {
+        assert_eq!(
+            dump_pipeline(&"key").get_packed_pipeline(),
+            [
+                &b"*1\r\n$5\r\nMULTI\r\n"[..],
+                b"*2\r\n$4\r\nDUMP\r\n$3\r\nkey\r\n",
+                b"*2\r\n$4\r\nPTTL\r\n$3\r\nkey\r\n",
+                b"*1\r\n$4\r\nEXEC\r\n",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn restore_cmd_ttl() {
+        let options = RestoreOptions::default().absolute_ttl(true);
+        assert!(restore_cmd(&"key", vec![1], -2, &options).is_none());
+        assert_eq!(
+            cmd_args(&restore_cmd(&"key", vec![1], -1, &options).unwrap())[..3],
+            ["RESTORE", "key", "0"]
+        );
+        let restore = cmd_args(&restore_cmd(&"key", vec![1], 1500, &options).unwrap());
+        assert_eq!(restore[2], "1500");
+        assert!(!restore.contains(&"ABSTTL".to_string()));
+    }
+}
@@ -9,12 +9,13 @@ use crate::types::{
     ValueComparison,
 };
 
-#[cfg(feature = "vector-sets")]
-use crate::types::Value;
+use crate::errors::{ParsingError, invalid_type_error};
+use crate::types::{Value, from_redis_value};
 
 #[cfg(feature = "vector-sets")]
 use serde::ser::Serialize;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 #[macro_use]
 mod macros;
@@ -38,15 +39,37 @@ pub mod geo;
 #[cfg(feature = "streams")]
 pub mod streams;
 
+#[cfg(all(feature = "streams", feature = "aio"))]
+mod stream_consumer;
+
+#[cfg(all(feature = "streams", feature = "aio"))]
+mod stream_tail;
+
 #[cfg(feature = "acl")]
 pub mod acl;
 
+pub mod function;
+
 #[cfg(feature = "vector-sets")]
 #[cfg_attr(docsrs, doc(cfg(feature = "vector-sets")))]
 pub mod vector_sets;
 
 pub mod hotkeys;
 
+pub mod server;
+
+pub mod migrate;
+
+pub mod keyspace;
+
+#[cfg(feature = "search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+pub mod search;
+
+#[cfg(feature = "timeseries")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+pub mod timeseries;
+
 #[cfg(any(feature = "cluster", feature = "cache-aio"))]
 enum Properties {
     ReadOnlyCacheable,
@@ -90,6 +113,13 @@ fn command_properties(cmd: &[u8]) -> Properties {
         | b"BF.INFO"
         | b"BF.MEXISTS"
         | b"BF.SCANDUMP"
+        | b"CF.COUNT"
+        | b"CF.EXISTS"
+        | b"CF.INFO"
+        | b"CF.MEXISTS"
+        | b"CF.SCANDUMP"
+        | b"CMS.INFO"
+        | b"CMS.QUERY"
         | b"BGREWRITEAOF"
         | b"BGSAVE"
         | b"PFCOUNT"
@@ -227,8 +257,28 @@ fn command_properties(cmd: &[u8]) -> Properties {
         | b"SSUBSCRIBE"
         | b"SUBSCRIBE"
         | b"SUNSUBSCRIBE"
+        | b"TDIGEST.BYRANK"
+        | b"TDIGEST.BYREVRANK"
+        | b"TDIGEST.CDF"
+        | b"TDIGEST.INFO"
+        | b"TDIGEST.MAX"
+        | b"TDIGEST.MIN"
+        | b"TDIGEST.QUANTILE"
+        | b"TDIGEST.RANK"
+        | b"TDIGEST.REVRANK"
+        | b"TDIGEST.TRIMMED_MEAN"
         | b"TIME"
+        | b"TOPK.INFO"
+        | b"TOPK.LIST"
+        | b"TOPK.QUERY"
         | b"TOUCH"
+        | b"TS.INFO"
+        | b"TS.MGET"
+        | b"TS.MRANGE"
+        | b"TS.MREVRANGE"
+        | b"TS.QUERYINDEX"
+        | b"TS.RANGE"
+        | b"TS.REVRANGE"
         | b"TTL"
         | b"UNSUBSCRIBE"
         | b"XINFO CONSUMERS"
@@ -470,6 +520,68 @@ implement_commands! {
         cmd("COPY").arg(source).arg(destination).arg(options).take()
     }
 
+    /// Serialize the value stored at key in a Redis-specific format, or `None` if the key
+    /// does not exist.
+    /// [Redis Docs](https://redis.io/commands/DUMP)
+    fn dump<K: ToSingleRedisArg>(key: K) -> (Option<Vec<u8>>) {
+        cmd("DUMP").arg(key).take()
+    }
+
+    /// Create a key from a value serialized with [`dump`](Self::dump), expiring in `ttl`
+    /// milliseconds, or never if `ttl` is 0.
+    /// Errors if the key already exists.
+    /// [Redis Docs](https://redis.io/commands/RESTORE)
+    fn restore<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, ttl: u64, serialized_value: V) -> (()) {
+        cmd("RESTORE").arg(key).arg(ttl).arg(serialized_value).take()
+    }
+
+    /// Create a key from a value serialized with [`dump`](Self::dump), with additional options.
+    /// [Redis Docs](https://redis.io/commands/RESTORE)
+    fn restore_options<K: ToSingleRedisArg, V: ToSingleRedisArg>(
+        key: K,
+        ttl: u64,
+        serialized_value: V,
+        options: &'a migrate::RestoreOptions
+    ) -> (()) {
+        cmd("RESTORE").arg(key).arg(ttl).arg(serialized_value).arg(options).take()
+    }
+
+    /// Atomically transfer a key to the database `destination_db` of another instance,
+    /// deleting it from the current one. `timeout` is in milliseconds.
+    /// [Redis Docs](https://redis.io/commands/MIGRATE)
+    fn migrate<H: ToSingleRedisArg, K: ToSingleRedisArg>(
+        host: H,
+        port: u16,
+        key: K,
+        destination_db: i64,
+        timeout: u64
+    ) -> (migrate::MigrateStatus) {
+        cmd("MIGRATE").arg(host).arg(port).arg(key).arg(destination_db).arg(timeout).take()
+    }
+
+    /// Atomically transfer one or more keys to the database `destination_db` of another
+    /// instance, with additional options. `timeout` is in milliseconds.
+    /// [Redis Docs](https://redis.io/commands/MIGRATE)
+    fn migrate_options<H: ToSingleRedisArg, K: ToRedisArgs>(
+        host: H,
+        port: u16,
+        keys: K,
+        destination_db: i64,
+        timeout: u64,
+        options: &'a migrate::MigrateOptions
+    ) -> (migrate::MigrateStatus) {
+        cmd("MIGRATE")
+            .arg(host)
+            .arg(port)
+            .arg("")
+            .arg(destination_db)
+            .arg(timeout)
+            .arg(options)
+            .arg("KEYS")
+            .arg(keys)
+            .take()
+    }
+
     /// Rename a key.
     /// Errors if key does not exist.
     /// [Redis Docs](https://redis.io/commands/RENAME)
@@ -492,6 +604,26 @@ implement_commands! {
         cmd("UNLINK").arg(key).take()
     }
 
+    /// Sort the elements of a list, set or sorted set.
+    /// Returns the sorted elements, or the values fetched by the `GET` patterns.
+    /// [Redis Docs](https://redis.io/commands/SORT)
+    fn sort<K: ToSingleRedisArg>(key: K, options: &'a SortOptions) -> Generic {
+        cmd("SORT").arg(key).arg(options).take()
+    }
+
+    /// Read-only variant of [`sort`](#method.sort), which can be routed to replicas.
+    /// [Redis Docs](https://redis.io/commands/SORT_RO)
+    fn sort_ro<K: ToSingleRedisArg>(key: K, options: &'a SortOptions) -> Generic {
+        cmd("SORT_RO").arg(key).arg(options).take()
+    }
+
+    /// Sort the elements of a list, set or sorted set and store the result as a list at
+    /// `destination`. Returns the number of stored elements.
+    /// [Redis Docs](https://redis.io/commands/SORT)
+    fn sort_store<K: ToSingleRedisArg, D: ToSingleRedisArg>(key: K, destination: D, options: &'a SortOptions) -> (usize) {
+        cmd("SORT").arg(key).arg(options).arg("STORE").arg(destination).take()
+    }
+
     // common string operations
 
     /// Append a value to a key.
@@ -546,6 +678,35 @@ implement_commands! {
         cmd("BITCOUNT").arg(key).arg(start).arg(end).take()
     }
 
+    /// Return the position of the first bit set to `bit` in a string.
+    /// Returns -1 if no such bit is found.
+    /// [Redis Docs](https://redis.io/commands/BITPOS)
+    fn bitpos<K: ToSingleRedisArg>(key: K, bit: bool) -> (isize) {
+        cmd("BITPOS").arg(key).arg(i32::from(bit)).take()
+    }
+
+    /// Return the position of the first bit set to `bit` in a range of a string, where
+    /// `start` and `end` are in bytes or bits according to `unit`.
+    /// Returns -1 if no such bit is found.
+    /// [Redis Docs](https://redis.io/commands/BITPOS)
+    fn bitpos_range<K: ToSingleRedisArg>(key: K, bit: bool, start: isize, end: isize, unit: BitRangeUnit) -> (isize) {
+        cmd("BITPOS").arg(key).arg(i32::from(bit)).arg(start).arg(end).arg(unit).take()
+    }
+
+    /// Run GET, SET and INCRBY operations on the integer fields of a string.
+    /// Returns one entry per GET, SET and INCRBY, `None` when an overflow made it fail.
+    /// [Redis Docs](https://redis.io/commands/BITFIELD)
+    fn bitfield<K: ToSingleRedisArg>(key: K, ops: &'a BitfieldOps) -> (Vec<Option<i64>>) {
+        cmd("BITFIELD").arg(key).arg(ops).take()
+    }
+
+    /// Read-only variant of [`bitfield`](Self::bitfield), which can be routed to replicas.
+    /// Only runs GET operations.
+    /// [Redis Docs](https://redis.io/commands/BITFIELD_RO)
+    fn bitfield_ro<K: ToSingleRedisArg>(key: K, ops: &'a BitfieldRoOps) -> (Vec<Option<i64>>) {
+        cmd("BITFIELD_RO").arg(key).arg(ops).take()
+    }
+
     /// Perform a bitwise AND between multiple keys (containing string values)
     /// and store the result in the destination key.
     /// Returns size of destination string after operation.
@@ -610,6 +771,25 @@ implement_commands! {
         cmd("BITOP").arg("ONE").arg(dstkey).arg(srckeys).take()
     }
 
+    /// Return the longest common subsequence of the strings stored at two keys.
+    /// [Redis Docs](https://redis.io/commands/LCS)
+    fn lcs<K1: ToSingleRedisArg, K2: ToSingleRedisArg>(key1: K1, key2: K2) -> (String) {
+        cmd("LCS").arg(key1).arg(key2).take()
+    }
+
+    /// Return the length of the longest common subsequence of the strings stored at two keys.
+    /// [Redis Docs](https://redis.io/commands/LCS)
+    fn lcs_len<K1: ToSingleRedisArg, K2: ToSingleRedisArg>(key1: K1, key2: K2) -> (usize) {
+        cmd("LCS").arg(key1).arg(key2).arg("LEN").take()
+    }
+
+    /// Return the positions of the matches of the longest common subsequence of the strings
+    /// stored at two keys.
+    /// [Redis Docs](https://redis.io/commands/LCS)
+    fn lcs_idx<K1: ToSingleRedisArg, K2: ToSingleRedisArg>(key1: K1, key2: K2, options: &'a LcsOptions) -> (LcsMatches) {
+        cmd("LCS").arg(key1).arg(key2).arg("IDX").arg(options).take()
+    }
+
     /// Get the length of the value stored in a key.
     /// 0 if key does not exist.
     /// [Redis Docs](https://redis.io/commands/STRLEN)
@@ -994,6 +1174,13 @@ implement_commands! {
         cmd("SINTER").arg(keys).take()
     }
 
+    /// Return the number of members in the intersection of multiple sets, stopping at
+    /// `limit` if given.
+    /// [Redis Docs](https://redis.io/commands/SINTERCARD)
+    fn sintercard<K: ToRedisArgs>(keys: K, limit: Option<usize>) -> (usize) {
+        cmd("SINTERCARD").arg(keys.num_of_args()).arg(keys).arg(limit.map(|_| "LIMIT")).arg(limit).take()
+    }
+
     /// Intersect multiple sets and store the resulting set in a key.
     /// [Redis Docs](https://redis.io/commands/SINTERSTORE)
     fn sinterstore<D: ToSingleRedisArg, K: ToRedisArgs>(dstkey: D, keys: K) -> (usize) {
@@ -1105,6 +1292,57 @@ implement_commands! {
         cmd("ZINCRBY").arg(key).arg(delta).arg(member).take()
     }
 
+    /// Compute the difference between the first and all successive sorted sets.
+    /// [Redis Docs](https://redis.io/commands/ZDIFF)
+    fn zdiff<K: ToRedisArgs>(keys: K) -> (Vec<String>) {
+        cmd("ZDIFF").arg(keys.num_of_args()).arg(keys).take()
+    }
+
+    /// Compute the difference between the first and all successive sorted sets, with scores.
+    /// [Redis Docs](https://redis.io/commands/ZDIFF)
+    fn zdiff_withscores<K: ToRedisArgs>(keys: K) -> (Vec<(String, f64)>) {
+        cmd("ZDIFF").arg(keys.num_of_args()).arg(keys).arg("WITHSCORES").take()
+    }
+
+    /// Compute the difference between the first and all successive sorted sets and store
+    /// the resulting sorted set in a new key.
+    /// [Redis Docs](https://redis.io/commands/ZDIFFSTORE)
+    fn zdiffstore<D: ToSingleRedisArg, K: ToRedisArgs>(dstkey: D, keys: K) -> (usize) {
+        cmd("ZDIFFSTORE").arg(dstkey).arg(keys.num_of_args()).arg(keys).take()
+    }
+
+    /// Intersect multiple sorted sets.
+    /// [Redis Docs](https://redis.io/commands/ZINTER)
+    fn zinter<K: ToRedisArgs>(keys: K) -> (Vec<String>) {
+        cmd("ZINTER").arg(keys.num_of_args()).arg(keys).take()
+    }
+
+    /// Intersect multiple sorted sets, with scores summed.
+    /// [Redis Docs](https://redis.io/commands/ZINTER)
+    fn zinter_withscores<K: ToRedisArgs>(keys: K) -> (Vec<(String, f64)>) {
+        cmd("ZINTER").arg(keys.num_of_args()).arg(keys).arg("WITHSCORES").take()
+    }
+
+    /// Intersect multiple sorted sets, with scores combined according to the options.
+    /// [Redis Docs](https://redis.io/commands/ZINTER)
+    fn zinter_options<K: ToRedisArgs>(keys: K, options: &'a ZAggregateOptions) -> (Vec<(String, f64)>) {
+        cmd("ZINTER").arg(keys.num_of_args()).arg(keys).arg(options).arg("WITHSCORES").take()
+    }
+
+    /// Return the number of members in the intersection of multiple sorted sets, stopping
+    /// at `limit` if given.
+    /// [Redis Docs](https://redis.io/commands/ZINTERCARD)
+    fn zintercard<K: ToRedisArgs>(keys: K, limit: Option<usize>) -> (usize) {
+        cmd("ZINTERCARD").arg(keys.num_of_args()).arg(keys).arg(limit.map(|_| "LIMIT")).arg(limit).take()
+    }
+
+    /// Intersect multiple sorted sets and store the resulting sorted set in
+    /// a new key, with scores combined according to the options.
+    /// [Redis Docs](https://redis.io/commands/ZINTERSTORE)
+    fn zinterstore_options<D: ToSingleRedisArg, K: ToRedisArgs>(dstkey: D, keys: K, options: &'a ZAggregateOptions) -> (usize) {
+        cmd("ZINTERSTORE").arg(dstkey).arg(keys.num_of_args()).arg(keys).arg(options).take()
+    }
+
     /// Intersect multiple sorted sets and store the resulting sorted set in
     /// a new key using SUM as aggregation function.
     /// [Redis Docs](https://redis.io/commands/ZINTERSTORE)
@@ -1239,6 +1477,43 @@ implement_commands! {
         cmd("ZRANGE").arg(key).arg(start).arg(stop).arg("WITHSCORES").take()
     }
 
+    /// Return a range of members in a sorted set, by index, score or lexicographical range
+    /// depending on the options.
+    /// [Redis Docs](https://redis.io/commands/ZRANGE)
+    fn zrange_options<K: ToSingleRedisArg, S: ToSingleRedisArg, E: ToSingleRedisArg>(
+        key: K,
+        start: S,
+        stop: E,
+        options: &'a ZRangeOptions
+    ) -> (Vec<String>) {
+        cmd("ZRANGE").arg(key).arg(start).arg(stop).arg(options).take()
+    }
+
+    /// Return a range of members in a sorted set with scores, by index, score or
+    /// lexicographical range depending on the options.
+    /// [Redis Docs](https://redis.io/commands/ZRANGE)
+    fn zrange_options_withscores<K: ToSingleRedisArg, S: ToSingleRedisArg, E: ToSingleRedisArg>(
+        key: K,
+        start: S,
+        stop: E,
+        options: &'a ZRangeOptions
+    ) -> (Vec<(String, f64)>) {
+        cmd("ZRANGE").arg(key).arg(start).arg(stop).arg(options).arg("WITHSCORES").take()
+    }
+
+    /// Store a range of members of a sorted set in a new key, by index, score or
+    /// lexicographical range depending on the options. Returns the number of stored members.
+    /// [Redis Docs](https://redis.io/commands/ZRANGESTORE)
+    fn zrangestore<D: ToSingleRedisArg, K: ToSingleRedisArg, S: ToSingleRedisArg, E: ToSingleRedisArg>(
+        dstkey: D,
+        key: K,
+        start: S,
+        stop: E,
+        options: &'a ZRangeOptions
+    ) -> (usize) {
+        cmd("ZRANGESTORE").arg(dstkey).arg(key).arg(start).arg(stop).arg(options).take()
+    }
+
     /// Return a range of members in a sorted set, by lexicographical range.
     /// [Redis Docs](https://redis.io/commands/ZRANGEBYLEX)
     fn zrangebylex<K: ToSingleRedisArg, M: ToSingleRedisArg, MM: ToSingleRedisArg>(key: K, min: M, max: MM) -> (Vec<String>) {
@@ -1383,6 +1658,31 @@ implement_commands! {
         cmd("ZMSCORE").arg(key).arg(members).take()
     }
 
+    /// Unions multiple sorted sets.
+    /// [Redis Docs](https://redis.io/commands/ZUNION)
+    fn zunion<K: ToRedisArgs>(keys: K) -> (Vec<String>) {
+        cmd("ZUNION").arg(keys.num_of_args()).arg(keys).take()
+    }
+
+    /// Unions multiple sorted sets, with scores summed.
+    /// [Redis Docs](https://redis.io/commands/ZUNION)
+    fn zunion_withscores<K: ToRedisArgs>(keys: K) -> (Vec<(String, f64)>) {
+        cmd("ZUNION").arg(keys.num_of_args()).arg(keys).arg("WITHSCORES").take()
+    }
+
+    /// Unions multiple sorted sets, with scores combined according to the options.
+    /// [Redis Docs](https://redis.io/commands/ZUNION)
+    fn zunion_options<K: ToRedisArgs>(keys: K, options: &'a ZAggregateOptions) -> (Vec<(String, f64)>) {
+        cmd("ZUNION").arg(keys.num_of_args()).arg(keys).arg(options).arg("WITHSCORES").take()
+    }
+
+    /// Unions multiple sorted sets and store the resulting sorted set in
+    /// a new key, with scores combined according to the options.
+    /// [Redis Docs](https://redis.io/commands/ZUNIONSTORE)
+    fn zunionstore_options<D: ToSingleRedisArg, K: ToRedisArgs>(dstkey: D, keys: K, options: &'a ZAggregateOptions) -> (usize) {
+        cmd("ZUNIONSTORE").arg(dstkey).arg(keys.num_of_args()).arg(keys).arg(options).take()
+    }
+
     /// Unions multiple sorted sets and store the resulting sorted set in
     /// a new key using SUM as aggregation function.
     /// [Redis Docs](https://redis.io/commands/ZUNIONSTORE)
@@ -1641,6 +1941,12 @@ implement_commands! {
         cmd("OBJECT").arg("REFCOUNT").arg(key).take()
     }
 
+    /// Get the number of bytes used by a key and its value, or `None` if the key doesn't exist.
+    /// [Redis Docs](https://redis.io/commands/MEMORY-USAGE)
+    fn memory_usage<K: ToSingleRedisArg>(key: K) -> (Option<u64>) {
+        cmd("MEMORY").arg("USAGE").arg(key).take()
+    }
+
     /// Returns the name of the current connection as set by CLIENT SETNAME.
     /// [Redis Docs](https://redis.io/commands/CLIENT)
     fn client_getname<>() -> (Option<String>) {
@@ -1659,6 +1965,108 @@ implement_commands! {
         cmd("CLIENT").arg("SETNAME").arg(connection_name).take()
     }
 
+    /// Returns information about the current connection.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-INFO)
+    fn client_info<>() -> (server::ClientInfo) {
+        cmd("CLIENT").arg("INFO").take()
+    }
+
+    /// Sets whether the current connection may be evicted when the server reaches its
+    /// client memory limit.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-NO-EVICT)
+    fn client_no_evict<>(enabled: bool) -> (()) {
+        cmd("CLIENT").arg("NO-EVICT").arg(if enabled { "ON" } else { "OFF" }).take()
+    }
+
+    /// Sets whether the commands of the current connection leave the LRU/LFU of the keys
+    /// they access untouched.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-NO-TOUCH)
+    fn client_no_touch<>(enabled: bool) -> (()) {
+        cmd("CLIENT").arg("NO-TOUCH").arg(if enabled { "ON" } else { "OFF" }).take()
+    }
+
+    // Durability commands
+
+    /// Blocks until the writes of the current connection are acknowledged by at least
+    /// `numreplicas` replicas, or `timeout` milliseconds elapse (0 blocks forever). Returns
+    /// the number of replicas that acknowledged the writes.
+    /// Use [`Pipeline::durability`](crate::Pipeline::durability) to wait after a pipeline.
+    /// [Redis Docs](https://redis.io/commands/WAIT)
+    fn wait<>(numreplicas: usize, timeout: u64) -> (usize) {
+        cmd("WAIT").arg(numreplicas).arg(timeout).take()
+    }
+
+    /// Blocks until the writes of the current connection are fsynced to the AOF of the local
+    /// instance (when `numlocal` is 1) and of at least `numreplicas` replicas, or `timeout`
+    /// milliseconds elapse (0 blocks forever).
+    /// Use [`Pipeline::durability`](crate::Pipeline::durability) to wait after a pipeline.
+    /// [Redis Docs](https://redis.io/commands/WAITAOF)
+    fn waitaof<>(numlocal: usize, numreplicas: usize, timeout: u64) -> (WaitAofReply) {
+        cmd("WAITAOF").arg(numlocal).arg(numreplicas).arg(timeout).take()
+    }
+
+    // Cluster introspection commands
+
+    /// Returns the shards of the cluster, with their slot ranges and nodes.
+    /// Use [`ShardInfo::topology`](crate::cluster_introspection::ShardInfo::topology) to turn
+    /// the reply into a [`ClusterTopology`](crate::cluster_read_routing::ClusterTopology).
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-SHARDS)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_shards<>() -> (Vec<crate::cluster_introspection::ShardInfo>) {
+        cmd("CLUSTER").arg("SHARDS").take()
+    }
+
+    /// Returns the nodes of the cluster, as seen by the node answering the command.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-NODES)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_nodes<>() -> (crate::cluster_introspection::ClusterNodes) {
+        cmd("CLUSTER").arg("NODES").take()
+    }
+
+    /// Returns the state of the cluster, as seen by the node answering the command.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-INFO)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_info<>() -> (crate::cluster_introspection::ClusterInfo) {
+        cmd("CLUSTER").arg("INFO").take()
+    }
+
+    /// Returns the ID of the node answering the command.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-MYID)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_myid<>() -> (String) {
+        cmd("CLUSTER").arg("MYID").take()
+    }
+
+    /// Returns the hash slot of the key.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-KEYSLOT)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_keyslot<K: ToSingleRedisArg>(key: K) -> (u16) {
+        cmd("CLUSTER").arg("KEYSLOT").arg(key).take()
+    }
+
+    /// Returns the number of keys in the hash slot. On cluster connections, the command is
+    /// sent to the primary serving the slot.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-COUNTKEYSINSLOT)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_countkeysinslot<>(slot: u16) -> (usize) {
+        cmd("CLUSTER").arg("COUNTKEYSINSLOT").arg(slot).take()
+    }
+
+    /// Returns up to `count` keys of the hash slot. On cluster connections, the command is
+    /// sent to the primary serving the slot.
+    /// [Redis Docs](https://redis.io/commands/CLUSTER-GETKEYSINSLOT)
+    #[cfg(feature = "cluster")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+    fn cluster_getkeysinslot<>(slot: u16, count: usize) -> (Vec<String>) {
+        cmd("CLUSTER").arg("GETKEYSINSLOT").arg(slot).arg(count).take()
+    }
+
     // ACL commands
 
     /// When Redis is configured to use an ACL file (with the aclfile
@@ -2014,6 +2422,76 @@ implement_commands! {
             .take()
     }
 
+    /// Return the members of a sorted set populated with geospatial information
+    /// using [`geo_add`](#method.geo_add), which are within the borders of the area
+    /// specified by a shape around a center. The center is either a member of the
+    /// sorted set or a position, and the shape is either a circle or a rectangle.
+    ///
+    /// Every item in the result can be read with [`redis::geo::GeoSearchResult`][1],
+    /// which support the multiple formats returned by `GEOSEARCH`.
+    ///
+    /// [1]: ./geo/struct.GeoSearchResult.html
+    ///
+    /// ```rust,no_run
+    /// use redis::{Commands, RedisResult};
+    /// use redis::geo::{GeoSearchBy, GeoSearchFrom, GeoSearchOptions, GeoSearchResult, RadiusOrder, Unit};
+    ///
+    /// fn search(con: &mut redis::Connection) -> (Vec<GeoSearchResult>) {
+    ///     let from = GeoSearchFrom::Member("Palermo".to_string());
+    ///     let by = GeoSearchBy::Radius(200.0, Unit::Kilometers);
+    ///     let opts = GeoSearchOptions::default().with_dist().order(RadiusOrder::Asc);
+    ///     con.geo_search("my_gis", from, by, opts).unwrap()
+    /// }
+    /// ```
+    ///
+    /// ```text
+    /// GEOSEARCH <key> <FROMMEMBER member | FROMLONLAT longitude latitude> <BYRADIUS radius unit | BYBOX width height unit> [ASC | DESC] [COUNT count [ANY]] [WITHCOORD] [WITHDIST] [WITHHASH]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/GEOSEARCH)
+    #[cfg(feature = "geospatial")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "geospatial")))]
+    fn geo_search<K: ToSingleRedisArg>(
+        key: K,
+        from: geo::GeoSearchFrom,
+        by: geo::GeoSearchBy,
+        options: geo::GeoSearchOptions
+    ) -> (Vec<geo::GeoSearchResult>) {
+        cmd("GEOSEARCH")
+            .arg(key)
+            .arg(from)
+            .arg(by)
+            .arg(options)
+            .take()
+    }
+
+    /// Like [`geo_search`](#method.geo_search), but stores the matching members in the
+    /// sorted set at `destination` instead of returning them. Returns the number of
+    /// stored members.
+    ///
+    /// ```text
+    /// GEOSEARCHSTORE <destination> <source> <FROMMEMBER member | FROMLONLAT longitude latitude> <BYRADIUS radius unit | BYBOX width height unit> [ASC | DESC] [COUNT count [ANY]] [STOREDIST]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/GEOSEARCHSTORE)
+    #[cfg(feature = "geospatial")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "geospatial")))]
+    fn geo_search_store<D: ToSingleRedisArg, S: ToSingleRedisArg>(
+        destination: D,
+        source: S,
+        from: geo::GeoSearchFrom,
+        by: geo::GeoSearchBy,
+        options: geo::GeoSearchStoreOptions
+    ) -> (usize) {
+        cmd("GEOSEARCHSTORE")
+            .arg(destination)
+            .arg(source)
+            .arg(from)
+            .arg(by)
+            .arg(options)
+            .take()
+    }
+
     //
     // streams commands
     //
@@ -2573,6 +3051,32 @@ implement_commands! {
         cmd("XINFO").arg("STREAM").arg(key).take()
     }
 
+    /// Returns the entries of the stream, its consumer groups with their pending entries lists,
+    /// and their consumers. At most 10 entries and pending entries per list are returned.
+    ///
+    /// ```text
+    /// XINFO STREAM <key> FULL
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/XINFO-STREAM)
+    #[cfg(feature = "streams")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streams")))]
+    fn xinfo_stream_full<K: ToRedisArgs>(key: K) -> (streams::StreamInfoFullReply) {
+        cmd("XINFO").arg("STREAM").arg(key).arg("FULL").take()
+    }
+
+    /// Like [`xinfo_stream_full`](Self::xinfo_stream_full), but returns at most `count` entries
+    /// and pending entries per list. A `count` of 0 returns all of them.
+    ///
+    /// ```text
+    /// XINFO STREAM <key> FULL COUNT <count>
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/XINFO-STREAM)
+    #[cfg(feature = "streams")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streams")))]
+    fn xinfo_stream_full_count<K: ToRedisArgs>(key: K, count: usize) -> (streams::StreamInfoFullReply) {
+        cmd("XINFO").arg("STREAM").arg(key).arg("FULL").arg("COUNT").arg(count).take()
+    }
+
     /// Returns the number of messages for a given stream `key`.
     ///
     /// ```text
@@ -3009,6 +3513,132 @@ assert_eq!(invok_2_res, 5);
         invocation.eval_cmd().take()
     }
 
+    // function commands
+
+    /// Load a function library and return its name.
+    ///
+    /// See [`Library`](crate::Library) for a higher level interface that also takes care of
+    /// invoking the library's functions.
+    ///
+    /// ```text
+    /// FUNCTION LOAD <code>
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-LOAD)
+    fn function_load<C: ToSingleRedisArg>(code: C) -> (String) {
+        cmd("FUNCTION").arg("LOAD").arg(code).take()
+    }
+
+    /// Load a function library, replacing an existing library with the same name, and return its name.
+    ///
+    /// ```text
+    /// FUNCTION LOAD REPLACE <code>
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-LOAD)
+    fn function_load_replace<C: ToSingleRedisArg>(code: C) -> (String) {
+        cmd("FUNCTION").arg("LOAD").arg("REPLACE").arg(code).take()
+    }
+
+    /// Delete a function library and all of its functions.
+    ///
+    /// ```text
+    /// FUNCTION DELETE <library-name>
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-DELETE)
+    fn function_delete<L: ToSingleRedisArg>(library_name: L) -> (()) {
+        cmd("FUNCTION").arg("DELETE").arg(library_name).take()
+    }
+
+    /// Delete all function libraries.
+    ///
+    /// ```text
+    /// FUNCTION FLUSH
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-FLUSH)
+    fn function_flush<>() -> (()) {
+        cmd("FUNCTION").arg("FLUSH").take()
+    }
+
+    /// Return information about all loaded function libraries.
+    ///
+    /// ```text
+    /// FUNCTION LIST
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-LIST)
+    fn function_list<>() -> (Vec<function::LibraryInfo>) {
+        cmd("FUNCTION").arg("LIST").take()
+    }
+
+    /// Return information about the loaded function libraries with options.
+    ///
+    /// ```text
+    /// FUNCTION LIST [LIBRARYNAME library-name-pattern] [WITHCODE]
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-LIST)
+    fn function_list_options<>(options: function::FunctionListOptions) -> (Vec<function::LibraryInfo>) {
+        cmd("FUNCTION").arg("LIST").arg(options).take()
+    }
+
+    /// Return information about the function that is currently running and the loaded libraries.
+    ///
+    /// On cluster connections this is sent to every node, so the reply is a map from node
+    /// address to [`FunctionStats`](function::FunctionStats) and has to be requested through
+    /// [`Commands`] with an explicit return type.
+    ///
+    /// ```text
+    /// FUNCTION STATS
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-STATS)
+    fn function_stats<>() -> (function::FunctionStats) {
+        cmd("FUNCTION").arg("STATS").take()
+    }
+
+    /// Return a serialized payload of all loaded function libraries.
+    ///
+    /// The payload can be restored with [`function_restore`](Self::function_restore).
+    ///
+    /// ```text
+    /// FUNCTION DUMP
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-DUMP)
+    fn function_dump<>() -> (Vec<u8>) {
+        cmd("FUNCTION").arg("DUMP").take()
+    }
+
+    /// Restore function libraries from a payload created by [`function_dump`](Self::function_dump).
+    ///
+    /// ```text
+    /// FUNCTION RESTORE <serialized-value> [FLUSH|APPEND|REPLACE]
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FUNCTION-RESTORE)
+    fn function_restore<>(payload: &'a [u8], policy: function::FunctionRestorePolicy) -> (()) {
+        cmd("FUNCTION").arg("RESTORE").arg(payload).arg(policy).take()
+    }
+
+    /// Invoke a function.
+    ///
+    /// Unlike [`FunctionInvocation::invoke`](crate::FunctionInvocation::invoke), this does _not_
+    /// load the function's library if it is missing.
+    ///
+    /// ```text
+    /// FCALL <function> <numkeys> [key [key ...]] [arg [arg ...]]
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FCALL)
+    fn fcall<F: ToSingleRedisArg, K: ToRedisArgs, A: ToRedisArgs>(function: F, keys: K, args: A) -> Generic {
+        cmd("FCALL").arg(function).arg(keys.num_of_args()).arg(keys).arg(args).take()
+    }
+
+    /// Invoke a read-only function, i.e. one that is registered with the `no-writes` flag.
+    ///
+    /// In cluster mode, read-only calls may be routed to replicas.
+    ///
+    /// ```text
+    /// FCALL_RO <function> <numkeys> [key [key ...]] [arg [arg ...]]
+    /// ```
+    /// [Redis Docs](https://redis.io/commands/FCALL_RO)
+    fn fcall_ro<F: ToSingleRedisArg, K: ToRedisArgs, A: ToRedisArgs>(function: F, keys: K, args: A) -> Generic {
+        cmd("FCALL_RO").arg(function).arg(keys.num_of_args()).arg(keys).arg(args).take()
+    }
+
     // cleanup commands
 
     /// Deletes all the keys of all databases
@@ -3266,42 +3896,1212 @@ assert_eq!(invok_2_res, 5);
     fn bf_scandump<K: ToSingleRedisArg>(key: K, iterator: i64) -> (crate::bloom::BloomFilterDumpChunk) {
         cmd("BF.SCANDUMP").arg(key).arg(iterator).take()
     }
-}
 
-/// Allows pubsub callbacks to stop receiving messages.
-///
-/// Arbitrary data may be returned from `Break`.
-#[non_exhaustive]
-pub enum ControlFlow<U> {
-    /// Continues.
-    Continue,
-    /// Breaks with a value.
-    Break(U),
-}
+    // Cuckoo filter commands
 
-/// The PubSub trait allows subscribing to one or more channels
-/// and receiving a callback whenever a message arrives.
-///
-/// Each method handles subscribing to the list of keys, waiting for
-/// messages, and unsubscribing from the same list of channels once
-/// a ControlFlow::Break is encountered.
-///
-/// Once (p)subscribe returns Ok(U), the connection is again safe to use
-/// for calling other methods.
-///
-/// # Examples
-///
-/// ```rust,no_run
-/// # fn do_something() -> redis::RedisResult<()> {
-/// use redis::{PubSubCommands, ControlFlow};
-/// let client = redis::Client::open("redis://127.0.0.1/")?;
-/// let mut con = client.get_connection()?;
-/// let mut count = 0;
-/// con.subscribe(&["foo"], |msg| {
-///     // do something with message
-///     assert_eq!(msg.get_channel(), Ok(String::from("foo")));
-///
-///     // increment messages seen counter
+    /// Adds an item to a Cuckoo filter, creating it with default settings if it does not yet exist.
+    ///
+    /// Adding an item multiple times makes it exist multiple times, see [`cf_addnx`](Self::cf_addnx).
+    ///
+    /// ```text
+    /// CF.ADD <key> <item>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.ADD)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_add<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, item: V) -> (bool) {
+        cmd("CF.ADD").arg(key).arg(item).take()
+    }
+
+    /// Adds an item to a Cuckoo filter if it does not exist yet, creating the filter with default settings if needed.
+    ///
+    /// Returns `false` if the item may already exist.
+    ///
+    /// ```text
+    /// CF.ADDNX <key> <item>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.ADDNX)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_addnx<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, item: V) -> (bool) {
+        cmd("CF.ADDNX").arg(key).arg(item).take()
+    }
+
+    /// Returns the number of times an item may exist in a Cuckoo filter.
+    ///
+    /// ```text
+    /// CF.COUNT <key> <item>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.COUNT)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_count<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, item: V) -> (usize) {
+        cmd("CF.COUNT").arg(key).arg(item).take()
+    }
+
+    /// Deletes one occurrence of an item from a Cuckoo filter.
+    ///
+    /// ```text
+    /// CF.DEL <key> <item>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.DEL)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_del<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, item: V) -> (bool) {
+        cmd("CF.DEL").arg(key).arg(item).take()
+    }
+
+    /// Checks if an item may exist in a Cuckoo filter.
+    ///
+    /// ```text
+    /// CF.EXISTS <key> <item>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.EXISTS)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_exists<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, item: V) -> (bool) {
+        cmd("CF.EXISTS").arg(key).arg(item).take()
+    }
+
+    /// Returns information about a Cuckoo filter.
+    ///
+    /// ```text
+    /// CF.INFO <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.INFO)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_info<K: ToSingleRedisArg>(key: K) -> (crate::bloom::CuckooFilterInfo) {
+        cmd("CF.INFO").arg(key).take()
+    }
+
+    /// Adds items to a Cuckoo filter, creating it with default settings if it does not yet exist.
+    ///
+    /// ```text
+    /// CF.INSERT <key> ITEMS <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.INSERT)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_insert<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V) -> (Vec<crate::bloom::CuckooFilterInsertResult>) {
+        cmd("CF.INSERT").arg(key).arg("ITEMS").arg(items).take()
+    }
+
+    /// Adds items to a Cuckoo filter, creating it with custom options if it does not exist yet.
+    ///
+    /// ```text
+    /// CF.INSERT <key> [CAPACITY capacity] [NOCREATE] ITEMS <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.INSERT)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_insert_options<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V, options: crate::bloom::CuckooFilterInsertOptions) -> (Vec<crate::bloom::CuckooFilterInsertResult>) {
+        cmd("CF.INSERT").arg(key).arg(options).arg("ITEMS").arg(items).take()
+    }
+
+    /// Adds items that do not exist yet to a Cuckoo filter, creating it with default settings if it does not yet exist.
+    ///
+    /// ```text
+    /// CF.INSERTNX <key> ITEMS <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.INSERTNX)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_insertnx<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V) -> (Vec<crate::bloom::CuckooFilterInsertResult>) {
+        cmd("CF.INSERTNX").arg(key).arg("ITEMS").arg(items).take()
+    }
+
+    /// Adds items that do not exist yet to a Cuckoo filter, creating it with custom options if it does not exist yet.
+    ///
+    /// ```text
+    /// CF.INSERTNX <key> [CAPACITY capacity] [NOCREATE] ITEMS <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.INSERTNX)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_insertnx_options<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V, options: crate::bloom::CuckooFilterInsertOptions) -> (Vec<crate::bloom::CuckooFilterInsertResult>) {
+        cmd("CF.INSERTNX").arg(key).arg(options).arg("ITEMS").arg(items).take()
+    }
+
+    /// Restores a Cuckoo filter previously saved using [`cf_scandump`](Self::cf_scandump).
+    ///
+    /// ```text
+    /// CF.LOADCHUNK <key> <iterator> <data>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.LOADCHUNK)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_loadchunk<K: ToSingleRedisArg>(key: K, chunk: crate::bloom::CuckooFilterDumpChunk) -> (()) {
+        cmd("CF.LOADCHUNK").arg(key).arg(chunk.iterator).arg(chunk.data).take()
+    }
+
+    /// Checks if items may exist in a Cuckoo filter.
+    ///
+    /// ```text
+    /// CF.MEXISTS <key> <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.MEXISTS)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_mexists<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: &'a [V]) -> (Vec<bool>) {
+        cmd("CF.MEXISTS").arg(key).arg(items).take()
+    }
+
+    /// Creates an empty Cuckoo filter with default settings.
+    ///
+    /// ```text
+    /// CF.RESERVE <key> <capacity>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.RESERVE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_reserve<K: ToSingleRedisArg>(key: K, capacity: usize) -> (()) {
+        cmd("CF.RESERVE").arg(key).arg(capacity).take()
+    }
+
+    /// Creates an empty Cuckoo filter with options.
+    ///
+    /// ```text
+    /// CF.RESERVE <key> <capacity> [BUCKETSIZE bucketsize] [MAXITERATIONS maxiterations] [EXPANSION expansion]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.RESERVE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_reserve_options<K: ToSingleRedisArg>(key: K, capacity: usize, options: crate::bloom::CuckooFilterReserveOptions) -> (()) {
+        cmd("CF.RESERVE").arg(key).arg(capacity).arg(options).take()
+    }
+
+    /// Begins an incremental save of the Cuckoo filter
+    ///
+    /// [`CuckooFilterDumpIterator`](crate::bloom::CuckooFilterDumpIterator) allows to dump a Cuckoo
+    /// filter in a more accessible way than manually dumping chunk by chunk.
+    ///
+    /// ```text
+    /// CF.SCANDUMP <key> <iterator>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CF.SCANDUMP)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cf_scandump<K: ToSingleRedisArg>(key: K, iterator: i64) -> (crate::bloom::CuckooFilterDumpChunk) {
+        cmd("CF.SCANDUMP").arg(key).arg(iterator).take()
+    }
+
+    // Count-Min Sketch commands
+
+    /// Increases the counts of items in a Count-Min Sketch, given as `(item, increment)` pairs.
+    /// Returns the updated count of each item.
+    ///
+    /// ```text
+    /// CMS.INCRBY <key> <item> <increment> [<item> <increment> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.INCRBY)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_incrby<K: ToSingleRedisArg, V: ToRedisArgs, I: ToRedisArgs>(key: K, items: &'a [(V, I)]) -> (Vec<u64>) {
+        cmd("CMS.INCRBY").arg(key).arg(items).take()
+    }
+
+    /// Returns information about a Count-Min Sketch.
+    ///
+    /// ```text
+    /// CMS.INFO <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.INFO)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_info<K: ToSingleRedisArg>(key: K) -> (crate::bloom::CountMinSketchInfo) {
+        cmd("CMS.INFO").arg(key).take()
+    }
+
+    /// Creates an empty Count-Min Sketch with the given dimensions.
+    ///
+    /// ```text
+    /// CMS.INITBYDIM <key> <width> <depth>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.INITBYDIM)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_initbydim<K: ToSingleRedisArg>(key: K, width: usize, depth: usize) -> (()) {
+        cmd("CMS.INITBYDIM").arg(key).arg(width).arg(depth).take()
+    }
+
+    /// Creates an empty Count-Min Sketch sized for the given overestimation error and the
+    /// probability of exceeding it.
+    ///
+    /// ```text
+    /// CMS.INITBYPROB <key> <error> <probability>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.INITBYPROB)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_initbyprob<K: ToSingleRedisArg>(key: K, error: f64, probability: f64) -> (()) {
+        cmd("CMS.INITBYPROB").arg(key).arg(error).arg(probability).take()
+    }
+
+    /// Merges Count-Min Sketches of the same dimensions into an existing destination sketch.
+    ///
+    /// ```text
+    /// CMS.MERGE <destination> <numKeys> <source1> <source2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.MERGE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_merge<D: ToSingleRedisArg, S: ToRedisArgs>(destination: D, sources: S) -> (()) {
+        cmd("CMS.MERGE").arg(destination).arg(sources.num_of_args()).arg(sources).take()
+    }
+
+    /// Merges Count-Min Sketches of the same dimensions into an existing destination sketch, with options.
+    ///
+    /// ```text
+    /// CMS.MERGE <destination> <numKeys> <source1> <source2> ... [WEIGHTS <weight1> <weight2> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.MERGE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_merge_options<D: ToSingleRedisArg, S: ToRedisArgs>(destination: D, sources: S, options: crate::bloom::CountMinSketchMergeOptions) -> (()) {
+        cmd("CMS.MERGE").arg(destination).arg(sources.num_of_args()).arg(sources).arg(options).take()
+    }
+
+    /// Returns the estimated counts of items in a Count-Min Sketch.
+    ///
+    /// ```text
+    /// CMS.QUERY <key> <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/CMS.QUERY)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn cms_query<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V) -> (Vec<u64>) {
+        cmd("CMS.QUERY").arg(key).arg(items).take()
+    }
+
+    // Top-K commands
+
+    /// Adds items to a Top-K sketch.
+    /// Returns, for each item, the item it expelled from the top list, if any.
+    ///
+    /// ```text
+    /// TOPK.ADD <key> <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.ADD)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_add<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V) -> (Vec<Option<String>>) {
+        cmd("TOPK.ADD").arg(key).arg(items).take()
+    }
+
+    /// Increases the counts of items in a Top-K sketch, given as `(item, increment)` pairs.
+    /// Returns, for each item, the item it expelled from the top list, if any.
+    ///
+    /// ```text
+    /// TOPK.INCRBY <key> <item> <increment> [<item> <increment> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.INCRBY)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_incrby<K: ToSingleRedisArg, V: ToRedisArgs, I: ToRedisArgs>(key: K, items: &'a [(V, I)]) -> (Vec<Option<String>>) {
+        cmd("TOPK.INCRBY").arg(key).arg(items).take()
+    }
+
+    /// Returns information about a Top-K sketch.
+    ///
+    /// ```text
+    /// TOPK.INFO <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.INFO)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_info<K: ToSingleRedisArg>(key: K) -> (crate::bloom::TopKInfo) {
+        cmd("TOPK.INFO").arg(key).take()
+    }
+
+    /// Returns the items in the top list of a Top-K sketch.
+    ///
+    /// ```text
+    /// TOPK.LIST <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.LIST)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_list<K: ToSingleRedisArg>(key: K) -> (Vec<String>) {
+        cmd("TOPK.LIST").arg(key).take()
+    }
+
+    /// Returns the items in the top list of a Top-K sketch, with their estimated counts.
+    ///
+    /// ```text
+    /// TOPK.LIST <key> WITHCOUNT
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.LIST)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_list_with_count<K: ToSingleRedisArg>(key: K) -> (Vec<(String, u64)>) {
+        cmd("TOPK.LIST").arg(key).arg("WITHCOUNT").take()
+    }
+
+    /// Checks if items are in the top list of a Top-K sketch.
+    ///
+    /// ```text
+    /// TOPK.QUERY <key> <item1> <item2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.QUERY)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_query<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, items: V) -> (Vec<bool>) {
+        cmd("TOPK.QUERY").arg(key).arg(items).take()
+    }
+
+    /// Creates an empty Top-K sketch keeping the `k` most frequent items, with default dimensions.
+    ///
+    /// ```text
+    /// TOPK.RESERVE <key> <topk>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.RESERVE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_reserve<K: ToSingleRedisArg>(key: K, k: usize) -> (()) {
+        cmd("TOPK.RESERVE").arg(key).arg(k).take()
+    }
+
+    /// Creates an empty Top-K sketch keeping the `k` most frequent items, with the given dimensions.
+    ///
+    /// ```text
+    /// TOPK.RESERVE <key> <topk> <width> <depth> <decay>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TOPK.RESERVE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn topk_reserve_options<K: ToSingleRedisArg>(key: K, k: usize, options: crate::bloom::TopKReserveOptions) -> (()) {
+        cmd("TOPK.RESERVE").arg(key).arg(k).arg(options).take()
+    }
+
+    // T-Digest commands
+
+    /// Adds observations to a T-Digest sketch.
+    ///
+    /// ```text
+    /// TDIGEST.ADD <key> <value1> <value2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.ADD)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_add<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, values: V) -> (()) {
+        cmd("TDIGEST.ADD").arg(key).arg(values).take()
+    }
+
+    /// Returns the estimated values of the observations with the given ranks, where rank 0 is the
+    /// smallest observation.
+    ///
+    /// ```text
+    /// TDIGEST.BYRANK <key> <rank1> <rank2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.BYRANK)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_byrank<K: ToSingleRedisArg, R: ToRedisArgs>(key: K, ranks: R) -> (Vec<f64>) {
+        cmd("TDIGEST.BYRANK").arg(key).arg(ranks).take()
+    }
+
+    /// Returns the estimated values of the observations with the given reverse ranks, where reverse
+    /// rank 0 is the largest observation.
+    ///
+    /// ```text
+    /// TDIGEST.BYREVRANK <key> <rank1> <rank2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.BYREVRANK)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_byrevrank<K: ToSingleRedisArg, R: ToRedisArgs>(key: K, ranks: R) -> (Vec<f64>) {
+        cmd("TDIGEST.BYREVRANK").arg(key).arg(ranks).take()
+    }
+
+    /// Returns the estimated fraction of observations smaller than or equal to each of the given values.
+    ///
+    /// ```text
+    /// TDIGEST.CDF <key> <value1> <value2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.CDF)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_cdf<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, values: V) -> (Vec<f64>) {
+        cmd("TDIGEST.CDF").arg(key).arg(values).take()
+    }
+
+    /// Creates an empty T-Digest sketch with default compression.
+    ///
+    /// ```text
+    /// TDIGEST.CREATE <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.CREATE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_create<K: ToSingleRedisArg>(key: K) -> (()) {
+        cmd("TDIGEST.CREATE").arg(key).take()
+    }
+
+    /// Creates an empty T-Digest sketch with options.
+    ///
+    /// ```text
+    /// TDIGEST.CREATE <key> [COMPRESSION compression]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.CREATE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_create_options<K: ToSingleRedisArg>(key: K, options: crate::bloom::TDigestCreateOptions) -> (()) {
+        cmd("TDIGEST.CREATE").arg(key).arg(options).take()
+    }
+
+    /// Returns information about a T-Digest sketch.
+    ///
+    /// ```text
+    /// TDIGEST.INFO <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.INFO)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_info<K: ToSingleRedisArg>(key: K) -> (crate::bloom::TDigestInfo) {
+        cmd("TDIGEST.INFO").arg(key).take()
+    }
+
+    /// Returns the largest observation of a T-Digest sketch, or `NaN` if it is empty.
+    ///
+    /// ```text
+    /// TDIGEST.MAX <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.MAX)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_max<K: ToSingleRedisArg>(key: K) -> (f64) {
+        cmd("TDIGEST.MAX").arg(key).take()
+    }
+
+    /// Merges T-Digest sketches into a destination sketch, creating it if needed.
+    ///
+    /// ```text
+    /// TDIGEST.MERGE <destination> <numKeys> <source1> <source2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.MERGE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_merge<D: ToSingleRedisArg, S: ToRedisArgs>(destination: D, sources: S) -> (()) {
+        cmd("TDIGEST.MERGE").arg(destination).arg(sources.num_of_args()).arg(sources).take()
+    }
+
+    /// Merges T-Digest sketches into a destination sketch with options, creating it if needed.
+    ///
+    /// ```text
+    /// TDIGEST.MERGE <destination> <numKeys> <source1> <source2> ... [COMPRESSION compression] [OVERRIDE]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.MERGE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_merge_options<D: ToSingleRedisArg, S: ToRedisArgs>(destination: D, sources: S, options: crate::bloom::TDigestMergeOptions) -> (()) {
+        cmd("TDIGEST.MERGE").arg(destination).arg(sources.num_of_args()).arg(sources).arg(options).take()
+    }
+
+    /// Returns the smallest observation of a T-Digest sketch, or `NaN` if it is empty.
+    ///
+    /// ```text
+    /// TDIGEST.MIN <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.MIN)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_min<K: ToSingleRedisArg>(key: K) -> (f64) {
+        cmd("TDIGEST.MIN").arg(key).take()
+    }
+
+    /// Returns the estimated values below which the given fractions of observations fall.
+    ///
+    /// ```text
+    /// TDIGEST.QUANTILE <key> <quantile1> <quantile2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.QUANTILE)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_quantile<K: ToSingleRedisArg, Q: ToRedisArgs>(key: K, quantiles: Q) -> (Vec<f64>) {
+        cmd("TDIGEST.QUANTILE").arg(key).arg(quantiles).take()
+    }
+
+    /// Returns the estimated ranks of the given values, where rank 0 is the smallest observation.
+    ///
+    /// ```text
+    /// TDIGEST.RANK <key> <value1> <value2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.RANK)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_rank<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, values: V) -> (Vec<i64>) {
+        cmd("TDIGEST.RANK").arg(key).arg(values).take()
+    }
+
+    /// Returns the estimated reverse ranks of the given values, where reverse rank 0 is the largest
+    /// observation.
+    ///
+    /// ```text
+    /// TDIGEST.REVRANK <key> <value1> <value2> ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.REVRANK)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_revrank<K: ToSingleRedisArg, V: ToRedisArgs>(key: K, values: V) -> (Vec<i64>) {
+        cmd("TDIGEST.REVRANK").arg(key).arg(values).take()
+    }
+
+    /// Removes all observations from a T-Digest sketch.
+    ///
+    /// ```text
+    /// TDIGEST.RESET <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.RESET)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_reset<K: ToSingleRedisArg>(key: K) -> (()) {
+        cmd("TDIGEST.RESET").arg(key).take()
+    }
+
+    /// Returns the mean of the observations between the given quantiles, e.g. `0.1` and `0.9`.
+    ///
+    /// ```text
+    /// TDIGEST.TRIMMED_MEAN <key> <low_cut_quantile> <high_cut_quantile>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TDIGEST.TRIMMED_MEAN)
+    #[cfg(feature = "bloom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
+    fn tdigest_trimmed_mean<K: ToSingleRedisArg>(key: K, low_cut_quantile: f64, high_cut_quantile: f64) -> (f64) {
+        cmd("TDIGEST.TRIMMED_MEAN").arg(key).arg(low_cut_quantile).arg(high_cut_quantile).take()
+    }
+
+    // RediSearch commands
+
+    /// Creates an index with the given schema, covering all hashes.
+    ///
+    /// ```text
+    /// FT.CREATE <index> SCHEMA <field> [<field> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.CREATE)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_create<I: ToSingleRedisArg>(index: I, schema: &'a search::Schema) -> (()) {
+        cmd("FT.CREATE").arg(index).arg(schema).take()
+    }
+
+    /// Creates an index with the given options and schema.
+    ///
+    /// ```text
+    /// FT.CREATE <index> [ON HASH | JSON] [PREFIX count prefix [prefix ...]] [...] SCHEMA <field> [<field> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.CREATE)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_create_options<I: ToSingleRedisArg>(index: I, options: &'a search::CreateIndexOptions, schema: &'a search::Schema) -> (()) {
+        cmd("FT.CREATE").arg(index).arg(options).arg(schema).take()
+    }
+
+    /// Adds a field to an existing index.
+    ///
+    /// ```text
+    /// FT.ALTER <index> SCHEMA ADD <field>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.ALTER)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_alter<I: ToSingleRedisArg>(index: I, field: &'a search::SchemaField) -> (()) {
+        cmd("FT.ALTER").arg(index).arg("SCHEMA").arg("ADD").arg(field).take()
+    }
+
+    /// Deletes an index, keeping the indexed documents.
+    ///
+    /// ```text
+    /// FT.DROPINDEX <index>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.DROPINDEX)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_dropindex<I: ToSingleRedisArg>(index: I) -> (()) {
+        cmd("FT.DROPINDEX").arg(index).take()
+    }
+
+    /// Deletes an index together with the indexed documents.
+    ///
+    /// ```text
+    /// FT.DROPINDEX <index> DD
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.DROPINDEX)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_dropindex_delete_documents<I: ToSingleRedisArg>(index: I) -> (()) {
+        cmd("FT.DROPINDEX").arg(index).arg("DD").take()
+    }
+
+    /// Returns the names of all indexes.
+    ///
+    /// ```text
+    /// FT._LIST
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT._LIST)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_list<>() -> (Vec<String>) {
+        cmd("FT._LIST").take()
+    }
+
+    /// Returns information and statistics about an index.
+    ///
+    /// ```text
+    /// FT.INFO <index>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.INFO)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_info<I: ToSingleRedisArg>(index: I) -> (search::IndexInfo) {
+        cmd("FT.INFO").arg(index).take()
+    }
+
+    /// Searches an index.
+    ///
+    /// ```text
+    /// FT.SEARCH <index> <query> [NOCONTENT] [WITHSCORES] [RETURN count identifier ...] [...]
+    /// ```
+    ///
+    /// With RESP2, the reply of queries using WITHPAYLOADS, or WITHSCORES along with NOCONTENT,
+    /// can only be parsed knowing the query, with [`SearchQuery::search`](crate::search::SearchQuery::search).
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.SEARCH)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_search<I: ToSingleRedisArg>(index: I, query: &'a search::SearchQuery) -> (search::SearchResult) {
+        cmd("FT.SEARCH").arg(index).arg(query).take()
+    }
+
+    /// Runs an aggregation over an index.
+    ///
+    /// ```text
+    /// FT.AGGREGATE <index> <query> [LOAD count field ...] [GROUPBY ...] [SORTBY ...] [...] [WITHCURSOR ...]
+    /// ```
+    ///
+    /// [`AggregateCursor`](crate::search::AggregateCursor) reads the results of aggregations
+    /// using a cursor batch by batch.
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.AGGREGATE)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_aggregate<I: ToSingleRedisArg>(index: I, query: &'a search::AggregateQuery) -> (search::AggregateResult) {
+        cmd("FT.AGGREGATE").arg(index).arg(query).take()
+    }
+
+    /// Reads the next batch of results from an aggregation cursor.
+    ///
+    /// ```text
+    /// FT.CURSOR READ <index> <cursor_id>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.CURSOR-READ)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_cursor_read<I: ToSingleRedisArg>(index: I, cursor_id: u64) -> (search::AggregateResult) {
+        cmd("FT.CURSOR").arg("READ").arg(index).arg(cursor_id).take()
+    }
+
+    /// Deletes an aggregation cursor.
+    ///
+    /// ```text
+    /// FT.CURSOR DEL <index> <cursor_id>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.CURSOR-DEL)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_cursor_del<I: ToSingleRedisArg>(index: I, cursor_id: u64) -> (()) {
+        cmd("FT.CURSOR").arg("DEL").arg(index).arg(cursor_id).take()
+    }
+
+    /// Returns the execution plan of a query.
+    ///
+    /// ```text
+    /// FT.EXPLAIN <index> <query>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.EXPLAIN)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_explain<I: ToSingleRedisArg, Q: ToSingleRedisArg>(index: I, query: Q) -> (String) {
+        cmd("FT.EXPLAIN").arg(index).arg(query).take()
+    }
+
+    /// Returns the distinct values of a tag field.
+    ///
+    /// ```text
+    /// FT.TAGVALS <index> <field>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.TAGVALS)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_tagvals<I: ToSingleRedisArg, F: ToSingleRedisArg>(index: I, field: F) -> (Vec<String>) {
+        cmd("FT.TAGVALS").arg(index).arg(field).take()
+    }
+
+    /// Adds an alias to an index.
+    ///
+    /// ```text
+    /// FT.ALIASADD <alias> <index>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.ALIASADD)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_aliasadd<A: ToSingleRedisArg, I: ToSingleRedisArg>(alias: A, index: I) -> (()) {
+        cmd("FT.ALIASADD").arg(alias).arg(index).take()
+    }
+
+    /// Points an alias to another index, creating it if needed.
+    ///
+    /// ```text
+    /// FT.ALIASUPDATE <alias> <index>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.ALIASUPDATE)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_aliasupdate<A: ToSingleRedisArg, I: ToSingleRedisArg>(alias: A, index: I) -> (()) {
+        cmd("FT.ALIASUPDATE").arg(alias).arg(index).take()
+    }
+
+    /// Removes an alias.
+    ///
+    /// ```text
+    /// FT.ALIASDEL <alias>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/FT.ALIASDEL)
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    fn ft_aliasdel<A: ToSingleRedisArg>(alias: A) -> (()) {
+        cmd("FT.ALIASDEL").arg(alias).take()
+    }
+
+    // RedisTimeSeries commands
+
+    /// Creates a time series with the default settings.
+    ///
+    /// ```text
+    /// TS.CREATE <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.CREATE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_create<K: ToSingleRedisArg>(key: K) -> (()) {
+        cmd("TS.CREATE").arg(key).take()
+    }
+
+    /// Creates a time series with the given settings.
+    ///
+    /// ```text
+    /// TS.CREATE <key> [RETENTION retentionPeriod] [ENCODING COMPRESSED | UNCOMPRESSED] [CHUNK_SIZE size] [DUPLICATE_POLICY policy] [IGNORE ignoreMaxTimediff ignoreMaxValDiff] [LABELS label value ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.CREATE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_create_options<K: ToSingleRedisArg>(key: K, options: &'a timeseries::TsCreateOptions) -> (()) {
+        cmd("TS.CREATE").arg(key).arg(options).take()
+    }
+
+    /// Updates the settings of a time series.
+    ///
+    /// ```text
+    /// TS.ALTER <key> [RETENTION retentionPeriod] [CHUNK_SIZE size] [DUPLICATE_POLICY policy] [IGNORE ignoreMaxTimediff ignoreMaxValDiff] [LABELS label value ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.ALTER)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_alter<K: ToSingleRedisArg>(key: K, options: &'a timeseries::TsAlterOptions) -> (()) {
+        cmd("TS.ALTER").arg(key).arg(options).take()
+    }
+
+    /// Appends a sample to a time series, creating it if needed.
+    /// The timestamp is given in milliseconds, or as `*` to use the server time.
+    /// Returns the timestamp of the sample.
+    ///
+    /// ```text
+    /// TS.ADD <key> <timestamp> <value>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.ADD)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_add<K: ToSingleRedisArg, T: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, timestamp: T, value: V) -> (u64) {
+        cmd("TS.ADD").arg(key).arg(timestamp).arg(value).take()
+    }
+
+    /// Appends a sample to a time series with the given options.
+    /// Returns the timestamp of the sample.
+    ///
+    /// ```text
+    /// TS.ADD <key> <timestamp> <value> [RETENTION retentionPeriod] [...] [ON_DUPLICATE policy_ovr] [LABELS label value ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.ADD)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_add_options<K: ToSingleRedisArg, T: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, timestamp: T, value: V, options: &'a timeseries::TsAddOptions) -> (u64) {
+        cmd("TS.ADD").arg(key).arg(timestamp).arg(value).arg(options).take()
+    }
+
+    /// Appends samples to existing time series, given as `(key, timestamp, value)` triples.
+    /// Returns the timestamp of each sample, or an error for each sample that was rejected.
+    ///
+    /// ```text
+    /// TS.MADD <key> <timestamp> <value> [<key> <timestamp> <value> ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MADD)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_madd<K: ToRedisArgs, T: ToRedisArgs, V: ToRedisArgs>(samples: &'a [(K, T, V)]) -> Generic {
+        cmd("TS.MADD").arg(samples).take()
+    }
+
+    /// Increases the value of the latest sample of a time series, creating it if needed.
+    /// Returns the timestamp of the updated sample.
+    ///
+    /// ```text
+    /// TS.INCRBY <key> <value>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.INCRBY)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_incrby<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, value: V) -> (u64) {
+        cmd("TS.INCRBY").arg(key).arg(value).take()
+    }
+
+    /// Increases the value of the latest sample of a time series with the given options.
+    /// Returns the timestamp of the updated sample.
+    ///
+    /// ```text
+    /// TS.INCRBY <key> <value> [TIMESTAMP timestamp] [RETENTION retentionPeriod] [...] [LABELS label value ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.INCRBY)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_incrby_options<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, value: V, options: &'a timeseries::TsIncrByOptions) -> (u64) {
+        cmd("TS.INCRBY").arg(key).arg(value).arg(options).take()
+    }
+
+    /// Decreases the value of the latest sample of a time series, creating it if needed.
+    /// Returns the timestamp of the updated sample.
+    ///
+    /// ```text
+    /// TS.DECRBY <key> <value>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.DECRBY)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_decrby<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, value: V) -> (u64) {
+        cmd("TS.DECRBY").arg(key).arg(value).take()
+    }
+
+    /// Decreases the value of the latest sample of a time series with the given options.
+    /// Returns the timestamp of the updated sample.
+    ///
+    /// ```text
+    /// TS.DECRBY <key> <value> [TIMESTAMP timestamp] [RETENTION retentionPeriod] [...] [LABELS label value ...]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.DECRBY)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_decrby_options<K: ToSingleRedisArg, V: ToSingleRedisArg>(key: K, value: V, options: &'a timeseries::TsIncrByOptions) -> (u64) {
+        cmd("TS.DECRBY").arg(key).arg(value).arg(options).take()
+    }
+
+    /// Returns the samples of a time series between two timestamps, in ascending order.
+    /// The timestamps are given in milliseconds, or as `-` and `+` for the earliest and latest samples.
+    ///
+    /// ```text
+    /// TS.RANGE <key> <fromTimestamp> <toTimestamp>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.RANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_range<K: ToSingleRedisArg, F: ToSingleRedisArg, T: ToSingleRedisArg>(key: K, from: F, to: T) -> (Vec<timeseries::Sample>) {
+        cmd("TS.RANGE").arg(key).arg(from).arg(to).take()
+    }
+
+    /// Returns the filtered or aggregated samples of a time series between two timestamps, in ascending order.
+    ///
+    /// ```text
+    /// TS.RANGE <key> <fromTimestamp> <toTimestamp> [LATEST] [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max] [COUNT count] [[ALIGN align] AGGREGATION aggregator bucketDuration [BUCKETTIMESTAMP bt] [EMPTY]]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.RANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_range_options<K: ToSingleRedisArg, F: ToSingleRedisArg, T: ToSingleRedisArg>(key: K, from: F, to: T, options: &'a timeseries::TsRangeOptions) -> (Vec<timeseries::Sample>) {
+        cmd("TS.RANGE").arg(key).arg(from).arg(to).arg(options).take()
+    }
+
+    /// Returns the samples of a time series between two timestamps, in descending order.
+    /// The timestamps are given in milliseconds, or as `-` and `+` for the earliest and latest samples.
+    ///
+    /// ```text
+    /// TS.REVRANGE <key> <fromTimestamp> <toTimestamp>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.REVRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_revrange<K: ToSingleRedisArg, F: ToSingleRedisArg, T: ToSingleRedisArg>(key: K, from: F, to: T) -> (Vec<timeseries::Sample>) {
+        cmd("TS.REVRANGE").arg(key).arg(from).arg(to).take()
+    }
+
+    /// Returns the filtered or aggregated samples of a time series between two timestamps, in descending order.
+    ///
+    /// ```text
+    /// TS.REVRANGE <key> <fromTimestamp> <toTimestamp> [LATEST] [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max] [COUNT count] [[ALIGN align] AGGREGATION aggregator bucketDuration [BUCKETTIMESTAMP bt] [EMPTY]]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.REVRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_revrange_options<K: ToSingleRedisArg, F: ToSingleRedisArg, T: ToSingleRedisArg>(key: K, from: F, to: T, options: &'a timeseries::TsRangeOptions) -> (Vec<timeseries::Sample>) {
+        cmd("TS.REVRANGE").arg(key).arg(from).arg(to).arg(options).take()
+    }
+
+    /// Returns the samples of all time series matching the filter between two timestamps, in ascending order.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    ///
+    /// ```text
+    /// TS.MRANGE <fromTimestamp> <toTimestamp> FILTER filterExpr ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mrange<F: ToSingleRedisArg, T: ToSingleRedisArg>(from: F, to: T, filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MRangeSeries>) {
+        cmd("TS.MRANGE").arg(from).arg(to).arg(filter).take()
+    }
+
+    /// Returns the filtered, aggregated or grouped samples of all time series matching the filter between two timestamps, in ascending order.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    /// A `GROUPBY` reduction is applied by each primary to its own series, see [`group_by`](timeseries::TsMRangeOptions::group_by).
+    ///
+    /// ```text
+    /// TS.MRANGE <fromTimestamp> <toTimestamp> [LATEST] [...] [WITHLABELS | SELECTED_LABELS label ...] [...] FILTER filterExpr ... [GROUPBY label REDUCE reducer]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mrange_options<F: ToSingleRedisArg, T: ToSingleRedisArg>(from: F, to: T, options: &'a timeseries::TsMRangeOptions, filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MRangeSeries>) {
+        cmd("TS.MRANGE").arg(from).arg(to).arg(options).arg(filter).arg(timeseries::GroupBy(options)).take()
+    }
+
+    /// Returns the samples of all time series matching the filter between two timestamps, in descending order.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    ///
+    /// ```text
+    /// TS.MREVRANGE <fromTimestamp> <toTimestamp> FILTER filterExpr ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MREVRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mrevrange<F: ToSingleRedisArg, T: ToSingleRedisArg>(from: F, to: T, filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MRangeSeries>) {
+        cmd("TS.MREVRANGE").arg(from).arg(to).arg(filter).take()
+    }
+
+    /// Returns the filtered, aggregated or grouped samples of all time series matching the filter between two timestamps, in descending order.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    /// A `GROUPBY` reduction is applied by each primary to its own series, see [`group_by`](timeseries::TsMRangeOptions::group_by).
+    ///
+    /// ```text
+    /// TS.MREVRANGE <fromTimestamp> <toTimestamp> [LATEST] [...] [WITHLABELS | SELECTED_LABELS label ...] [...] FILTER filterExpr ... [GROUPBY label REDUCE reducer]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MREVRANGE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mrevrange_options<F: ToSingleRedisArg, T: ToSingleRedisArg>(from: F, to: T, options: &'a timeseries::TsMRangeOptions, filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MRangeSeries>) {
+        cmd("TS.MREVRANGE").arg(from).arg(to).arg(options).arg(filter).arg(timeseries::GroupBy(options)).take()
+    }
+
+    /// Returns the latest sample of all time series matching the filter.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    ///
+    /// ```text
+    /// TS.MGET FILTER filterExpr ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MGET)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mget<>(filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MGetSeries>) {
+        cmd("TS.MGET").arg(filter).take()
+    }
+
+    /// Returns the latest sample and the labels of all time series matching the filter.
+    /// On cluster connections, the command is sent to all primaries and the series are combined.
+    ///
+    /// ```text
+    /// TS.MGET [LATEST] [WITHLABELS | SELECTED_LABELS label ...] FILTER filterExpr ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.MGET)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_mget_options<>(options: &'a timeseries::TsMGetOptions, filter: &'a timeseries::TsFilter) -> (Vec<timeseries::MGetSeries>) {
+        cmd("TS.MGET").arg(options).arg(filter).take()
+    }
+
+    /// Returns the keys of all time series matching the filter.
+    /// On cluster connections, the command is sent to all primaries and the keys are combined.
+    ///
+    /// ```text
+    /// TS.QUERYINDEX filterExpr ...
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.QUERYINDEX)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_queryindex<>(filter: &'a timeseries::TsFilter) -> (Vec<String>) {
+        cmd("TS.QUERYINDEX").arg(timeseries::FilterExpressions(filter)).take()
+    }
+
+    /// Creates a compaction rule, which aggregates the samples of a source series into a destination series.
+    /// Both series have to exist and be stored in the same slot.
+    ///
+    /// ```text
+    /// TS.CREATERULE <sourceKey> <destKey> AGGREGATION aggregator bucketDuration [alignTimestamp]
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.CREATERULE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_createrule<S: ToSingleRedisArg, D: ToSingleRedisArg>(source: S, dest: D, aggregator: timeseries::TsAggregationType, bucket_duration: u64) -> (()) {
+        cmd("TS.CREATERULE").arg(source).arg(dest).arg("AGGREGATION").arg(aggregator).arg(bucket_duration).take()
+    }
+
+    /// Deletes a compaction rule.
+    ///
+    /// ```text
+    /// TS.DELETERULE <sourceKey> <destKey>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.DELETERULE)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_deleterule<S: ToSingleRedisArg, D: ToSingleRedisArg>(source: S, dest: D) -> (()) {
+        cmd("TS.DELETERULE").arg(source).arg(dest).take()
+    }
+
+    /// Returns information about a time series.
+    ///
+    /// ```text
+    /// TS.INFO <key>
+    /// ```
+    ///
+    /// [Redis Docs](https://redis.io/commands/TS.INFO)
+    #[cfg(feature = "timeseries")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+    fn ts_info<K: ToSingleRedisArg>(key: K) -> (timeseries::TsInfo) {
+        cmd("TS.INFO").arg(key).take()
+    }
+}
+
+/// Allows pubsub callbacks to stop receiving messages.
+///
+/// Arbitrary data may be returned from `Break`.
+#[non_exhaustive]
+pub enum ControlFlow<U> {
+    /// Continues.
+    Continue,
+    /// Breaks with a value.
+    Break(U),
+}
+
+/// The PubSub trait allows subscribing to one or more channels
+/// and receiving a callback whenever a message arrives.
+///
+/// Each method handles subscribing to the list of keys, waiting for
+/// messages, and unsubscribing from the same list of channels once
+/// a ControlFlow::Break is encountered.
+///
+/// Once (p)subscribe returns Ok(U), the connection is again safe to use
+/// for calling other methods.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn do_something() -> redis::RedisResult<()> {
+/// use redis::{PubSubCommands, ControlFlow};
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let mut con = client.get_connection()?;
+/// let mut count = 0;
+/// con.subscribe(&["foo"], |msg| {
+///     // do something with message
+///     assert_eq!(msg.get_channel(), Ok(String::from("foo")));
+///
+///     // increment messages seen counter
 ///     count += 1;
 ///     match count {
 ///         // stop after receiving 10 messages
@@ -3484,64 +5284,520 @@ impl ToRedisArgs for ScanOptions {
 ///     con.lpos(key, value, opts)
 /// }
 /// ```
-#[derive(Default)]
-pub struct LposOptions {
-    count: Option<usize>,
-    maxlen: Option<usize>,
-    rank: Option<isize>,
+#[derive(Default)]
+pub struct LposOptions {
+    count: Option<usize>,
+    maxlen: Option<usize>,
+    rank: Option<isize>,
+}
+
+impl LposOptions {
+    /// Limit the results to the first N matching items.
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = Some(n);
+        self
+    }
+
+    /// Return the value of N from the matching items.
+    pub fn rank(mut self, n: isize) -> Self {
+        self.rank = Some(n);
+        self
+    }
+
+    /// Limit the search to N items in the list.
+    pub fn maxlen(mut self, n: usize) -> Self {
+        self.maxlen = Some(n);
+        self
+    }
+}
+
+impl ToRedisArgs for LposOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(n) = self.count {
+            out.write_arg(b"COUNT");
+            out.write_arg_fmt(n);
+        }
+
+        if let Some(n) = self.rank {
+            out.write_arg(b"RANK");
+            out.write_arg_fmt(n);
+        }
+
+        if let Some(n) = self.maxlen {
+            out.write_arg(b"MAXLEN");
+            out.write_arg_fmt(n);
+        }
+    }
+
+    fn num_of_args(&self) -> usize {
+        let mut len = 0;
+        if self.count.is_some() {
+            len += 2;
+        }
+        if self.rank.is_some() {
+            len += 2;
+        }
+        if self.maxlen.is_some() {
+            len += 2;
+        }
+        len
+    }
+}
+
+/// Options for the [LCS](https://redis.io/commands/lcs) command with `IDX`
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, LcsMatches, LcsOptions, RedisResult};
+/// fn common_parts(con: &mut redis::Connection) -> RedisResult<LcsMatches> {
+///     let opts = LcsOptions::default().min_match_len(4).with_match_len();
+///     con.lcs_idx("text:old", "text:new", &opts)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct LcsOptions {
+    min_match_len: Option<usize>,
+    with_match_len: bool,
+}
+
+impl LcsOptions {
+    /// Only return the matches of at least `len` characters (`MINMATCHLEN`).
+    pub fn min_match_len(mut self, len: usize) -> Self {
+        self.min_match_len = Some(len);
+        self
+    }
+
+    /// Return the length of each match (`WITHMATCHLEN`).
+    pub fn with_match_len(mut self) -> Self {
+        self.with_match_len = true;
+        self
+    }
+}
+
+impl ToRedisArgs for LcsOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(len) = self.min_match_len {
+            out.write_arg(b"MINMATCHLEN");
+            out.write_arg_fmt(len);
+        }
+        if self.with_match_len {
+            out.write_arg(b"WITHMATCHLEN");
+        }
+    }
+}
+
+/// The reply of [`lcs_idx`](Commands::lcs_idx)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LcsMatches {
+    /// The matches, from the last to the first. Each match is the range of the match in the
+    /// first and in the second string, and its length if requested with
+    /// [`LcsOptions::with_match_len`].
+    pub matches: Vec<(Range<usize>, Range<usize>, Option<usize>)>,
+    /// The length of the longest common subsequence.
+    pub len: usize,
+}
+
+impl FromRedisValue for LcsMatches {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        // The server sends inclusive `[start, end]` positions.
+        fn range(v: Value) -> Result<Range<usize>, ParsingError> {
+            let (start, end): (usize, usize) = from_redis_value(v)?;
+            Ok(start..end + 1)
+        }
+
+        let mut fields: HashMap<String, Value> = from_redis_value(v)?;
+        let entries: Vec<Vec<Value>> =
+            from_redis_value(fields.remove("matches").unwrap_or(Value::Nil))?;
+        let mut matches = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let mut entry = entry.into_iter();
+            let (Some(a), Some(b)) = (entry.next(), entry.next()) else {
+                invalid_type_error!("expected the ranges of the match");
+            };
+            let len = entry.next().map(from_redis_value).transpose()?;
+            matches.push((range(a)?, range(b)?, len));
+        }
+        Ok(LcsMatches {
+            matches,
+            len: from_redis_value(fields.remove("len").unwrap_or(Value::Nil))?,
+        })
+    }
+}
+
+/// The reply of [`waitaof`](Commands::waitaof)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WaitAofReply {
+    /// The number of local instances (0 or 1) that fsynced the writes.
+    pub local: usize,
+    /// The number of replicas that fsynced the writes.
+    pub replicas: usize,
+}
+
+impl FromRedisValue for WaitAofReply {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (local, replicas) = from_redis_value(v)?;
+        Ok(WaitAofReply { local, replicas })
+    }
+}
+
+/// The integer type of a [BITFIELD](https://redis.io/commands/bitfield) field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitfieldType {
+    /// A signed integer of the given width, up to 64 bits (`i<bits>`).
+    Signed(u8),
+    /// An unsigned integer of the given width, up to 63 bits (`u<bits>`).
+    Unsigned(u8),
+}
+
+impl ToRedisArgs for BitfieldType {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            BitfieldType::Signed(bits) => out.write_arg_fmt(format_args!("i{bits}")),
+            BitfieldType::Unsigned(bits) => out.write_arg_fmt(format_args!("u{bits}")),
+        }
+    }
+}
+
+impl ToSingleRedisArg for BitfieldType {}
+
+/// The offset of a [BITFIELD](https://redis.io/commands/bitfield) field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitfieldOffset {
+    /// An offset in bits.
+    Bits(usize),
+    /// An offset in multiples of the width of the field type (`#<index>`), to address
+    /// an array of fields of the same type.
+    Index(usize),
+}
+
+impl ToRedisArgs for BitfieldOffset {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            BitfieldOffset::Bits(offset) => out.write_arg_fmt(offset),
+            BitfieldOffset::Index(index) => out.write_arg_fmt(format_args!("#{index}")),
+        }
+    }
+}
+
+impl ToSingleRedisArg for BitfieldOffset {}
+
+/// The behavior of [BITFIELD](https://redis.io/commands/bitfield) SET and INCRBY operations
+/// on overflow
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitfieldOverflow {
+    /// Wrap around, the server default.
+    #[default]
+    Wrap,
+    /// Saturate to the minimum or maximum value of the type.
+    Sat,
+    /// Leave the field unchanged and reply `None` for the operation.
+    Fail,
+}
+
+#[derive(Clone, Debug)]
+enum BitfieldOp {
+    Get(BitfieldType, BitfieldOffset),
+    Set(BitfieldType, BitfieldOffset, i64),
+    IncrBy(BitfieldType, BitfieldOffset, i64),
+    Overflow(BitfieldOverflow),
+}
+
+/// Operations of the [BITFIELD](https://redis.io/commands/bitfield) command
+///
+/// See [`BitfieldRoOps`] for the read-only BITFIELD_RO command.
+///
+/// The operations are run in order, and the reply has one entry per GET, SET and INCRBY.
+/// An overflow mode applies to the SET and INCRBY operations that follow it.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{BitfieldOffset, BitfieldOps, BitfieldOverflow, BitfieldType, Commands, RedisResult};
+/// fn bump_counter(con: &mut redis::Connection, counter: usize) -> RedisResult<Vec<Option<i64>>> {
+///     let ops = BitfieldOps::default()
+///         .overflow(BitfieldOverflow::Sat)
+///         .incr_by(BitfieldType::Unsigned(8), BitfieldOffset::Index(counter), 1)
+///         .get(BitfieldType::Unsigned(8), BitfieldOffset::Index(counter + 1));
+///     con.bitfield("counters", &ops)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct BitfieldOps {
+    ops: Vec<BitfieldOp>,
+}
+
+impl BitfieldOps {
+    /// Read the field of type `ty` at `offset` (`GET`).
+    pub fn get(mut self, ty: BitfieldType, offset: BitfieldOffset) -> Self {
+        self.ops.push(BitfieldOp::Get(ty, offset));
+        self
+    }
+
+    /// Set the field of type `ty` at `offset` to `value`, replying with its previous
+    /// value (`SET`).
+    pub fn set(mut self, ty: BitfieldType, offset: BitfieldOffset, value: i64) -> Self {
+        self.ops.push(BitfieldOp::Set(ty, offset, value));
+        self
+    }
+
+    /// Increment the field of type `ty` at `offset` by `increment`, replying with its new
+    /// value (`INCRBY`).
+    pub fn incr_by(mut self, ty: BitfieldType, offset: BitfieldOffset, increment: i64) -> Self {
+        self.ops.push(BitfieldOp::IncrBy(ty, offset, increment));
+        self
+    }
+
+    /// Set the overflow behavior of the following SET and INCRBY operations (`OVERFLOW`).
+    pub fn overflow(mut self, overflow: BitfieldOverflow) -> Self {
+        self.ops.push(BitfieldOp::Overflow(overflow));
+        self
+    }
+}
+
+impl ToRedisArgs for BitfieldOps {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        for op in &self.ops {
+            match op {
+                BitfieldOp::Get(ty, offset) => {
+                    out.write_arg(b"GET");
+                    ty.write_redis_args(out);
+                    offset.write_redis_args(out);
+                }
+                BitfieldOp::Set(ty, offset, value) => {
+                    out.write_arg(b"SET");
+                    ty.write_redis_args(out);
+                    offset.write_redis_args(out);
+                    out.write_arg_fmt(value);
+                }
+                BitfieldOp::IncrBy(ty, offset, increment) => {
+                    out.write_arg(b"INCRBY");
+                    ty.write_redis_args(out);
+                    offset.write_redis_args(out);
+                    out.write_arg_fmt(increment);
+                }
+                BitfieldOp::Overflow(overflow) => {
+                    out.write_arg(b"OVERFLOW");
+                    out.write_arg(match overflow {
+                        BitfieldOverflow::Wrap => b"WRAP",
+                        BitfieldOverflow::Sat => b"SAT",
+                        BitfieldOverflow::Fail => b"FAIL",
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Operations of the [BITFIELD_RO](https://redis.io/commands/bitfield_ro) command, which
+/// only accepts GET operations
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{BitfieldOffset, BitfieldRoOps, BitfieldType, Commands, RedisResult};
+/// fn read_counters(con: &mut redis::Connection) -> RedisResult<Vec<Option<i64>>> {
+///     let ops = BitfieldRoOps::default()
+///         .get(BitfieldType::Unsigned(8), BitfieldOffset::Index(0))
+///         .get(BitfieldType::Unsigned(8), BitfieldOffset::Index(1));
+///     con.bitfield_ro("counters", &ops)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct BitfieldRoOps {
+    gets: Vec<(BitfieldType, BitfieldOffset)>,
+}
+
+impl BitfieldRoOps {
+    /// Read the field of type `ty` at `offset` (`GET`).
+    pub fn get(mut self, ty: BitfieldType, offset: BitfieldOffset) -> Self {
+        self.gets.push((ty, offset));
+        self
+    }
+}
+
+impl ToRedisArgs for BitfieldRoOps {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        for (ty, offset) in &self.gets {
+            out.write_arg(b"GET");
+            ty.write_redis_args(out);
+            offset.write_redis_args(out);
+        }
+    }
+}
+
+/// The unit of the range of the [BITPOS](https://redis.io/commands/bitpos) and
+/// [BITCOUNT](https://redis.io/commands/bitcount) commands
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitRangeUnit {
+    /// The range is in bytes, the server default.
+    #[default]
+    Byte,
+    /// The range is in bits.
+    Bit,
+}
+
+impl ToRedisArgs for BitRangeUnit {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(match self {
+            BitRangeUnit::Byte => b"BYTE",
+            BitRangeUnit::Bit => b"BIT",
+        });
+    }
+}
+
+impl ToSingleRedisArg for BitRangeUnit {}
+
+/// Sort direction of the [SORT](https://redis.io/commands/sort) command
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// Let the server use its default, ascending order.
+    #[default]
+    Default,
+    /// Sort from small to large.
+    Asc,
+    /// Sort from large to small.
+    Desc,
+}
+
+/// Options for the [SORT](https://redis.io/commands/sort) and
+/// [SORT_RO](https://redis.io/commands/sort_ro) commands
+///
+/// Patterns given to [`by`](Self::by) and [`get`](Self::get) are substituted by the server,
+/// replacing the first `*` with each element of the sorted collection. In cluster mode every
+/// pattern must contain a hash tag that maps to the slot of the sorted key, otherwise the
+/// command is rejected with a client error before being sent.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult, SortOptions, SortOrder};
+/// fn users_by_age(con: &mut redis::Connection) -> RedisResult<Vec<Option<String>>> {
+///     let opts = SortOptions::default()
+///         .by("{users}:age:*")
+///         .get("#")
+///         .get("{users}:name:*")
+///         .order(SortOrder::Desc)
+///         .limit(0, 10);
+///     con.sort("{users}:ids", &opts)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct SortOptions {
+    by: Option<String>,
+    limit: Option<(isize, isize)>,
+    get: Vec<String>,
+    order: SortOrder,
+    alpha: bool,
 }
 
-impl LposOptions {
-    /// Limit the results to the first N matching items.
-    pub fn count(mut self, n: usize) -> Self {
-        self.count = Some(n);
+impl SortOptions {
+    /// Sort by the values of the external keys matching `pattern` instead of by the
+    /// elements themselves. A pattern without `*`, such as `nosort`, skips sorting.
+    pub fn by(mut self, pattern: impl Into<String>) -> Self {
+        self.by = Some(pattern.into());
         self
     }
 
-    /// Return the value of N from the matching items.
-    pub fn rank(mut self, n: isize) -> Self {
-        self.rank = Some(n);
+    /// Return `count` elements, starting at `offset`.
+    pub fn limit(mut self, offset: isize, count: isize) -> Self {
+        self.limit = Some((offset, count));
         self
     }
 
-    /// Limit the search to N items in the list.
-    pub fn maxlen(mut self, n: usize) -> Self {
-        self.maxlen = Some(n);
+    /// Return the values of the external keys matching `pattern` instead of the elements.
+    /// Can be called multiple times; `#` returns the element itself.
+    pub fn get(mut self, pattern: impl Into<String>) -> Self {
+        self.get.push(pattern.into());
+        self
+    }
+
+    /// Set the sort direction.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sort lexicographically instead of numerically.
+    pub fn alpha(mut self) -> Self {
+        self.alpha = true;
         self
     }
 }
 
-impl ToRedisArgs for LposOptions {
+impl ToRedisArgs for SortOptions {
     fn write_redis_args<W>(&self, out: &mut W)
     where
         W: ?Sized + RedisWrite,
     {
-        if let Some(n) = self.count {
-            out.write_arg(b"COUNT");
-            out.write_arg_fmt(n);
+        if let Some(pattern) = &self.by {
+            out.write_arg(b"BY");
+            out.write_arg(pattern.as_bytes());
         }
 
-        if let Some(n) = self.rank {
-            out.write_arg(b"RANK");
-            out.write_arg_fmt(n);
+        if let Some((offset, count)) = self.limit {
+            out.write_arg(b"LIMIT");
+            out.write_arg_fmt(offset);
+            out.write_arg_fmt(count);
         }
 
-        if let Some(n) = self.maxlen {
-            out.write_arg(b"MAXLEN");
-            out.write_arg_fmt(n);
+        for pattern in &self.get {
+            out.write_arg(b"GET");
+            out.write_arg(pattern.as_bytes());
+        }
+
+        match self.order {
+            SortOrder::Asc => out.write_arg(b"ASC"),
+            SortOrder::Desc => out.write_arg(b"DESC"),
+            SortOrder::Default => (),
+        }
+
+        if self.alpha {
+            out.write_arg(b"ALPHA");
         }
     }
 
     fn num_of_args(&self) -> usize {
-        let mut len = 0;
-        if self.count.is_some() {
+        let mut len = 2 * self.get.len();
+        if self.by.is_some() {
             len += 2;
         }
-        if self.rank.is_some() {
-            len += 2;
+        if self.limit.is_some() {
+            len += 3;
         }
-        if self.maxlen.is_some() {
-            len += 2;
+        if self.order != SortOrder::Default {
+            len += 1;
+        }
+        if self.alpha {
+            len += 1;
         }
         len
     }
@@ -3972,6 +6228,155 @@ impl ToRedisArgs for SortedSetAddOptions {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ZRangeBy {
+    #[default]
+    Rank,
+    Score,
+    Lex,
+}
+
+/// Options for the [ZRANGE](https://redis.io/commands/zrange) and
+/// [ZRANGESTORE](https://redis.io/commands/zrangestore) commands
+///
+/// The `start` and `stop` arguments of the commands are ranks by default, scores with
+/// [`by_score`](Self::by_score), such as `1`, `(1` or `+inf`, and members with
+/// [`by_lex`](Self::by_lex), such as `[a`, `(a` or `-`.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult, ZRangeOptions};
+/// fn top_scores(con: &mut redis::Connection) -> RedisResult<Vec<(String, f64)>> {
+///     let opts = ZRangeOptions::default().by_score().rev().limit(0, 10);
+///     con.zrange_options_withscores("leaderboard", "+inf", 100, &opts)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ZRangeOptions {
+    by: ZRangeBy,
+    rev: bool,
+    limit: Option<(isize, isize)>,
+}
+
+impl ZRangeOptions {
+    /// Interpret `start` and `stop` as scores (`BYSCORE`).
+    pub fn by_score(mut self) -> Self {
+        self.by = ZRangeBy::Score;
+        self
+    }
+
+    /// Interpret `start` and `stop` as lexicographical ranges of members (`BYLEX`).
+    pub fn by_lex(mut self) -> Self {
+        self.by = ZRangeBy::Lex;
+        self
+    }
+
+    /// Order the members from the highest to the lowest score (`REV`). `start` must then be
+    /// the higher bound.
+    pub fn rev(mut self) -> Self {
+        self.rev = true;
+        self
+    }
+
+    /// Return `count` members, starting at `offset`. Only valid with
+    /// [`by_score`](Self::by_score) or [`by_lex`](Self::by_lex).
+    pub fn limit(mut self, offset: isize, count: isize) -> Self {
+        self.limit = Some((offset, count));
+        self
+    }
+}
+
+impl ToRedisArgs for ZRangeOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self.by {
+            ZRangeBy::Rank => {}
+            ZRangeBy::Score => out.write_arg(b"BYSCORE"),
+            ZRangeBy::Lex => out.write_arg(b"BYLEX"),
+        }
+        if self.rev {
+            out.write_arg(b"REV");
+        }
+        if let Some((offset, count)) = self.limit {
+            out.write_arg(b"LIMIT");
+            out.write_arg_fmt(offset);
+            out.write_arg_fmt(count);
+        }
+    }
+}
+
+/// How the scores of a member are combined by ZINTER, ZUNION and their STORE variants
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ZAggregate {
+    /// Add the scores.
+    #[default]
+    Sum,
+    /// Keep the lowest score.
+    Min,
+    /// Keep the highest score.
+    Max,
+}
+
+/// Options for the [ZINTER](https://redis.io/commands/zinter),
+/// [ZUNION](https://redis.io/commands/zunion) and their STORE variants
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{Commands, RedisResult, ZAggregate, ZAggregateOptions};
+/// fn combined(con: &mut redis::Connection) -> RedisResult<Vec<(String, f64)>> {
+///     let opts = ZAggregateOptions::default()
+///         .weights([1.0, 0.5])
+///         .aggregate(ZAggregate::Max);
+///     con.zunion_options(&["scores:week", "scores:month"], &opts)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ZAggregateOptions {
+    weights: Vec<f64>,
+    aggregate: Option<ZAggregate>,
+}
+
+impl ZAggregateOptions {
+    /// Multiply the scores of each input set by the matching weight, in the order of the keys.
+    pub fn weights(mut self, weights: impl IntoIterator<Item = f64>) -> Self {
+        self.weights = weights.into_iter().collect();
+        self
+    }
+
+    /// Set how the scores of a member are combined.
+    pub fn aggregate(mut self, aggregate: ZAggregate) -> Self {
+        self.aggregate = Some(aggregate);
+        self
+    }
+}
+
+impl ToRedisArgs for ZAggregateOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if !self.weights.is_empty() {
+            out.write_arg(b"WEIGHTS");
+            for weight in &self.weights {
+                out.write_arg_fmt(weight);
+            }
+        }
+        if let Some(aggregate) = self.aggregate {
+            out.write_arg(b"AGGREGATE");
+            out.write_arg(match aggregate {
+                ZAggregate::Sum => b"SUM",
+                ZAggregate::Min => b"MIN",
+                ZAggregate::Max => b"MAX",
+            });
+        }
+    }
+}
+
 /// Creates HELLO command for RESP3 with RedisConnectionInfo
 /// [Redis Docs](https://redis.io/commands/HELLO)
 pub fn resp3_hello(connection_info: &RedisConnectionInfo) -> Cmd {
@@ -4169,3 +6574,422 @@ impl AsyncHotkeysCommands for crate::aio::MultiplexedConnection {}
 
 #[cfg(all(feature = "aio", feature = "connection-manager"))]
 impl AsyncHotkeysCommands for crate::aio::ConnectionManager {}
+
+/// Server administration commands with typed replies.
+///
+/// On standalone connections each command returns the reply of the connected server. On
+/// cluster connections the command is sent to all nodes, and the reply is a map from the
+/// address of each node to its reply, see [`Reply`](Self::Reply).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::ServerCommands;
+///
+/// # fn example() -> redis::RedisResult<()> {
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let mut con = client.get_connection()?;
+///
+/// for entry in con.slowlog_get(Some(10))? {
+///     println!("{} took {:?}: {:?}", entry.id, entry.duration, entry.args);
+/// }
+/// let timeout = con.config_get("timeout")?;
+/// println!("timeout is {}", timeout["timeout"]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ServerCommands: Sized {
+    /// The reply type of a command returning `T`: `T` itself on standalone connections, or a
+    /// map from node address to `T` on cluster connections.
+    type Reply<T: FromRedisValue>: FromRedisValue;
+
+    /// Sends `cmd` to the server, or to every node of a cluster.
+    fn query_server<T: FromRedisValue>(&mut self, cmd: Cmd) -> RedisResult<Self::Reply<T>>;
+
+    /// Get the values of the configuration parameters matching `pattern`.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-GET)
+    fn config_get<P: ToSingleRedisArg>(
+        &mut self,
+        pattern: P,
+    ) -> RedisResult<Self::Reply<std::collections::HashMap<String, String>>> {
+        self.query_server(cmd("CONFIG").arg("GET").arg(pattern).take())
+    }
+
+    /// Set a configuration parameter at runtime.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-SET)
+    fn config_set<P: ToSingleRedisArg, V: ToSingleRedisArg>(
+        &mut self,
+        parameter: P,
+        value: V,
+    ) -> RedisResult<Self::Reply<()>> {
+        self.query_server(cmd("CONFIG").arg("SET").arg(parameter).arg(value).take())
+    }
+
+    /// Reset the statistics reported by `INFO` and `LATENCY HISTOGRAM`.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-RESETSTAT)
+    fn config_resetstat(&mut self) -> RedisResult<Self::Reply<()>> {
+        self.query_server(cmd("CONFIG").arg("RESETSTAT").take())
+    }
+
+    /// Get the entries of the slow log, newest first. Returns the 10 latest entries if
+    /// `count` is `None`.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-GET)
+    fn slowlog_get(
+        &mut self,
+        count: Option<usize>,
+    ) -> RedisResult<Self::Reply<Vec<server::SlowlogEntry>>> {
+        self.query_server(cmd("SLOWLOG").arg("GET").arg(count).take())
+    }
+
+    /// Get the number of entries in the slow log.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-LEN)
+    fn slowlog_len(&mut self) -> RedisResult<Self::Reply<usize>> {
+        self.query_server(cmd("SLOWLOG").arg("LEN").take())
+    }
+
+    /// Remove all the entries of the slow log.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-RESET)
+    fn slowlog_reset(&mut self) -> RedisResult<Self::Reply<()>> {
+        self.query_server(cmd("SLOWLOG").arg("RESET").take())
+    }
+
+    /// Get the clients connected to the server.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-LIST)
+    fn client_list(&mut self) -> RedisResult<Self::Reply<server::ClientList>> {
+        self.query_server(cmd("CLIENT").arg("LIST").take())
+    }
+
+    /// Close the client connections matching `filter`. Returns the number of closed
+    /// connections, or an error without sending the command if `filter` is empty.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-KILL)
+    fn client_kill(&mut self, filter: server::ClientKillFilter) -> RedisResult<Self::Reply<usize>> {
+        filter.check()?;
+        self.query_server(cmd("CLIENT").arg("KILL").arg(filter).take())
+    }
+
+    /// Suspend the commands selected by `mode` of all the clients for `timeout`.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-PAUSE)
+    fn client_pause(
+        &mut self,
+        timeout: std::time::Duration,
+        mode: server::ClientPauseMode,
+    ) -> RedisResult<Self::Reply<()>> {
+        self.query_server(
+            cmd("CLIENT")
+                .arg("PAUSE")
+                .arg(timeout.as_millis() as u64)
+                .arg(mode)
+                .take(),
+        )
+    }
+
+    /// Resume the clients suspended by [`client_pause`](Self::client_pause).
+    /// [Redis Docs](https://redis.io/commands/CLIENT-UNPAUSE)
+    fn client_unpause(&mut self) -> RedisResult<Self::Reply<()>> {
+        self.query_server(cmd("CLIENT").arg("UNPAUSE").take())
+    }
+
+    /// Get the latest latency spike of every event.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-LATEST)
+    fn latency_latest(&mut self) -> RedisResult<Self::Reply<Vec<server::LatencyEvent>>> {
+        self.query_server(cmd("LATENCY").arg("LATEST").take())
+    }
+
+    /// Get the latency spikes recorded for `event`.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-HISTORY)
+    fn latency_history<E: ToSingleRedisArg>(
+        &mut self,
+        event: E,
+    ) -> RedisResult<Self::Reply<Vec<server::LatencySample>>> {
+        self.query_server(cmd("LATENCY").arg("HISTORY").arg(event).take())
+    }
+
+    /// Reset the latency spikes of all events. Returns the number of reset events.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-RESET)
+    fn latency_reset(&mut self) -> RedisResult<Self::Reply<usize>> {
+        self.query_server(cmd("LATENCY").arg("RESET").take())
+    }
+
+    /// Get the memory usage of the server.
+    /// [Redis Docs](https://redis.io/commands/MEMORY-STATS)
+    fn memory_stats(&mut self) -> RedisResult<Self::Reply<server::MemoryStats>> {
+        self.query_server(cmd("MEMORY").arg("STATS").take())
+    }
+
+    /// Get a human readable report of the memory problems detected by the server.
+    /// [Redis Docs](https://redis.io/commands/MEMORY-DOCTOR)
+    fn memory_doctor(&mut self) -> RedisResult<Self::Reply<String>> {
+        self.query_server(cmd("MEMORY").arg("DOCTOR").take())
+    }
+
+    /// Get information and statistics about the server. Returns the default sections if
+    /// `sections` is empty.
+    /// [Redis Docs](https://redis.io/commands/INFO)
+    fn info<S: ToRedisArgs>(&mut self, sections: S) -> RedisResult<Self::Reply<crate::InfoDict>> {
+        self.query_server(cmd("INFO").arg(sections).take())
+    }
+
+    /// Get the number of keys in the selected database.
+    /// [Redis Docs](https://redis.io/commands/DBSIZE)
+    fn dbsize(&mut self) -> RedisResult<Self::Reply<usize>> {
+        self.query_server(cmd("DBSIZE"))
+    }
+
+    /// Get the Unix timestamp, in seconds, of the last successful save to disk.
+    /// [Redis Docs](https://redis.io/commands/LASTSAVE)
+    fn lastsave(&mut self) -> RedisResult<Self::Reply<u64>> {
+        self.query_server(cmd("LASTSAVE"))
+    }
+
+    /// Save the dataset to disk in the background.
+    /// [Redis Docs](https://redis.io/commands/BGSAVE)
+    fn bgsave(&mut self) -> RedisResult<Self::Reply<()>> {
+        self.query_server(cmd("BGSAVE"))
+    }
+}
+
+impl ServerCommands for Connection {
+    type Reply<T: FromRedisValue> = T;
+
+    fn query_server<T: FromRedisValue>(&mut self, cmd: Cmd) -> RedisResult<T> {
+        cmd.query(self)
+    }
+}
+
+#[cfg(feature = "cluster")]
+impl<C> ServerCommands for crate::cluster::ClusterConnection<C>
+where
+    C: ConnectionLike + crate::cluster::Connect,
+{
+    type Reply<T: FromRedisValue> = std::collections::HashMap<String, T>;
+
+    fn query_server<T: FromRedisValue>(
+        &mut self,
+        cmd: Cmd,
+    ) -> RedisResult<std::collections::HashMap<String, T>> {
+        let value = self.route_command(&cmd, all_nodes_routing())?;
+        Ok(crate::from_redis_value(value)?)
+    }
+}
+
+/// Routes a command to every node of the cluster, keeping the reply of each node.
+#[cfg(feature = "cluster")]
+fn all_nodes_routing() -> crate::cluster_routing::RoutingInfo {
+    crate::cluster_routing::RoutingInfo::MultiNode((
+        crate::cluster_routing::MultipleNodeRoutingInfo::AllNodes,
+        None,
+    ))
+}
+
+/// Async version of [`ServerCommands`].
+#[cfg(feature = "aio")]
+pub trait AsyncServerCommands: Send + Sized {
+    /// The reply type of a command returning `T`: `T` itself on standalone connections, or a
+    /// map from node address to `T` on cluster connections.
+    type Reply<T: FromRedisValue + Send + 'static>: FromRedisValue + Send + 'static;
+
+    /// Sends `cmd` to the server, or to every node of a cluster.
+    fn query_server<T: FromRedisValue + Send + 'static>(
+        &mut self,
+        cmd: Cmd,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<T>>;
+
+    /// Get the values of the configuration parameters matching `pattern`.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-GET)
+    fn config_get<P: ToSingleRedisArg>(
+        &mut self,
+        pattern: P,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<std::collections::HashMap<String, String>>> {
+        self.query_server(cmd("CONFIG").arg("GET").arg(pattern).take())
+    }
+
+    /// Set a configuration parameter at runtime.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-SET)
+    fn config_set<P: ToSingleRedisArg, V: ToSingleRedisArg>(
+        &mut self,
+        parameter: P,
+        value: V,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(cmd("CONFIG").arg("SET").arg(parameter).arg(value).take())
+    }
+
+    /// Reset the statistics reported by `INFO` and `LATENCY HISTOGRAM`.
+    /// [Redis Docs](https://redis.io/commands/CONFIG-RESETSTAT)
+    fn config_resetstat(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(cmd("CONFIG").arg("RESETSTAT").take())
+    }
+
+    /// Get the entries of the slow log, newest first. Returns the 10 latest entries if
+    /// `count` is `None`.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-GET)
+    fn slowlog_get(
+        &mut self,
+        count: Option<usize>,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<Vec<server::SlowlogEntry>>> {
+        self.query_server(cmd("SLOWLOG").arg("GET").arg(count).take())
+    }
+
+    /// Get the number of entries in the slow log.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-LEN)
+    fn slowlog_len(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<usize>> {
+        self.query_server(cmd("SLOWLOG").arg("LEN").take())
+    }
+
+    /// Remove all the entries of the slow log.
+    /// [Redis Docs](https://redis.io/commands/SLOWLOG-RESET)
+    fn slowlog_reset(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(cmd("SLOWLOG").arg("RESET").take())
+    }
+
+    /// Get the clients connected to the server.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-LIST)
+    fn client_list(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<server::ClientList>> {
+        self.query_server(cmd("CLIENT").arg("LIST").take())
+    }
+
+    /// Close the client connections matching `filter`. Returns the number of closed
+    /// connections, or an error without sending the command if `filter` is empty.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-KILL)
+    fn client_kill(
+        &mut self,
+        filter: server::ClientKillFilter,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<usize>> {
+        if let Err(err) = filter.check() {
+            return Box::pin(async move { Err(err) });
+        }
+        self.query_server(cmd("CLIENT").arg("KILL").arg(filter).take())
+    }
+
+    /// Suspend the commands selected by `mode` of all the clients for `timeout`.
+    /// [Redis Docs](https://redis.io/commands/CLIENT-PAUSE)
+    fn client_pause(
+        &mut self,
+        timeout: std::time::Duration,
+        mode: server::ClientPauseMode,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(
+            cmd("CLIENT")
+                .arg("PAUSE")
+                .arg(timeout.as_millis() as u64)
+                .arg(mode)
+                .take(),
+        )
+    }
+
+    /// Resume the clients suspended by [`client_pause`](Self::client_pause).
+    /// [Redis Docs](https://redis.io/commands/CLIENT-UNPAUSE)
+    fn client_unpause(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(cmd("CLIENT").arg("UNPAUSE").take())
+    }
+
+    /// Get the latest latency spike of every event.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-LATEST)
+    fn latency_latest(
+        &mut self,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<Vec<server::LatencyEvent>>> {
+        self.query_server(cmd("LATENCY").arg("LATEST").take())
+    }
+
+    /// Get the latency spikes recorded for `event`.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-HISTORY)
+    fn latency_history<E: ToSingleRedisArg>(
+        &mut self,
+        event: E,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<Vec<server::LatencySample>>> {
+        self.query_server(cmd("LATENCY").arg("HISTORY").arg(event).take())
+    }
+
+    /// Reset the latency spikes of all events. Returns the number of reset events.
+    /// [Redis Docs](https://redis.io/commands/LATENCY-RESET)
+    fn latency_reset(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<usize>> {
+        self.query_server(cmd("LATENCY").arg("RESET").take())
+    }
+
+    /// Get the memory usage of the server.
+    /// [Redis Docs](https://redis.io/commands/MEMORY-STATS)
+    fn memory_stats(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<server::MemoryStats>> {
+        self.query_server(cmd("MEMORY").arg("STATS").take())
+    }
+
+    /// Get a human readable report of the memory problems detected by the server.
+    /// [Redis Docs](https://redis.io/commands/MEMORY-DOCTOR)
+    fn memory_doctor(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<String>> {
+        self.query_server(cmd("MEMORY").arg("DOCTOR").take())
+    }
+
+    /// Get information and statistics about the server. Returns the default sections if
+    /// `sections` is empty.
+    /// [Redis Docs](https://redis.io/commands/INFO)
+    fn info<S: ToRedisArgs>(
+        &mut self,
+        sections: S,
+    ) -> crate::types::RedisFuture<'_, Self::Reply<crate::InfoDict>> {
+        self.query_server(cmd("INFO").arg(sections).take())
+    }
+
+    /// Get the number of keys in the selected database.
+    /// [Redis Docs](https://redis.io/commands/DBSIZE)
+    fn dbsize(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<usize>> {
+        self.query_server(cmd("DBSIZE"))
+    }
+
+    /// Get the Unix timestamp, in seconds, of the last successful save to disk.
+    /// [Redis Docs](https://redis.io/commands/LASTSAVE)
+    fn lastsave(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<u64>> {
+        self.query_server(cmd("LASTSAVE"))
+    }
+
+    /// Save the dataset to disk in the background.
+    /// [Redis Docs](https://redis.io/commands/BGSAVE)
+    fn bgsave(&mut self) -> crate::types::RedisFuture<'_, Self::Reply<()>> {
+        self.query_server(cmd("BGSAVE"))
+    }
+}
+
+#[cfg(feature = "aio")]
+impl AsyncServerCommands for crate::aio::MultiplexedConnection {
+    type Reply<T: FromRedisValue + Send + 'static> = T;
+
+    fn query_server<T: FromRedisValue + Send + 'static>(
+        &mut self,
+        cmd: Cmd,
+    ) -> crate::types::RedisFuture<'_, T> {
+        Box::pin(async move { cmd.query_async(self).await })
+    }
+}
+
+#[cfg(all(feature = "aio", feature = "connection-manager"))]
+impl AsyncServerCommands for crate::aio::ConnectionManager {
+    type Reply<T: FromRedisValue + Send + 'static> = T;
+
+    fn query_server<T: FromRedisValue + Send + 'static>(
+        &mut self,
+        cmd: Cmd,
+    ) -> crate::types::RedisFuture<'_, T> {
+        Box::pin(async move { cmd.query_async(self).await })
+    }
+}
+
+#[cfg(feature = "cluster-async")]
+impl<C> AsyncServerCommands for crate::cluster_async::ClusterConnection<C>
+where
+    C: crate::aio::ConnectionLike
+        + crate::cluster_async::Connect
+        + Clone
+        + Send
+        + Sync
+        + Unpin
+        + 'static,
+{
+    type Reply<T: FromRedisValue + Send + 'static> = std::collections::HashMap<String, T>;
+
+    fn query_server<T: FromRedisValue + Send + 'static>(
+        &mut self,
+        cmd: Cmd,
+    ) -> crate::types::RedisFuture<'_, std::collections::HashMap<String, T>> {
+        Box::pin(async move {
+            let value = self.route_command(cmd, all_nodes_routing()).await?;
+            Ok(crate::from_redis_value(value)?)
+        })
+    }
+}
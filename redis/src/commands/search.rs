@@ -0,0 +1,2473 @@
+//! Defines types to use with the RediSearch (`FT.*`) commands.
+//!
+//! An index is described by a [`Schema`], which lists the indexed fields, and optionally by
+//! [`CreateIndexOptions`], which select the key type and key prefixes the index covers. Queries
+//! are built with [`SearchQuery`] for `FT.SEARCH` and with [`AggregateQuery`] for `FT.AGGREGATE`.
+//!
+//! ```rust,no_run
+//! use redis::search::{
+//!     CreateIndexOptions, IndexDataType, NumericField, Schema, SearchQuery, SearchResult,
+//!     SortOrder, TagField, TextField,
+//! };
+//! use redis::Commands;
+//!
+//! # fn do_something() -> redis::RedisResult<()> {
+//! # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+//! # let mut con = client.get_connection().unwrap();
+//! let schema = Schema::new()
+//!     .field(TextField::new("title").weight(2.0))
+//!     .field(TagField::new("tags"))
+//!     .field(NumericField::new("price").sortable());
+//! let options = CreateIndexOptions::default()
+//!     .on(IndexDataType::Hash)
+//!     .prefix("product:");
+//! let _: () = con.ft_create_options("products", &options, &schema)?;
+//!
+//! let query = SearchQuery::new("@title:shoe @tags:{sale}")
+//!     .sort_by("price", SortOrder::Asc)
+//!     .limit(0, 10);
+//! let result: SearchResult = con.ft_search("products", &query)?;
+//! for document in result.documents {
+//!     println!("{}: {:?}", document.id, document.fields);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! # Cluster support
+//!
+//! The index name is used as the routing key of the `FT.*` commands, so all commands concerning
+//! the same index are sent to the same shard. Read-only commands may be sent to a replica of the
+//! shard, except for aggregations creating a cursor: the cursor only exists on the node which ran
+//! the aggregation, so these are sent to the primary, like the `FT.CURSOR` commands reading it.
+
+use std::collections::HashMap;
+
+use crate::cmd::{Cmd, cmd};
+use crate::connection::ConnectionLike;
+use crate::errors::{ParsingError, invalid_type_error};
+use crate::types::{
+    FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value, from_redis_value,
+};
+
+/// The type of keys an index is built on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IndexDataType {
+    /// Index hashes. This is the default.
+    #[default]
+    Hash,
+    /// Index JSON documents. Requires the JSON module.
+    Json,
+}
+
+impl ToRedisArgs for IndexDataType {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            IndexDataType::Hash => out.write_arg(b"HASH"),
+            IndexDataType::Json => out.write_arg(b"JSON"),
+        }
+    }
+}
+
+impl ToSingleRedisArg for IndexDataType {}
+
+/// The order of a sort operation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// Ascending order. This is the default.
+    #[default]
+    Asc,
+    /// Descending order.
+    Desc,
+}
+
+impl ToRedisArgs for SortOrder {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            SortOrder::Asc => out.write_arg(b"ASC"),
+            SortOrder::Desc => out.write_arg(b"DESC"),
+        }
+    }
+}
+
+impl ToSingleRedisArg for SortOrder {}
+
+/// Options for [`ft_create_options`][1].
+///
+/// ```text
+/// FT.CREATE index [ON HASH | JSON] [PREFIX count prefix [prefix ...]] [FILTER filter]
+///   [LANGUAGE default_lang] [LANGUAGE_FIELD lang_attribute] [SCORE default_score]
+///   [SCORE_FIELD score_attribute] [MAXTEXTFIELDS] [TEMPORARY seconds] [NOOFFSETS] [NOHL]
+///   [NOFIELDS] [NOFREQS] [STOPWORDS count [stopword ...]] [SKIPINITIALSCAN]
+///   SCHEMA field_name [AS alias] TEXT | TAG | NUMERIC | GEO | VECTOR [...] ...
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ft_create_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CreateIndexOptions {
+    on: Option<IndexDataType>,
+    prefixes: Vec<String>,
+    filter: Option<String>,
+    language: Option<String>,
+    language_field: Option<String>,
+    score: Option<f64>,
+    score_field: Option<String>,
+    max_text_fields: bool,
+    temporary: Option<u64>,
+    no_offsets: bool,
+    no_highlight: bool,
+    no_fields: bool,
+    no_freqs: bool,
+    stopwords: Option<Vec<String>>,
+    skip_initial_scan: bool,
+}
+
+impl CreateIndexOptions {
+    /// Sets the type of keys the index is built on.
+    pub fn on(mut self, data_type: IndexDataType) -> Self {
+        self.on = Some(data_type);
+        self
+    }
+
+    /// Adds a key prefix. Only keys starting with one of the prefixes are indexed.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefixes.push(prefix.to_string());
+        self
+    }
+
+    /// Only index keys for which the given filter expression evaluates to true.
+    pub fn filter(mut self, expression: &str) -> Self {
+        self.filter = Some(expression.to_string());
+        self
+    }
+
+    /// Sets the default language used for stemming.
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Sets the field holding the language of each document.
+    pub fn language_field(mut self, field: &str) -> Self {
+        self.language_field = Some(field.to_string());
+        self
+    }
+
+    /// Sets the default score of documents.
+    pub fn score(mut self, score: f64) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Sets the field holding the score of each document.
+    pub fn score_field(mut self, field: &str) -> Self {
+        self.score_field = Some(field.to_string());
+        self
+    }
+
+    /// Allows adding more than 32 text fields to the index later on.
+    pub fn max_text_fields(mut self) -> Self {
+        self.max_text_fields = true;
+        self
+    }
+
+    /// Creates a lightweight index that expires after `seconds` of inactivity.
+    pub fn temporary(mut self, seconds: u64) -> Self {
+        self.temporary = Some(seconds);
+        self
+    }
+
+    /// Doesn't store term offsets, which disables exact phrase searches and highlighting.
+    pub fn no_offsets(mut self) -> Self {
+        self.no_offsets = true;
+        self
+    }
+
+    /// Doesn't store the data required for highlighting.
+    pub fn no_highlight(mut self) -> Self {
+        self.no_highlight = true;
+        self
+    }
+
+    /// Doesn't store the fields of terms, which disables filtering by field.
+    pub fn no_fields(mut self) -> Self {
+        self.no_fields = true;
+        self
+    }
+
+    /// Doesn't store term frequencies, which disables sorting by frequency.
+    pub fn no_freqs(mut self) -> Self {
+        self.no_freqs = true;
+        self
+    }
+
+    /// Replaces the default stop words. An empty list disables stop words entirely.
+    pub fn stopwords<I, S>(mut self, stopwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords = Some(stopwords.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Doesn't scan and index the keys already present in the database.
+    pub fn skip_initial_scan(mut self) -> Self {
+        self.skip_initial_scan = true;
+        self
+    }
+}
+
+impl ToRedisArgs for CreateIndexOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(on) = self.on {
+            out.write_arg(b"ON");
+            on.write_redis_args(out);
+        }
+        if !self.prefixes.is_empty() {
+            out.write_arg(b"PREFIX");
+            out.write_arg_fmt(self.prefixes.len());
+            for prefix in &self.prefixes {
+                out.write_arg(prefix.as_bytes());
+            }
+        }
+        if let Some(ref filter) = self.filter {
+            out.write_arg(b"FILTER");
+            out.write_arg(filter.as_bytes());
+        }
+        if let Some(ref language) = self.language {
+            out.write_arg(b"LANGUAGE");
+            out.write_arg(language.as_bytes());
+        }
+        if let Some(ref field) = self.language_field {
+            out.write_arg(b"LANGUAGE_FIELD");
+            out.write_arg(field.as_bytes());
+        }
+        if let Some(score) = self.score {
+            out.write_arg(b"SCORE");
+            score.write_redis_args(out);
+        }
+        if let Some(ref field) = self.score_field {
+            out.write_arg(b"SCORE_FIELD");
+            out.write_arg(field.as_bytes());
+        }
+        if self.max_text_fields {
+            out.write_arg(b"MAXTEXTFIELDS");
+        }
+        if let Some(seconds) = self.temporary {
+            out.write_arg(b"TEMPORARY");
+            out.write_arg_fmt(seconds);
+        }
+        if self.no_offsets {
+            out.write_arg(b"NOOFFSETS");
+        }
+        if self.no_highlight {
+            out.write_arg(b"NOHL");
+        }
+        if self.no_fields {
+            out.write_arg(b"NOFIELDS");
+        }
+        if self.no_freqs {
+            out.write_arg(b"NOFREQS");
+        }
+        if let Some(ref stopwords) = self.stopwords {
+            out.write_arg(b"STOPWORDS");
+            out.write_arg_fmt(stopwords.len());
+            for stopword in stopwords {
+                out.write_arg(stopword.as_bytes());
+            }
+        }
+        if self.skip_initial_scan {
+            out.write_arg(b"SKIPINITIALSCAN");
+        }
+    }
+}
+
+/// The fields of an index, passed to [`ft_create`][1] and [`ft_create_options`][2].
+///
+/// [1]: ../trait.Commands.html#method.ft_create
+/// [2]: ../trait.Commands.html#method.ft_create_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct Schema {
+    fields: Vec<SchemaField>,
+}
+
+impl Schema {
+    /// Creates an empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field to the schema.
+    pub fn field(mut self, field: impl Into<SchemaField>) -> Self {
+        self.fields.push(field.into());
+        self
+    }
+}
+
+impl ToRedisArgs for Schema {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(b"SCHEMA");
+        for field in &self.fields {
+            field.write_redis_args(out);
+        }
+    }
+}
+
+/// A single field of a [`Schema`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum SchemaField {
+    /// A full-text field.
+    Text(TextField),
+    /// A tag field.
+    Tag(TagField),
+    /// A numeric field.
+    Numeric(NumericField),
+    /// A geo field.
+    Geo(GeoField),
+    /// A vector field.
+    Vector(VectorField),
+}
+
+impl ToRedisArgs for SchemaField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            SchemaField::Text(field) => field.write_redis_args(out),
+            SchemaField::Tag(field) => field.write_redis_args(out),
+            SchemaField::Numeric(field) => field.write_redis_args(out),
+            SchemaField::Geo(field) => field.write_redis_args(out),
+            SchemaField::Vector(field) => field.write_redis_args(out),
+        }
+    }
+}
+
+/// Attributes shared by all field types.
+#[derive(Clone, Debug)]
+struct FieldAttributes {
+    identifier: String,
+    alias: Option<String>,
+    sortable: bool,
+    no_index: bool,
+}
+
+impl FieldAttributes {
+    fn new(identifier: &str) -> Self {
+        FieldAttributes {
+            identifier: identifier.to_string(),
+            alias: None,
+            sortable: false,
+            no_index: false,
+        }
+    }
+
+    fn write_identifier<W>(&self, out: &mut W, field_type: &[u8])
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.identifier.as_bytes());
+        if let Some(ref alias) = self.alias {
+            out.write_arg(b"AS");
+            out.write_arg(alias.as_bytes());
+        }
+        out.write_arg(field_type);
+    }
+
+    fn write_flags<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.sortable {
+            out.write_arg(b"SORTABLE");
+        }
+        if self.no_index {
+            out.write_arg(b"NOINDEX");
+        }
+    }
+}
+
+macro_rules! implement_field_attributes {
+    ($($field:ident => $variant:ident),*) => {
+        $(
+            impl $field {
+                /// Sets the name the field is referred to in queries. This is required to query
+                /// JSON paths conveniently.
+                pub fn alias(mut self, alias: &str) -> Self {
+                    self.attributes.alias = Some(alias.to_string());
+                    self
+                }
+
+                /// Allows sorting results by this field.
+                pub fn sortable(mut self) -> Self {
+                    self.attributes.sortable = true;
+                    self
+                }
+
+                /// Doesn't index the field. Combined with [`sortable`](Self::sortable) the field
+                /// can still be used for sorting.
+                pub fn no_index(mut self) -> Self {
+                    self.attributes.no_index = true;
+                    self
+                }
+            }
+
+            impl From<$field> for SchemaField {
+                fn from(field: $field) -> Self {
+                    SchemaField::$variant(field)
+                }
+            }
+        )*
+    };
+}
+
+/// A full-text field.
+///
+/// ```text
+/// identifier [AS alias] TEXT [NOSTEM] [WEIGHT weight] [PHONETIC matcher] [WITHSUFFIXTRIE] [SORTABLE] [NOINDEX]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TextField {
+    attributes: FieldAttributes,
+    no_stem: bool,
+    weight: Option<f64>,
+    phonetic: Option<String>,
+    with_suffix_trie: bool,
+}
+
+impl TextField {
+    /// Creates a text field for the given hash field or JSON path.
+    pub fn new(identifier: &str) -> Self {
+        TextField {
+            attributes: FieldAttributes::new(identifier),
+            no_stem: false,
+            weight: None,
+            phonetic: None,
+            with_suffix_trie: false,
+        }
+    }
+
+    /// Disables stemming for this field.
+    pub fn no_stem(mut self) -> Self {
+        self.no_stem = true;
+        self
+    }
+
+    /// Sets the importance of this field when scoring results. Defaults to 1.
+    pub fn weight(mut self, weight: f64) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Enables phonetic matching with the given matcher, e.g. `dm:en`.
+    pub fn phonetic(mut self, matcher: &str) -> Self {
+        self.phonetic = Some(matcher.to_string());
+        self
+    }
+
+    /// Keeps a suffix trie to speed up contains and suffix queries.
+    pub fn with_suffix_trie(mut self) -> Self {
+        self.with_suffix_trie = true;
+        self
+    }
+}
+
+impl ToRedisArgs for TextField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.attributes.write_identifier(out, b"TEXT");
+        if self.no_stem {
+            out.write_arg(b"NOSTEM");
+        }
+        if let Some(weight) = self.weight {
+            out.write_arg(b"WEIGHT");
+            weight.write_redis_args(out);
+        }
+        if let Some(ref matcher) = self.phonetic {
+            out.write_arg(b"PHONETIC");
+            out.write_arg(matcher.as_bytes());
+        }
+        if self.with_suffix_trie {
+            out.write_arg(b"WITHSUFFIXTRIE");
+        }
+        self.attributes.write_flags(out);
+    }
+}
+
+/// A tag field, holding a list of exact-match values.
+///
+/// ```text
+/// identifier [AS alias] TAG [SEPARATOR separator] [CASESENSITIVE] [WITHSUFFIXTRIE] [SORTABLE] [NOINDEX]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TagField {
+    attributes: FieldAttributes,
+    separator: Option<char>,
+    case_sensitive: bool,
+    with_suffix_trie: bool,
+}
+
+impl TagField {
+    /// Creates a tag field for the given hash field or JSON path.
+    pub fn new(identifier: &str) -> Self {
+        TagField {
+            attributes: FieldAttributes::new(identifier),
+            separator: None,
+            case_sensitive: false,
+            with_suffix_trie: false,
+        }
+    }
+
+    /// Sets the character separating the tags of hash fields. Defaults to `,`.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Keeps the original letter case of the tags.
+    pub fn case_sensitive(mut self) -> Self {
+        self.case_sensitive = true;
+        self
+    }
+
+    /// Keeps a suffix trie to speed up contains and suffix queries.
+    pub fn with_suffix_trie(mut self) -> Self {
+        self.with_suffix_trie = true;
+        self
+    }
+}
+
+impl ToRedisArgs for TagField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.attributes.write_identifier(out, b"TAG");
+        if let Some(separator) = self.separator {
+            out.write_arg(b"SEPARATOR");
+            out.write_arg_fmt(separator);
+        }
+        if self.case_sensitive {
+            out.write_arg(b"CASESENSITIVE");
+        }
+        if self.with_suffix_trie {
+            out.write_arg(b"WITHSUFFIXTRIE");
+        }
+        self.attributes.write_flags(out);
+    }
+}
+
+/// A numeric field.
+///
+/// ```text
+/// identifier [AS alias] NUMERIC [SORTABLE] [NOINDEX]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct NumericField {
+    attributes: FieldAttributes,
+}
+
+impl NumericField {
+    /// Creates a numeric field for the given hash field or JSON path.
+    pub fn new(identifier: &str) -> Self {
+        NumericField {
+            attributes: FieldAttributes::new(identifier),
+        }
+    }
+}
+
+impl ToRedisArgs for NumericField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.attributes.write_identifier(out, b"NUMERIC");
+        self.attributes.write_flags(out);
+    }
+}
+
+/// A geo field, holding `longitude,latitude` pairs.
+///
+/// ```text
+/// identifier [AS alias] GEO [SORTABLE] [NOINDEX]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct GeoField {
+    attributes: FieldAttributes,
+}
+
+impl GeoField {
+    /// Creates a geo field for the given hash field or JSON path.
+    pub fn new(identifier: &str) -> Self {
+        GeoField {
+            attributes: FieldAttributes::new(identifier),
+        }
+    }
+}
+
+impl ToRedisArgs for GeoField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.attributes.write_identifier(out, b"GEO");
+        self.attributes.write_flags(out);
+    }
+}
+
+implement_field_attributes!(
+    TextField => Text,
+    TagField => Tag,
+    NumericField => Numeric,
+    GeoField => Geo
+);
+
+/// The indexing algorithm of a [`VectorField`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VectorAlgorithm {
+    /// Brute force search.
+    Flat,
+    /// Hierarchical Navigable Small World graph, for approximate nearest neighbour search.
+    Hnsw,
+}
+
+/// The element type of the vectors in a [`VectorField`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VectorType {
+    /// 16-bit brain floating point numbers.
+    BFloat16,
+    /// 16-bit floating point numbers.
+    Float16,
+    /// 32-bit floating point numbers.
+    Float32,
+    /// 64-bit floating point numbers.
+    Float64,
+}
+
+/// The metric used to compare the vectors of a [`VectorField`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DistanceMetric {
+    /// Euclidean distance.
+    L2,
+    /// Inner product.
+    InnerProduct,
+    /// Cosine distance.
+    Cosine,
+}
+
+/// A vector field, used for vector similarity queries.
+///
+/// ```text
+/// identifier [AS alias] VECTOR FLAT | HNSW count TYPE type DIM dim DISTANCE_METRIC metric [attribute value ...]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct VectorField {
+    identifier: String,
+    alias: Option<String>,
+    algorithm: VectorAlgorithm,
+    vector_type: VectorType,
+    dim: usize,
+    distance_metric: DistanceMetric,
+    attributes: Vec<(String, String)>,
+}
+
+impl VectorField {
+    /// Creates a vector field with the mandatory attributes.
+    pub fn new(
+        identifier: &str,
+        algorithm: VectorAlgorithm,
+        vector_type: VectorType,
+        dim: usize,
+        distance_metric: DistanceMetric,
+    ) -> Self {
+        VectorField {
+            identifier: identifier.to_string(),
+            alias: None,
+            algorithm,
+            vector_type,
+            dim,
+            distance_metric,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Sets the name the field is referred to in queries.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// Adds an algorithm specific attribute, e.g. `M` or `EF_CONSTRUCTION` for HNSW.
+    pub fn attribute(mut self, name: &str, value: impl ToString) -> Self {
+        self.attributes.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl From<VectorField> for SchemaField {
+    fn from(field: VectorField) -> Self {
+        SchemaField::Vector(field)
+    }
+}
+
+impl ToRedisArgs for VectorField {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.identifier.as_bytes());
+        if let Some(ref alias) = self.alias {
+            out.write_arg(b"AS");
+            out.write_arg(alias.as_bytes());
+        }
+        out.write_arg(b"VECTOR");
+        out.write_arg(match self.algorithm {
+            VectorAlgorithm::Flat => b"FLAT",
+            VectorAlgorithm::Hnsw => b"HNSW",
+        });
+        out.write_arg_fmt(6 + 2 * self.attributes.len());
+        out.write_arg(b"TYPE");
+        out.write_arg(match self.vector_type {
+            VectorType::BFloat16 => b"BFLOAT16".as_slice(),
+            VectorType::Float16 => b"FLOAT16",
+            VectorType::Float32 => b"FLOAT32",
+            VectorType::Float64 => b"FLOAT64",
+        });
+        out.write_arg(b"DIM");
+        out.write_arg_fmt(self.dim);
+        out.write_arg(b"DISTANCE_METRIC");
+        out.write_arg(match self.distance_metric {
+            DistanceMetric::L2 => b"L2".as_slice(),
+            DistanceMetric::InnerProduct => b"IP",
+            DistanceMetric::Cosine => b"COSINE",
+        });
+        for (name, value) in &self.attributes {
+            out.write_arg(name.as_bytes());
+            out.write_arg(value.as_bytes());
+        }
+    }
+}
+
+fn single_arg<T: ToSingleRedisArg>(value: T) -> Vec<u8> {
+    value.to_redis_args().pop().unwrap_or_default()
+}
+
+fn write_params<W>(params: &[(String, Vec<u8>)], out: &mut W)
+where
+    W: ?Sized + RedisWrite,
+{
+    if !params.is_empty() {
+        out.write_arg(b"PARAMS");
+        out.write_arg_fmt(params.len() * 2);
+        for (name, value) in params {
+            out.write_arg(name.as_bytes());
+            out.write_arg(value);
+        }
+    }
+}
+
+/// A query for [`ft_search`][1].
+///
+/// ```text
+/// FT.SEARCH index query [NOCONTENT] [VERBATIM] [WITHSCORES] [WITHPAYLOADS] [INKEYS count key [key ...]]
+///   [INFIELDS count field [field ...]] [RETURN count identifier [AS property] ...]
+///   [SLOP slop] [TIMEOUT timeout] [INORDER] [LANGUAGE language] [SCORER scorer]
+///   [SORTBY sortby [ASC | DESC]] [LIMIT offset num] [PARAMS nargs name value ...] [DIALECT dialect]
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::search::SearchQuery;
+///
+/// // The 3 nearest neighbours of a vector, using query parameters
+/// let vector: Vec<u8> = [0.1f32, 0.2, 0.3]
+///     .iter()
+///     .flat_map(|f| f.to_le_bytes())
+///     .collect();
+/// let query = SearchQuery::new("*=>[KNN 3 @embedding $vec AS distance]")
+///     .param("vec", vector)
+///     .sort_by("distance", Default::default())
+///     .return_field("distance")
+///     .dialect(2);
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ft_search
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct SearchQuery {
+    query: String,
+    no_content: bool,
+    verbatim: bool,
+    with_scores: bool,
+    with_payloads: bool,
+    in_keys: Vec<String>,
+    in_fields: Vec<String>,
+    return_fields: Vec<(String, Option<String>)>,
+    slop: Option<i64>,
+    timeout: Option<u64>,
+    in_order: bool,
+    language: Option<String>,
+    scorer: Option<String>,
+    sort_by: Option<(String, SortOrder)>,
+    limit: Option<(usize, usize)>,
+    params: Vec<(String, Vec<u8>)>,
+    dialect: Option<u8>,
+}
+
+impl SearchQuery {
+    /// Creates a query from a query string, e.g. `@title:hello @price:[0 100]`.
+    pub fn new(query: &str) -> Self {
+        SearchQuery {
+            query: query.to_string(),
+            no_content: false,
+            verbatim: false,
+            with_scores: false,
+            with_payloads: false,
+            in_keys: Vec::new(),
+            in_fields: Vec::new(),
+            return_fields: Vec::new(),
+            slop: None,
+            timeout: None,
+            in_order: false,
+            language: None,
+            scorer: None,
+            sort_by: None,
+            limit: None,
+            params: Vec::new(),
+            dialect: None,
+        }
+    }
+
+    /// Only returns the document ids, without their content.
+    pub fn no_content(mut self) -> Self {
+        self.no_content = true;
+        self
+    }
+
+    /// Disables stemming of the query terms.
+    pub fn verbatim(mut self) -> Self {
+        self.verbatim = true;
+        self
+    }
+
+    /// Returns the relative score of each document.
+    ///
+    /// With RESP2, combining this with [`no_content`](Self::no_content) yields a reply that
+    /// can't be told apart from a list of ids, so it must be parsed with
+    /// [`parse_result`](Self::parse_result) or read with [`search`](Self::search).
+    pub fn with_scores(mut self) -> Self {
+        self.with_scores = true;
+        self
+    }
+
+    /// Returns the payload of each document.
+    ///
+    /// With RESP2, the reply must be parsed with [`parse_result`](Self::parse_result) or read
+    /// with [`search`](Self::search).
+    pub fn with_payloads(mut self) -> Self {
+        self.with_payloads = true;
+        self
+    }
+
+    /// Limits the result to the given keys.
+    pub fn in_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.in_keys.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Limits the full-text search to the given fields.
+    pub fn in_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.in_fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the given field instead of the whole document. Can be called multiple times.
+    pub fn return_field(mut self, identifier: &str) -> Self {
+        self.return_fields.push((identifier.to_string(), None));
+        self
+    }
+
+    /// Returns the given field under another name instead of the whole document.
+    pub fn return_field_as(mut self, identifier: &str, property: &str) -> Self {
+        self.return_fields
+            .push((identifier.to_string(), Some(property.to_string())));
+        self
+    }
+
+    /// Allows a maximum of `slop` intervening terms between the phrase terms.
+    pub fn slop(mut self, slop: i64) -> Self {
+        self.slop = Some(slop);
+        self
+    }
+
+    /// Sets the query timeout in milliseconds.
+    pub fn timeout(mut self, milliseconds: u64) -> Self {
+        self.timeout = Some(milliseconds);
+        self
+    }
+
+    /// Requires the phrase terms to appear in the same order as in the query.
+    pub fn in_order(mut self) -> Self {
+        self.in_order = true;
+        self
+    }
+
+    /// Sets the language used for stemming the query terms.
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Sets the scoring function, e.g. `BM25`.
+    pub fn scorer(mut self, scorer: &str) -> Self {
+        self.scorer = Some(scorer.to_string());
+        self
+    }
+
+    /// Sorts the results by a sortable field.
+    pub fn sort_by(mut self, field: &str, order: SortOrder) -> Self {
+        self.sort_by = Some((field.to_string(), order));
+        self
+    }
+
+    /// Returns `num` results, skipping the first `offset` ones. Defaults to `0 10`.
+    pub fn limit(mut self, offset: usize, num: usize) -> Self {
+        self.limit = Some((offset, num));
+        self
+    }
+
+    /// Sets a parameter that can be referred to as `$name` in the query string.
+    pub fn param<V: ToSingleRedisArg>(mut self, name: &str, value: V) -> Self {
+        self.params.push((name.to_string(), single_arg(value)));
+        self
+    }
+
+    /// Sets the query dialect.
+    pub fn dialect(mut self, dialect: u8) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    fn layout(&self) -> SearchReplyLayout {
+        SearchReplyLayout {
+            scores: self.with_scores,
+            payloads: self.with_payloads,
+            content: !self.no_content,
+        }
+    }
+
+    /// Parses a reply of `FT.SEARCH` run with this query.
+    ///
+    /// Unlike the [`FromRedisValue`] implementation of [`SearchResult`], which has to guess the
+    /// layout of RESP2 replies, this reads the layout from the flags of the query.
+    pub fn parse_result(&self, reply: Value) -> Result<SearchResult, ParsingError> {
+        SearchResult::from_reply(reply, Some(self.layout()))
+    }
+
+    /// Runs the query on `index` and parses the reply with [`parse_result`](Self::parse_result).
+    pub fn search<C: ConnectionLike>(&self, index: &str, con: &mut C) -> RedisResult<SearchResult> {
+        let reply = cmd("FT.SEARCH").arg(index).arg(self).query(con)?;
+        Ok(self.parse_result(reply)?)
+    }
+
+    /// Runs the query on `index` and parses the reply with [`parse_result`](Self::parse_result).
+    #[cfg(feature = "aio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+    pub async fn search_async<C: crate::aio::ConnectionLike>(
+        &self,
+        index: &str,
+        con: &mut C,
+    ) -> RedisResult<SearchResult> {
+        let reply = cmd("FT.SEARCH")
+            .arg(index)
+            .arg(self)
+            .query_async(con)
+            .await?;
+        Ok(self.parse_result(reply)?)
+    }
+}
+
+// The parts of each document in a RESP2 search reply, following its id.
+#[derive(Clone, Copy, Debug)]
+struct SearchReplyLayout {
+    scores: bool,
+    payloads: bool,
+    content: bool,
+}
+
+impl ToRedisArgs for SearchQuery {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.query.as_bytes());
+        if self.no_content {
+            out.write_arg(b"NOCONTENT");
+        }
+        if self.verbatim {
+            out.write_arg(b"VERBATIM");
+        }
+        if self.with_scores {
+            out.write_arg(b"WITHSCORES");
+        }
+        if self.with_payloads {
+            out.write_arg(b"WITHPAYLOADS");
+        }
+        if !self.in_keys.is_empty() {
+            out.write_arg(b"INKEYS");
+            out.write_arg_fmt(self.in_keys.len());
+            for key in &self.in_keys {
+                out.write_arg(key.as_bytes());
+            }
+        }
+        if !self.in_fields.is_empty() {
+            out.write_arg(b"INFIELDS");
+            out.write_arg_fmt(self.in_fields.len());
+            for field in &self.in_fields {
+                out.write_arg(field.as_bytes());
+            }
+        }
+        if !self.return_fields.is_empty() {
+            out.write_arg(b"RETURN");
+            let count: usize = self
+                .return_fields
+                .iter()
+                .map(|(_, property)| if property.is_some() { 3 } else { 1 })
+                .sum();
+            out.write_arg_fmt(count);
+            for (identifier, property) in &self.return_fields {
+                out.write_arg(identifier.as_bytes());
+                if let Some(property) = property {
+                    out.write_arg(b"AS");
+                    out.write_arg(property.as_bytes());
+                }
+            }
+        }
+        if let Some(slop) = self.slop {
+            out.write_arg(b"SLOP");
+            out.write_arg_fmt(slop);
+        }
+        if let Some(timeout) = self.timeout {
+            out.write_arg(b"TIMEOUT");
+            out.write_arg_fmt(timeout);
+        }
+        if self.in_order {
+            out.write_arg(b"INORDER");
+        }
+        if let Some(ref language) = self.language {
+            out.write_arg(b"LANGUAGE");
+            out.write_arg(language.as_bytes());
+        }
+        if let Some(ref scorer) = self.scorer {
+            out.write_arg(b"SCORER");
+            out.write_arg(scorer.as_bytes());
+        }
+        if let Some((ref field, order)) = self.sort_by {
+            out.write_arg(b"SORTBY");
+            out.write_arg(field.as_bytes());
+            order.write_redis_args(out);
+        }
+        if let Some((offset, num)) = self.limit {
+            out.write_arg(b"LIMIT");
+            out.write_arg_fmt(offset);
+            out.write_arg_fmt(num);
+        }
+        write_params(&self.params, out);
+        if let Some(dialect) = self.dialect {
+            out.write_arg(b"DIALECT");
+            out.write_arg_fmt(dialect);
+        }
+    }
+}
+
+/// A reducer of a `GROUPBY` step of an [`AggregateQuery`].
+///
+/// ```text
+/// REDUCE function nargs arg [arg ...] [AS name]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Reducer {
+    function: String,
+    args: Vec<String>,
+    alias: Option<String>,
+}
+
+impl Reducer {
+    /// Creates a reducer calling an arbitrary reduce function.
+    pub fn new<I, S>(function: &str, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Reducer {
+            function: function.to_string(),
+            args: args.into_iter().map(Into::into).collect(),
+            alias: None,
+        }
+    }
+
+    /// Counts the records of each group.
+    pub fn count() -> Self {
+        Self::new("COUNT", [] as [String; 0])
+    }
+
+    /// Counts the distinct values of `property` in each group.
+    pub fn count_distinct(property: &str) -> Self {
+        Self::new("COUNT_DISTINCT", [property])
+    }
+
+    /// Sums the values of `property` in each group.
+    pub fn sum(property: &str) -> Self {
+        Self::new("SUM", [property])
+    }
+
+    /// Determines the minimal value of `property` in each group.
+    pub fn min(property: &str) -> Self {
+        Self::new("MIN", [property])
+    }
+
+    /// Determines the maximal value of `property` in each group.
+    pub fn max(property: &str) -> Self {
+        Self::new("MAX", [property])
+    }
+
+    /// Averages the values of `property` in each group.
+    pub fn avg(property: &str) -> Self {
+        Self::new("AVG", [property])
+    }
+
+    /// Collects the distinct values of `property` in each group.
+    pub fn to_list(property: &str) -> Self {
+        Self::new("TOLIST", [property])
+    }
+
+    /// Sets the name of the property holding the reduced value.
+    pub fn alias(mut self, name: &str) -> Self {
+        self.alias = Some(name.to_string());
+        self
+    }
+}
+
+impl ToRedisArgs for Reducer {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(b"REDUCE");
+        out.write_arg(self.function.as_bytes());
+        out.write_arg_fmt(self.args.len());
+        for arg in &self.args {
+            out.write_arg(arg.as_bytes());
+        }
+        if let Some(ref alias) = self.alias {
+            out.write_arg(b"AS");
+            out.write_arg(alias.as_bytes());
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum AggregateStep {
+    GroupBy(Vec<String>, Vec<Reducer>),
+    SortBy(Vec<(String, SortOrder)>, Option<usize>),
+    Apply(String, String),
+    Filter(String),
+    Limit(usize, usize),
+}
+
+impl ToRedisArgs for AggregateStep {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            AggregateStep::GroupBy(properties, reducers) => {
+                out.write_arg(b"GROUPBY");
+                out.write_arg_fmt(properties.len());
+                for property in properties {
+                    out.write_arg(property.as_bytes());
+                }
+                for reducer in reducers {
+                    reducer.write_redis_args(out);
+                }
+            }
+            AggregateStep::SortBy(properties, max) => {
+                out.write_arg(b"SORTBY");
+                out.write_arg_fmt(properties.len() * 2);
+                for (property, order) in properties {
+                    out.write_arg(property.as_bytes());
+                    order.write_redis_args(out);
+                }
+                if let Some(max) = max {
+                    out.write_arg(b"MAX");
+                    out.write_arg_fmt(max);
+                }
+            }
+            AggregateStep::Apply(expression, alias) => {
+                out.write_arg(b"APPLY");
+                out.write_arg(expression.as_bytes());
+                out.write_arg(b"AS");
+                out.write_arg(alias.as_bytes());
+            }
+            AggregateStep::Filter(expression) => {
+                out.write_arg(b"FILTER");
+                out.write_arg(expression.as_bytes());
+            }
+            AggregateStep::Limit(offset, num) => {
+                out.write_arg(b"LIMIT");
+                out.write_arg_fmt(offset);
+                out.write_arg_fmt(num);
+            }
+        }
+    }
+}
+
+/// A query for [`ft_aggregate`][1].
+///
+/// The pipeline steps (`GROUPBY`, `SORTBY`, `APPLY`, `FILTER` and `LIMIT`) are sent in the order
+/// they were added.
+///
+/// ```text
+/// FT.AGGREGATE index query [VERBATIM] [LOAD count field [field ...] | LOAD *] [TIMEOUT timeout]
+///   [GROUPBY nargs property [property ...] [REDUCE function nargs arg [arg ...] [AS name] ...] ...]
+///   [SORTBY nargs property ASC | DESC [property ASC | DESC ...] [MAX num]] [APPLY expression AS name ...]
+///   [LIMIT offset num] [FILTER filter] [WITHCURSOR [COUNT read_size] [MAXIDLE idle_time]]
+///   [PARAMS nargs name value ...] [DIALECT dialect]
+/// ```
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::search::{AggregateQuery, Reducer, SortOrder};
+///
+/// // The number of products per category, largest categories first
+/// let query = AggregateQuery::new("*")
+///     .group_by(["@category"], [Reducer::count().alias("count")])
+///     .sort_by([("@count", SortOrder::Desc)]);
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ft_aggregate
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct AggregateQuery {
+    query: String,
+    verbatim: bool,
+    load: Option<Vec<String>>,
+    timeout: Option<u64>,
+    steps: Vec<AggregateStep>,
+    cursor: Option<(Option<usize>, Option<u64>)>,
+    params: Vec<(String, Vec<u8>)>,
+    dialect: Option<u8>,
+}
+
+impl AggregateQuery {
+    /// Creates an aggregation over the documents matching the query string.
+    pub fn new(query: &str) -> Self {
+        AggregateQuery {
+            query: query.to_string(),
+            verbatim: false,
+            load: None,
+            timeout: None,
+            steps: Vec::new(),
+            cursor: None,
+            params: Vec::new(),
+            dialect: None,
+        }
+    }
+
+    /// Disables stemming of the query terms.
+    pub fn verbatim(mut self) -> Self {
+        self.verbatim = true;
+        self
+    }
+
+    /// Loads the given document fields, so that they can be used in the pipeline.
+    pub fn load<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.load = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Loads all document fields.
+    pub fn load_all(mut self) -> Self {
+        self.load = Some(Vec::new());
+        self
+    }
+
+    /// Sets the query timeout in milliseconds.
+    pub fn timeout(mut self, milliseconds: u64) -> Self {
+        self.timeout = Some(milliseconds);
+        self
+    }
+
+    /// Groups the records by the given properties and reduces each group.
+    pub fn group_by<P, S, R>(mut self, properties: P, reducers: R) -> Self
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+        R: IntoIterator<Item = Reducer>,
+    {
+        self.steps.push(AggregateStep::GroupBy(
+            properties.into_iter().map(Into::into).collect(),
+            reducers.into_iter().collect(),
+        ));
+        self
+    }
+
+    /// Sorts the records by the given properties.
+    pub fn sort_by<P, S>(mut self, properties: P) -> Self
+    where
+        P: IntoIterator<Item = (S, SortOrder)>,
+        S: Into<String>,
+    {
+        self.steps.push(AggregateStep::SortBy(
+            properties
+                .into_iter()
+                .map(|(property, order)| (property.into(), order))
+                .collect(),
+            None,
+        ));
+        self
+    }
+
+    /// Sorts the records by the given properties, keeping only the first `max` records.
+    pub fn sort_by_max<P, S>(mut self, properties: P, max: usize) -> Self
+    where
+        P: IntoIterator<Item = (S, SortOrder)>,
+        S: Into<String>,
+    {
+        self = self.sort_by(properties);
+        if let Some(AggregateStep::SortBy(_, limit)) = self.steps.last_mut() {
+            *limit = Some(max);
+        }
+        self
+    }
+
+    /// Computes a new property from an expression.
+    pub fn apply(mut self, expression: &str, alias: &str) -> Self {
+        self.steps.push(AggregateStep::Apply(
+            expression.to_string(),
+            alias.to_string(),
+        ));
+        self
+    }
+
+    /// Drops the records for which the expression evaluates to false.
+    pub fn filter(mut self, expression: &str) -> Self {
+        self.steps
+            .push(AggregateStep::Filter(expression.to_string()));
+        self
+    }
+
+    /// Keeps `num` records, skipping the first `offset` ones.
+    pub fn limit(mut self, offset: usize, num: usize) -> Self {
+        self.steps.push(AggregateStep::Limit(offset, num));
+        self
+    }
+
+    /// Returns the result in batches, which are read with [`ft_cursor_read`][1].
+    ///
+    /// [`AggregateCursor`] takes care of reading the batches.
+    ///
+    /// [1]: ../trait.Commands.html#method.ft_cursor_read
+    pub fn with_cursor(mut self) -> Self {
+        self.cursor.get_or_insert((None, None));
+        self
+    }
+
+    /// Returns the result in batches of `count` records. Implies [`with_cursor`](Self::with_cursor).
+    pub fn cursor_count(mut self, count: usize) -> Self {
+        self.cursor.get_or_insert((None, None)).0 = Some(count);
+        self
+    }
+
+    /// Deletes the cursor after it was idle for the given number of milliseconds.
+    /// Implies [`with_cursor`](Self::with_cursor).
+    pub fn cursor_max_idle(mut self, milliseconds: u64) -> Self {
+        self.cursor.get_or_insert((None, None)).1 = Some(milliseconds);
+        self
+    }
+
+    /// Sets a parameter that can be referred to as `$name` in the query string.
+    pub fn param<V: ToSingleRedisArg>(mut self, name: &str, value: V) -> Self {
+        self.params.push((name.to_string(), single_arg(value)));
+        self
+    }
+
+    /// Sets the query dialect.
+    pub fn dialect(mut self, dialect: u8) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+}
+
+impl ToRedisArgs for AggregateQuery {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.query.as_bytes());
+        if self.verbatim {
+            out.write_arg(b"VERBATIM");
+        }
+        match self.load {
+            Some(ref fields) if fields.is_empty() => {
+                out.write_arg(b"LOAD");
+                out.write_arg(b"*");
+            }
+            Some(ref fields) => {
+                out.write_arg(b"LOAD");
+                out.write_arg_fmt(fields.len());
+                for field in fields {
+                    out.write_arg(field.as_bytes());
+                }
+            }
+            None => {}
+        }
+        if let Some(timeout) = self.timeout {
+            out.write_arg(b"TIMEOUT");
+            out.write_arg_fmt(timeout);
+        }
+        for step in &self.steps {
+            step.write_redis_args(out);
+        }
+        if let Some((count, max_idle)) = self.cursor {
+            out.write_arg(b"WITHCURSOR");
+            if let Some(count) = count {
+                out.write_arg(b"COUNT");
+                out.write_arg_fmt(count);
+            }
+            if let Some(max_idle) = max_idle {
+                out.write_arg(b"MAXIDLE");
+                out.write_arg_fmt(max_idle);
+            }
+        }
+        write_params(&self.params, out);
+        if let Some(dialect) = self.dialect {
+            out.write_arg(b"DIALECT");
+            out.write_arg_fmt(dialect);
+        }
+    }
+}
+
+/// Reply of [`ft_search`][1].
+///
+/// Both the RESP2 and the RESP3 reply formats are supported. The layout of RESP2 replies
+/// depends on the flags of the query and is guessed when converting a reply with
+/// [`FromRedisValue`], which fails for replies with [`SearchQuery::with_payloads`] or with both
+/// [`SearchQuery::with_scores`] and [`SearchQuery::no_content`]. Use [`SearchQuery::search`] or
+/// [`SearchQuery::parse_result`] for these.
+///
+/// [1]: ../trait.Commands.html#method.ft_search
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SearchResult {
+    /// The total number of matching documents. This may be larger than the number of returned
+    /// documents.
+    pub total: usize,
+    /// The returned documents.
+    pub documents: Vec<SearchDocument>,
+}
+
+/// A single document of a [`SearchResult`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SearchDocument {
+    /// The key of the document.
+    pub id: String,
+    /// The score of the document, if it was requested with [`SearchQuery::with_scores`].
+    pub score: Option<f64>,
+    /// The payload of the document, if it was requested with [`SearchQuery::with_payloads`]
+    /// and the document has one.
+    pub payload: Option<Vec<u8>>,
+    /// The returned fields of the document. Empty if the query used
+    /// [`SearchQuery::no_content`].
+    pub fields: HashMap<String, Value>,
+    /// The whole document serialized as JSON, for indexes on JSON documents queried without
+    /// [`SearchQuery::return_field`].
+    pub json: Option<String>,
+}
+
+impl SearchDocument {
+    fn new(
+        id: Value,
+        score: Option<Value>,
+        payload: Option<Value>,
+        fields: Option<Value>,
+    ) -> Result<Self, ParsingError> {
+        let mut document = SearchDocument {
+            id: from_redis_value(id)?,
+            score: score.map(from_redis_value).transpose()?,
+            payload: payload
+                .map(from_redis_value::<Option<_>>)
+                .transpose()?
+                .flatten(),
+            ..Default::default()
+        };
+        if let Some(fields) = fields {
+            document.fields = from_redis_value::<Option<_>>(fields)?.unwrap_or_default();
+        }
+        if let Some(json) = document.fields.remove("$") {
+            document.json = Some(from_redis_value(json)?);
+        }
+        Ok(document)
+    }
+
+    /// Returns the value of a field converted to the requested type, or `None` if the field
+    /// wasn't returned.
+    pub fn get<T: FromRedisValue>(&self, field: &str) -> Option<Result<T, ParsingError>> {
+        self.fields.get(field).map(T::from_redis_value_ref)
+    }
+
+    /// Deserializes the [`json`](Self::json) payload of the document.
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn json_as<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<Option<T>> {
+        self.json.as_deref().map(serde_json::from_str).transpose()
+    }
+}
+
+impl SearchResult {
+    // Parses a reply, guessing the layout of RESP2 replies when it isn't known.
+    fn from_reply(v: Value, layout: Option<SearchReplyLayout>) -> Result<Self, ParsingError> {
+        match v {
+            Value::Array(items) => {
+                // RESP2: the total, followed by the id, the optional score, the optional payload
+                // and the optional fields of every document
+                let mut items = items.into_iter();
+                let Some(total) = items.next() else {
+                    invalid_type_error!("search reply is empty");
+                };
+                let mut result = SearchResult {
+                    total: from_redis_value(total)?,
+                    documents: Vec::new(),
+                };
+                let items: Vec<Value> = items.collect();
+                let layout = match layout {
+                    Some(layout) => layout,
+                    None => match items
+                        .iter()
+                        .position(|item| matches!(item, Value::Array(_) | Value::Nil))
+                    {
+                        None => SearchReplyLayout {
+                            scores: false,
+                            payloads: false,
+                            content: false,
+                        },
+                        Some(1) => SearchReplyLayout {
+                            scores: false,
+                            payloads: false,
+                            content: true,
+                        },
+                        Some(2) => SearchReplyLayout {
+                            scores: true,
+                            payloads: false,
+                            content: true,
+                        },
+                        Some(_) => invalid_type_error!("unexpected search reply layout"),
+                    },
+                };
+                let stride = 1
+                    + usize::from(layout.scores)
+                    + usize::from(layout.payloads)
+                    + usize::from(layout.content);
+                if !items.len().is_multiple_of(stride) {
+                    invalid_type_error!("unexpected search reply layout");
+                }
+                let mut items = items.into_iter();
+                while let Some(id) = items.next() {
+                    let score = if layout.scores { items.next() } else { None };
+                    let payload = if layout.payloads { items.next() } else { None };
+                    let fields = if layout.content { items.next() } else { None };
+                    result
+                        .documents
+                        .push(SearchDocument::new(id, score, payload, fields)?);
+                }
+                Ok(result)
+            }
+            Value::Map(_) => {
+                // RESP3
+                let mut map: HashMap<String, Value> = from_redis_value(v)?;
+                let mut result = SearchResult::default();
+                if let Some(total) = map.remove("total_results") {
+                    result.total = from_redis_value(total)?;
+                }
+                if let Some(Value::Array(documents)) = map.remove("results") {
+                    for document in documents {
+                        let mut document: HashMap<String, Value> = from_redis_value(document)?;
+                        let Some(id) = document.remove("id") else {
+                            invalid_type_error!("search result without id");
+                        };
+                        result.documents.push(SearchDocument::new(
+                            id,
+                            document.remove("score"),
+                            document.remove("payload"),
+                            document.remove("extra_attributes"),
+                        )?);
+                    }
+                }
+                Ok(result)
+            }
+            _ => invalid_type_error!(v, "Response type not SearchResult compatible."),
+        }
+    }
+}
+
+impl FromRedisValue for SearchResult {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        SearchResult::from_reply(v, None)
+    }
+}
+
+/// Reply of [`ft_aggregate`][1] and [`ft_cursor_read`][2].
+///
+/// Both the RESP2 and the RESP3 reply formats are supported.
+///
+/// [1]: ../trait.Commands.html#method.ft_aggregate
+/// [2]: ../trait.Commands.html#method.ft_cursor_read
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct AggregateResult {
+    /// The number of results. Depending on the pipeline, this may be larger than the number of
+    /// returned rows.
+    pub total: usize,
+    /// The returned records, each mapping the property names to their values.
+    pub rows: Vec<HashMap<String, Value>>,
+    /// The id of the cursor to continue reading from, if the query used a cursor. An id of `0`
+    /// means that the cursor is exhausted.
+    pub cursor_id: Option<u64>,
+}
+
+impl AggregateResult {
+    fn from_rows(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::Array(items) => {
+                // RESP2: the total, followed by the records as flat key value arrays
+                let mut items = items.into_iter();
+                let Some(total) = items.next() else {
+                    invalid_type_error!("aggregate reply is empty");
+                };
+                Ok(AggregateResult {
+                    total: from_redis_value(total)?,
+                    rows: items
+                        .map(from_redis_value)
+                        .collect::<Result<_, ParsingError>>()?,
+                    cursor_id: None,
+                })
+            }
+            Value::Map(_) => {
+                // RESP3
+                let mut map: HashMap<String, Value> = from_redis_value(v)?;
+                let mut result = AggregateResult::default();
+                if let Some(total) = map.remove("total_results") {
+                    result.total = from_redis_value(total)?;
+                }
+                if let Some(Value::Array(rows)) = map.remove("results") {
+                    for row in rows {
+                        let mut row: HashMap<String, Value> = from_redis_value(row)?;
+                        result.rows.push(match row.remove("extra_attributes") {
+                            Some(attributes) => from_redis_value(attributes)?,
+                            None => HashMap::new(),
+                        });
+                    }
+                }
+                Ok(result)
+            }
+            _ => invalid_type_error!(v, "Response type not AggregateResult compatible."),
+        }
+    }
+}
+
+impl FromRedisValue for AggregateResult {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            // Replies of cursor reads are a pair of the result and the cursor id
+            Value::Array(ref items)
+                if items.len() == 2 && matches!(items[0], Value::Array(_) | Value::Map(_)) =>
+            {
+                let Value::Array(items) = v else {
+                    unreachable!()
+                };
+                let mut items = items.into_iter();
+                let (Some(rows), Some(cursor_id)) = (items.next(), items.next()) else {
+                    unreachable!()
+                };
+                let mut result = AggregateResult::from_rows(rows)?;
+                result.cursor_id = Some(from_redis_value(cursor_id)?);
+                Ok(result)
+            }
+            _ => AggregateResult::from_rows(v),
+        }
+    }
+}
+
+/// Reads the result of an [`AggregateQuery`] batch by batch, using a cursor.
+///
+/// The first batch is read with `FT.AGGREGATE`, all following ones with `FT.CURSOR READ`. The
+/// cursor is deleted by the server once it is exhausted. If the iteration is stopped early,
+/// [`delete`](Self::delete) frees the cursor right away instead of waiting for it to time out.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use redis::search::{AggregateCursor, AggregateQuery};
+///
+/// # fn do_something() -> redis::RedisResult<()> {
+/// # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+/// # let mut con = client.get_connection().unwrap();
+/// let query = AggregateQuery::new("*").load(["@title"]).cursor_count(100);
+/// let mut cursor = AggregateCursor::new("products", query);
+/// while let Some(rows) = cursor.next_batch(&mut con)? {
+///     for row in rows {
+///         println!("{row:?}");
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct AggregateCursor {
+    index: String,
+    query: Option<AggregateQuery>,
+    cursor_id: u64,
+}
+
+impl AggregateCursor {
+    /// Creates a cursor over the result of `query`. A cursor is requested even if the query
+    /// doesn't use [`AggregateQuery::with_cursor`].
+    pub fn new(index: &str, query: AggregateQuery) -> Self {
+        AggregateCursor {
+            index: index.to_string(),
+            query: Some(query.with_cursor()),
+            cursor_id: 0,
+        }
+    }
+
+    /// Returns the id of the server-side cursor, or `None` if it wasn't created yet or is
+    /// exhausted.
+    pub fn cursor_id(&self) -> Option<u64> {
+        (self.cursor_id != 0).then_some(self.cursor_id)
+    }
+
+    fn next_cmd(&mut self) -> Option<Cmd> {
+        if let Some(query) = self.query.take() {
+            return Some(cmd("FT.AGGREGATE").arg(&self.index).arg(query).take());
+        }
+        self.cursor_id().map(|cursor_id| {
+            cmd("FT.CURSOR")
+                .arg("READ")
+                .arg(&self.index)
+                .arg(cursor_id)
+                .take()
+        })
+    }
+
+    fn handle_result(&mut self, result: AggregateResult) -> Vec<HashMap<String, Value>> {
+        self.cursor_id = result.cursor_id.unwrap_or_default();
+        result.rows
+    }
+
+    /// Reads the next batch of rows, or returns `None` if the result is exhausted.
+    pub fn next_batch<C: ConnectionLike>(
+        &mut self,
+        con: &mut C,
+    ) -> RedisResult<Option<Vec<HashMap<String, Value>>>> {
+        let Some(cmd) = self.next_cmd() else {
+            return Ok(None);
+        };
+        let result = cmd.query(con)?;
+        Ok(Some(self.handle_result(result)))
+    }
+
+    /// Reads the next batch of rows, or returns `None` if the result is exhausted.
+    #[cfg(feature = "aio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+    pub async fn next_batch_async<C: crate::aio::ConnectionLike>(
+        &mut self,
+        con: &mut C,
+    ) -> RedisResult<Option<Vec<HashMap<String, Value>>>> {
+        let Some(cmd) = self.next_cmd() else {
+            return Ok(None);
+        };
+        let result = cmd.query_async(con).await?;
+        Ok(Some(self.handle_result(result)))
+    }
+
+    fn delete_cmd(&self) -> Option<Cmd> {
+        self.cursor_id().map(|cursor_id| {
+            cmd("FT.CURSOR")
+                .arg("DEL")
+                .arg(&self.index)
+                .arg(cursor_id)
+                .take()
+        })
+    }
+
+    /// Deletes the server-side cursor, if it is still open.
+    pub fn delete<C: ConnectionLike>(self, con: &mut C) -> RedisResult<()> {
+        match self.delete_cmd() {
+            Some(cmd) => cmd.exec(con),
+            None => Ok(()),
+        }
+    }
+
+    /// Deletes the server-side cursor, if it is still open.
+    #[cfg(feature = "aio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+    pub async fn delete_async<C: crate::aio::ConnectionLike>(self, con: &mut C) -> RedisResult<()> {
+        match self.delete_cmd() {
+            Some(cmd) => cmd.exec_async(con).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reply of [`ft_info`][1].
+///
+/// [1]: ../trait.Commands.html#method.ft_info
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct IndexInfo {
+    /// The name of the index.
+    pub index_name: String,
+    /// The options the index was created with, e.g. `NOFREQS`.
+    pub index_options: Vec<String>,
+    /// The type of keys the index is built on, e.g. `HASH`.
+    pub key_type: String,
+    /// The key prefixes covered by the index.
+    pub prefixes: Vec<String>,
+    /// The fields of the index.
+    pub attributes: Vec<AttributeInfo>,
+    /// The number of indexed documents.
+    pub num_docs: usize,
+    /// The number of distinct indexed terms.
+    pub num_terms: usize,
+    /// The number of records in the inverted indexes.
+    pub num_records: usize,
+    /// Whether the index is still being built.
+    pub indexing: bool,
+    /// The fraction of keys that are already indexed, between 0 and 1.
+    pub percent_indexed: f64,
+    /// The number of keys that failed to be indexed.
+    pub hash_indexing_failures: usize,
+}
+
+/// A field of an index, as reported by [`IndexInfo`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AttributeInfo {
+    /// The hash field or JSON path the field is built from.
+    pub identifier: String,
+    /// The name the field is referred to in queries.
+    pub attribute: String,
+    /// The type of the field, e.g. `TEXT`.
+    pub field_type: String,
+    /// Whether the field is sortable.
+    pub sortable: bool,
+    /// Whether the field is not indexed.
+    pub no_index: bool,
+}
+
+impl FromRedisValue for IndexInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut info = IndexInfo::default();
+        if let Some(v) = map.remove("index_name") {
+            info.index_name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("index_options") {
+            info.index_options = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("index_definition") {
+            let mut definition: HashMap<String, Value> = from_redis_value(v)?;
+            if let Some(v) = definition.remove("key_type") {
+                info.key_type = from_redis_value(v)?;
+            }
+            if let Some(v) = definition.remove("prefixes") {
+                info.prefixes = from_redis_value(v)?;
+            }
+        }
+        if let Some(v) = map.remove("attributes") {
+            info.attributes = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("num_docs") {
+            info.num_docs = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("num_terms") {
+            info.num_terms = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("num_records") {
+            info.num_records = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("indexing") {
+            info.indexing = from_redis_value::<i64>(v)? != 0;
+        }
+        if let Some(v) = map.remove("percent_indexed") {
+            info.percent_indexed = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("hash_indexing_failures") {
+            info.hash_indexing_failures = from_redis_value(v)?;
+        }
+        Ok(info)
+    }
+}
+
+impl AttributeInfo {
+    fn set_flag(&mut self, flag: &str) {
+        match flag {
+            "SORTABLE" => self.sortable = true,
+            "NOINDEX" => self.no_index = true,
+            _ => {}
+        }
+    }
+}
+
+impl FromRedisValue for AttributeInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut info = AttributeInfo::default();
+        match v {
+            Value::Array(items) => {
+                // RESP2: key value pairs mixed with flags that don't have a value
+                let mut items = items.into_iter();
+                while let Some(item) = items.next() {
+                    let Ok(name) = from_redis_value::<String>(item) else {
+                        continue;
+                    };
+                    match name.as_str() {
+                        "identifier" | "attribute" | "type" => {
+                            let Some(value) = items.next() else {
+                                invalid_type_error!("attribute without value");
+                            };
+                            let value = from_redis_value(value)?;
+                            match name.as_str() {
+                                "identifier" => info.identifier = value,
+                                "attribute" => info.attribute = value,
+                                _ => info.field_type = value,
+                            }
+                        }
+                        flag => info.set_flag(flag),
+                    }
+                }
+            }
+            Value::Map(_) => {
+                // RESP3
+                let mut map: HashMap<String, Value> = from_redis_value(v)?;
+                if let Some(v) = map.remove("identifier") {
+                    info.identifier = from_redis_value(v)?;
+                }
+                if let Some(v) = map.remove("attribute") {
+                    info.attribute = from_redis_value(v)?;
+                }
+                if let Some(v) = map.remove("type") {
+                    info.field_type = from_redis_value(v)?;
+                }
+                if let Some(v) = map.remove("flags") {
+                    for flag in from_redis_value::<Vec<String>>(v)? {
+                        info.set_flag(&flag);
+                    }
+                }
+            }
+            _ => invalid_type_error!(v, "Response type not AttributeInfo compatible."),
+        }
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{args, bulk};
+
+    #[test]
+    fn test_create_index_options() {
+        let options = CreateIndexOptions::default()
+            .on(IndexDataType::Json)
+            .prefix("a:")
+            .prefix("b:")
+            .filter("@age > 16")
+            .language("german")
+            .score(0.5)
+            .temporary(60)
+            .no_highlight()
+            .stopwords(["foo", "bar"])
+            .skip_initial_scan();
+        assert_eq!(
+            args(options),
+            vec![
+                "ON",
+                "JSON",
+                "PREFIX",
+                "2",
+                "a:",
+                "b:",
+                "FILTER",
+                "@age > 16",
+                "LANGUAGE",
+                "german",
+                "SCORE",
+                "0.5",
+                "TEMPORARY",
+                "60",
+                "NOHL",
+                "STOPWORDS",
+                "2",
+                "foo",
+                "bar",
+                "SKIPINITIALSCAN"
+            ]
+        );
+        assert!(args(CreateIndexOptions::default()).is_empty());
+        assert_eq!(
+            args(CreateIndexOptions::default().stopwords([] as [&str; 0])),
+            vec!["STOPWORDS", "0"]
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Schema::new()
+            .field(
+                TextField::new("$.title")
+                    .alias("title")
+                    .weight(2.0)
+                    .no_stem(),
+            )
+            .field(TagField::new("tags").separator(';').case_sensitive())
+            .field(NumericField::new("price").sortable())
+            .field(GeoField::new("location").no_index())
+            .field(
+                VectorField::new(
+                    "embedding",
+                    VectorAlgorithm::Hnsw,
+                    VectorType::Float32,
+                    128,
+                    DistanceMetric::Cosine,
+                )
+                .attribute("M", 40),
+            );
+        assert_eq!(
+            args(schema),
+            vec![
+                "SCHEMA",
+                "$.title",
+                "AS",
+                "title",
+                "TEXT",
+                "NOSTEM",
+                "WEIGHT",
+                "2.0",
+                "tags",
+                "TAG",
+                "SEPARATOR",
+                ";",
+                "CASESENSITIVE",
+                "price",
+                "NUMERIC",
+                "SORTABLE",
+                "location",
+                "GEO",
+                "NOINDEX",
+                "embedding",
+                "VECTOR",
+                "HNSW",
+                "8",
+                "TYPE",
+                "FLOAT32",
+                "DIM",
+                "128",
+                "DISTANCE_METRIC",
+                "COSINE",
+                "M",
+                "40"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_query() {
+        assert_eq!(args(SearchQuery::new("*")), vec!["*"]);
+
+        let query = SearchQuery::new("@title:hello")
+            .no_content()
+            .with_scores()
+            .in_keys(["doc:1", "doc:2"])
+            .in_fields(["title"])
+            .return_field("title")
+            .return_field_as("price", "cost")
+            .slop(1)
+            .timeout(100)
+            .in_order()
+            .sort_by("price", SortOrder::Desc)
+            .limit(10, 20)
+            .param("name", "value")
+            .dialect(2);
+        assert_eq!(
+            args(query),
+            vec![
+                "@title:hello",
+                "NOCONTENT",
+                "WITHSCORES",
+                "INKEYS",
+                "2",
+                "doc:1",
+                "doc:2",
+                "INFIELDS",
+                "1",
+                "title",
+                "RETURN",
+                "4",
+                "title",
+                "price",
+                "AS",
+                "cost",
+                "SLOP",
+                "1",
+                "TIMEOUT",
+                "100",
+                "INORDER",
+                "SORTBY",
+                "price",
+                "DESC",
+                "LIMIT",
+                "10",
+                "20",
+                "PARAMS",
+                "2",
+                "name",
+                "value",
+                "DIALECT",
+                "2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_query() {
+        let query = AggregateQuery::new("*")
+            .load(["@price"])
+            .group_by(
+                ["@category"],
+                [Reducer::count().alias("count"), Reducer::sum("@price")],
+            )
+            .sort_by_max([("@count", SortOrder::Desc)], 5)
+            .apply("@count * 2", "double")
+            .filter("@count > 1")
+            .limit(0, 3)
+            .cursor_count(10);
+        assert_eq!(
+            args(query),
+            vec![
+                "*",
+                "LOAD",
+                "1",
+                "@price",
+                "GROUPBY",
+                "1",
+                "@category",
+                "REDUCE",
+                "COUNT",
+                "0",
+                "AS",
+                "count",
+                "REDUCE",
+                "SUM",
+                "1",
+                "@price",
+                "SORTBY",
+                "2",
+                "@count",
+                "DESC",
+                "MAX",
+                "5",
+                "APPLY",
+                "@count * 2",
+                "AS",
+                "double",
+                "FILTER",
+                "@count > 1",
+                "LIMIT",
+                "0",
+                "3",
+                "WITHCURSOR",
+                "COUNT",
+                "10"
+            ]
+        );
+        assert_eq!(
+            args(AggregateQuery::new("*").load_all()),
+            vec!["*", "LOAD", "*"]
+        );
+    }
+
+    #[test]
+    fn test_search_result_resp2() {
+        let value = Value::Array(vec![
+            Value::Int(5),
+            bulk("doc:1"),
+            Value::Array(vec![bulk("title"), bulk("hello")]),
+            bulk("doc:2"),
+            Value::Array(vec![bulk("$"), bulk(r#"{"title":"world"}"#)]),
+        ]);
+        let result = SearchResult::from_redis_value(value).unwrap();
+        assert_eq!(result.total, 5);
+        assert_eq!(result.documents.len(), 2);
+        assert_eq!(result.documents[0].id, "doc:1");
+        assert_eq!(result.documents[0].score, None);
+        assert_eq!(
+            result.documents[0].get::<String>("title"),
+            Some(Ok("hello".to_string()))
+        );
+        assert_eq!(result.documents[1].id, "doc:2");
+        assert!(result.documents[1].fields.is_empty());
+        assert_eq!(
+            result.documents[1].json.as_deref(),
+            Some(r#"{"title":"world"}"#)
+        );
+    }
+
+    #[test]
+    fn test_search_result_resp2_with_scores() {
+        let value = Value::Array(vec![
+            Value::Int(1),
+            bulk("doc:1"),
+            bulk("1.5"),
+            Value::Array(vec![bulk("title"), bulk("hello")]),
+        ]);
+        let result = SearchResult::from_redis_value(value).unwrap();
+        assert_eq!(result.documents[0].id, "doc:1");
+        assert_eq!(result.documents[0].score, Some(1.5));
+        assert_eq!(result.documents[0].fields.len(), 1);
+    }
+
+    #[test]
+    fn test_search_result_resp2_no_content() {
+        let value = Value::Array(vec![Value::Int(2), bulk("doc:1"), bulk("doc:2")]);
+        let result = SearchResult::from_redis_value(value).unwrap();
+        let ids: Vec<_> = result.documents.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["doc:1", "doc:2"]);
+        assert!(result.documents.iter().all(|d| d.fields.is_empty()));
+    }
+
+    #[test]
+    fn test_search_result_resp2_with_scores_no_content() {
+        let query = SearchQuery::new("*").with_scores().no_content();
+        let value = Value::Array(vec![
+            Value::Int(2),
+            bulk("doc:1"),
+            bulk("1.5"),
+            bulk("doc:2"),
+            bulk("0.5"),
+        ]);
+        let result = query.parse_result(value).unwrap();
+        assert_eq!(result.total, 2);
+        let documents: Vec<_> = result
+            .documents
+            .iter()
+            .map(|d| (d.id.as_str(), d.score))
+            .collect();
+        assert_eq!(documents, vec![("doc:1", Some(1.5)), ("doc:2", Some(0.5))]);
+        assert!(result.documents.iter().all(|d| d.fields.is_empty()));
+    }
+
+    #[test]
+    fn test_search_result_resp2_with_payloads_no_content() {
+        let query = SearchQuery::new("*").with_payloads().no_content();
+        assert_eq!(args(query.clone()), vec!["*", "NOCONTENT", "WITHPAYLOADS"]);
+        let value = Value::Array(vec![
+            Value::Int(2),
+            bulk("doc:1"),
+            bulk("payload"),
+            bulk("doc:2"),
+            Value::Nil,
+        ]);
+        let result = query.parse_result(value).unwrap();
+        assert_eq!(result.documents.len(), 2);
+        assert_eq!(result.documents[0].id, "doc:1");
+        assert_eq!(
+            result.documents[0].payload.as_deref(),
+            Some(&b"payload"[..])
+        );
+        assert_eq!(result.documents[1].id, "doc:2");
+        assert_eq!(result.documents[1].payload, None);
+        assert_eq!(result.documents[1].score, None);
+    }
+
+    #[test]
+    fn test_search_result_resp3() {
+        let value = Value::Map(vec![
+            (bulk("attributes"), Value::Array(vec![])),
+            (bulk("format"), bulk("STRING")),
+            (
+                bulk("results"),
+                Value::Array(vec![Value::Map(vec![
+                    (bulk("id"), bulk("doc:1")),
+                    (bulk("score"), Value::Double(0.5)),
+                    (
+                        bulk("extra_attributes"),
+                        Value::Map(vec![(bulk("title"), bulk("hello"))]),
+                    ),
+                    (bulk("values"), Value::Array(vec![])),
+                ])]),
+            ),
+            (bulk("total_results"), Value::Int(1)),
+            (bulk("warning"), Value::Array(vec![])),
+        ]);
+        let result = SearchResult::from_redis_value(value).unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.documents[0].id, "doc:1");
+        assert_eq!(result.documents[0].score, Some(0.5));
+        assert_eq!(
+            result.documents[0].get::<String>("title"),
+            Some(Ok("hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_result() {
+        let resp2 = Value::Array(vec![
+            Value::Int(2),
+            Value::Array(vec![
+                bulk("category"),
+                bulk("shoes"),
+                bulk("count"),
+                bulk("3"),
+            ]),
+            Value::Array(vec![
+                bulk("category"),
+                bulk("hats"),
+                bulk("count"),
+                bulk("1"),
+            ]),
+        ]);
+        let result = AggregateResult::from_redis_value(resp2).unwrap();
+        assert_eq!(result.total, 2);
+        assert_eq!(result.cursor_id, None);
+        assert_eq!(result.rows.len(), 2);
+        assert_eq!(result.rows[0].get("category"), Some(&bulk("shoes")));
+
+        let resp2_cursor = Value::Array(vec![
+            Value::Array(vec![
+                Value::Int(1),
+                Value::Array(vec![bulk("count"), bulk("3")]),
+            ]),
+            Value::Int(42),
+        ]);
+        let result = AggregateResult::from_redis_value(resp2_cursor).unwrap();
+        assert_eq!(result.cursor_id, Some(42));
+        assert_eq!(result.rows[0].get("count"), Some(&bulk("3")));
+
+        let resp3_cursor = Value::Array(vec![
+            Value::Map(vec![
+                (
+                    bulk("results"),
+                    Value::Array(vec![Value::Map(vec![
+                        (
+                            bulk("extra_attributes"),
+                            Value::Map(vec![(bulk("count"), bulk("3"))]),
+                        ),
+                        (bulk("values"), Value::Array(vec![])),
+                    ])]),
+                ),
+                (bulk("total_results"), Value::Int(1)),
+            ]),
+            Value::Int(0),
+        ]);
+        let result = AggregateResult::from_redis_value(resp3_cursor).unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.cursor_id, Some(0));
+        assert_eq!(result.rows[0].get("count"), Some(&bulk("3")));
+    }
+
+    #[test]
+    fn test_index_info() {
+        let value = Value::Array(vec![
+            bulk("index_name"),
+            bulk("idx"),
+            bulk("index_options"),
+            Value::Array(vec![]),
+            bulk("index_definition"),
+            Value::Array(vec![
+                bulk("key_type"),
+                bulk("HASH"),
+                bulk("prefixes"),
+                Value::Array(vec![bulk("doc:")]),
+                bulk("default_score"),
+                bulk("1"),
+            ]),
+            bulk("attributes"),
+            Value::Array(vec![Value::Array(vec![
+                bulk("identifier"),
+                bulk("title"),
+                bulk("attribute"),
+                bulk("title"),
+                bulk("type"),
+                bulk("TEXT"),
+                bulk("WEIGHT"),
+                bulk("1"),
+                bulk("SORTABLE"),
+            ])]),
+            bulk("num_docs"),
+            Value::Int(3),
+            bulk("indexing"),
+            Value::Int(0),
+            bulk("percent_indexed"),
+            bulk("1"),
+        ]);
+        let info = IndexInfo::from_redis_value(value).unwrap();
+        assert_eq!(info.index_name, "idx");
+        assert_eq!(info.key_type, "HASH");
+        assert_eq!(info.prefixes, vec!["doc:".to_string()]);
+        assert_eq!(info.num_docs, 3);
+        assert!(!info.indexing);
+        assert_eq!(info.percent_indexed, 1.0);
+        assert_eq!(
+            info.attributes,
+            vec![AttributeInfo {
+                identifier: "title".to_string(),
+                attribute: "title".to_string(),
+                field_type: "TEXT".to_string(),
+                sortable: true,
+                no_index: false,
+            }]
+        );
+    }
+}
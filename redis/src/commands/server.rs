@@ -0,0 +1,549 @@
+//! Defines types to use with the server administration commands.
+//!
+//! The commands themselves are provided by [`ServerCommands`](crate::ServerCommands) and
+//! [`AsyncServerCommands`](crate::AsyncServerCommands). On cluster connections every command
+//! is sent to all nodes, and each reply is a map from node address to the reply of that node.
+
+use crate::errors::{ErrorKind, ParsingError, RedisError, invalid_type_error};
+use crate::types::{
+    FromRedisValue, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value, from_redis_value,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// An entry of the slow log, as returned by [`slowlog_get`](crate::ServerCommands::slowlog_get).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SlowlogEntry {
+    /// Unique, progressive identifier of the entry.
+    pub id: u64,
+    /// Unix timestamp, in seconds, at which the command was processed.
+    pub timestamp: u64,
+    /// Time needed to execute the command.
+    pub duration: Duration,
+    /// The command and its arguments, possibly truncated by the server.
+    pub args: Vec<String>,
+    /// The `ip:port` address of the client that sent the command.
+    pub client_addr: Option<String>,
+    /// The name of the client set with `CLIENT SETNAME`, if any.
+    pub client_name: Option<String>,
+}
+
+impl FromRedisValue for SlowlogEntry {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let Value::Array(items) = v else {
+            invalid_type_error!(v, "expected array response");
+        };
+        let mut items = items.into_iter();
+        let mut next = |name: &str| match items.next() {
+            Some(item) => Ok(item),
+            None => Err(ParsingError::from(format!(
+                "missing slow log field `{name}`"
+            ))),
+        };
+
+        let id = from_redis_value(next("id")?)?;
+        let timestamp = from_redis_value(next("timestamp")?)?;
+        let duration = Duration::from_micros(from_redis_value(next("duration")?)?);
+        let args: Vec<Vec<u8>> = from_redis_value(next("args")?)?;
+        let args = args
+            .iter()
+            .map(|arg| String::from_utf8_lossy(arg).into_owned())
+            .collect();
+        // Older servers don't report the client.
+        let client_addr = next("client_addr").ok().map(from_redis_value).transpose()?;
+        let client_name = match next("client_name") {
+            Ok(name) => Some(from_redis_value::<String>(name)?).filter(|name| !name.is_empty()),
+            Err(_) => None,
+        };
+
+        Ok(SlowlogEntry {
+            id,
+            timestamp,
+            duration,
+            args,
+            client_addr,
+            client_name,
+        })
+    }
+}
+
+/// The latest latency spike of an event, as returned by
+/// [`latency_latest`](crate::ServerCommands::latency_latest).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LatencyEvent {
+    /// The name of the event, e.g. `command` or `fork`.
+    pub event: String,
+    /// Unix timestamp, in seconds, of the latest spike.
+    pub timestamp: u64,
+    /// Latency of the latest spike.
+    pub latest: Duration,
+    /// Highest latency recorded for this event.
+    pub max: Duration,
+}
+
+impl FromRedisValue for LatencyEvent {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (event, timestamp, latest, max): (String, u64, u64, u64) = match v {
+            // Newer servers may append fields, which are ignored.
+            Value::Array(mut items) if items.len() > 4 => {
+                items.truncate(4);
+                from_redis_value(Value::Array(items))?
+            }
+            v => from_redis_value(v)?,
+        };
+        Ok(LatencyEvent {
+            event,
+            timestamp,
+            latest: Duration::from_millis(latest),
+            max: Duration::from_millis(max),
+        })
+    }
+}
+
+/// A latency spike of an event, as returned by
+/// [`latency_history`](crate::ServerCommands::latency_history).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LatencySample {
+    /// Unix timestamp, in seconds, of the spike.
+    pub timestamp: u64,
+    /// Latency of the spike.
+    pub latency: Duration,
+}
+
+impl FromRedisValue for LatencySample {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (timestamp, latency): (u64, u64) = from_redis_value(v)?;
+        Ok(LatencySample {
+            timestamp,
+            latency: Duration::from_millis(latency),
+        })
+    }
+}
+
+/// Memory usage of the server, as returned by
+/// [`memory_stats`](crate::ServerCommands::memory_stats).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MemoryStats {
+    /// Peak memory consumed by the server, in bytes (`peak.allocated`).
+    pub peak_allocated: u64,
+    /// Total number of bytes allocated by the server (`total.allocated`).
+    pub total_allocated: u64,
+    /// Initial amount of memory consumed by the server at startup, in bytes
+    /// (`startup.allocated`).
+    pub startup_allocated: u64,
+    /// Sum of all the overheads, in bytes (`overhead.total`).
+    pub overhead_total: u64,
+    /// Total number of keys stored across all databases (`keys.count`).
+    pub keys_count: u64,
+    /// Size in bytes of the dataset, excluding the overheads (`dataset.bytes`).
+    pub dataset_bytes: u64,
+    /// Ratio between the resident and the allocated memory (`fragmentation`).
+    pub fragmentation: f64,
+    /// All the other fields reported by the server, such as the per-database overheads.
+    pub other: HashMap<String, Value>,
+}
+
+impl FromRedisValue for MemoryStats {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut other: HashMap<String, Value> = from_redis_value(v)?;
+        let mut take = |name: &str| match other.remove(name) {
+            Some(value) => Ok(value),
+            None => Err(ParsingError::from(format!("missing field `{name}`"))),
+        };
+
+        Ok(MemoryStats {
+            peak_allocated: from_redis_value(take("peak.allocated")?)?,
+            total_allocated: from_redis_value(take("total.allocated")?)?,
+            startup_allocated: from_redis_value(take("startup.allocated")?)?,
+            overhead_total: from_redis_value(take("overhead.total")?)?,
+            keys_count: from_redis_value(take("keys.count")?)?,
+            dataset_bytes: from_redis_value(take("dataset.bytes")?)?,
+            fragmentation: from_redis_value(take("fragmentation")?)?,
+            other,
+        })
+    }
+}
+
+/// A client connected to the server, as returned by
+/// [`client_list`](crate::ServerCommands::client_list) and
+/// [`client_info`](crate::Commands::client_info).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClientInfo {
+    /// Unique identifier of the client.
+    pub id: u64,
+    /// Address and port of the client.
+    pub addr: String,
+    /// Address and port of the local end of the connection on the server.
+    pub laddr: Option<String>,
+    /// The name of the client set with `CLIENT SETNAME`, if any.
+    pub name: Option<String>,
+    /// Time elapsed since the client connected.
+    pub age: Duration,
+    /// Time elapsed since the last command of the client.
+    pub idle: Duration,
+    /// The client flags, one character per flag, e.g. `N` for a regular client.
+    pub flags: String,
+    /// The selected database.
+    pub db: i64,
+    /// The last command run by the client.
+    pub cmd: String,
+    /// The authenticated ACL user.
+    pub user: Option<String>,
+    /// Total memory consumed by the client, in bytes (`tot-mem`).
+    pub memory: Option<u64>,
+    /// All the fields reported by the server, including the ones above.
+    pub fields: HashMap<String, String>,
+}
+
+impl ClientInfo {
+    fn parse(line: &str) -> Result<Self, ParsingError> {
+        let fields: HashMap<String, String> = line
+            .split_ascii_whitespace()
+            .filter_map(|field| field.split_once('='))
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        let text = |name: &str| fields.get(name).filter(|value| !value.is_empty()).cloned();
+        let number = |name: &str| match fields.get(name).map(|value| value.parse()) {
+            Some(Ok(number)) => Ok(number),
+            _ => Err(ParsingError::from(format!(
+                "missing or invalid client field `{name}`"
+            ))),
+        };
+
+        Ok(ClientInfo {
+            id: number("id")?,
+            addr: text("addr").unwrap_or_default(),
+            laddr: text("laddr"),
+            name: text("name"),
+            age: Duration::from_secs(number("age")?),
+            idle: Duration::from_secs(number("idle")?),
+            flags: text("flags").unwrap_or_default(),
+            db: number("db")? as i64,
+            cmd: text("cmd").unwrap_or_default(),
+            user: text("user"),
+            memory: fields
+                .contains_key("tot-mem")
+                .then(|| number("tot-mem"))
+                .transpose()?,
+            fields,
+        })
+    }
+}
+
+impl FromRedisValue for ClientInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let line: String = from_redis_value(v)?;
+        ClientInfo::parse(line.trim())
+    }
+}
+
+/// The clients connected to the server, as returned by
+/// [`client_list`](crate::ServerCommands::client_list).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClientList {
+    /// One entry per connected client.
+    pub clients: Vec<ClientInfo>,
+}
+
+impl FromRedisValue for ClientList {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let lines: String = from_redis_value(v)?;
+        let clients = lines
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| ClientInfo::parse(line.trim()))
+            .collect::<Result<_, _>>()?;
+        Ok(ClientList { clients })
+    }
+}
+
+/// Filters selecting the clients closed by [`client_kill`](crate::ServerCommands::client_kill).
+/// A client is closed only if it matches all the given filters, and at least one filter must
+/// be given.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::ServerCommands;
+/// use redis::server::ClientKillFilter;
+/// # fn example(con: &mut redis::Connection) -> redis::RedisResult<()> {
+/// let killed = con.client_kill(ClientKillFilter::default().user("batch").max_age(3600))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ClientKillFilter {
+    id: Option<u64>,
+    addr: Option<String>,
+    user: Option<String>,
+    skip_me: Option<bool>,
+    max_age: Option<u64>,
+}
+
+impl ClientKillFilter {
+    /// Close the client with the given ID.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Close the client connected from the given `ip:port` address.
+    pub fn addr(mut self, addr: impl Into<String>) -> Self {
+        self.addr = Some(addr.into());
+        self
+    }
+
+    /// Close the clients authenticated as the given ACL user.
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Whether the client sending the command may be closed. The server skips it by default.
+    pub fn skip_me(mut self, skip_me: bool) -> Self {
+        self.skip_me = Some(skip_me);
+        self
+    }
+
+    /// Close the clients connected for longer than `seconds`.
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Rejects a filter without any condition, which the server would parse as the legacy
+    /// `CLIENT KILL ip:port` form.
+    pub(crate) fn check(&self) -> Result<(), RedisError> {
+        if self.id.is_none()
+            && self.addr.is_none()
+            && self.user.is_none()
+            && self.skip_me.is_none()
+            && self.max_age.is_none()
+        {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "CLIENT KILL requires at least one filter",
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl ToRedisArgs for ClientKillFilter {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(id) = self.id {
+            out.write_arg(b"ID");
+            out.write_arg_fmt(id);
+        }
+        if let Some(addr) = &self.addr {
+            out.write_arg(b"ADDR");
+            out.write_arg(addr.as_bytes());
+        }
+        if let Some(user) = &self.user {
+            out.write_arg(b"USER");
+            out.write_arg(user.as_bytes());
+        }
+        if let Some(skip_me) = self.skip_me {
+            out.write_arg(b"SKIPME");
+            out.write_arg(if skip_me { b"YES" } else { b"NO" });
+        }
+        if let Some(max_age) = self.max_age {
+            out.write_arg(b"MAXAGE");
+            out.write_arg_fmt(max_age);
+        }
+    }
+}
+
+/// The commands blocked by [`client_pause`](crate::ServerCommands::client_pause).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClientPauseMode {
+    /// Block all the commands of all the clients.
+    #[default]
+    All,
+    /// Only block the commands that may modify the dataset.
+    Write,
+}
+
+impl ToRedisArgs for ClientPauseMode {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(match self {
+            ClientPauseMode::All => b"ALL",
+            ClientPauseMode::Write => b"WRITE",
+        });
+    }
+}
+
+impl ToSingleRedisArg for ClientPauseMode {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::bulk;
+
+    #[test]
+    fn test_slowlog_entry() {
+        let entry = SlowlogEntry::from_redis_value(Value::Array(vec![
+            Value::Int(14),
+            Value::Int(1309448221),
+            Value::Int(15),
+            Value::Array(vec![bulk("ping")]),
+            bulk("127.0.0.1:58217"),
+            bulk("worker-123"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            entry,
+            SlowlogEntry {
+                id: 14,
+                timestamp: 1309448221,
+                duration: Duration::from_micros(15),
+                args: vec!["ping".to_string()],
+                client_addr: Some("127.0.0.1:58217".to_string()),
+                client_name: Some("worker-123".to_string()),
+            }
+        );
+
+        let entry = SlowlogEntry::from_redis_value(Value::Array(vec![
+            Value::Int(1),
+            Value::Int(1309448128),
+            Value::Int(30),
+            Value::Array(vec![bulk("set"), Value::BulkString(vec![0xff, b'k'])]),
+            bulk("127.0.0.1:58217"),
+            bulk(""),
+        ]))
+        .unwrap();
+        assert_eq!(entry.args, vec!["set".to_string(), "\u{fffd}k".to_string()]);
+        assert_eq!(entry.client_name, None);
+
+        assert!(SlowlogEntry::from_redis_value(Value::Array(vec![Value::Int(1)])).is_err());
+    }
+
+    #[test]
+    fn test_latency_replies() {
+        let event = LatencyEvent::from_redis_value(Value::Array(vec![
+            bulk("command"),
+            Value::Int(1405067976),
+            Value::Int(251),
+            Value::Int(1001),
+        ]))
+        .unwrap();
+        assert_eq!(event.event, "command");
+        assert_eq!(event.timestamp, 1405067976);
+        assert_eq!(event.latest, Duration::from_millis(251));
+        assert_eq!(event.max, Duration::from_millis(1001));
+
+        let sample = LatencySample::from_redis_value(Value::Array(vec![
+            Value::Int(1405067822),
+            Value::Int(251),
+        ]))
+        .unwrap();
+        assert_eq!(sample.timestamp, 1405067822);
+        assert_eq!(sample.latency, Duration::from_millis(251));
+    }
+
+    #[test]
+    fn test_memory_stats() {
+        let fields = [
+            ("peak.allocated", Value::Int(1000)),
+            ("total.allocated", Value::Int(900)),
+            ("startup.allocated", Value::Int(800)),
+            ("overhead.total", Value::Int(850)),
+            ("keys.count", Value::Int(2)),
+            ("dataset.bytes", Value::Int(50)),
+            ("fragmentation", bulk("1.5")),
+            (
+                "db.0",
+                Value::Map(vec![(bulk("overhead.hashtable.main"), Value::Int(72))]),
+            ),
+        ];
+        let stats = MemoryStats::from_redis_value(Value::Map(
+            fields
+                .iter()
+                .map(|(name, value)| (bulk(name), value.clone()))
+                .collect(),
+        ))
+        .unwrap();
+        assert_eq!(stats.peak_allocated, 1000);
+        assert_eq!(stats.keys_count, 2);
+        assert_eq!(stats.fragmentation, 1.5);
+        assert_eq!(stats.other.len(), 1);
+        assert!(stats.other.contains_key("db.0"));
+    }
+
+    #[test]
+    fn test_client_list() {
+        let list = "id=3 addr=127.0.0.1:50188 laddr=127.0.0.1:6379 fd=8 name=worker age=12 idle=2 \
+            flags=N db=1 sub=0 psub=0 multi=-1 tot-mem=22400 cmd=client|list user=default lib-name=\n\
+            id=4 addr=127.0.0.1:50190 fd=9 name= age=0 idle=0 flags=P db=0 cmd=ping\n";
+        let list = ClientList::from_redis_value(bulk(list)).unwrap();
+        assert_eq!(list.clients.len(), 2);
+
+        let client = &list.clients[0];
+        assert_eq!(client.id, 3);
+        assert_eq!(client.addr, "127.0.0.1:50188");
+        assert_eq!(client.laddr.as_deref(), Some("127.0.0.1:6379"));
+        assert_eq!(client.name.as_deref(), Some("worker"));
+        assert_eq!(client.age, Duration::from_secs(12));
+        assert_eq!(client.idle, Duration::from_secs(2));
+        assert_eq!(client.flags, "N");
+        assert_eq!(client.db, 1);
+        assert_eq!(client.cmd, "client|list");
+        assert_eq!(client.user.as_deref(), Some("default"));
+        assert_eq!(client.memory, Some(22400));
+        assert_eq!(client.fields["multi"], "-1");
+
+        let client = &list.clients[1];
+        assert_eq!((client.name.as_ref(), client.user.as_ref()), (None, None));
+        assert_eq!((client.laddr.as_ref(), client.memory), (None, None));
+
+        let info = ClientInfo::from_redis_value(Value::VerbatimString {
+            format: crate::VerbatimFormat::Text,
+            text: "id=5 addr=127.0.0.1:1 age=0 idle=0 flags=N db=0 cmd=client|info\n".to_string(),
+        })
+        .unwrap();
+        assert_eq!(info.id, 5);
+        assert!(ClientInfo::from_redis_value(bulk("addr=127.0.0.1:1")).is_err());
+    }
+
+    #[test]
+    fn test_client_kill_filter() {
+        let args = ClientKillFilter::default()
+            .max_age(60)
+            .skip_me(false)
+            .user("batch")
+            .addr("127.0.0.1:5000")
+            .id(7)
+            .to_redis_args();
+        let expected: Vec<&[u8]> = vec![
+            b"ID",
+            b"7",
+            b"ADDR",
+            b"127.0.0.1:5000",
+            b"USER",
+            b"batch",
+            b"SKIPME",
+            b"NO",
+            b"MAXAGE",
+            b"60",
+        ];
+        assert_eq!(args, expected);
+        assert_eq!(ClientPauseMode::Write.to_redis_args(), vec![b"WRITE"]);
+
+        let err = ClientKillFilter::default().check().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+        assert!(ClientKillFilter::default().skip_me(true).check().is_ok());
+    }
+}
@@ -0,0 +1,367 @@
+//! Defines [`StreamConsumer`], an async worker of a stream consumer group.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures_util::{Stream, stream};
+
+use super::streams::{
+    StreamAutoClaimOptions, StreamAutoClaimReply, StreamId, StreamPendingCountReply,
+    StreamReadOptions, StreamReadReply,
+};
+use crate::aio::ConnectionLike;
+use crate::cmd::cmd;
+use crate::types::{HashMap, RedisResult};
+use crate::{AsyncCommands, from_redis_value_ref};
+
+/// Field of the dead-letter entries holding the key of the original stream.
+pub const DEAD_LETTER_SOURCE_KEY: &str = "source-key";
+/// Field of the dead-letter entries holding the ID of the original entry.
+pub const DEAD_LETTER_SOURCE_ID: &str = "source-id";
+/// Field of the dead-letter entries holding the delivery count of the original entry.
+pub const DEAD_LETTER_DELIVERIES: &str = "deliveries";
+
+/// Returns the BLOCK argument for a blocking read of `block`, leaving the reply time to arrive
+/// before the connection's `response_timeout`.
+pub(super) fn block_millis(block: Duration, response_timeout: Option<Duration>) -> usize {
+    let block = match response_timeout {
+        Some(timeout) => block.min(timeout / 2),
+        None => block,
+    };
+    block.as_millis().max(1) as usize
+}
+
+/// Options of a [`StreamConsumer`]
+///
+/// By default, the group is created at `$`, entries are read 10 at a time with a 200ms BLOCK,
+/// and entries pending for more than a minute are claimed every 10 seconds.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StreamConsumerOptions {
+    group: String,
+    consumer: String,
+    start_id: String,
+    count: usize,
+    block: Duration,
+    claim_idle: Option<Duration>,
+    claim_interval: Duration,
+    dead_letter: Option<(String, usize)>,
+}
+
+impl StreamConsumerOptions {
+    /// Creates the options of consumer `consumer` of group `group`.
+    pub fn new(group: impl Into<String>, consumer: impl Into<String>) -> Self {
+        Self {
+            group: group.into(),
+            consumer: consumer.into(),
+            start_id: "$".to_string(),
+            count: 10,
+            block: Duration::from_millis(200),
+            claim_idle: Some(Duration::from_secs(60)),
+            claim_interval: Duration::from_secs(10),
+            dead_letter: None,
+        }
+    }
+
+    /// Sets the ID the group is created at, when it doesn't exist. Use `0` to consume the
+    /// entries already in the stream.
+    pub fn start_id(mut self, id: impl Into<String>) -> Self {
+        self.start_id = id.into();
+        self
+    }
+
+    /// Sets the maximum number of entries read or claimed at once.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets how long XREADGROUP blocks waiting for new entries. It is shortened to half the
+    /// [response timeout](ConnectionLike::response_timeout) of the connection when longer, so
+    /// the reads don't time out, and is at least 1ms.
+    pub fn block(mut self, block: Duration) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets how long an entry must stay pending before it is claimed with XAUTOCLAIM.
+    pub fn claim_idle(mut self, idle: Duration) -> Self {
+        self.claim_idle = Some(idle);
+        self
+    }
+
+    /// Sets how often the pending entries are scanned with XAUTOCLAIM.
+    pub fn claim_interval(mut self, interval: Duration) -> Self {
+        self.claim_interval = interval;
+        self
+    }
+
+    /// Never claim the pending entries of other consumers.
+    pub fn no_claim(mut self) -> Self {
+        self.claim_idle = None;
+        self
+    }
+
+    /// Moves claimed entries delivered more than `max_deliveries` times to the stream `key`
+    /// instead of delivering them again. The dead-letter entries hold the fields of the original
+    /// entry along with [`DEAD_LETTER_SOURCE_KEY`], [`DEAD_LETTER_SOURCE_ID`] and
+    /// [`DEAD_LETTER_DELIVERIES`].
+    pub fn dead_letter(mut self, key: impl Into<String>, max_deliveries: usize) -> Self {
+        self.dead_letter = Some((key.into(), max_deliveries));
+        self
+    }
+}
+
+/// An entry delivered by a [`StreamConsumer`]
+///
+/// The entry stays pending in the group until it is acknowledged with [`ack`](Self::ack).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StreamDelivery<C> {
+    /// The entry, with [`delivered_count`](StreamId::delivered_count) set.
+    pub entry: StreamId,
+    con: C,
+    key: String,
+    group: String,
+}
+
+impl<C: ConnectionLike + Send + Sync> StreamDelivery<C> {
+    /// Acknowledges the entry with XACK.
+    pub async fn ack(mut self) -> RedisResult<()> {
+        self.con
+            .xack::<_, _, _, ()>(&self.key, &self.group, &[&self.entry.id])
+            .await
+    }
+}
+
+/// An async worker of a stream consumer group
+///
+/// The consumer reads new entries with XREADGROUP and periodically claims the entries left
+/// pending by other consumers with XAUTOCLAIM. It works over any cloneable async connection,
+/// such as [`ConnectionManager`](crate::aio::ConnectionManager) or the async cluster connection.
+///
+/// # Example
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use redis::streams::{StreamConsumer, StreamConsumerOptions};
+/// # async fn do_something() -> redis::RedisResult<()> {
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let con = client.get_connection_manager().await?;
+/// let options = StreamConsumerOptions::new("workers", "worker-1").dead_letter("jobs:dead", 5);
+/// let consumer = StreamConsumer::new(con, "jobs", options).await?;
+/// let mut deliveries = Box::pin(consumer.into_stream());
+/// while let Some(delivery) = deliveries.next().await {
+///     let delivery = delivery?;
+///     println!("{:?}", delivery.entry.map);
+///     delivery.ack().await?;
+/// }
+/// # Ok(()) }
+/// ```
+pub struct StreamConsumer<C> {
+    con: C,
+    key: String,
+    options: StreamConsumerOptions,
+    buffer: VecDeque<StreamId>,
+    claim_cursor: String,
+    last_claim: Option<Instant>,
+}
+
+impl<C: ConnectionLike + Clone + Send + Sync> StreamConsumer<C> {
+    /// Creates a consumer of the stream `key`, creating the stream and the group if they don't
+    /// exist.
+    pub async fn new(
+        con: C,
+        key: impl Into<String>,
+        options: StreamConsumerOptions,
+    ) -> RedisResult<Self> {
+        let mut consumer = StreamConsumer {
+            con,
+            key: key.into(),
+            options,
+            buffer: VecDeque::new(),
+            claim_cursor: "0-0".to_string(),
+            last_claim: None,
+        };
+        consumer.create_group().await?;
+        Ok(consumer)
+    }
+
+    /// Returns the next entry, waiting for one if needed.
+    pub async fn next_delivery(&mut self) -> RedisResult<StreamDelivery<C>> {
+        loop {
+            if let Some(entry) = self.buffer.pop_front() {
+                return Ok(StreamDelivery {
+                    entry,
+                    con: self.con.clone(),
+                    key: self.key.clone(),
+                    group: self.options.group.clone(),
+                });
+            }
+            if let Some(idle) = self.options.claim_idle
+                && self.claim_due()
+            {
+                self.claim(idle).await?;
+                if !self.buffer.is_empty() {
+                    continue;
+                }
+            }
+            self.read().await?;
+        }
+    }
+
+    /// Converts the consumer into a [`Stream`] of entries.
+    pub fn into_stream(self) -> impl Stream<Item = RedisResult<StreamDelivery<C>>> + Send
+    where
+        C: 'static,
+    {
+        stream::unfold(self, |mut consumer| async move {
+            let delivery = consumer.next_delivery().await;
+            Some((delivery, consumer))
+        })
+    }
+
+    /// Passes each entry to `handler`, and acknowledges it when the handler succeeds. Entries
+    /// the handler fails on stay pending, and are claimed again after the idle time.
+    ///
+    /// This only returns on Redis errors.
+    pub async fn run<F, Fut, E>(mut self, mut handler: F) -> RedisResult<()>
+    where
+        F: FnMut(StreamId) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+    {
+        loop {
+            let delivery = self.next_delivery().await?;
+            if handler(delivery.entry.clone()).await.is_ok() {
+                delivery.ack().await?;
+            }
+        }
+    }
+
+    async fn create_group(&mut self) -> RedisResult<()> {
+        match self
+            .con
+            .xgroup_create_mkstream::<_, _, _, ()>(
+                &self.key,
+                &self.options.group,
+                &self.options.start_id,
+            )
+            .await
+        {
+            Err(err) if err.code() == Some("BUSYGROUP") => Ok(()),
+            result => result,
+        }
+    }
+
+    fn claim_due(&self) -> bool {
+        self.claim_cursor != "0-0"
+            || self
+                .last_claim
+                .is_none_or(|last| last.elapsed() >= self.options.claim_interval)
+    }
+
+    async fn read(&mut self) -> RedisResult<()> {
+        let options = StreamReadOptions::default()
+            .group(&self.options.group, &self.options.consumer)
+            .count(self.options.count)
+            .block(block_millis(
+                self.options.block,
+                self.con.response_timeout(),
+            ));
+        let reply: Option<StreamReadReply> =
+            match self.con.xread_options(&[&self.key], &[">"], &options).await {
+                Err(err) if err.code() == Some("NOGROUP") => return self.create_group().await,
+                reply => reply?,
+            };
+        for stream in reply.into_iter().flat_map(|reply| reply.keys) {
+            self.buffer.extend(stream.ids.into_iter().map(|mut entry| {
+                entry.delivered_count = Some(1);
+                entry
+            }));
+        }
+        Ok(())
+    }
+
+    async fn claim(&mut self, idle: Duration) -> RedisResult<()> {
+        let reply: StreamAutoClaimReply = match self
+            .con
+            .xautoclaim_options(
+                &self.key,
+                &self.options.group,
+                &self.options.consumer,
+                idle.as_millis() as u64,
+                &self.claim_cursor,
+                StreamAutoClaimOptions::default().count(self.options.count),
+            )
+            .await
+        {
+            Err(err) if err.code() == Some("NOGROUP") => return self.create_group().await,
+            reply => reply?,
+        };
+        self.claim_cursor = reply.next_stream_id;
+        if self.claim_cursor == "0-0" {
+            self.last_claim = Some(Instant::now());
+        }
+        if reply.claimed.is_empty() {
+            return Ok(());
+        }
+
+        // XAUTOCLAIM doesn't return the delivery counts, which XPENDING does. Each claimed entry
+        // is looked up by its ID, as a range between the claimed IDs may also hold entries the
+        // consumer already owned.
+        let mut pipe = crate::pipe();
+        for entry in &reply.claimed {
+            pipe.xpending_consumer_count(
+                &self.key,
+                &self.options.group,
+                &entry.id,
+                &entry.id,
+                1,
+                &self.options.consumer,
+            );
+        }
+        let pending: Vec<StreamPendingCountReply> = pipe.query_async(&mut self.con).await?;
+        let deliveries: HashMap<String, usize> = pending
+            .into_iter()
+            .flat_map(|pending| pending.ids)
+            .map(|pending| (pending.id, pending.times_delivered))
+            .collect();
+
+        for mut entry in reply.claimed {
+            entry.delivered_count = deliveries.get(&entry.id).copied();
+            match (&self.options.dead_letter, entry.delivered_count) {
+                (Some((dead_letter, max_deliveries)), Some(count)) if count > *max_deliveries => {
+                    let dead_letter = dead_letter.clone();
+                    self.send_to_dead_letter(&dead_letter, &entry, count)
+                        .await?;
+                }
+                _ => self.buffer.push_back(entry),
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_to_dead_letter(
+        &mut self,
+        dead_letter: &str,
+        entry: &StreamId,
+        deliveries: usize,
+    ) -> RedisResult<()> {
+        let mut xadd = cmd("XADD");
+        xadd.arg(dead_letter).arg("*");
+        for (field, value) in &entry.map {
+            xadd.arg(field).arg(from_redis_value_ref::<Vec<u8>>(value)?);
+        }
+        xadd.arg(DEAD_LETTER_SOURCE_KEY)
+            .arg(&self.key)
+            .arg(DEAD_LETTER_SOURCE_ID)
+            .arg(&entry.id)
+            .arg(DEAD_LETTER_DELIVERIES)
+            .arg(deliveries);
+        // Both keys may be in different slots, so this can't be a transaction.
+        xadd.query_async::<()>(&mut self.con).await?;
+        self.con
+            .xack::<_, _, _, ()>(&self.key, &self.options.group, &[&entry.id])
+            .await
+    }
+}
@@ -0,0 +1,198 @@
+//! Defines [`StreamTail`], which reads streams continuously with blocking XREAD calls.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures_util::{Stream, stream};
+
+use super::stream_consumer::block_millis;
+use super::streams::{StreamId, StreamRangeReply, StreamReadOptions, StreamReadReply};
+use crate::AsyncCommands;
+use crate::aio::ConnectionLike;
+use crate::types::RedisResult;
+
+/// Where a [`StreamTail`] starts reading the streams.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StreamTailStart {
+    /// Only read the entries added after the tail is first polled, like `$`.
+    #[default]
+    Latest,
+    /// Read all the entries of the streams, like `0`.
+    Beginning,
+    /// Read the entries after the given ID.
+    After(String),
+}
+
+/// Options of a [`StreamTail`]
+///
+/// By default, the tail starts at the latest entry and reads at most 100 entries at a time,
+/// blocking for 200ms.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StreamTailOptions {
+    start: StreamTailStart,
+    count: usize,
+    block: Duration,
+}
+
+impl Default for StreamTailOptions {
+    fn default() -> Self {
+        Self {
+            start: StreamTailStart::Latest,
+            count: 100,
+            block: Duration::from_millis(200),
+        }
+    }
+}
+
+impl StreamTailOptions {
+    /// Sets where the tail starts reading the streams.
+    pub fn start(mut self, start: StreamTailStart) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the maximum number of entries read at once from each stream.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets how long XREAD blocks waiting for new entries. It is shortened to half the
+    /// [response timeout](ConnectionLike::response_timeout) of the connection when longer, so
+    /// the reads don't time out, and is at least 1ms.
+    pub fn block(mut self, block: Duration) -> Self {
+        self.block = block;
+        self
+    }
+}
+
+/// A continuous reader of one or more streams
+///
+/// The tail keeps the ID of the last entry it delivered from each stream, and reads the
+/// following entries with XREAD. Errors are returned without losing that position, so reading
+/// resumes from the last delivered entry once the connection recovers, e.g. after a
+/// [`ConnectionManager`](crate::aio::ConnectionManager) reconnects.
+///
+/// On a cluster connection, all the streams must be in the same slot.
+///
+/// # Example
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use redis::streams::{StreamTail, StreamTailOptions, StreamTailStart};
+/// # async fn do_something() -> redis::RedisResult<()> {
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let con = client.get_connection_manager().await?;
+/// let options = StreamTailOptions::default().start(StreamTailStart::Beginning);
+/// let mut entries = Box::pin(StreamTail::new(con, ["events"], options).into_stream());
+/// while let Some(entry) = entries.next().await {
+///     let entry = entry?;
+///     println!("{}: {:?}", entry.id, entry.map);
+/// }
+/// # Ok(()) }
+/// ```
+pub struct StreamTail<C> {
+    con: C,
+    keys: Vec<String>,
+    ids: Vec<String>,
+    resolved: bool,
+    options: StreamTailOptions,
+    buffer: VecDeque<(usize, StreamId)>,
+}
+
+impl<C: ConnectionLike + Send + Sync> StreamTail<C> {
+    /// Creates a tail of the streams `keys`.
+    pub fn new<K: Into<String>>(
+        con: C,
+        keys: impl IntoIterator<Item = K>,
+        options: StreamTailOptions,
+    ) -> Self {
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        let (start, resolved) = match &options.start {
+            StreamTailStart::Latest => ("$".to_string(), false),
+            StreamTailStart::Beginning => ("0".to_string(), true),
+            StreamTailStart::After(id) => (id.clone(), true),
+        };
+        StreamTail {
+            con,
+            ids: vec![start; keys.len()],
+            keys,
+            resolved,
+            options,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Returns the streams, each with the ID of the last entry delivered from it, or the ID
+    /// reading starts after.
+    pub fn positions(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.keys
+            .iter()
+            .map(String::as_str)
+            .zip(self.ids.iter().map(String::as_str))
+    }
+
+    /// Returns the next entry of the streams, waiting for one if needed.
+    pub async fn next_entry(&mut self) -> RedisResult<StreamId> {
+        loop {
+            if let Some((index, entry)) = self.buffer.pop_front() {
+                self.ids[index].clone_from(&entry.id);
+                return Ok(entry);
+            }
+            if !self.resolved {
+                self.resolve_latest().await?;
+            }
+            self.read().await?;
+        }
+    }
+
+    /// Converts the tail into a [`Stream`] of entries. The stream never ends, and yields the
+    /// errors of the underlying reads without stopping.
+    pub fn into_stream(self) -> impl Stream<Item = RedisResult<StreamId>> + Send
+    where
+        C: 'static,
+    {
+        stream::unfold(self, |mut tail| async move {
+            let entry = tail.next_entry().await;
+            Some((entry, tail))
+        })
+    }
+
+    // Reading from `$` again after each read would miss the entries added in between, so the
+    // latest IDs are fetched once instead.
+    async fn resolve_latest(&mut self) -> RedisResult<()> {
+        for (key, id) in self.keys.iter().zip(self.ids.iter_mut()) {
+            if id == "$" {
+                let last: StreamRangeReply = self.con.xrevrange_count(key, "+", "-", 1).await?;
+                *id = last
+                    .ids
+                    .into_iter()
+                    .next()
+                    .map_or_else(|| "0-0".to_string(), |entry| entry.id);
+            }
+        }
+        self.resolved = true;
+        Ok(())
+    }
+
+    async fn read(&mut self) -> RedisResult<()> {
+        let options = StreamReadOptions::default()
+            .count(self.options.count)
+            .block(block_millis(
+                self.options.block,
+                self.con.response_timeout(),
+            ));
+        let reply: Option<StreamReadReply> = self
+            .con
+            .xread_options(&self.keys, &self.ids, &options)
+            .await?;
+        for stream in reply.into_iter().flat_map(|reply| reply.keys) {
+            if let Some(index) = self.keys.iter().position(|key| *key == stream.key) {
+                self.buffer
+                    .extend(stream.ids.into_iter().map(|entry| (index, entry)));
+            }
+        }
+        Ok(())
+    }
+}
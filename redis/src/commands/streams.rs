@@ -8,6 +8,16 @@ use crate::{
 };
 use crate::{from_redis_value, from_redis_value_ref, types::ToSingleRedisArg};
 
+#[cfg(feature = "aio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+pub use super::stream_consumer::{
+    DEAD_LETTER_DELIVERIES, DEAD_LETTER_SOURCE_ID, DEAD_LETTER_SOURCE_KEY, StreamConsumer,
+    StreamConsumerOptions, StreamDelivery,
+};
+#[cfg(feature = "aio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+pub use super::stream_tail::{StreamTail, StreamTailOptions, StreamTailStart};
+
 // Stream Maxlen Enum
 
 /// Utility enum for passing `MAXLEN [= or ~] [COUNT]`
@@ -961,6 +971,76 @@ pub struct StreamInfoGroup {
     pub lag: Option<usize>,
 }
 
+/// Reply type used with [`xinfo_stream_full`] and [`xinfo_stream_full_count`] commands,
+/// containing the entries of the stream and the state of its consumer groups.
+///
+/// [`xinfo_stream_full`]: ../trait.Commands.html#method.xinfo_stream_full
+/// [`xinfo_stream_full_count`]: ../trait.Commands.html#method.xinfo_stream_full_count
+///
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct StreamInfoFullReply {
+    /// Number of elements of the stream.
+    pub length: usize,
+    /// The number of keys in the radix tree representing the stream.
+    pub radix_tree_keys: usize,
+    /// The number of nodes in the radix tree representing the stream.
+    pub radix_tree_nodes: usize,
+    /// The last generated ID that may not be the same as the last
+    /// entry ID in case some entry was deleted.
+    pub last_generated_id: String,
+    /// The maximal entry ID that was deleted from the stream (Redis 7.0+).
+    pub max_deleted_entry_id: Option<String>,
+    /// The count of all entries added to the stream during its lifetime (Redis 7.0+).
+    pub entries_added: Option<usize>,
+    /// The first entry ID recorded in the stream (Redis 7.0+).
+    pub recorded_first_entry_id: Option<String>,
+    /// The entries of the stream, in ascending ID order.
+    pub entries: Vec<StreamId>,
+    /// The consumer groups associated with the stream.
+    pub groups: Vec<StreamInfoFullGroup>,
+}
+
+/// A group parsed from [`xinfo_stream_full`] command.
+///
+/// [`xinfo_stream_full`]: ../trait.Commands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct StreamInfoFullGroup {
+    /// The group, as returned by [`xinfo_groups`](../trait.Commands.html#method.xinfo_groups).
+    pub base: StreamInfoGroup,
+    /// The pending entries list of the group. `last_delivered_ms` holds the Unix time in
+    /// milliseconds of the last delivery, rather than the time elapsed since.
+    pub pel: Vec<StreamPendingId>,
+    /// The consumers of the group.
+    pub consumers: Vec<StreamInfoFullConsumer>,
+}
+
+/// A consumer parsed from [`xinfo_stream_full`] command.
+///
+/// Unlike [`StreamInfoConsumer`], it holds the times of the last interactions of the consumer
+/// rather than the time elapsed since.
+///
+/// [`xinfo_stream_full`]: ../trait.Commands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+#[non_exhaustive]
+pub struct StreamInfoFullConsumer {
+    /// Name of the consumer.
+    pub name: String,
+    /// Unix time in milliseconds of the last attempted interaction of the consumer.
+    pub seen_time: u64,
+    /// Unix time in milliseconds of the last successful interaction of the consumer, or `None`
+    /// if it never had one or the server is older than Redis 7.2.
+    pub active_time: Option<u64>,
+    /// Number of pending messages for this specific consumer.
+    pub pending: usize,
+    /// The pending entries of the consumer. `last_delivered_ms` holds the Unix time in
+    /// milliseconds of the last delivery, rather than the time elapsed since.
+    pub pel: Vec<StreamPendingId>,
+}
+
 /// Represents a pending message parsed from [`xpending`] methods.
 ///
 /// [`xpending`]: ../trait.Commands.html#method.xpending
@@ -1010,7 +1090,7 @@ impl StreamId {
             if let Some(v) = values.first_mut() {
                 stream_id.id = from_redis_value(std::mem::take(v))?;
             }
-            if let Some(v) = values.first_mut() {
+            if let Some(v) = values.get_mut(1) {
                 stream_id.map = from_redis_value(std::mem::take(v))?;
             }
         }
@@ -1076,7 +1156,7 @@ impl FromRedisValue for StreamAutoClaimReply {
             });
         };
         let (claimed, invalid_entries) = match entry {
-            Value::BulkString(_) => {
+            entry if entry.as_bulk_string().is_some() => {
                 // JUSTID response
                 let claimed_count = arr.len();
                 let ids: Vec<Option<String>> = from_redis_value(claimed)?;
@@ -1429,39 +1509,158 @@ impl FromRedisValue for StreamInfoGroupsReply {
         let groups: Vec<HashMap<String, Value>> = from_redis_value(v)?;
         let mut reply = StreamInfoGroupsReply::default();
         for mut map in groups {
-            let mut g = StreamInfoGroup::default();
-            if let Some(v) = map.remove("name") {
-                g.name = from_redis_value(v)?;
-            }
-            if let Some(v) = map.remove("pending") {
-                g.pending = from_redis_value(v)?;
-            }
-            if let Some(v) = map.remove("consumers") {
-                g.consumers = from_redis_value(v)?;
-            }
-            if let Some(v) = map.remove("last-delivered-id") {
-                g.last_delivered_id = from_redis_value(v)?;
-            }
-            if let Some(v) = map.remove("entries-read") {
-                g.entries_read = if let Value::Nil = v {
-                    None
-                } else {
-                    Some(from_redis_value(v)?)
-                };
-            }
-            if let Some(v) = map.remove("lag") {
-                g.lag = if let Value::Nil = v {
-                    None
-                } else {
-                    Some(from_redis_value(v)?)
-                };
-            }
-            reply.groups.push(g);
+            reply.groups.push(StreamInfoGroup::from_map(&mut map)?);
         }
         Ok(reply)
     }
 }
 
+impl StreamInfoGroup {
+    fn from_map(map: &mut HashMap<String, Value>) -> Result<Self, ParsingError> {
+        let mut g = StreamInfoGroup::default();
+        if let Some(v) = map.remove("name") {
+            g.name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("pending") {
+            g.pending = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("consumers") {
+            g.consumers = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("last-delivered-id") {
+            g.last_delivered_id = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("entries-read") {
+            g.entries_read = if let Value::Nil = v {
+                None
+            } else {
+                Some(from_redis_value(v)?)
+            };
+        }
+        if let Some(v) = map.remove("lag") {
+            g.lag = if let Value::Nil = v {
+                None
+            } else {
+                Some(from_redis_value(v)?)
+            };
+        }
+        Ok(g)
+    }
+}
+
+impl FromRedisValue for StreamInfoFullReply {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut reply = StreamInfoFullReply::default();
+        if let Some(v) = map.remove("length") {
+            reply.length = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("radix-tree-keys") {
+            reply.radix_tree_keys = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("radix-tree-nodes") {
+            reply.radix_tree_nodes = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("last-generated-id") {
+            reply.last_generated_id = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("max-deleted-entry-id") {
+            reply.max_deleted_entry_id = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("entries-added") {
+            reply.entries_added = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("recorded-first-entry-id") {
+            reply.recorded_first_entry_id = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("entries") {
+            let entries: Vec<Value> = from_redis_value(v)?;
+            reply.entries = entries
+                .into_iter()
+                .map(StreamId::from_array_value)
+                .collect::<Result<_, _>>()?;
+        }
+        if let Some(v) = map.remove("groups") {
+            let groups: Vec<HashMap<String, Value>> = from_redis_value(v)?;
+            reply.groups = groups
+                .into_iter()
+                .map(StreamInfoFullGroup::from_map)
+                .collect::<Result<_, _>>()?;
+        }
+        Ok(reply)
+    }
+}
+
+impl StreamInfoFullGroup {
+    fn from_map(mut map: HashMap<String, Value>) -> Result<Self, ParsingError> {
+        // Unlike in XINFO GROUPS, `consumers` holds the consumers rather than their count.
+        let consumers = map.remove("consumers");
+        let mut group = StreamInfoFullGroup {
+            base: StreamInfoGroup::from_map(&mut map)?,
+            ..Default::default()
+        };
+        if let Some(v) = map.remove("pel-count") {
+            group.base.pending = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("pel") {
+            let pel: Vec<(String, String, usize, usize)> = from_redis_value(v)?;
+            group.pel = pel
+                .into_iter()
+                .map(
+                    |(id, consumer, last_delivered_ms, times_delivered)| StreamPendingId {
+                        id,
+                        consumer,
+                        last_delivered_ms,
+                        times_delivered,
+                    },
+                )
+                .collect();
+        }
+        if let Some(v) = consumers {
+            let consumers: Vec<HashMap<String, Value>> = from_redis_value(v)?;
+            group.consumers = consumers
+                .into_iter()
+                .map(StreamInfoFullConsumer::from_map)
+                .collect::<Result<_, _>>()?;
+            group.base.consumers = group.consumers.len();
+        }
+        Ok(group)
+    }
+}
+
+impl StreamInfoFullConsumer {
+    fn from_map(mut map: HashMap<String, Value>) -> Result<Self, ParsingError> {
+        let mut consumer = StreamInfoFullConsumer::default();
+        if let Some(v) = map.remove("name") {
+            consumer.name = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("seen-time") {
+            consumer.seen_time = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("active-time") {
+            // Consumers that never read successfully have an active time of -1.
+            let active_time: i64 = from_redis_value(v)?;
+            consumer.active_time = u64::try_from(active_time).ok();
+        }
+        if let Some(v) = map.remove("pel-count") {
+            consumer.pending = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("pel") {
+            let pel: Vec<(String, usize, usize)> = from_redis_value(v)?;
+            consumer.pel = pel
+                .into_iter()
+                .map(|(id, last_delivered_ms, times_delivered)| StreamPendingId {
+                    id,
+                    consumer: consumer.name.clone(),
+                    last_delivered_ms,
+                    times_delivered,
+                })
+                .collect();
+        }
+        Ok(consumer)
+    }
+}
+
 /// Deletion policy for stream entries.
 #[derive(Debug, Clone, Default)]
 #[non_exhaustive]
@@ -1798,6 +1997,130 @@ mod tests {
         }
     }
 
+    mod stream_info_full_reply {
+        use super::*;
+        use crate::Value;
+        use crate::test_utils::bulk;
+
+        // Builds a RESP2 flat array or a RESP3 map from key/value pairs.
+        fn map(resp3: bool, pairs: Vec<(&str, Value)>) -> Value {
+            if resp3 {
+                Value::Map(pairs.into_iter().map(|(k, v)| (bulk(k), v)).collect())
+            } else {
+                Value::Array(pairs.into_iter().flat_map(|(k, v)| [bulk(k), v]).collect())
+            }
+        }
+
+        fn reply(resp3: bool) -> Value {
+            let consumer = map(
+                resp3,
+                vec![
+                    ("name", bulk("c1")),
+                    ("seen-time", Value::Int(1700000000500)),
+                    ("active-time", Value::Int(-1)),
+                    ("pel-count", Value::Int(1)),
+                    (
+                        "pel",
+                        Value::Array(vec![Value::Array(vec![
+                            bulk("1-0"),
+                            Value::Int(1700000000000),
+                            Value::Int(2),
+                        ])]),
+                    ),
+                ],
+            );
+            let group = map(
+                resp3,
+                vec![
+                    ("name", bulk("g1")),
+                    ("last-delivered-id", bulk("1-0")),
+                    ("entries-read", Value::Int(1)),
+                    ("lag", Value::Nil),
+                    ("pel-count", Value::Int(1)),
+                    (
+                        "pel",
+                        Value::Array(vec![Value::Array(vec![
+                            bulk("1-0"),
+                            bulk("c1"),
+                            Value::Int(1700000000000),
+                            Value::Int(2),
+                        ])]),
+                    ),
+                    ("consumers", Value::Array(vec![consumer])),
+                ],
+            );
+            map(
+                resp3,
+                vec![
+                    ("length", Value::Int(2)),
+                    ("radix-tree-keys", Value::Int(1)),
+                    ("radix-tree-nodes", Value::Int(2)),
+                    ("last-generated-id", bulk("2-0")),
+                    ("max-deleted-entry-id", bulk("0-0")),
+                    ("entries-added", Value::Int(2)),
+                    ("recorded-first-entry-id", bulk("1-0")),
+                    (
+                        "entries",
+                        Value::Array(vec![
+                            Value::Array(vec![
+                                bulk("1-0"),
+                                Value::Array(vec![bulk("field"), bulk("a")]),
+                            ]),
+                            Value::Array(vec![
+                                bulk("2-0"),
+                                Value::Array(vec![bulk("field"), bulk("b")]),
+                            ]),
+                        ]),
+                    ),
+                    ("groups", Value::Array(vec![group])),
+                ],
+            )
+        }
+
+        fn check(reply: StreamInfoFullReply) {
+            assert_eq!(reply.length, 2);
+            assert_eq!(reply.radix_tree_keys, 1);
+            assert_eq!(reply.radix_tree_nodes, 2);
+            assert_eq!(reply.last_generated_id, "2-0");
+            assert_eq!(reply.max_deleted_entry_id.as_deref(), Some("0-0"));
+            assert_eq!(reply.entries_added, Some(2));
+            assert_eq!(reply.recorded_first_entry_id.as_deref(), Some("1-0"));
+
+            assert_eq!(reply.entries.len(), 2);
+            assert_eq!(reply.entries[1].id, "2-0");
+            assert_eq!(reply.entries[1].get("field"), Some("b".to_string()));
+
+            let group = &reply.groups[0];
+            assert_eq!(group.base.name, "g1");
+            assert_eq!(group.base.pending, 1);
+            assert_eq!(group.base.consumers, 1);
+            assert_eq!(group.base.entries_read, Some(1));
+            assert_eq!(group.base.lag, None);
+            assert_eq!(group.pel[0].id, "1-0");
+            assert_eq!(group.pel[0].consumer, "c1");
+            assert_eq!(group.pel[0].times_delivered, 2);
+
+            let consumer = &group.consumers[0];
+            assert_eq!(consumer.name, "c1");
+            assert_eq!(consumer.seen_time, 1700000000500);
+            assert_eq!(consumer.active_time, None);
+            assert_eq!(consumer.pending, 1);
+            assert_eq!(consumer.pel[0].consumer, "c1");
+            assert_eq!(consumer.pel[0].last_delivered_ms, 1700000000000);
+            assert_eq!(consumer.pel[0].times_delivered, 2);
+        }
+
+        #[test]
+        fn parses_resp2_response() {
+            check(StreamInfoFullReply::from_redis_value(reply(false)).unwrap());
+        }
+
+        #[test]
+        fn parses_resp3_response() {
+            check(StreamInfoFullReply::from_redis_value(reply(true)).unwrap());
+        }
+    }
+
     mod stream_trim_options {
         use super::*;
 
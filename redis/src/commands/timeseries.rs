@@ -0,0 +1,1398 @@
+//! Defines types to use with the RedisTimeSeries (`TS.*`) commands.
+//!
+//! ```rust,no_run
+//! use redis::timeseries::{
+//!     DuplicatePolicy, Sample, TsAggregation, TsAggregationType, TsCreateOptions, TsFilter,
+//!     TsRangeOptions, MRangeSeries,
+//! };
+//! use redis::Commands;
+//!
+//! # fn do_something() -> redis::RedisResult<()> {
+//! # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+//! # let mut con = client.get_connection().unwrap();
+//! let options = TsCreateOptions::default()
+//!     .retention(86_400_000)
+//!     .duplicate_policy(DuplicatePolicy::Last)
+//!     .label("sensor", "temperature")
+//!     .label("room", "kitchen");
+//! let _: () = con.ts_create_options("temperature:kitchen", &options)?;
+//! let _: u64 = con.ts_add("temperature:kitchen", "*", 21.5)?;
+//!
+//! // Hourly averages of a single series
+//! let options = TsRangeOptions::default()
+//!     .aggregation(TsAggregation::new(TsAggregationType::Avg, 3_600_000));
+//! let samples: Vec<Sample> = con.ts_range_options("temperature:kitchen", "-", "+", &options)?;
+//!
+//! // All temperature series, from all nodes of a cluster
+//! let series: Vec<MRangeSeries> =
+//!     con.ts_mrange("-", "+", &TsFilter::new().equals("sensor", "temperature"))?;
+//! # Ok(()) }
+//! ```
+//!
+//! # Cluster support
+//!
+//! `TS.MRANGE`, `TS.MREVRANGE`, `TS.MGET` and `TS.QUERYINDEX` select series by their labels
+//! instead of by key, so cluster connections send them to every primary and combine the
+//! replies.
+
+use std::collections::HashMap;
+
+use crate::errors::{ParsingError, invalid_type_error};
+use crate::types::{
+    FromRedisValue, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value, from_redis_value,
+};
+
+/// How to handle samples with a timestamp that already exists in the series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DuplicatePolicy {
+    /// Reject the new sample.
+    Block,
+    /// Keep the existing sample.
+    First,
+    /// Replace the existing sample.
+    Last,
+    /// Keep the smaller value.
+    Min,
+    /// Keep the larger value.
+    Max,
+    /// Store the sum of both values.
+    Sum,
+}
+
+impl ToRedisArgs for DuplicatePolicy {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(match self {
+            DuplicatePolicy::Block => b"BLOCK".as_slice(),
+            DuplicatePolicy::First => b"FIRST",
+            DuplicatePolicy::Last => b"LAST",
+            DuplicatePolicy::Min => b"MIN",
+            DuplicatePolicy::Max => b"MAX",
+            DuplicatePolicy::Sum => b"SUM",
+        });
+    }
+}
+
+impl ToSingleRedisArg for DuplicatePolicy {}
+
+/// How the samples of a series are stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TsEncoding {
+    /// Compress the samples. This is the default.
+    Compressed,
+    /// Store the samples without compression.
+    Uncompressed,
+}
+
+impl ToRedisArgs for TsEncoding {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(match self {
+            TsEncoding::Compressed => b"COMPRESSED".as_slice(),
+            TsEncoding::Uncompressed => b"UNCOMPRESSED",
+        });
+    }
+}
+
+impl ToSingleRedisArg for TsEncoding {}
+
+/// The function used to aggregate the samples of a time bucket or to reduce grouped series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TsAggregationType {
+    /// Arithmetic mean of the values.
+    Avg,
+    /// Sum of the values.
+    Sum,
+    /// Minimal value.
+    Min,
+    /// Maximal value.
+    Max,
+    /// Difference between the maximal and the minimal value.
+    Range,
+    /// Number of values.
+    Count,
+    /// Value with the lowest timestamp.
+    First,
+    /// Value with the highest timestamp.
+    Last,
+    /// Population standard deviation of the values.
+    StdP,
+    /// Sample standard deviation of the values.
+    StdS,
+    /// Population variance of the values.
+    VarP,
+    /// Sample variance of the values.
+    VarS,
+    /// Time-weighted average of the values. Not supported as a reducer.
+    Twa,
+}
+
+impl ToRedisArgs for TsAggregationType {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(match self {
+            TsAggregationType::Avg => b"AVG".as_slice(),
+            TsAggregationType::Sum => b"SUM",
+            TsAggregationType::Min => b"MIN",
+            TsAggregationType::Max => b"MAX",
+            TsAggregationType::Range => b"RANGE",
+            TsAggregationType::Count => b"COUNT",
+            TsAggregationType::First => b"FIRST",
+            TsAggregationType::Last => b"LAST",
+            TsAggregationType::StdP => b"STD.P",
+            TsAggregationType::StdS => b"STD.S",
+            TsAggregationType::VarP => b"VAR.P",
+            TsAggregationType::VarS => b"VAR.S",
+            TsAggregationType::Twa => b"TWA",
+        });
+    }
+}
+
+impl ToSingleRedisArg for TsAggregationType {}
+
+/// Settings shared by the commands that can create a series.
+#[derive(Clone, Debug, Default)]
+struct SeriesSettings {
+    retention: Option<u64>,
+    encoding: Option<TsEncoding>,
+    chunk_size: Option<usize>,
+    duplicate_policy: Option<DuplicatePolicy>,
+    ignore: Option<(u64, f64)>,
+    labels: Vec<(String, String)>,
+}
+
+impl ToRedisArgs for SeriesSettings {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(retention) = self.retention {
+            out.write_arg(b"RETENTION");
+            out.write_arg_fmt(retention);
+        }
+        if let Some(encoding) = self.encoding {
+            out.write_arg(b"ENCODING");
+            encoding.write_redis_args(out);
+        }
+        if let Some(chunk_size) = self.chunk_size {
+            out.write_arg(b"CHUNK_SIZE");
+            out.write_arg_fmt(chunk_size);
+        }
+        if let Some(policy) = self.duplicate_policy {
+            out.write_arg(b"DUPLICATE_POLICY");
+            policy.write_redis_args(out);
+        }
+        if let Some((max_time_diff, max_value_diff)) = self.ignore {
+            out.write_arg(b"IGNORE");
+            out.write_arg_fmt(max_time_diff);
+            max_value_diff.write_redis_args(out);
+        }
+        // LABELS consumes all remaining arguments, so it has to come last
+        if !self.labels.is_empty() {
+            out.write_arg(b"LABELS");
+            for (label, value) in &self.labels {
+                out.write_arg(label.as_bytes());
+                out.write_arg(value.as_bytes());
+            }
+        }
+    }
+}
+
+macro_rules! implement_series_settings {
+    ($($options:ident),*) => {
+        $(
+            impl $options {
+                /// Sets the maximal age of samples in milliseconds, compared to the latest
+                /// sample. `0` keeps samples forever.
+                pub fn retention(mut self, milliseconds: u64) -> Self {
+                    self.settings.retention = Some(milliseconds);
+                    self
+                }
+
+                /// Sets the memory size of a data chunk in bytes. Must be a multiple of 8.
+                pub fn chunk_size(mut self, bytes: usize) -> Self {
+                    self.settings.chunk_size = Some(bytes);
+                    self
+                }
+
+                /// Sets how samples with an existing timestamp are handled.
+                pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+                    self.settings.duplicate_policy = Some(policy);
+                    self
+                }
+
+                /// Ignores new samples that differ from the latest sample by at most
+                /// `max_time_diff` milliseconds and `max_value_diff`.
+                pub fn ignore(mut self, max_time_diff: u64, max_value_diff: f64) -> Self {
+                    self.settings.ignore = Some((max_time_diff, max_value_diff));
+                    self
+                }
+
+                /// Adds a label, which can be used to select the series in `TS.MRANGE` and
+                /// `TS.MGET`.
+                pub fn label(mut self, label: &str, value: &str) -> Self {
+                    self.settings
+                        .labels
+                        .push((label.to_string(), value.to_string()));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! implement_series_encoding {
+    ($($options:ident),*) => {
+        $(
+            impl $options {
+                /// Sets how the samples are stored.
+                pub fn encoding(mut self, encoding: TsEncoding) -> Self {
+                    self.settings.encoding = Some(encoding);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+/// Options for [`ts_create_options`][1].
+///
+/// ```text
+/// TS.CREATE key [RETENTION retentionPeriod] [ENCODING COMPRESSED | UNCOMPRESSED]
+///   [CHUNK_SIZE size] [DUPLICATE_POLICY policy] [IGNORE ignoreMaxTimediff ignoreMaxValDiff]
+///   [LABELS label value ...]
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_create_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsCreateOptions {
+    settings: SeriesSettings,
+}
+
+impl ToRedisArgs for TsCreateOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.settings.write_redis_args(out);
+    }
+}
+
+/// Options for [`ts_alter`][1].
+///
+/// Setting labels replaces all existing labels of the series.
+///
+/// ```text
+/// TS.ALTER key [RETENTION retentionPeriod] [CHUNK_SIZE size] [DUPLICATE_POLICY policy]
+///   [IGNORE ignoreMaxTimediff ignoreMaxValDiff] [LABELS label value ...]
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_alter
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsAlterOptions {
+    settings: SeriesSettings,
+}
+
+impl ToRedisArgs for TsAlterOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.settings.write_redis_args(out);
+    }
+}
+
+/// Options for [`ts_add_options`][1].
+///
+/// Apart from [`on_duplicate`](Self::on_duplicate), the options only apply if the series
+/// doesn't exist yet and is created by the command.
+///
+/// ```text
+/// TS.ADD key timestamp value [RETENTION retentionPeriod] [ENCODING COMPRESSED | UNCOMPRESSED]
+///   [CHUNK_SIZE size] [DUPLICATE_POLICY policy] [ON_DUPLICATE policy_ovr]
+///   [IGNORE ignoreMaxTimediff ignoreMaxValDiff] [LABELS label value ...]
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_add_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsAddOptions {
+    on_duplicate: Option<DuplicatePolicy>,
+    settings: SeriesSettings,
+}
+
+impl TsAddOptions {
+    /// Overrides the duplicate policy of the series for this sample.
+    pub fn on_duplicate(mut self, policy: DuplicatePolicy) -> Self {
+        self.on_duplicate = Some(policy);
+        self
+    }
+}
+
+impl ToRedisArgs for TsAddOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(policy) = self.on_duplicate {
+            out.write_arg(b"ON_DUPLICATE");
+            policy.write_redis_args(out);
+        }
+        self.settings.write_redis_args(out);
+    }
+}
+
+/// Options for [`ts_incrby_options`][1] and [`ts_decrby_options`][2].
+///
+/// Apart from [`timestamp`](Self::timestamp), the options only apply if the series doesn't
+/// exist yet and is created by the command.
+///
+/// ```text
+/// TS.INCRBY key addend [TIMESTAMP timestamp] [RETENTION retentionPeriod]
+///   [ENCODING COMPRESSED | UNCOMPRESSED] [CHUNK_SIZE size] [DUPLICATE_POLICY policy]
+///   [IGNORE ignoreMaxTimediff ignoreMaxValDiff] [LABELS label value ...]
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_incrby_options
+/// [2]: ../trait.Commands.html#method.ts_decrby_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsIncrByOptions {
+    timestamp: Option<u64>,
+    settings: SeriesSettings,
+}
+
+impl TsIncrByOptions {
+    /// Sets the timestamp of the updated sample in milliseconds. Defaults to the server time.
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
+impl ToRedisArgs for TsIncrByOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(timestamp) = self.timestamp {
+            out.write_arg(b"TIMESTAMP");
+            out.write_arg_fmt(timestamp);
+        }
+        self.settings.write_redis_args(out);
+    }
+}
+
+implement_series_settings!(
+    TsCreateOptions,
+    TsAlterOptions,
+    TsAddOptions,
+    TsIncrByOptions
+);
+implement_series_encoding!(TsCreateOptions, TsAddOptions, TsIncrByOptions);
+
+/// The reference point of the time buckets of a [`TsAggregation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TsAlign {
+    /// Align the buckets to the start of the queried range.
+    Start,
+    /// Align the buckets to the end of the queried range.
+    End,
+    /// Align the buckets to the given timestamp.
+    Timestamp(u64),
+}
+
+/// The timestamp reported for each time bucket of a [`TsAggregation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TsBucketTimestamp {
+    /// The start of the bucket. This is the default.
+    Start,
+    /// The end of the bucket.
+    End,
+    /// The middle of the bucket.
+    Mid,
+}
+
+/// Aggregates the samples of a range query into time buckets.
+///
+/// ```text
+/// [ALIGN align] AGGREGATION aggregator bucketDuration [BUCKETTIMESTAMP bt] [EMPTY]
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TsAggregation {
+    aggregator: TsAggregationType,
+    bucket_duration: u64,
+    align: Option<TsAlign>,
+    bucket_timestamp: Option<TsBucketTimestamp>,
+    empty: bool,
+}
+
+impl TsAggregation {
+    /// Aggregates the samples into buckets of `bucket_duration` milliseconds.
+    pub fn new(aggregator: TsAggregationType, bucket_duration: u64) -> Self {
+        TsAggregation {
+            aggregator,
+            bucket_duration,
+            align: None,
+            bucket_timestamp: None,
+            empty: false,
+        }
+    }
+
+    /// Sets the reference point of the buckets. Defaults to timestamp 0.
+    pub fn align(mut self, align: TsAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Sets the timestamp reported for each bucket.
+    pub fn bucket_timestamp(mut self, bucket_timestamp: TsBucketTimestamp) -> Self {
+        self.bucket_timestamp = Some(bucket_timestamp);
+        self
+    }
+
+    /// Reports buckets without samples as well.
+    pub fn empty(mut self) -> Self {
+        self.empty = true;
+        self
+    }
+}
+
+impl ToRedisArgs for TsAggregation {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(align) = self.align {
+            out.write_arg(b"ALIGN");
+            match align {
+                TsAlign::Start => out.write_arg(b"-"),
+                TsAlign::End => out.write_arg(b"+"),
+                TsAlign::Timestamp(timestamp) => out.write_arg_fmt(timestamp),
+            }
+        }
+        out.write_arg(b"AGGREGATION");
+        self.aggregator.write_redis_args(out);
+        out.write_arg_fmt(self.bucket_duration);
+        if let Some(bucket_timestamp) = self.bucket_timestamp {
+            out.write_arg(b"BUCKETTIMESTAMP");
+            out.write_arg(match bucket_timestamp {
+                TsBucketTimestamp::Start => b"-",
+                TsBucketTimestamp::End => b"+",
+                TsBucketTimestamp::Mid => b"~",
+            });
+        }
+        if self.empty {
+            out.write_arg(b"EMPTY");
+        }
+    }
+}
+
+/// Filters shared by the range queries.
+#[derive(Clone, Debug, Default)]
+struct RangeFilters {
+    latest: bool,
+    filter_by_ts: Vec<u64>,
+    filter_by_value: Option<(f64, f64)>,
+    count: Option<usize>,
+    aggregation: Option<TsAggregation>,
+}
+
+impl RangeFilters {
+    fn write_filters<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.latest {
+            out.write_arg(b"LATEST");
+        }
+        if !self.filter_by_ts.is_empty() {
+            out.write_arg(b"FILTER_BY_TS");
+            for timestamp in &self.filter_by_ts {
+                out.write_arg_fmt(timestamp);
+            }
+        }
+        if let Some((min, max)) = self.filter_by_value {
+            out.write_arg(b"FILTER_BY_VALUE");
+            min.write_redis_args(out);
+            max.write_redis_args(out);
+        }
+    }
+
+    fn write_aggregation<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(count) = self.count {
+            out.write_arg(b"COUNT");
+            out.write_arg_fmt(count);
+        }
+        if let Some(ref aggregation) = self.aggregation {
+            aggregation.write_redis_args(out);
+        }
+    }
+}
+
+macro_rules! implement_range_filters {
+    ($($options:ident),*) => {
+        $(
+            impl $options {
+                /// Also reports the latest, possibly partial, bucket of compacted series.
+                pub fn latest(mut self) -> Self {
+                    self.filters.latest = true;
+                    self
+                }
+
+                /// Only reports samples with one of the given timestamps.
+                pub fn filter_by_ts<I: IntoIterator<Item = u64>>(mut self, timestamps: I) -> Self {
+                    self.filters.filter_by_ts.extend(timestamps);
+                    self
+                }
+
+                /// Only reports samples with a value between `min` and `max`, inclusive.
+                pub fn filter_by_value(mut self, min: f64, max: f64) -> Self {
+                    self.filters.filter_by_value = Some((min, max));
+                    self
+                }
+
+                /// Limits the number of reported samples per series.
+                pub fn count(mut self, count: usize) -> Self {
+                    self.filters.count = Some(count);
+                    self
+                }
+
+                /// Aggregates the samples into time buckets.
+                pub fn aggregation(mut self, aggregation: TsAggregation) -> Self {
+                    self.filters.aggregation = Some(aggregation);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+/// Options for [`ts_range_options`][1] and [`ts_revrange_options`][2].
+///
+/// ```text
+/// TS.RANGE key fromTimestamp toTimestamp [LATEST] [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max]
+///   [COUNT count] [[ALIGN align] AGGREGATION aggregator bucketDuration [BUCKETTIMESTAMP bt] [EMPTY]]
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_range_options
+/// [2]: ../trait.Commands.html#method.ts_revrange_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsRangeOptions {
+    filters: RangeFilters,
+}
+
+impl ToRedisArgs for TsRangeOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.filters.write_filters(out);
+        self.filters.write_aggregation(out);
+    }
+}
+
+/// Which labels are reported by `TS.MRANGE` and `TS.MGET`.
+#[derive(Clone, Debug, Default)]
+enum LabelSelection {
+    #[default]
+    None,
+    All,
+    Selected(Vec<String>),
+}
+
+impl ToRedisArgs for LabelSelection {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self {
+            LabelSelection::None => {}
+            LabelSelection::All => out.write_arg(b"WITHLABELS"),
+            LabelSelection::Selected(labels) => {
+                out.write_arg(b"SELECTED_LABELS");
+                for label in labels {
+                    out.write_arg(label.as_bytes());
+                }
+            }
+        }
+    }
+}
+
+/// Options for [`ts_mrange_options`][1] and [`ts_mrevrange_options`][2].
+///
+/// ```text
+/// TS.MRANGE fromTimestamp toTimestamp [LATEST] [FILTER_BY_TS ts ...] [FILTER_BY_VALUE min max]
+///   [WITHLABELS | SELECTED_LABELS label ...] [COUNT count]
+///   [[ALIGN align] AGGREGATION aggregator bucketDuration [BUCKETTIMESTAMP bt] [EMPTY]]
+///   FILTER filterExpr ... [GROUPBY label REDUCE reducer]
+/// ```
+///
+/// The `GROUPBY` clause is set with [`group_by`](Self::group_by). On cluster connections,
+/// groups are reduced per node, so a group may be reported once per node.
+///
+/// [1]: ../trait.Commands.html#method.ts_mrange_options
+/// [2]: ../trait.Commands.html#method.ts_mrevrange_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsMRangeOptions {
+    filters: RangeFilters,
+    labels: LabelSelection,
+    group_by: Option<(String, TsAggregationType)>,
+}
+
+impl TsMRangeOptions {
+    /// Reports all labels of each series.
+    pub fn with_labels(mut self) -> Self {
+        self.labels = LabelSelection::All;
+        self
+    }
+
+    /// Reports the given labels of each series.
+    pub fn selected_labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.labels = LabelSelection::Selected(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Groups the series by the value of `label` and reduces each group into a single series.
+    ///
+    /// On cluster connections, the command is sent to every primary and each of them reduces
+    /// only the series it holds, so a group whose series live on several shards is reported
+    /// once per shard. Reduce across shards on the client, or select series that share a
+    /// hash tag, if a single series per group is needed.
+    pub fn group_by(mut self, label: &str, reducer: TsAggregationType) -> Self {
+        self.group_by = Some((label.to_string(), reducer));
+        self
+    }
+
+    pub(crate) fn write_group_by<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some((ref label, reducer)) = self.group_by {
+            out.write_arg(b"GROUPBY");
+            out.write_arg(label.as_bytes());
+            out.write_arg(b"REDUCE");
+            reducer.write_redis_args(out);
+        }
+    }
+}
+
+impl ToRedisArgs for TsMRangeOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.filters.write_filters(out);
+        self.labels.write_redis_args(out);
+        self.filters.write_aggregation(out);
+    }
+}
+
+implement_range_filters!(TsRangeOptions, TsMRangeOptions);
+
+/// Options for [`ts_mget_options`][1].
+///
+/// ```text
+/// TS.MGET [LATEST] [WITHLABELS | SELECTED_LABELS label ...] FILTER filterExpr ...
+/// ```
+///
+/// [1]: ../trait.Commands.html#method.ts_mget_options
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsMGetOptions {
+    latest: bool,
+    labels: LabelSelection,
+}
+
+impl TsMGetOptions {
+    /// Reports the latest, possibly partial, bucket of compacted series.
+    pub fn latest(mut self) -> Self {
+        self.latest = true;
+        self
+    }
+
+    /// Reports all labels of each series.
+    pub fn with_labels(mut self) -> Self {
+        self.labels = LabelSelection::All;
+        self
+    }
+
+    /// Reports the given labels of each series.
+    pub fn selected_labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.labels = LabelSelection::Selected(labels.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl ToRedisArgs for TsMGetOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.latest {
+            out.write_arg(b"LATEST");
+        }
+        self.labels.write_redis_args(out);
+    }
+}
+
+/// Selects series by their labels, for the `TS.M*` commands and `TS.QUERYINDEX`.
+///
+/// All expressions have to match. At least one of them has to be an
+/// [`equals`](Self::equals) or [`is_in`](Self::is_in) expression.
+///
+/// ```text
+/// FILTER filterExpr ...
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TsFilter {
+    expressions: Vec<String>,
+}
+
+impl TsFilter {
+    /// Creates a filter without expressions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches series where `label` equals `value` (`label=value`).
+    pub fn equals(mut self, label: &str, value: &str) -> Self {
+        self.expressions.push(format!("{label}={value}"));
+        self
+    }
+
+    /// Matches series where `label` doesn't equal `value` (`label!=value`).
+    pub fn not_equals(mut self, label: &str, value: &str) -> Self {
+        self.expressions.push(format!("{label}!={value}"));
+        self
+    }
+
+    /// Matches series that have the label (`label!=`).
+    pub fn has_label(mut self, label: &str) -> Self {
+        self.expressions.push(format!("{label}!="));
+        self
+    }
+
+    /// Matches series that don't have the label (`label=`).
+    pub fn missing_label(mut self, label: &str) -> Self {
+        self.expressions.push(format!("{label}="));
+        self
+    }
+
+    /// Matches series where `label` equals one of the values (`label=(value1,value2)`).
+    pub fn is_in<I, S>(mut self, label: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let values: Vec<S> = values.into_iter().collect();
+        let values: Vec<&str> = values.iter().map(AsRef::as_ref).collect();
+        self.expressions
+            .push(format!("{label}=({})", values.join(",")));
+        self
+    }
+
+    /// Matches series where `label` equals none of the values (`label!=(value1,value2)`).
+    pub fn not_in<I, S>(mut self, label: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let values: Vec<S> = values.into_iter().collect();
+        let values: Vec<&str> = values.iter().map(AsRef::as_ref).collect();
+        self.expressions
+            .push(format!("{label}!=({})", values.join(",")));
+        self
+    }
+}
+
+impl ToRedisArgs for TsFilter {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(b"FILTER");
+        for expression in &self.expressions {
+            out.write_arg(expression.as_bytes());
+        }
+    }
+}
+
+/// Writes the filter expressions without the `FILTER` keyword, as expected by `TS.QUERYINDEX`.
+pub(crate) struct FilterExpressions<'a>(pub(crate) &'a TsFilter);
+
+impl ToRedisArgs for FilterExpressions<'_> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        for expression in &self.0.expressions {
+            out.write_arg(expression.as_bytes());
+        }
+    }
+}
+
+/// Writes the `GROUPBY` clause of [`TsMRangeOptions`].
+pub(crate) struct GroupBy<'a>(pub(crate) &'a TsMRangeOptions);
+
+impl ToRedisArgs for GroupBy<'_> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.0.write_group_by(out);
+    }
+}
+
+/// A single sample of a time series.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Sample {
+    /// The timestamp of the sample in milliseconds.
+    pub timestamp: u64,
+    /// The value of the sample.
+    pub value: f64,
+}
+
+impl FromRedisValue for Sample {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (timestamp, value) = from_redis_value(v)?;
+        Ok(Sample { timestamp, value })
+    }
+}
+
+/// Parses the labels of a series, which are reported as pairs in RESP2 and as a map in RESP3.
+/// Labels without value, which are reported for missing `SELECTED_LABELS`, are skipped.
+fn parse_labels(v: Value) -> Result<HashMap<String, String>, ParsingError> {
+    let labels: Vec<(String, Option<String>)> = match v {
+        Value::Map(_) => from_redis_value::<HashMap<String, Option<String>>>(v)?
+            .into_iter()
+            .collect(),
+        _ => from_redis_value(v)?,
+    };
+    Ok(labels
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| (label, value)))
+        .collect())
+}
+
+/// Splits a series of a `TS.MRANGE` or `TS.MGET` reply into its key and its remaining items.
+///
+/// RESP2 reports each series as an array starting with the key, while RESP3 reports a map from
+/// key to the remaining items, which is passed here one entry at a time.
+fn split_series(v: Value) -> Result<(String, Vec<Value>), ParsingError> {
+    match v {
+        Value::Array(items) => {
+            let mut items = items.into_iter();
+            let Some(key) = items.next() else {
+                invalid_type_error!("series reply is empty");
+            };
+            Ok((from_redis_value(key)?, items.collect()))
+        }
+        Value::Map(entries) if entries.len() == 1 => {
+            let Some((key, items)) = entries.into_iter().next() else {
+                unreachable!()
+            };
+            let Value::Array(items) = items else {
+                invalid_type_error!(items, "expected the items of the series as an array");
+            };
+            Ok((from_redis_value(key)?, items))
+        }
+        _ => invalid_type_error!(v, "Response type not series compatible."),
+    }
+}
+
+/// A series reported by [`ts_mrange`][1] and [`ts_mrevrange`][2].
+///
+/// [1]: ../trait.Commands.html#method.ts_mrange
+/// [2]: ../trait.Commands.html#method.ts_mrevrange
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct MRangeSeries {
+    /// The key of the series, or the `label=value` name of a group when grouping.
+    pub key: String,
+    /// The requested labels of the series.
+    pub labels: HashMap<String, String>,
+    /// The samples of the series.
+    pub samples: Vec<Sample>,
+}
+
+impl FromRedisValue for MRangeSeries {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (key, items) = split_series(v)?;
+        // The labels come first and the samples last. RESP3 and grouped replies report
+        // additional metadata in between.
+        let mut items = items.into_iter();
+        let (Some(labels), Some(samples)) = (items.next(), items.next_back()) else {
+            invalid_type_error!("expected labels and samples");
+        };
+        Ok(MRangeSeries {
+            key,
+            labels: parse_labels(labels)?,
+            samples: from_redis_value(samples)?,
+        })
+    }
+}
+
+/// A series reported by [`ts_mget`][1].
+///
+/// [1]: ../trait.Commands.html#method.ts_mget
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct MGetSeries {
+    /// The key of the series.
+    pub key: String,
+    /// The requested labels of the series.
+    pub labels: HashMap<String, String>,
+    /// The latest sample of the series, or `None` if the series is empty.
+    pub sample: Option<Sample>,
+}
+
+impl FromRedisValue for MGetSeries {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (key, items) = split_series(v)?;
+        let mut items = items.into_iter();
+        let (Some(labels), Some(sample)) = (items.next(), items.next()) else {
+            invalid_type_error!("expected labels and sample");
+        };
+        let sample = match sample {
+            Value::Array(ref values) if values.is_empty() => None,
+            sample => Some(from_redis_value(sample)?),
+        };
+        Ok(MGetSeries {
+            key,
+            labels: parse_labels(labels)?,
+            sample,
+        })
+    }
+}
+
+/// A compaction rule of a series, as reported by [`TsInfo`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TsRule {
+    /// The key of the series the compacted samples are written to.
+    pub dest_key: String,
+    /// The duration of the time buckets in milliseconds.
+    pub bucket_duration: u64,
+    /// The aggregation function, e.g. `AVG`.
+    pub aggregator: String,
+    /// The alignment of the time buckets.
+    pub align_timestamp: u64,
+}
+
+impl FromRedisValue for TsRule {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let (dest_key, bucket_duration, aggregator, align_timestamp) = match v {
+            Value::Array(ref items) if items.len() == 3 => {
+                let (dest_key, bucket_duration, aggregator) = from_redis_value(v)?;
+                (dest_key, bucket_duration, aggregator, 0)
+            }
+            _ => from_redis_value(v)?,
+        };
+        Ok(TsRule {
+            dest_key,
+            bucket_duration,
+            aggregator,
+            align_timestamp,
+        })
+    }
+}
+
+/// Reply of [`ts_info`][1].
+///
+/// [1]: ../trait.Commands.html#method.ts_info
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TsInfo {
+    /// The number of samples in the series.
+    pub total_samples: u64,
+    /// The memory used by the series in bytes.
+    pub memory_usage: u64,
+    /// The timestamp of the first sample.
+    pub first_timestamp: u64,
+    /// The timestamp of the last sample.
+    pub last_timestamp: u64,
+    /// The retention period in milliseconds. `0` means that samples are kept forever.
+    pub retention_time: u64,
+    /// The number of data chunks.
+    pub chunk_count: u64,
+    /// The memory size of a data chunk in bytes.
+    pub chunk_size: u64,
+    /// The chunk encoding, e.g. `compressed`.
+    pub chunk_type: String,
+    /// The duplicate policy of the series, or `None` if the server default applies.
+    pub duplicate_policy: Option<String>,
+    /// The labels of the series.
+    pub labels: HashMap<String, String>,
+    /// The key of the series this series is a compaction of, if any.
+    pub source_key: Option<String>,
+    /// The compaction rules writing into other series.
+    pub rules: Vec<TsRule>,
+}
+
+impl FromRedisValue for TsInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let mut map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut info = TsInfo::default();
+        if let Some(v) = map.remove("totalSamples") {
+            info.total_samples = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("memoryUsage") {
+            info.memory_usage = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("firstTimestamp") {
+            info.first_timestamp = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("lastTimestamp") {
+            info.last_timestamp = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("retentionTime") {
+            info.retention_time = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("chunkCount") {
+            info.chunk_count = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("chunkSize") {
+            info.chunk_size = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("chunkType") {
+            info.chunk_type = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("duplicatePolicy") {
+            info.duplicate_policy = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("labels") {
+            info.labels = parse_labels(v)?;
+        }
+        if let Some(v) = map.remove("sourceKey") {
+            info.source_key = from_redis_value(v)?;
+        }
+        if let Some(v) = map.remove("rules") {
+            info.rules = match v {
+                // RESP3 reports the rules as a map from destination key to the rule
+                Value::Map(rules) => rules
+                    .into_iter()
+                    .map(|(key, rule)| {
+                        let Value::Array(mut items) = rule else {
+                            invalid_type_error!(rule, "expected rule as array");
+                        };
+                        items.insert(0, key);
+                        from_redis_value(Value::Array(items))
+                    })
+                    .collect::<Result<_, _>>()?,
+                v => from_redis_value(v)?,
+            };
+        }
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{args, bulk};
+
+    #[test]
+    fn test_create_options() {
+        let options = TsCreateOptions::default()
+            .retention(1000)
+            .encoding(TsEncoding::Uncompressed)
+            .chunk_size(128)
+            .duplicate_policy(DuplicatePolicy::Sum)
+            .ignore(10, 0.5)
+            .label("sensor", "1")
+            .label("room", "kitchen");
+        assert_eq!(
+            args(options),
+            vec![
+                "RETENTION",
+                "1000",
+                "ENCODING",
+                "UNCOMPRESSED",
+                "CHUNK_SIZE",
+                "128",
+                "DUPLICATE_POLICY",
+                "SUM",
+                "IGNORE",
+                "10",
+                "0.5",
+                "LABELS",
+                "sensor",
+                "1",
+                "room",
+                "kitchen"
+            ]
+        );
+        assert!(args(TsCreateOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_add_and_incrby_options() {
+        let options = TsAddOptions::default()
+            .on_duplicate(DuplicatePolicy::Max)
+            .retention(5)
+            .label("a", "b");
+        assert_eq!(
+            args(options),
+            vec!["ON_DUPLICATE", "MAX", "RETENTION", "5", "LABELS", "a", "b"]
+        );
+
+        let options = TsIncrByOptions::default().timestamp(42).chunk_size(64);
+        assert_eq!(args(options), vec!["TIMESTAMP", "42", "CHUNK_SIZE", "64"]);
+    }
+
+    #[test]
+    fn test_range_options() {
+        let options = TsRangeOptions::default()
+            .latest()
+            .filter_by_ts([1, 2])
+            .filter_by_value(0.0, 10.5)
+            .count(3)
+            .aggregation(
+                TsAggregation::new(TsAggregationType::StdP, 1000)
+                    .align(TsAlign::End)
+                    .bucket_timestamp(TsBucketTimestamp::Mid)
+                    .empty(),
+            );
+        assert_eq!(
+            args(options),
+            vec![
+                "LATEST",
+                "FILTER_BY_TS",
+                "1",
+                "2",
+                "FILTER_BY_VALUE",
+                "0.0",
+                "10.5",
+                "COUNT",
+                "3",
+                "ALIGN",
+                "+",
+                "AGGREGATION",
+                "STD.P",
+                "1000",
+                "BUCKETTIMESTAMP",
+                "~",
+                "EMPTY"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mrange_options_and_filter() {
+        let options = TsMRangeOptions::default()
+            .selected_labels(["room"])
+            .count(1)
+            .group_by("room", TsAggregationType::Max);
+        assert_eq!(
+            args(&options),
+            vec!["SELECTED_LABELS", "room", "COUNT", "1"]
+        );
+        assert_eq!(
+            args(GroupBy(&options)),
+            vec!["GROUPBY", "room", "REDUCE", "MAX"]
+        );
+
+        let filter = TsFilter::new()
+            .equals("sensor", "temperature")
+            .not_equals("room", "attic")
+            .has_label("floor")
+            .missing_label("broken")
+            .is_in("building", ["a", "b"])
+            .not_in("owner", ["x"]);
+        assert_eq!(
+            args(&filter),
+            vec![
+                "FILTER",
+                "sensor=temperature",
+                "room!=attic",
+                "floor!=",
+                "broken=",
+                "building=(a,b)",
+                "owner!=(x)"
+            ]
+        );
+        assert_eq!(args(FilterExpressions(&filter)).len(), 6);
+
+        assert_eq!(
+            args(TsMGetOptions::default().latest().with_labels()),
+            vec!["LATEST", "WITHLABELS"]
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let resp2 = Value::Array(vec![Value::Int(1000), bulk("1.5")]);
+        assert_eq!(
+            Sample::from_redis_value(resp2).unwrap(),
+            Sample {
+                timestamp: 1000,
+                value: 1.5
+            }
+        );
+        let resp3 = Value::Array(vec![Value::Int(1000), Value::Double(1.5)]);
+        assert_eq!(Sample::from_redis_value(resp3).unwrap().value, 1.5);
+    }
+
+    #[test]
+    fn test_mrange_series_resp2() {
+        let value = Value::Array(vec![Value::Array(vec![
+            bulk("temp:1"),
+            Value::Array(vec![
+                Value::Array(vec![bulk("room"), bulk("kitchen")]),
+                Value::Array(vec![bulk("floor"), Value::Nil]),
+            ]),
+            Value::Array(vec![
+                Value::Array(vec![Value::Int(1), bulk("20")]),
+                Value::Array(vec![Value::Int(2), bulk("21")]),
+            ]),
+        ])]);
+        let series: Vec<MRangeSeries> = from_redis_value(value).unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].key, "temp:1");
+        assert_eq!(
+            series[0].labels,
+            HashMap::from([("room".to_string(), "kitchen".to_string())])
+        );
+        assert_eq!(series[0].samples.len(), 2);
+        assert_eq!(series[0].samples[1].value, 21.0);
+    }
+
+    #[test]
+    fn test_mrange_series_resp3() {
+        let value = Value::Map(vec![(
+            bulk("temp:1"),
+            Value::Array(vec![
+                Value::Map(vec![(bulk("room"), bulk("kitchen"))]),
+                Value::Map(vec![(bulk("aggregators"), Value::Array(vec![]))]),
+                Value::Array(vec![Value::Array(vec![Value::Int(1), Value::Double(20.0)])]),
+            ]),
+        )]);
+        let series: Vec<MRangeSeries> = from_redis_value(value).unwrap();
+        assert_eq!(series[0].key, "temp:1");
+        assert_eq!(series[0].labels["room"], "kitchen");
+        assert_eq!(
+            series[0].samples,
+            vec![Sample {
+                timestamp: 1,
+                value: 20.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_mget_series() {
+        let resp2 = Value::Array(vec![
+            Value::Array(vec![
+                bulk("temp:1"),
+                Value::Array(vec![]),
+                Value::Array(vec![Value::Int(5), bulk("3")]),
+            ]),
+            Value::Array(vec![
+                bulk("temp:2"),
+                Value::Array(vec![]),
+                Value::Array(vec![]),
+            ]),
+        ]);
+        let series: Vec<MGetSeries> = from_redis_value(resp2).unwrap();
+        assert_eq!(
+            series[0].sample,
+            Some(Sample {
+                timestamp: 5,
+                value: 3.0
+            })
+        );
+        assert_eq!(series[1].key, "temp:2");
+        assert_eq!(series[1].sample, None);
+
+        let resp3 = Value::Map(vec![(
+            bulk("temp:1"),
+            Value::Array(vec![
+                Value::Map(vec![]),
+                Value::Array(vec![Value::Int(5), Value::Double(3.0)]),
+            ]),
+        )]);
+        let series: Vec<MGetSeries> = from_redis_value(resp3).unwrap();
+        assert_eq!(series[0].key, "temp:1");
+        assert_eq!(series[0].sample.unwrap().timestamp, 5);
+    }
+
+    #[test]
+    fn test_info() {
+        let value = Value::Array(vec![
+            bulk("totalSamples"),
+            Value::Int(2),
+            bulk("retentionTime"),
+            Value::Int(0),
+            bulk("chunkType"),
+            bulk("compressed"),
+            bulk("duplicatePolicy"),
+            Value::Nil,
+            bulk("labels"),
+            Value::Array(vec![Value::Array(vec![bulk("room"), bulk("kitchen")])]),
+            bulk("sourceKey"),
+            Value::Nil,
+            bulk("rules"),
+            Value::Array(vec![Value::Array(vec![
+                bulk("temp:avg"),
+                Value::Int(60000),
+                bulk("AVG"),
+                Value::Int(0),
+            ])]),
+        ]);
+        let info = TsInfo::from_redis_value(value).unwrap();
+        assert_eq!(info.total_samples, 2);
+        assert_eq!(info.chunk_type, "compressed");
+        assert_eq!(info.duplicate_policy, None);
+        assert_eq!(info.labels["room"], "kitchen");
+        assert_eq!(info.source_key, None);
+        assert_eq!(
+            info.rules,
+            vec![TsRule {
+                dest_key: "temp:avg".to_string(),
+                bucket_duration: 60000,
+                aggregator: "AVG".to_string(),
+                align_timestamp: 0,
+            }]
+        );
+
+        let resp3_rules = Value::Map(vec![(
+            bulk("rules"),
+            Value::Map(vec![(
+                bulk("temp:avg"),
+                Value::Array(vec![Value::Int(60000), bulk("AVG"), Value::Int(0)]),
+            )]),
+        )]);
+        let info = TsInfo::from_redis_value(resp3_rules).unwrap();
+        assert_eq!(info.rules[0].dest_key, "temp:avg");
+    }
+}
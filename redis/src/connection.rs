@@ -2179,6 +2179,12 @@ impl<'a> PubSub<'a> {
         Ok(())
     }
 
+    /// Sends a command that isn't a (un)subscription, e.g. CONFIG SET before subscribing.
+    pub(crate) fn req_command(&mut self, cmd: &mut Cmd) -> RedisResult<Value> {
+        self.cache_messages_until_received_response(cmd, false)?
+            .extract_error()
+    }
+
     /// Sends a ping with a message to the server
     pub fn ping_message<T: FromRedisValue>(&mut self, message: impl ToRedisArgs) -> RedisResult<T> {
         Ok(from_redis_value(
@@ -2300,9 +2306,9 @@ impl Msg {
     /// method.  If the channel is not a valid string (which really should
     /// not happen) then the return value is `"?"`.
     pub fn get_channel_name(&self) -> &str {
-        match self.channel {
-            Value::BulkString(ref bytes) => from_utf8(bytes).unwrap_or("?"),
-            _ => "?",
+        match self.channel.as_bulk_string() {
+            Some(bytes) => from_utf8(bytes).unwrap_or("?"),
+            None => "?",
         }
     }
 
@@ -2315,10 +2321,7 @@ impl Msg {
     /// as an alternative to the `get_payload` function if you are interested
     /// in the raw bytes in it.
     pub fn get_payload_bytes(&self) -> &[u8] {
-        match self.payload {
-            Value::BulkString(ref bytes) => bytes,
-            _ => b"",
-        }
+        self.payload.as_bulk_string().unwrap_or(b"")
     }
 
     /// Returns true if the message was constructed from a pattern
@@ -40,8 +40,8 @@ pub enum ErrorKind {
     /// An error returned from the server
     Server(ServerErrorKind),
 
-    #[cfg(feature = "json")]
-    /// Error Serializing a struct to JSON form
+    #[cfg(any(feature = "json", feature = "msgpack", feature = "bincode"))]
+    /// Error serializing a value to JSON, MessagePack or bincode
     Serialize,
 
     /// Redis Servers prior to v6.0.0 doesn't support RESP3.
@@ -305,7 +305,7 @@ impl RedisError {
             ErrorKind::EmptySentinelList => "empty sentinel list",
             ErrorKind::Server(ServerErrorKind::NotBusy) => "not busy",
             ErrorKind::ClusterConnectionNotFound => "connection to node in cluster not found",
-            #[cfg(feature = "json")]
+            #[cfg(any(feature = "json", feature = "msgpack", feature = "bincode"))]
             ErrorKind::Serialize => "serializing",
             ErrorKind::RESP3NotSupported => "resp3 is not supported by server",
             ErrorKind::Parse => "parse error",
@@ -438,7 +438,7 @@ impl RedisError {
             ErrorKind::InvalidClientConfig => RetryMethod::NoRetry,
             ErrorKind::Client => RetryMethod::NoRetry,
             ErrorKind::EmptySentinelList => RetryMethod::NoRetry,
-            #[cfg(feature = "json")]
+            #[cfg(any(feature = "json", feature = "msgpack", feature = "bincode"))]
             ErrorKind::Serialize => RetryMethod::NoRetry,
             ErrorKind::RESP3NotSupported => RetryMethod::NoRetry,
 
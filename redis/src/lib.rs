@@ -90,7 +90,7 @@
 //! if so desired.  Some of them are turned on by default.
 //!
 //! * `acl`: enables acl support (enabled by default)
-//! * `bloom`: enables support for the Bloom filter module (optional)
+//! * `bloom`: enables support for the Bloom filter, Cuckoo filter, Count-Min Sketch, Top-K and T-Digest commands of the RedisBloom module (optional)
 //! * `tokio-comp`: enables support for async usage with the Tokio runtime (optional)
 //! * `smol-comp`: enables support for async usage with the Smol runtime (optional)
 //! * `geospatial`: enables geospatial support (enabled by default)
@@ -106,6 +106,10 @@
 //! * `uuid`: enables type conversion to UUID (optional)
 //! * `sentinel`: enables high-level interfaces for communication with Redis sentinels (optional)
 //! * `json`: enables high-level interfaces for communication with the JSON module (optional)
+//! * `search`: enables support for the RediSearch (`FT.*`) commands (optional)
+//! * `timeseries`: enables support for the RedisTimeSeries (`TS.*`) commands (optional)
+//! * `msgpack`, `bincode`: enables the `codec::MsgPack` and `codec::Bincode` value wrappers, like `json` does for `codec::Json` (optional)
+//! * `derive`: enables the `FromRedisValue` and `ToRedisArgs` derive macros (optional)
 //! * `cache-aio`: enables **experimental** client side caching for MultiplexedConnection, ConnectionManager and async ClusterConnection (optional)
 //!
 //! ## Connection Parameters
@@ -238,6 +242,38 @@
 //! # }
 //! ```
 //!
+//! With the `derive` feature, the conversions of structs stored as hashes, of newtypes and of
+//! enums stored as strings can be derived:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "derive")]
+//! # fn do_something() -> redis::RedisResult<()> {
+//! # use redis::Commands;
+//! # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+//! # let mut con = client.get_connection().unwrap();
+//! #[derive(redis::FromRedisValue, redis::ToRedisArgs)]
+//! enum Role {
+//!     #[redis(rename = "admin")]
+//!     Admin,
+//!     #[redis(rename = "member")]
+//!     Member,
+//! }
+//!
+//! #[derive(redis::FromRedisValue, redis::ToRedisArgs)]
+//! struct User {
+//!     name: String,
+//!     role: Role,
+//!     #[redis(rename = "last-login")]
+//!     last_login: Option<u64>,
+//! }
+//!
+//! let user = User { name: "alice".to_string(), role: Role::Admin, last_login: None };
+//! redis::cmd("HSET").arg("user:1").arg(&user).exec(&mut con)?;
+//! let user: User = con.hgetall("user:1")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # RESP3 support
 //! Since Redis / Valkey version 6, a newer communication protocol called RESP3 is supported.
 //! Using this protocol allows the user both to receive a more varied `Value` results, for users
@@ -651,10 +687,14 @@ pub use crate::client::Client;
 #[cfg(feature = "cache-aio")]
 pub use crate::cmd::CommandCacheConfig;
 pub use crate::cmd::{Arg, Cmd, Iter, cmd, pack_command, pipe};
+pub use crate::commands::function::{Function, FunctionInvocation, Library};
 pub use crate::commands::{
+    BitRangeUnit, BitfieldOffset, BitfieldOps, BitfieldOverflow, BitfieldRoOps, BitfieldType,
     Commands, ControlFlow, CopyOptions, Direction, FlushAllOptions, FlushDbOptions,
-    HashFieldExpirationOptions, HotkeysCommands, LposOptions, MSetOptions, PubSubCommands,
-    ScanOptions, SetOptions, SortedSetAddOptions, TypedCommands, UpdateCheck,
+    HashFieldExpirationOptions, HotkeysCommands, LcsMatches, LcsOptions, LposOptions, MSetOptions,
+    PubSubCommands, ScanOptions, ServerCommands, SetOptions, SortOptions, SortOrder,
+    SortedSetAddOptions, TypedCommands, UpdateCheck, WaitAofReply, ZAggregate, ZAggregateOptions,
+    ZRangeOptions,
     hotkeys::{
         HOTKEYS_COUNT_MAX, HOTKEYS_COUNT_MIN, HotKeyEntry, HotkeysOptions, HotkeysResponse,
         SlotRange,
@@ -665,7 +705,7 @@ pub use crate::connection::{
     RedisConnectionInfo, TlsMode, parse_redis_url, transaction,
 };
 pub use crate::parser::{Parser, parse_redis_value};
-pub use crate::pipeline::Pipeline;
+pub use crate::pipeline::{Durability, DurabilityAck, Pipeline};
 #[cfg(feature = "script")]
 #[cfg_attr(docsrs, doc(cfg(feature = "script")))]
 pub use crate::script::{Script, ScriptInvocation};
@@ -689,9 +729,11 @@ pub use crate::types::{
     // utility functions
     from_redis_value_ref,
     from_redis_value,
+    from_redis_value_borrowed,
 
     // conversion traits
     FromRedisValue,
+    FromRedisValueRef,
 
     // utility types
     InfoDict,
@@ -721,6 +763,10 @@ pub use crate::types::{
 
 pub use crate::types::{calculate_value_digest, is_valid_16_bytes_hex_digest};
 
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use redis_derive::{FromRedisValue, ToRedisArgs};
+
 pub use crate::errors::{
     ErrorKind, ParsingError, RedisError, RetryMethod, ServerError, ServerErrorKind,
     make_extension_error,
@@ -744,6 +790,14 @@ pub use commands::acl;
 #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
 pub mod aio;
 
+pub use commands::function;
+
+pub use commands::server;
+
+pub use commands::migrate;
+
+pub use commands::keyspace;
+
 #[cfg(feature = "bloom")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bloom")))]
 pub mod bloom;
@@ -752,6 +806,13 @@ pub mod bloom;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub use crate::commands::{JsonCommands, json};
 
+#[cfg(any(feature = "json", feature = "msgpack", feature = "bincode"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "json", feature = "msgpack", feature = "bincode")))
+)]
+pub mod codec;
+
 #[cfg(all(feature = "json", feature = "aio"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "aio"))))]
 pub use crate::commands::JsonAsyncCommands;
@@ -760,10 +821,22 @@ pub use crate::commands::JsonAsyncCommands;
 #[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
 pub use crate::commands::AsyncHotkeysCommands;
 
+#[cfg(feature = "aio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aio")))]
+pub use crate::commands::AsyncServerCommands;
+
 #[cfg(feature = "vector-sets")]
 #[cfg_attr(docsrs, doc(cfg(feature = "vector-sets")))]
 pub use crate::commands::vector_sets;
 
+#[cfg(feature = "search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+pub use crate::commands::search;
+
+#[cfg(feature = "timeseries")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timeseries")))]
+pub use crate::commands::timeseries;
+
 #[cfg(feature = "geospatial")]
 #[cfg_attr(docsrs, doc(cfg(feature = "geospatial")))]
 pub use commands::geo;
@@ -788,6 +861,11 @@ pub use cluster_handling::routing as cluster_routing;
 #[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
 pub use cluster_handling::read_routing as cluster_read_routing;
 
+/// Typed replies of the cluster introspection commands.
+#[cfg(feature = "cluster")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cluster")))]
+pub use cluster_handling::introspection as cluster_introspection;
+
 #[cfg(feature = "r2d2")]
 #[cfg_attr(docsrs, doc(cfg(feature = "r2d2")))]
 mod r2d2;
@@ -839,6 +917,8 @@ mod errors;
 pub mod io;
 mod parser;
 mod script;
+#[cfg(test)]
+mod test_utils;
 mod types;
 
 macro_rules! check_resp3 {
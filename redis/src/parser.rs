@@ -356,17 +356,99 @@ macro_rules! to_redis_err {
 mod aio_support {
     use super::*;
 
-    use bytes::{Buf, BytesMut};
+    use bytes::{Buf, Bytes, BytesMut};
     use tokio::io::AsyncRead;
     use tokio_util::codec::{Decoder, Encoder};
 
+    // The longest valid bulk string header: `$`, 20 digits and `\r`.
+    const MAX_BULK_HEADER_LEN: usize = 22;
+
+    // How far ahead of the received data the buffer is grown for a large bulk string, so a
+    // bogus length in a header doesn't allocate memory the reply never fills.
+    const MAX_BULK_RESERVE: usize = 1024 * 1024;
+
+    // Parses the `$<length>\r\n` header at the start of `bytes`, returning the header's and the
+    // bulk string's lengths. `Some(None)` means more bytes are needed, and `None` that the buffer
+    // doesn't start with the header of a non-nil bulk string.
+    fn bulk_header(bytes: &[u8], eof: bool) -> Option<Option<(usize, usize)>> {
+        if bytes.first() != Some(&b'$') {
+            return None;
+        }
+        let header_len = bytes.len().min(MAX_BULK_HEADER_LEN);
+        let Some(cr) = bytes[..header_len].iter().position(|b| *b == b'\r') else {
+            return (header_len < MAX_BULK_HEADER_LEN && !eof).then_some(None);
+        };
+        let length = std::str::from_utf8(&bytes[1..cr]).ok()?.parse().ok()?;
+        match bytes.get(cr + 1) {
+            Some(b'\n') => Some(Some((cr + 2, length))),
+            Some(_) => None,
+            None => (!eof).then_some(None),
+        }
+    }
+
     #[derive(Default)]
     pub struct ValueCodec {
         state: AnySendSyncPartialState,
+        // Whether `state` holds a partially parsed value, which must be completed by the parser
+        // before values can be sliced out of the buffer.
+        in_progress: bool,
+        bulk_bytes_threshold: Option<usize>,
     }
 
     impl ValueCodec {
+        /// Top-level bulk strings at least `threshold` bytes long are returned as
+        /// [`Value::BulkBytes`] sharing the read buffer, instead of being copied out of it.
+        pub(crate) fn set_bulk_bytes_threshold(mut self, threshold: Option<usize>) -> Self {
+            self.bulk_bytes_threshold = threshold;
+            self
+        }
+
+        // Returns `None` when the buffer doesn't start with a bulk string of at least
+        // `threshold` bytes, so the parser handles it, including any protocol error.
+        fn decode_bulk_bytes(
+            &mut self,
+            bytes: &mut BytesMut,
+            threshold: usize,
+            eof: bool,
+        ) -> Option<Option<Value>> {
+            if self.in_progress {
+                return None;
+            }
+            let Some((data_start, length)) = bulk_header(bytes, eof)? else {
+                return Some(None);
+            };
+            if length < threshold {
+                return None;
+            }
+
+            let frame_len = data_start.checked_add(length)?.checked_add(2)?;
+            if bytes.len() < frame_len {
+                if eof {
+                    return None;
+                }
+                // Growing the buffer in large steps avoids copying the value on each
+                // reallocation.
+                bytes.reserve((frame_len - bytes.len()).min(MAX_BULK_RESERVE));
+                return Some(None);
+            }
+            if &bytes[frame_len - 2..frame_len] != b"\r\n" {
+                return None;
+            }
+
+            bytes.advance(data_start);
+            let data = bytes.split_to(length).freeze();
+            bytes.advance(2);
+            Some(Some(Value::BulkBytes(data)))
+        }
+
         fn decode_stream(&mut self, bytes: &mut BytesMut, eof: bool) -> RedisResult<Option<Value>> {
+            if let Some(threshold) = self.bulk_bytes_threshold
+                && let Some(result) = self.decode_bulk_bytes(bytes, threshold, eof)
+            {
+                return Ok(result);
+            }
+
+            let was_empty = bytes.is_empty();
             let (opt, removed_len) = {
                 let buffer = &bytes[..];
                 let mut stream =
@@ -384,6 +466,7 @@ mod aio_support {
             };
 
             bytes.advance(removed_len);
+            self.in_progress = opt.is_none() && !was_empty;
             match opt {
                 Some(result) => Ok(Some(result)),
                 None => Ok(None),
@@ -412,6 +495,139 @@ mod aio_support {
         }
     }
 
+    /// A reply read by [`StreamingValueCodec`], where streamed bulk strings are split into
+    /// their header, body chunks and end.
+    #[derive(Debug, PartialEq)]
+    pub(crate) enum ReplyFrame {
+        Value(Value),
+        // The start of a streamed bulk string, with its length.
+        BulkStart(usize),
+        // A non-empty part of a streamed bulk string, sliced out of the read buffer.
+        BulkChunk(Bytes),
+        BulkEnd,
+    }
+
+    #[derive(Default)]
+    enum StreamState {
+        #[default]
+        Idle,
+        // The next reply is streamed if it's a bulk string.
+        Requested,
+        Body {
+            remaining: usize,
+        },
+        // Only the `\r\n` terminating the bulk string is left.
+        Trailer,
+    }
+
+    /// Wraps [`ValueCodec`] to stream requested bulk string replies as they are read, instead of
+    /// buffering them whole.
+    pub(crate) struct StreamingValueCodec {
+        inner: ValueCodec,
+        state: StreamState,
+    }
+
+    impl StreamingValueCodec {
+        pub(crate) fn new(inner: ValueCodec) -> Self {
+            Self {
+                inner,
+                state: StreamState::Idle,
+            }
+        }
+
+        /// Streams the next reply if it's a bulk string. Out-of-band push messages preceding it
+        /// are decoded as usual. Must only be called between replies.
+        pub(crate) fn stream_next_reply(&mut self) {
+            debug_assert!(matches!(self.state, StreamState::Idle));
+            self.state = StreamState::Requested;
+        }
+
+        fn decode_frame(
+            &mut self,
+            bytes: &mut BytesMut,
+            eof: bool,
+        ) -> RedisResult<Option<ReplyFrame>> {
+            match self.state {
+                StreamState::Requested if !self.inner.in_progress => {
+                    if let Some(header) = bulk_header(bytes, eof) {
+                        let Some((data_start, length)) = header else {
+                            return Ok(None);
+                        };
+                        bytes.advance(data_start);
+                        self.state = match length {
+                            0 => StreamState::Trailer,
+                            remaining => StreamState::Body { remaining },
+                        };
+                        return Ok(Some(ReplyFrame::BulkStart(length)));
+                    }
+                }
+                StreamState::Body { remaining } => {
+                    if bytes.is_empty() {
+                        return Self::incomplete(eof);
+                    }
+                    let chunk = bytes.split_to(remaining.min(bytes.len())).freeze();
+                    self.state = match remaining - chunk.len() {
+                        0 => StreamState::Trailer,
+                        remaining => StreamState::Body { remaining },
+                    };
+                    return Ok(Some(ReplyFrame::BulkChunk(chunk)));
+                }
+                StreamState::Trailer => {
+                    if bytes.len() < 2 {
+                        return Self::incomplete(eof);
+                    }
+                    if &bytes[..2] != b"\r\n" {
+                        return Err(
+                            ParsingError::from("bulk string is missing its terminator").into()
+                        );
+                    }
+                    bytes.advance(2);
+                    self.state = StreamState::Idle;
+                    return Ok(Some(ReplyFrame::BulkEnd));
+                }
+                StreamState::Idle | StreamState::Requested => {}
+            }
+
+            let value = self.inner.decode_stream(bytes, eof)?;
+            // Replies other than bulk strings aren't streamed.
+            if matches!(self.state, StreamState::Requested)
+                && value
+                    .as_ref()
+                    .is_some_and(|value| !matches!(value, Value::Push { .. }))
+            {
+                self.state = StreamState::Idle;
+            }
+            Ok(value.map(ReplyFrame::Value))
+        }
+
+        fn incomplete(eof: bool) -> RedisResult<Option<ReplyFrame>> {
+            if eof {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            Ok(None)
+        }
+    }
+
+    impl Encoder<Vec<u8>> for StreamingValueCodec {
+        type Error = RedisError;
+        fn encode(&mut self, item: Vec<u8>, dst: &mut BytesMut) -> Result<(), Self::Error> {
+            self.inner.encode(item, dst)
+        }
+    }
+
+    impl Decoder for StreamingValueCodec {
+        type Item = ReplyFrame;
+        type Error = RedisError;
+
+        fn decode(&mut self, bytes: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+            self.decode_frame(bytes, false)
+        }
+
+        fn decode_eof(&mut self, bytes: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+            self.decode_frame(bytes, true)
+        }
+    }
+
     /// Parses a redis value asynchronously.
     pub async fn parse_redis_value_async<R>(
         decoder: &mut combine::stream::Decoder<AnySendSyncPartialState, PointerOffset<[u8]>>,
@@ -533,6 +749,198 @@ mod tests {
         assert_eq!(result, Value::Okay);
     }
 
+    #[cfg(feature = "aio")]
+    #[test]
+    fn decode_bulk_bytes_above_threshold() {
+        use tokio_util::codec::Decoder;
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+
+        let mut bytes = bytes::BytesMut::from(
+            b"$5\r\nhello\r\n$3\r\nfoo\r\n*1\r\n$5\r\nworld\r\n$-1\r\n".as_slice(),
+        );
+        let start = bytes.as_ptr();
+        let Some(Value::BulkBytes(hello)) = codec.decode(&mut bytes).unwrap() else {
+            panic!("expected bulk bytes");
+        };
+        assert_eq!(hello, b"hello".as_slice());
+        // The value is sliced out of the buffer instead of being copied.
+        assert_eq!(hello.as_ptr(), start.wrapping_add(4));
+
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(Value::BulkString(b"foo".to_vec())))
+        );
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(Value::Array(vec![Value::BulkString(
+                b"world".to_vec()
+            )])))
+        );
+        assert_eq!(codec.decode(&mut bytes), Ok(Some(Value::Nil)));
+        assert_eq!(codec.decode(&mut bytes), Ok(None));
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn decode_bulk_bytes_with_oversized_length() {
+        use tokio_util::codec::Decoder;
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+
+        let mut bytes = bytes::BytesMut::from(b"$18446744073709551615\r\n".as_slice());
+        assert!(codec.decode(&mut bytes).is_err());
+
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+        let mut bytes = bytes::BytesMut::from(b"$1000000000000\r\nhello".as_slice());
+        assert_eq!(codec.decode(&mut bytes), Ok(None));
+        // The buffer isn't grown to the claimed length before the data arrives.
+        assert!(bytes.capacity() <= 2 * 1024 * 1024);
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn decode_bulk_bytes_in_chunks() {
+        use tokio_util::codec::Decoder;
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+        let input = b"*1\r\n$5\r\nfirst\r\n$6\r\nsecond\r\n+OK\r\n";
+
+        let mut bytes = bytes::BytesMut::new();
+        let mut values = vec![];
+        for byte in input {
+            bytes.extend_from_slice(&[*byte]);
+            if let Some(value) = codec.decode(&mut bytes).unwrap() {
+                values.push(value);
+            }
+        }
+        assert_eq!(
+            values,
+            vec![
+                Value::Array(vec![Value::BulkString(b"first".to_vec())]),
+                Value::BulkBytes(bytes::Bytes::from_static(b"second")),
+                Value::Okay,
+            ]
+        );
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn decode_bulk_bytes_reports_protocol_errors() {
+        use tokio_util::codec::Decoder;
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+
+        let mut bytes = bytes::BytesMut::from(b"$5\r\nhello!!".as_slice());
+        assert!(codec.decode(&mut bytes).is_err());
+
+        let mut codec = ValueCodec::default().set_bulk_bytes_threshold(Some(4));
+        let mut bytes = bytes::BytesMut::from(b"$5\r\nhel".as_slice());
+        assert!(codec.decode_eof(&mut bytes).is_err());
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn streaming_codec_streams_requested_bulk_strings() {
+        use tokio_util::codec::Decoder;
+        let mut codec = StreamingValueCodec::new(ValueCodec::default());
+
+        let mut bytes = bytes::BytesMut::from(b"$3\r\nfoo\r\n$1".as_slice());
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::BulkString(b"foo".to_vec()))))
+        );
+
+        codec.stream_next_reply();
+        assert_eq!(codec.decode(&mut bytes), Ok(None));
+        bytes.extend_from_slice(b"1\r\nhello");
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::BulkStart(11)))
+        );
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::BulkChunk(bytes::Bytes::from_static(
+                b"hello"
+            ))))
+        );
+        assert_eq!(codec.decode(&mut bytes), Ok(None));
+        bytes.extend_from_slice(b" world\r");
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::BulkChunk(bytes::Bytes::from_static(
+                b" world"
+            ))))
+        );
+        assert_eq!(codec.decode(&mut bytes), Ok(None));
+        bytes.extend_from_slice(b"\n+OK\r\n");
+        assert_eq!(codec.decode(&mut bytes), Ok(Some(ReplyFrame::BulkEnd)));
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::Okay)))
+        );
+
+        codec.stream_next_reply();
+        let mut bytes = bytes::BytesMut::from(b"$0\r\n\r\n".as_slice());
+        assert_eq!(codec.decode(&mut bytes), Ok(Some(ReplyFrame::BulkStart(0))));
+        assert_eq!(codec.decode(&mut bytes), Ok(Some(ReplyFrame::BulkEnd)));
+        assert!(bytes.is_empty());
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn streaming_codec_decodes_other_replies() {
+        use tokio_util::codec::Decoder;
+        let mut codec = StreamingValueCodec::new(ValueCodec::default());
+
+        codec.stream_next_reply();
+        let mut bytes = bytes::BytesMut::from(
+            b">2\r\n$7\r\nmessage\r\n$2\r\nhi\r\n$-1\r\n$2\r\nhi\r\n".as_slice(),
+        );
+        assert_matches!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::Push { .. })))
+        );
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::Nil)))
+        );
+        // Only the requested reply is streamed.
+        assert_eq!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::BulkString(b"hi".to_vec()))))
+        );
+
+        codec.stream_next_reply();
+        let mut bytes = bytes::BytesMut::from(b"-ERR nope\r\n".as_slice());
+        assert_matches!(
+            codec.decode(&mut bytes),
+            Ok(Some(ReplyFrame::Value(Value::ServerError(_))))
+        );
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn streaming_codec_reports_truncated_bulk_strings() {
+        use tokio_util::codec::Decoder;
+        let mut codec = StreamingValueCodec::new(ValueCodec::default());
+
+        codec.stream_next_reply();
+        let mut bytes = bytes::BytesMut::from(b"$5\r\nhelloXY".as_slice());
+        assert_eq!(codec.decode(&mut bytes), Ok(Some(ReplyFrame::BulkStart(5))));
+        assert_matches!(codec.decode(&mut bytes), Ok(Some(ReplyFrame::BulkChunk(_))));
+        assert!(codec.decode(&mut bytes).is_err());
+
+        let mut codec = StreamingValueCodec::new(ValueCodec::default());
+        codec.stream_next_reply();
+        let mut bytes = bytes::BytesMut::from(b"$5\r\nhel".as_slice());
+        assert_eq!(
+            codec.decode_eof(&mut bytes),
+            Ok(Some(ReplyFrame::BulkStart(5)))
+        );
+        assert_matches!(
+            codec.decode_eof(&mut bytes),
+            Ok(Some(ReplyFrame::BulkChunk(_)))
+        );
+        assert!(codec.decode_eof(&mut bytes).is_err());
+    }
+
     #[test]
     fn parse_nested_error_and_handle_more_inputs() {
         // from https://redis.io/docs/interact/transactions/ -
@@ -3,8 +3,9 @@
 #[cfg(feature = "cache-aio")]
 use crate::cmd::CommandCacheConfig;
 use crate::cmd::{Cmd, cmd, cmd_len};
+use crate::commands::WaitAofReply;
 use crate::connection::ConnectionLike;
-use crate::errors::ErrorKind;
+use crate::errors::{ErrorKind, ParsingError, invalid_type_error};
 use crate::types::{FromRedisValue, HashSet, RedisResult, ToRedisArgs, Value, from_redis_value};
 
 /// Represents a redis command pipeline.
@@ -14,6 +15,91 @@ pub struct Pipeline {
     pub(crate) transaction_mode: bool,
     pub(crate) ignored_commands: HashSet<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) durability: Option<Durability>,
+}
+
+/// The acknowledgement a pipeline waits for after its commands, see [`Pipeline::durability`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Durability {
+    /// Wait until the writes reach `replicas` replicas, with [WAIT](https://redis.io/commands/wait).
+    Replicas {
+        /// The number of replicas to wait for.
+        replicas: usize,
+        /// The timeout in milliseconds, 0 blocks forever.
+        timeout: u64,
+    },
+    /// Wait until the writes are fsynced to the AOF of the local instance and of `replicas`
+    /// replicas, with [WAITAOF](https://redis.io/commands/waitaof).
+    Aof {
+        /// The number of local instances to wait for, 0 or 1.
+        local: usize,
+        /// The number of replicas to wait for.
+        replicas: usize,
+        /// The timeout in milliseconds, 0 blocks forever.
+        timeout: u64,
+    },
+}
+
+impl Durability {
+    /// Wait until the writes reach `replicas` replicas (`WAIT`).
+    pub fn replicas(replicas: usize, timeout: u64) -> Self {
+        Durability::Replicas { replicas, timeout }
+    }
+
+    /// Wait until the writes are fsynced to the local AOF if `local` is 1, and to the AOF of
+    /// `replicas` replicas (`WAITAOF`).
+    pub fn aof(local: usize, replicas: usize, timeout: u64) -> Self {
+        Durability::Aof {
+            local,
+            replicas,
+            timeout,
+        }
+    }
+
+    pub(crate) fn command(&self) -> Cmd {
+        match *self {
+            Durability::Replicas { replicas, timeout } => {
+                cmd("WAIT").arg(replicas).arg(timeout).take()
+            }
+            Durability::Aof {
+                local,
+                replicas,
+                timeout,
+            } => cmd("WAITAOF").arg(local).arg(replicas).arg(timeout).take(),
+        }
+    }
+}
+
+/// The acknowledgement reported after the results of a pipeline with
+/// [`Pipeline::durability`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurabilityAck {
+    /// The number of replicas that acknowledged the writes, for [`Durability::Replicas`].
+    Replicas(usize),
+    /// The reply of `WAITAOF`, for [`Durability::Aof`].
+    Aof(WaitAofReply),
+}
+
+impl DurabilityAck {
+    /// The number of replicas that acknowledged the writes.
+    pub fn replicas(&self) -> usize {
+        match *self {
+            DurabilityAck::Replicas(replicas) => replicas,
+            DurabilityAck::Aof(reply) => reply.replicas,
+        }
+    }
+}
+
+impl FromRedisValue for DurabilityAck {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::Int(_) => Ok(DurabilityAck::Replicas(from_redis_value(v)?)),
+            Value::Array(_) => Ok(DurabilityAck::Aof(from_redis_value(v)?)),
+            _ => invalid_type_error!(v, "expected a WAIT or WAITAOF reply"),
+        }
+    }
 }
 
 /// A pipeline allows you to send multiple commands in one go to the
@@ -51,6 +137,7 @@ impl Pipeline {
             transaction_mode: false,
             ignored_commands: HashSet::new(),
             ignore_errors: false,
+            durability: None,
         }
     }
 
@@ -130,7 +217,21 @@ impl Pipeline {
 
     /// Returns the encoded pipeline commands.
     pub fn get_packed_pipeline(&self) -> Vec<u8> {
-        encode_pipeline(&self.commands, self.transaction_mode)
+        encode_pipeline(
+            &self.commands,
+            self.transaction_mode,
+            self.durability.as_ref(),
+        )
+    }
+
+    /// The number of replies to skip and to read for this pipeline.
+    pub(crate) fn response_counts(&self) -> (usize, usize) {
+        let ack = usize::from(self.durability.is_some());
+        if self.transaction_mode {
+            (self.commands.len() + 1, 1 + ack)
+        } else {
+            (0, self.commands.len() + ack)
+        }
     }
 
     /// Returns the number of commands currently queued by the usr in the pipeline.
@@ -182,19 +283,8 @@ impl Pipeline {
             ));
         }
 
-        let response = if self.transaction_mode {
-            con.req_packed_commands(
-                &encode_pipeline(&self.commands, true),
-                self.commands.len() + 1,
-                1,
-            )?
-        } else {
-            con.req_packed_commands(
-                &encode_pipeline(&self.commands, false),
-                0,
-                self.commands.len(),
-            )?
-        };
+        let (offset, count) = self.response_counts();
+        let response = con.req_packed_commands(&self.get_packed_pipeline(), offset, count)?;
 
         self.complete_request(response)
     }
@@ -206,13 +296,8 @@ impl Pipeline {
         &self,
         con: &mut impl crate::aio::ConnectionLike,
     ) -> RedisResult<T> {
-        let response = if self.transaction_mode {
-            con.req_packed_commands(self, self.commands.len() + 1, 1)
-                .await?
-        } else {
-            con.req_packed_commands(self, 0, self.commands.len())
-                .await?
-        };
+        let (offset, count) = self.response_counts();
+        let response = con.req_packed_commands(self, offset, count).await?;
 
         self.complete_request(response)
     }
@@ -236,7 +321,11 @@ impl Pipeline {
     }
 
     fn complete_request<T: FromRedisValue>(&self, mut response: Vec<Value>) -> RedisResult<T> {
-        let response = if self.is_transaction() {
+        let ack = match self.durability {
+            Some(_) => response.pop(),
+            None => None,
+        };
+        let mut response = if self.is_transaction() {
             match response.pop() {
                 Some(Value::Nil) => {
                     return Ok(from_redis_value(Value::Nil)?);
@@ -253,14 +342,18 @@ impl Pipeline {
         } else {
             response
         };
+        response.extend(ack);
 
         self.compose_response(response)
     }
 }
 
-fn encode_pipeline(cmds: &[Cmd], atomic: bool) -> Vec<u8> {
+fn encode_pipeline(cmds: &[Cmd], atomic: bool, durability: Option<&Durability>) -> Vec<u8> {
     let mut rv = vec![];
     write_pipeline(&mut rv, cmds, atomic);
+    if let Some(durability) = durability {
+        durability.command().write_packed_command(&mut rv);
+    }
     rv
 }
 
@@ -309,6 +402,31 @@ macro_rules! implement_pipeline_commands {
                 self.commands.iter()
             }
 
+            /// Waits for the writes of the pipeline to be acknowledged, by sending `WAIT` or `WAITAOF`
+            /// after its commands (after `EXEC` in atomic mode). The
+            /// [`DurabilityAck`](crate::DurabilityAck) is reported after the results of the commands.
+            ///
+            /// In a cluster pipeline, the command is sent to every node that received writes, and
+            /// the lowest acknowledgement is reported.
+            ///
+            /// ```rust,no_run
+            /// # let client = redis::Client::open("redis://127.0.0.1/").unwrap();
+            /// # let mut con = client.get_connection().unwrap();
+            /// use redis::{Durability, DurabilityAck};
+            /// let (k1, ack) : (i32, DurabilityAck) = redis::pipe()
+            ///     .durability(Durability::replicas(1, 100))
+            ///     .cmd("SET").arg("key_1").arg(42).ignore()
+            ///     .cmd("INCR").arg("key_1").query(&mut con).unwrap();
+            /// if ack.replicas() < 1 {
+            ///     // the write may be lost on failover
+            /// }
+            /// ```
+            #[inline]
+            pub fn durability(&mut self, durability: crate::pipeline::Durability) -> &mut Self {
+                self.durability = Some(durability);
+                self
+            }
+
             /// Instructs the pipeline to ignore the return value of this command.
             ///
             /// On any successful result the value from this command is thrown away.
@@ -491,4 +609,53 @@ mod tests {
                 .is_err_and(|e| e.to_string().contains("CrossSlot"))
         )
     }
+
+    #[test]
+    fn test_pipeline_durability_is_sent_after_exec() {
+        let mut pipeline = test_pipe();
+        pipeline.atomic().durability(Durability::aof(1, 2, 100));
+        let mut expected = encode_pipeline(&pipeline.commands, true, None);
+        expected.extend(cmd("WAITAOF").arg(1).arg(2).arg(100).get_packed_command());
+
+        assert_eq!(pipeline.get_packed_pipeline(), expected);
+        assert_eq!(pipeline.response_counts(), (5, 2));
+    }
+
+    #[test]
+    fn test_pipeline_reports_durability_ack_after_results() {
+        let mut pipeline = test_pipe();
+        pipeline.durability(Durability::replicas(1, 0));
+        let inputs = vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3),
+            Value::Okay,
+            Value::Int(1),
+        ];
+        let (first, last, ack): (i64, String, DurabilityAck) =
+            pipeline.complete_request(inputs).unwrap();
+
+        assert_eq!((first, last.as_str()), (1, "OK"));
+        assert_eq!(ack, DurabilityAck::Replicas(1));
+        assert_eq!(pipeline.response_counts(), (0, 5));
+    }
+
+    #[test]
+    fn test_atomic_pipeline_reports_durability_ack_after_results() {
+        let mut pipeline = test_pipe();
+        pipeline.atomic().durability(Durability::aof(1, 0, 0));
+        let results = Value::Array(vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3),
+            Value::Okay,
+        ]);
+        let ack = Value::Array(vec![Value::Int(1), Value::Int(0)]);
+        let (first, last, ack): (i64, String, DurabilityAck) =
+            pipeline.complete_request(vec![results, ack]).unwrap();
+
+        assert_eq!((first, last.as_str()), (1, "OK"));
+        assert_eq!(ack.replicas(), 0);
+        assert!(matches!(ack, DurabilityAck::Aof(reply) if reply.local == 1));
+    }
 }
@@ -108,26 +108,36 @@ impl SubscriptionTracker {
 
     pub(crate) fn get_subscription_pipeline(&self) -> Pipeline {
         let mut pipeline = crate::pipe();
-        if !self.subscriptions.is_empty() {
-            let cmd = pipeline.cmd("SUBSCRIBE");
-            for channel in self.subscriptions.iter() {
-                cmd.arg(channel);
-            }
-        }
-        if !self.s_subscriptions.is_empty() {
-            let cmd = pipeline.cmd("SSUBSCRIBE");
-            for channel in self.s_subscriptions.iter() {
-                cmd.arg(channel);
-            }
-        }
-        if !self.p_subscriptions.is_empty() {
-            let cmd = pipeline.cmd("PSUBSCRIBE");
-            for channel in self.p_subscriptions.iter() {
+        add_subscription_cmds(&mut pipeline, "SUBSCRIBE", &self.subscriptions);
+        add_subscription_cmds(&mut pipeline, "SSUBSCRIBE", &self.s_subscriptions);
+        add_subscription_cmds(&mut pipeline, "PSUBSCRIBE", &self.p_subscriptions);
+
+        pipeline
+    }
+
+    /// Returns whether the channels of a command from [`Self::get_subscription_pipeline`] are
+    /// keyspace notification channels, which must be subscribed on every cluster node.
+    pub(crate) fn is_node_local(cmd: &Cmd) -> bool {
+        matches!(
+            cmd.args_iter().nth(1),
+            Some(Arg::Simple(channel)) if crate::keyspace::is_node_local_channel(channel)
+        )
+    }
+}
+
+// Node-local channels are subscribed with their own command, so that a cluster connection can
+// route them apart from the other channels.
+fn add_subscription_cmds(pipeline: &mut Pipeline, name: &str, channels: &HashSet<Vec<u8>>) {
+    let (node_local, others): (Vec<_>, Vec<_>) = channels
+        .iter()
+        .partition(|channel| crate::keyspace::is_node_local_channel(channel));
+    for channels in [others, node_local] {
+        if !channels.is_empty() {
+            let cmd = pipeline.cmd(name);
+            for channel in channels {
                 cmd.arg(channel);
             }
         }
-
-        pipeline
     }
 }
 
@@ -187,6 +197,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_separate_node_local_subscriptions() {
+        let mut tracker = SubscriptionTracker::default();
+
+        tracker.update_with_cmd(cmd("PSUBSCRIBE").arg("__keys*").arg("__keyspace@0__:*"));
+
+        let result = tracker.get_subscription_pipeline();
+        let mut expected = pipe();
+        expected
+            .cmd("PSUBSCRIBE")
+            .arg("__keys*")
+            .cmd("PSUBSCRIBE")
+            .arg("__keyspace@0__:*");
+        assert_eq!(
+            result.get_packed_pipeline(),
+            expected.get_packed_pipeline(),
+            "{}",
+            String::from_utf8(result.get_packed_pipeline()).unwrap()
+        );
+        let node_local: Vec<_> = result
+            .cmd_iter()
+            .map(SubscriptionTracker::is_node_local)
+            .collect();
+        assert_eq!(node_local, [false, true]);
+    }
+
     #[test]
     fn test_only_unsubscribe_from_existing_subscriptions() {
         let mut tracker = SubscriptionTracker::default();
@@ -0,0 +1,30 @@
+//! Helpers shared by the unit tests.
+
+// Each helper is only used by the tests of some features.
+#![allow(dead_code)]
+
+use crate::{Arg, Cmd, ToRedisArgs, Value};
+
+/// Builds a bulk string reply holding `s`.
+pub(crate) fn bulk(s: &str) -> Value {
+    Value::BulkString(s.as_bytes().to_vec())
+}
+
+/// Returns the arguments written by `value`, which must be valid UTF-8.
+pub(crate) fn args<T: ToRedisArgs>(value: T) -> Vec<String> {
+    value
+        .to_redis_args()
+        .into_iter()
+        .map(|arg| String::from_utf8(arg).unwrap())
+        .collect()
+}
+
+/// Returns the arguments of `cmd`, with cursors written as `<cursor>`.
+pub(crate) fn cmd_args(cmd: &Cmd) -> Vec<String> {
+    cmd.args_iter()
+        .map(|arg| match arg {
+            Arg::Simple(arg) => String::from_utf8_lossy(arg).into_owned(),
+            Arg::Cursor => "<cursor>".to_string(),
+        })
+        .collect()
+}
@@ -152,6 +152,15 @@ pub enum Value {
     Int(i64),
     /// An arbitrary binary data, usually represents a binary-safe string.
     BulkString(Vec<u8>),
+    /// A binary-safe string that shares the read buffer of the connection instead of being
+    /// copied out of it.
+    ///
+    /// This is only produced for top-level replies of async connections, when the bulk string
+    /// is at least as long as the threshold set with
+    /// [`AsyncConnectionConfig::set_bulk_bytes_threshold`](crate::AsyncConnectionConfig::set_bulk_bytes_threshold).
+    /// It converts like a [`Value::BulkString`], and without copying into [`bytes::Bytes`].
+    #[cfg(feature = "bytes")]
+    BulkBytes(bytes::Bytes),
     /// A response containing an array with more data. This is generally used by redis
     /// to express nested structures.
     Array(Vec<Value>),
@@ -458,7 +467,7 @@ impl Value {
                 if items.len() != 2 {
                     return false;
                 }
-                matches!(items[0], Value::BulkString(_)) && matches!(items[1], Value::Array(_))
+                items[0].as_bulk_string().is_some() && matches!(items[1], Value::Array(_))
             }
             _ => false,
         }
@@ -514,6 +523,27 @@ impl Value {
         }
     }
 
+    /// Returns an `&[u8]` if `self` is a bulk string, whether it owns its bytes or shares the
+    /// read buffer as a `BulkBytes` value.
+    pub fn as_bulk_string(&self) -> Option<&[u8]> {
+        match self {
+            Value::BulkString(bytes) => Some(bytes),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns a `Vec<u8>` if `self` is a bulk string, otherwise returns `Err(self)`.
+    pub fn into_bulk_string(self) -> Result<Vec<u8>, Value> {
+        match self {
+            Value::BulkString(bytes) => Ok(bytes),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(bytes) => Ok(bytes.into()),
+            _ => Err(self),
+        }
+    }
+
     /// If value contains a server error, return it as an Err. Otherwise wrap the value in Ok.
     pub fn extract_error(self) -> RedisResult<Self> {
         match self {
@@ -571,6 +601,11 @@ impl fmt::Debug for Value {
                 Ok(x) => write!(fmt, "bulk-string('{x:?}')"),
                 Err(_) => write!(fmt, "binary-data({val:?})"),
             },
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref val) => match from_utf8(val) {
+                Ok(x) => write!(fmt, "bulk-string('{x:?}')"),
+                Err(_) => write!(fmt, "binary-data({val:?})"),
+            },
             Value::Array(ref values) => write!(fmt, "array({values:?})"),
             Value::Push { ref kind, ref data } => write!(fmt, "push({kind:?}, {data:?})"),
             Value::Okay => write!(fmt, "ok"),
@@ -1582,8 +1617,8 @@ fn vec_to_array<T, const N: usize>(
 
 impl<T: FromRedisValue, const N: usize> FromRedisValue for [T; N] {
     fn from_redis_value_ref(value: &Value) -> Result<[T; N], ParsingError> {
-        match *value {
-            Value::BulkString(ref bytes) => match FromRedisValue::from_byte_slice(bytes) {
+        if let Some(bytes) = value.as_bulk_string() {
+            return match FromRedisValue::from_byte_slice(bytes) {
                 Some(items) => vec_to_array(items, value),
                 None => {
                     let msg = format!(
@@ -1592,7 +1627,9 @@ impl<T: FromRedisValue, const N: usize> FromRedisValue for [T; N] {
                     );
                     crate::errors::invalid_type_error!(value, msg)
                 }
-            },
+            };
+        }
+        match *value {
             Value::Array(ref items) => {
                 let items = FromRedisValue::from_redis_value_refs(items)?;
                 vec_to_array(items, value)
@@ -1722,6 +1759,11 @@ macro_rules! from_redis_value_for_num_internal {
                 Ok(rv) => Ok(rv),
                 Err(_) => crate::errors::invalid_type_error!(v, "Could not convert from string."),
             },
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref bytes) => match from_utf8(bytes)?.parse::<$t>() {
+                Ok(rv) => Ok(rv),
+                Err(_) => crate::errors::invalid_type_error!(v, "Could not convert from string."),
+            },
             Value::Double(val) => Ok(val as $t),
             _ => crate::errors::invalid_type_error!(v, "Response type not convertible to numeric."),
         }
@@ -1794,6 +1836,11 @@ macro_rules! from_redis_value_for_bignum_internal {
                 Ok(rv) => Ok(rv),
                 Err(_) => crate::errors::invalid_type_error!(v, "Could not convert from string."),
             },
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref bytes) => match from_utf8(bytes)?.parse::<$t>() {
+                Ok(rv) => Ok(rv),
+                Err(_) => crate::errors::invalid_type_error!(v, "Could not convert from string."),
+            },
             _ => crate::errors::invalid_type_error!(v, "Response type not convertible to numeric."),
         }
     }};
@@ -1830,6 +1877,13 @@ from_redis_value_for_bignum!(num_bigint::BigUint);
 impl FromRedisValue for bool {
     fn from_redis_value_ref(v: &Value) -> Result<bool, ParsingError> {
         let v = get_inner_value(v);
+        if let Some(bytes) = v.as_bulk_string() {
+            return match bytes {
+                b"1" => Ok(true),
+                b"0" => Ok(false),
+                _ => crate::errors::invalid_type_error!(v, "Response type not bool compatible."),
+            };
+        }
         match *v {
             Value::Nil => Ok(false),
             Value::Int(val) => Ok(val != 0),
@@ -1842,15 +1896,6 @@ impl FromRedisValue for bool {
                     crate::errors::invalid_type_error!(v, "Response status not valid boolean");
                 }
             }
-            Value::BulkString(ref bytes) => {
-                if bytes == b"1" {
-                    Ok(true)
-                } else if bytes == b"0" {
-                    Ok(false)
-                } else {
-                    crate::errors::invalid_type_error!(v, "Response type not bool compatible.");
-                }
-            }
             Value::Boolean(b) => Ok(b),
             Value::Okay => Ok(true),
             _ => crate::errors::invalid_type_error!(v, "Response type not bool compatible."),
@@ -1867,6 +1912,8 @@ impl FromRedisValue for CString {
         let v = get_inner_value(v);
         match *v {
             Value::BulkString(ref bytes) => Ok(CString::new(bytes.as_slice())?),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref bytes) => Ok(CString::new(bytes.as_ref())?),
             Value::Okay => Ok(CString::new("OK")?),
             Value::SimpleString(ref val) => Ok(CString::new(val.as_bytes())?),
             _ => crate::errors::invalid_type_error!(v, "Response type not CString compatible."),
@@ -1876,6 +1923,8 @@ impl FromRedisValue for CString {
         let v = get_owned_inner_value(v);
         match v {
             Value::BulkString(bytes) => Ok(CString::new(bytes)?),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(bytes) => Ok(CString::new(Vec::from(bytes))?),
             Value::Okay => Ok(CString::new("OK")?),
             Value::SimpleString(val) => Ok(CString::new(val)?),
             _ => crate::errors::invalid_type_error!(v, "Response type not CString compatible."),
@@ -1888,6 +1937,8 @@ impl FromRedisValue for String {
         let v = get_inner_value(v);
         match *v {
             Value::BulkString(ref bytes) => Ok(from_utf8(bytes)?.to_string()),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref bytes) => Ok(from_utf8(bytes)?.to_string()),
             Value::Okay => Ok("OK".to_string()),
             Value::SimpleString(ref val) => Ok(val.to_string()),
             Value::VerbatimString {
@@ -1904,6 +1955,8 @@ impl FromRedisValue for String {
         let v = get_owned_inner_value(v);
         match v {
             Value::BulkString(bytes) => Ok(Self::from_utf8(bytes)?),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(bytes) => Ok(Self::from_utf8(bytes.into())?),
             Value::Okay => Ok("OK".to_string()),
             Value::SimpleString(val) => Ok(val),
             Value::VerbatimString { format: _, text } => Ok(text),
@@ -1959,6 +2012,14 @@ macro_rules! from_vec_from_redis_value {
                             format!("Conversion to {} failed.", std::any::type_name::<$Type>())
                         ),
                     },
+                    #[cfg(feature = "bytes")]
+                    Value::BulkBytes(bytes) => match FromRedisValue::from_byte_slice(bytes) {
+                        Some(x) => Ok($convert(x)),
+                        None => crate::errors::invalid_type_error!(
+                            v,
+                            format!("Conversion to {} failed.", std::any::type_name::<$Type>())
+                        ),
+                    },
                     Value::Array(items) => FromRedisValue::from_redis_value_refs(items).map($convert),
                     Value::Set(items) => FromRedisValue::from_redis_value_refs(items).map($convert),
                     Value::Map(items) => {
@@ -1985,6 +2046,8 @@ macro_rules! from_vec_from_redis_value {
                     // for the element type `u8`, which directly consumes the entire
                     // array of bytes.
                     Value::BulkString(bytes) => FromRedisValue::from_byte_vec(bytes).map($convert),
+                    #[cfg(feature = "bytes")]
+                    Value::BulkBytes(bytes) => FromRedisValue::from_byte_vec(bytes.into()).map($convert),
                     Value::Array(items) => FromRedisValue::from_redis_values(items).map($convert),
                     Value::Set(items) => FromRedisValue::from_redis_values(items).map($convert),
                     Value::Map(items) => {
@@ -2390,6 +2453,7 @@ impl FromRedisValue for bytes::Bytes {
         let v = get_inner_value(v);
         match v {
             Value::BulkString(bytes_vec) => Ok(bytes::Bytes::copy_from_slice(bytes_vec.as_ref())),
+            Value::BulkBytes(bytes) => Ok(bytes.clone()),
             _ => crate::errors::invalid_type_error!(v, "Not a bulk string"),
         }
     }
@@ -2397,6 +2461,7 @@ impl FromRedisValue for bytes::Bytes {
         let v = get_owned_inner_value(v);
         match v {
             Value::BulkString(bytes_vec) => Ok(bytes_vec.into()),
+            Value::BulkBytes(bytes) => Ok(bytes),
             _ => crate::errors::invalid_type_error!(v, "Not a bulk string"),
         }
     }
@@ -2407,6 +2472,8 @@ impl FromRedisValue for uuid::Uuid {
     fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
         match *v {
             Value::BulkString(ref bytes) => Ok(uuid::Uuid::from_slice(bytes)?),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(ref bytes) => Ok(uuid::Uuid::from_slice(bytes)?),
             _ => crate::errors::invalid_type_error!(v, "Response type not uuid compatible."),
         }
     }
@@ -2441,6 +2508,309 @@ pub fn from_redis_value<T: FromRedisValue>(v: Value) -> Result<T, ParsingError>
     FromRedisValue::from_redis_value(v)
 }
 
+/// This trait is used to convert a redis value into a type that borrows from it.
+///
+/// Unlike [`FromRedisValue`], which always produces owned data, this allows decoding replies
+/// into types like `&str`, `&[u8]` or `HashMap<&str, &[u8]>` without copying each string out of
+/// the value. Query the reply, or the replies of a pipeline, as a [`Value`] first, then borrow
+/// from it:
+///
+/// ```rust,no_run
+/// # fn do_something(con: &mut redis::Connection) -> redis::RedisResult<()> {
+/// use std::collections::HashMap;
+/// use redis::{Commands, Value, from_redis_value_borrowed};
+///
+/// let reply: Value = con.hgetall("my_hash")?;
+/// let fields: HashMap<&str, &[u8]> = from_redis_value_borrowed(&reply)?;
+///
+/// let replies: Value = redis::pipe().get("a").hgetall("my_hash").query(con)?;
+/// let (a, fields): (Option<&str>, Vec<(&str, i64)>) = from_redis_value_borrowed(&replies)?;
+/// # Ok(()) }
+/// ```
+///
+/// Owned scalar types like integers and `String` are supported as well, so they can be mixed with
+/// borrowed ones in tuples and collections. Binary data should be read as `&[u8]`, as `Vec<u8>`
+/// is read like any other vector, from an array reply.
+pub trait FromRedisValueRef<'a>: Sized {
+    /// Given a redis `Value` this attempts to convert it into the given
+    /// destination type, borrowing from the value where possible.
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError>;
+
+    /// Similar to `from_redis_value_borrowed` but constructs a vector of objects
+    /// from a slice of values.  This primarily exists internally to customize
+    /// the behavior for vectors of tuples.
+    fn from_redis_values_borrowed(items: &'a [Value]) -> Result<Vec<Self>, ParsingError> {
+        items
+            .iter()
+            .map(FromRedisValueRef::from_redis_value_borrowed)
+            .collect()
+    }
+
+    /// Constructs a vector of objects from the entries of a map.  This primarily
+    /// exists internally to read maps into vectors of pairs.
+    fn from_redis_map_entries_borrowed(
+        _entries: &'a [(Value, Value)],
+    ) -> Result<Vec<Self>, ParsingError> {
+        Err("Map response can only be converted into a vector of pairs".into())
+    }
+}
+
+impl<'a> FromRedisValueRef<'a> for &'a [u8] {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        let v = get_inner_value(v);
+        match v {
+            Value::BulkString(bytes) => Ok(bytes),
+            #[cfg(feature = "bytes")]
+            Value::BulkBytes(bytes) => Ok(bytes),
+            Value::SimpleString(val) => Ok(val.as_bytes()),
+            Value::VerbatimString { format: _, text } => Ok(text.as_bytes()),
+            Value::Okay => Ok(b"OK"),
+            _ => crate::errors::invalid_type_error!(v, "Response type not bytes compatible."),
+        }
+    }
+}
+
+impl<'a> FromRedisValueRef<'a> for &'a str {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        let v = get_inner_value(v);
+        match v {
+            Value::SimpleString(val) => Ok(val),
+            Value::VerbatimString { format: _, text } => Ok(text),
+            _ => match <&[u8]>::from_redis_value_borrowed(v) {
+                Ok(bytes) => Ok(from_utf8(bytes)?),
+                Err(_) => {
+                    crate::errors::invalid_type_error!(v, "Response type not string compatible.")
+                }
+            },
+        }
+    }
+}
+
+impl<'a> FromRedisValueRef<'a> for Cow<'a, str> {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        match <&str>::from_redis_value_borrowed(v) {
+            Ok(val) => Ok(Cow::Borrowed(val)),
+            Err(_) => String::from_redis_value_ref(v).map(Cow::Owned),
+        }
+    }
+}
+
+impl<'a> FromRedisValueRef<'a> for Cow<'a, [u8]> {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        <&[u8]>::from_redis_value_borrowed(v).map(Cow::Borrowed)
+    }
+}
+
+impl<'a> FromRedisValueRef<'a> for &'a Value {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        Ok(v)
+    }
+}
+
+macro_rules! from_redis_value_ref_for_owned {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl<'a> FromRedisValueRef<'a> for $t {
+                fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+                    FromRedisValue::from_redis_value_ref(v)
+                }
+            }
+        )*
+    };
+}
+
+from_redis_value_ref_for_owned!(
+    u8,
+    i8,
+    i16,
+    u16,
+    i32,
+    u32,
+    i64,
+    u64,
+    i128,
+    u128,
+    f32,
+    f64,
+    isize,
+    usize,
+    bool,
+    String,
+    Value,
+    (),
+);
+
+impl<'a, T: FromRedisValueRef<'a>> FromRedisValueRef<'a> for Option<T> {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        match get_inner_value(v) {
+            Value::Nil => Ok(None),
+            v => T::from_redis_value_borrowed(v).map(Some),
+        }
+    }
+}
+
+impl<'a, T: FromRedisValueRef<'a>> FromRedisValueRef<'a> for Vec<T> {
+    fn from_redis_value_borrowed(v: &'a Value) -> Result<Self, ParsingError> {
+        let v = get_inner_value(v);
+        match v {
+            Value::Array(items) | Value::Set(items) => T::from_redis_values_borrowed(items),
+            Value::Map(entries) => T::from_redis_map_entries_borrowed(entries),
+            Value::Nil => Ok(vec![]),
+            _ => crate::errors::invalid_type_error!(v, "Response type not vector compatible."),
+        }
+    }
+}
+
+macro_rules! impl_from_redis_value_ref_for_map {
+    (for <$($TypeParam:ident),+> $MapType:ty, where ($($WhereClause:tt)+)) => {
+        impl<'a, $($TypeParam),+ > FromRedisValueRef<'a> for $MapType
+        where
+            $($WhereClause)+
+        {
+            fn from_redis_value_borrowed(v: &'a Value) -> Result<$MapType, ParsingError> {
+                let v = get_inner_value(v);
+                match v {
+                    Value::Nil => Ok(Default::default()),
+                    _ => v
+                        .as_map_iter()
+                        .ok_or_else(|| crate::errors::invalid_type_error_inner!(v, "Response type not map compatible"))?
+                        .map(|(k, v)| {
+                            Ok((
+                                FromRedisValueRef::from_redis_value_borrowed(k)?,
+                                FromRedisValueRef::from_redis_value_borrowed(v)?,
+                            ))
+                        })
+                        .collect(),
+                }
+            }
+        }
+    };
+}
+
+impl_from_redis_value_ref_for_map!(
+    for <K, V, S> std::collections::HashMap<K, V, S>,
+    where (K: FromRedisValueRef<'a> + Eq + Hash, V: FromRedisValueRef<'a>, S: BuildHasher + Default)
+);
+
+impl_from_redis_value_ref_for_map!(
+    for <K, V> std::collections::BTreeMap<K, V>,
+    where (K: FromRedisValueRef<'a> + Eq + Ord, V: FromRedisValueRef<'a>)
+);
+
+#[cfg(feature = "hashbrown")]
+impl_from_redis_value_ref_for_map!(
+    for <K, V, S> hashbrown::HashMap<K, V, S>,
+    where (K: FromRedisValueRef<'a> + Eq + Hash, V: FromRedisValueRef<'a>, S: BuildHasher + Default)
+);
+
+#[cfg(feature = "ahash")]
+impl_from_redis_value_ref_for_map!(
+    for <K, V> ahash::AHashMap<K, V>,
+    where (K: FromRedisValueRef<'a> + Eq + Hash, V: FromRedisValueRef<'a>)
+);
+
+macro_rules! from_redis_value_ref_for_tuple {
+    () => ();
+    ($(#[$meta:meta],)*$($name:ident,)+) => (
+        $(#[$meta])*
+        impl<'a, $($name: FromRedisValueRef<'a>),*> FromRedisValueRef<'a> for ($($name,)*) {
+            // we have local variables named T1 as dummies and those
+            // variables are unused.
+            #[allow(non_snake_case, unused_variables)]
+            fn from_redis_value_borrowed(v: &'a Value) -> Result<($($name,)*), ParsingError> {
+                let v = get_inner_value(v);
+                // hacky way to count the tuple size
+                let mut n = 0;
+                $(let $name = (); n += 1;)*
+
+                match v {
+                    Value::Array(items) | Value::Set(items) => {
+                        if items.len() != n {
+                            crate::errors::invalid_type_error!(v, "Array response of wrong dimension")
+                        }
+                        let mut items = items.iter();
+                        Ok(($({let $name = (); FromRedisValueRef::from_redis_value_borrowed(
+                            items.next().unwrap())?},)*))
+                    }
+
+                    Value::Map(items) => {
+                        if n != items.len() * 2 {
+                            crate::errors::invalid_type_error!(v, "Map response of wrong dimension")
+                        }
+                        let mut items = items.iter().flat_map(|(a, b)| [a, b]);
+                        Ok(($({let $name = (); FromRedisValueRef::from_redis_value_borrowed(
+                            items.next().unwrap())?},)*))
+                    }
+
+                    _ => crate::errors::invalid_type_error!(v, "Not a Array response")
+                }
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn from_redis_values_borrowed(items: &'a [Value]) -> Result<Vec<($($name,)*)>, ParsingError> {
+                // hacky way to count the tuple size
+                let mut n = 0;
+                $(let $name = (); n += 1;)*
+
+                if items.iter().all(|item| item.is_collection_of_len(n)) {
+                    return items.iter().map(FromRedisValueRef::from_redis_value_borrowed).collect();
+                }
+
+                let mut rv = Vec::with_capacity(items.len() / n);
+                for chunk in items.chunks(n) {
+                    match chunk {
+                        [$($name),*] => rv.push(($(FromRedisValueRef::from_redis_value_borrowed($name)?,)*)),
+                         _ => return Err(format!("Vector of length {} doesn't have arity of {n}", items.len()).into()),
+                    }
+                }
+                Ok(rv)
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn from_redis_map_entries_borrowed(
+                entries: &'a [(Value, Value)],
+            ) -> Result<Vec<($($name,)*)>, ParsingError> {
+                // hacky way to count the tuple size
+                let mut n = 0;
+                $(let $name = (); n += 1;)*
+                if n != 2 {
+                    return Err(format!("Map entries don't have arity of {n}").into());
+                }
+
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        let mut items = [key, value].into_iter();
+                        Ok(($({let $name = (); FromRedisValueRef::from_redis_value_borrowed(
+                            items.next().unwrap())?},)*))
+                    })
+                    .collect()
+            }
+        }
+    )
+}
+
+from_redis_value_ref_for_tuple! { #[cfg_attr(docsrs, doc(fake_variadic))], #[doc = "This trait is implemented for tuples up to 12 items long."], T, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, T8, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, T8, T9, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, }
+from_redis_value_ref_for_tuple! { #[doc(hidden)], T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, }
+
+/// A shortcut function to invoke `FromRedisValueRef::from_redis_value_borrowed`
+/// to make the API slightly nicer.
+pub fn from_redis_value_borrowed<'a, T: FromRedisValueRef<'a>>(
+    v: &'a Value,
+) -> Result<T, ParsingError> {
+    FromRedisValueRef::from_redis_value_borrowed(v)
+}
+
 /// Calculates a digest/hash of the given value for use with Redis value comparison operations.
 /// This function uses the XXH3 algorithm, which is the same algorithm used by Redis for its DIGEST command.
 /// The resulting digest can be used with `ValueComparison::IFDEQ` and `ValueComparison::IFDNE`.
@@ -405,6 +405,12 @@ where
     }
     Ok(())
 }
+
+/// Builds a bulk string reply holding `s`.
+pub fn bulk(s: &str) -> Value {
+    Value::BulkString(s.as_bytes().to_vec())
+}
+
 pub fn encode_value<W>(value: &Value, writer: &mut W) -> io::Result<()>
 where
     W: io::Write,
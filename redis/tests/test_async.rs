@@ -12,9 +12,10 @@ mod basic_async {
     #[cfg(feature = "json")]
     use redis::JsonAsyncCommands;
     use redis::{
-        AsyncCommands, ErrorKind, IntoConnectionInfo, ParsingError, ProtocolVersion, PushKind,
-        RedisConnectionInfo, RedisError, RedisResult, ScanOptions, ServerErrorKind, Value,
-        aio::ConnectionLike, cmd, pipe,
+        AsyncCommands, AsyncServerCommands, Durability, DurabilityAck, ErrorKind,
+        IntoConnectionInfo, ParsingError, ProtocolVersion, PushKind, RedisConnectionInfo,
+        RedisError, RedisResult, ScanOptions, ServerErrorKind, Value, aio::ConnectionLike, cmd,
+        pipe,
     };
     use redis_test::redis_value;
     #[cfg(feature = "json")]
@@ -154,6 +155,355 @@ mod basic_async {
         };
     }
 
+    #[async_test]
+    async fn test_server_commands() {
+        let ctx = TestContext::new();
+        let mut conn = ctx.async_connection().await.unwrap();
+
+        let _: () = conn.set("key", "value").await.unwrap();
+        assert_eq!(conn.dbsize().await, Ok(1));
+        let config = conn.config_get("maxmemory*").await.unwrap();
+        assert!(config.contains_key("maxmemory-policy"));
+        assert_eq!(conn.memory_stats().await.unwrap().keys_count, 1);
+        let info = conn.info(&["keyspace"]).await.unwrap();
+        assert!(info.get::<String>("db0").unwrap().starts_with("keys=1,"));
+    }
+
+    #[async_test]
+    async fn test_keyspace_notifications() {
+        use redis::keyspace::{KeyspaceEvent, KeyspaceSubscription};
+
+        let ctx = TestContext::new();
+        let mut con = ctx.async_connection().await.unwrap();
+        let mut pubsub = ctx.async_pubsub().await.unwrap();
+        KeyspaceSubscription::new()
+            .keys("key")
+            .notify_keyspace_events("K$")
+            .subscribe_async(&mut pubsub)
+            .await
+            .unwrap();
+
+        let _: () = con.set("key", 1).await.unwrap();
+
+        let msg = pubsub.on_message().next().await.unwrap();
+        let event = KeyspaceEvent::from_msg(&msg).unwrap();
+        assert_eq!(event.key, b"key");
+        assert_eq!(event.event, "set");
+    }
+
+    #[async_test]
+    async fn test_pipeline_durability() {
+        async fn test(mut conn: impl ConnectionLike + Send + Sync) {
+            let (value, ack): (i64, DurabilityAck) = pipe()
+                .durability(Durability::replicas(0, 100))
+                .set("key", 1)
+                .ignore()
+                .incr("key", 1)
+                .query_async(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(value, 2);
+            assert_eq!(ack.replicas(), 0);
+            let replicas: usize = conn.wait(0, 100).await.unwrap();
+            assert_eq!(replicas, 0);
+        }
+        let ctx = TestContext::new();
+
+        test(ctx.async_connection().await.unwrap()).await;
+
+        #[cfg(feature = "connection-manager")]
+        test(ctx.client.get_connection_manager().await.unwrap()).await;
+    }
+
+    #[cfg(feature = "streams")]
+    #[async_test]
+    async fn test_stream_consumer() {
+        use redis::streams::{StreamConsumer, StreamConsumerOptions, StreamPendingReply};
+
+        async fn test(mut conn: impl ConnectionLike + Clone + Send + Sync + 'static) {
+            let options = StreamConsumerOptions::new("group", "consumer").start_id("0");
+            let consumer = StreamConsumer::new(conn.clone(), "stream", options)
+                .await
+                .unwrap();
+            let id: String = conn.xadd("stream", "*", &[("field", "1")]).await.unwrap();
+
+            let mut deliveries = Box::pin(consumer.into_stream());
+            let delivery = deliveries.next().await.unwrap().unwrap();
+            assert_eq!(delivery.entry.id, id);
+            assert_eq!(delivery.entry.delivered_count, Some(1));
+            assert_eq!(delivery.entry.get("field"), Some(1));
+            delivery.ack().await.unwrap();
+
+            let pending: StreamPendingReply = conn.xpending("stream", "group").await.unwrap();
+            assert_eq!(pending.count(), 0);
+            let _: () = conn.del("stream").await.unwrap();
+        }
+        let ctx = TestContext::new();
+
+        test(ctx.async_connection().await.unwrap()).await;
+
+        #[cfg(feature = "connection-manager")]
+        test(ctx.client.get_connection_manager().await.unwrap()).await;
+    }
+
+    #[cfg(feature = "streams")]
+    #[async_test]
+    async fn test_stream_consumer_block_longer_than_response_timeout() {
+        use redis::streams::{StreamConsumer, StreamConsumerOptions};
+
+        let ctx = TestContext::new();
+        let config = redis::AsyncConnectionConfig::new()
+            .set_response_timeout(Some(Duration::from_millis(100)));
+        let mut con = ctx
+            .client
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+            .unwrap();
+        let options = StreamConsumerOptions::new("group", "consumer").block(Duration::from_secs(1));
+        let mut consumer = StreamConsumer::new(con.clone(), "stream", options)
+            .await
+            .unwrap();
+
+        // The entry is added after several reads, which would have timed out with a 1s BLOCK.
+        let mut writer = con.clone();
+        let (delivery, id) = futures::join!(consumer.next_delivery(), async move {
+            sleep(Duration::from_millis(300).into()).await;
+            let id: String = writer.xadd("stream", "*", &[("field", "1")]).await.unwrap();
+            id
+        });
+        assert_eq!(delivery.unwrap().entry.id, id);
+        let _: () = con.del("stream").await.unwrap();
+    }
+
+    #[cfg(feature = "streams")]
+    #[async_test]
+    async fn test_stream_consumer_dead_letter() {
+        use redis::streams::{
+            DEAD_LETTER_DELIVERIES, DEAD_LETTER_SOURCE_ID, StreamConsumer, StreamConsumerOptions,
+            StreamPendingReply, StreamRangeReply,
+        };
+
+        let ctx = TestContext::new();
+        let mut con = ctx.async_connection().await.unwrap();
+        let options = StreamConsumerOptions::new("group", "consumer")
+            .start_id("0")
+            .claim_idle(Duration::ZERO)
+            .claim_interval(Duration::ZERO)
+            .dead_letter("dead", 2);
+        let mut consumer = StreamConsumer::new(con.clone(), "stream", options)
+            .await
+            .unwrap();
+        let id: String = con.xadd("stream", "*", &[("field", "1")]).await.unwrap();
+
+        // The entry isn't acknowledged, so it is claimed again until it is dead-lettered.
+        for deliveries in 1..=2 {
+            let delivery = consumer.next_delivery().await.unwrap();
+            assert_eq!(delivery.entry.id, id);
+            assert_eq!(delivery.entry.delivered_count, Some(deliveries));
+        }
+        let other: String = con.xadd("stream", "*", &[("field", "2")]).await.unwrap();
+        let delivery = consumer.next_delivery().await.unwrap();
+        assert_eq!(delivery.entry.id, other);
+        delivery.ack().await.unwrap();
+
+        let dead: StreamRangeReply = con.xrange_all("dead").await.unwrap();
+        assert_eq!(dead.ids.len(), 1);
+        let entry = &dead.ids[0];
+        assert_eq!(entry.get("field"), Some(1));
+        assert_eq!(entry.get(DEAD_LETTER_SOURCE_ID), Some(id));
+        assert_eq!(entry.get(DEAD_LETTER_DELIVERIES), Some(3));
+        let pending: StreamPendingReply = con.xpending("stream", "group").await.unwrap();
+        assert_eq!(pending.count(), 0);
+    }
+
+    #[cfg(feature = "streams")]
+    #[async_test]
+    async fn test_stream_consumer_claim_around_owned_entries() {
+        use redis::streams::{
+            DEAD_LETTER_SOURCE_ID, StreamClaimOptions, StreamConsumer, StreamConsumerOptions,
+            StreamRangeReply, StreamReadOptions,
+        };
+
+        let ctx = TestContext::new();
+        let mut con = ctx.async_connection().await.unwrap();
+        let options = StreamConsumerOptions::new("group", "consumer")
+            .start_id("0")
+            .claim_idle(Duration::from_millis(200))
+            .claim_interval(Duration::ZERO)
+            .dead_letter("dead", 1);
+        let mut consumer = StreamConsumer::new(con.clone(), "stream", options)
+            .await
+            .unwrap();
+        let mut ids = Vec::new();
+        for field in 1..=3 {
+            let id: String = con.xadd("stream", "*", &[("field", field)]).await.unwrap();
+            ids.push(id);
+        }
+        let _: redis::Value = con
+            .xread_options(
+                &["stream"],
+                &[">"],
+                &StreamReadOptions::default().group("group", "other"),
+            )
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(300).into()).await;
+        // The consumer takes over the middle entry, which stays pending to it without being
+        // idle long enough to be claimed.
+        let _: redis::Value = con
+            .xclaim_options(
+                "stream",
+                "group",
+                "consumer",
+                0,
+                &[&ids[1]],
+                StreamClaimOptions::default().with_justid(),
+            )
+            .await
+            .unwrap();
+        let last: String = con.xadd("stream", "*", &[("field", 4)]).await.unwrap();
+
+        // Both claimed entries are past their delivery limit, including the one after the
+        // entry the consumer already owned.
+        let delivery = consumer.next_delivery().await.unwrap();
+        assert_eq!(delivery.entry.id, last);
+        let dead: StreamRangeReply = con.xrange_all("dead").await.unwrap();
+        let sources: Vec<Option<String>> = dead
+            .ids
+            .iter()
+            .map(|entry| entry.get(DEAD_LETTER_SOURCE_ID))
+            .collect();
+        assert_eq!(sources, [Some(ids[0].clone()), Some(ids[2].clone())]);
+    }
+
+    #[cfg(feature = "streams")]
+    #[async_test]
+    async fn test_stream_tail() {
+        use redis::streams::{StreamTail, StreamTailOptions, StreamTailStart};
+
+        async fn test(mut conn: impl ConnectionLike + Clone + Send + Sync + 'static) {
+            let old: String = conn.xadd("a", "*", &[("field", "old")]).await.unwrap();
+
+            let mut tail = StreamTail::new(conn.clone(), ["a", "b"], StreamTailOptions::default());
+            let mut writer = conn.clone();
+            let (entry, new) = futures::join!(tail.next_entry(), async move {
+                sleep(Duration::from_millis(50).into()).await;
+                let id: String = writer.xadd("b", "*", &[("field", "new")]).await.unwrap();
+                id
+            });
+            let entry = entry.unwrap();
+            assert_eq!(entry.id, new);
+            assert_eq!(entry.get("field"), Some("new".to_string()));
+            assert_eq!(
+                tail.positions().collect::<Vec<_>>(),
+                [("a", old.as_str()), ("b", new.as_str())]
+            );
+
+            let options = StreamTailOptions::default().start(StreamTailStart::Beginning);
+            let mut entries = Box::pin(StreamTail::new(conn.clone(), ["a"], options).into_stream());
+            assert_eq!(entries.next().await.unwrap().unwrap().id, old);
+
+            let _: () = conn.del(&["a", "b"]).await.unwrap();
+        }
+        let ctx = TestContext::new();
+
+        test(ctx.async_connection().await.unwrap()).await;
+
+        #[cfg(feature = "connection-manager")]
+        test(ctx.client.get_connection_manager().await.unwrap()).await;
+    }
+
+    #[cfg(all(feature = "streams", feature = "connection-manager"))]
+    #[async_test]
+    async fn test_stream_tail_block_longer_than_response_timeout() {
+        use redis::aio::ConnectionManagerConfig;
+        use redis::streams::{StreamTail, StreamTailOptions};
+
+        let ctx = TestContext::new();
+        let config =
+            ConnectionManagerConfig::new().set_response_timeout(Some(Duration::from_millis(100)));
+        let mut conn = ctx
+            .client
+            .get_connection_manager_with_config(config)
+            .await
+            .unwrap();
+        let options = StreamTailOptions::default().block(Duration::from_secs(1));
+        let mut tail = StreamTail::new(conn.clone(), ["stream"], options);
+
+        // The entry is added after several reads, which would have timed out with a 1s BLOCK.
+        let mut writer = conn.clone();
+        let (entry, id) = futures::join!(tail.next_entry(), async move {
+            sleep(Duration::from_millis(300).into()).await;
+            let id: String = writer.xadd("stream", "*", &[("field", 1)]).await.unwrap();
+            id
+        });
+        assert_eq!(entry.unwrap().id, id);
+        let _: () = conn.del("stream").await.unwrap();
+    }
+
+    #[cfg(all(feature = "streams", feature = "connection-manager"))]
+    #[async_test]
+    async fn test_stream_tail_resumes_after_reconnect() {
+        use redis::streams::{StreamTail, StreamTailOptions, StreamTailStart};
+
+        let ctx = TestContext::new();
+        let mut conn = ctx.client.get_connection_manager().await.unwrap();
+        let first: String = conn.xadd("stream", "*", &[("field", 1)]).await.unwrap();
+        let second: String = conn.xadd("stream", "*", &[("field", 2)]).await.unwrap();
+
+        let options = StreamTailOptions::default()
+            .start(StreamTailStart::Beginning)
+            .count(1);
+        let mut tail = StreamTail::new(conn.clone(), ["stream"], options);
+        assert_eq!(tail.next_entry().await.unwrap().id, first);
+
+        kill_client_async(&mut conn, &ctx.client).await.unwrap();
+        let third: String = loop {
+            match conn.xadd("stream", "*", &[("field", 3)]).await {
+                Ok(id) => break id,
+                Err(_) => {
+                    sleep(Duration::from_millis(50).into()).await;
+                }
+            }
+        };
+
+        let mut ids = Vec::new();
+        while ids.len() < 2 {
+            if let Ok(entry) = tail.next_entry().await {
+                ids.push(entry.id);
+            }
+        }
+        assert_eq!(ids, [second, third]);
+    }
+
+    #[async_test]
+    async fn test_copy_keys() {
+        let ctx = TestContext::new();
+        let mut source = ctx.async_connection().await.unwrap();
+        let mut destination = ctx.async_connection().await.unwrap();
+        cmd("SELECT")
+            .arg(1)
+            .exec_async(&mut destination)
+            .await
+            .unwrap();
+        let _: () = destination.del("key").await.unwrap();
+
+        let _: () = source.set_ex("key", "value", 100).await.unwrap();
+        let opts = redis::migrate::RestoreOptions::default();
+        let copied = redis::migrate::copy_keys_async(
+            &mut source,
+            &mut destination,
+            &["key", "missing"],
+            &opts,
+        )
+        .await;
+        assert_eq!(copied, Ok(1));
+        let value: String = destination.get("key").await.unwrap();
+        assert_eq!(value, "value");
+        let ttl: i64 = destination.ttl("key").await.unwrap();
+        assert!(ttl > 0 && ttl <= 100);
+    }
+
     #[async_test]
     async fn test_set_write_backpressure_boundary_does_not_break_connection() {
         let ctx = TestContext::new();
@@ -169,6 +519,94 @@ mod basic_async {
         assert_eq!(result, "value");
     }
 
+    #[async_test]
+    async fn test_bulk_bytes_threshold() {
+        let ctx = TestContext::new();
+        let config = redis::AsyncConnectionConfig::new().set_bulk_bytes_threshold(1024);
+        let mut conn = ctx
+            .client
+            .get_multiplexed_async_connection_with_config(&config)
+            .await
+            .unwrap();
+        let large = vec![7u8; 1024 * 1024];
+        let _: () = conn.set("large", &large).await.unwrap();
+        let _: () = conn.set("small", "value").await.unwrap();
+
+        let value: Value = conn.get("large").await.unwrap();
+        assert_eq!(value, Value::BulkBytes(large.clone().into()));
+        let bytes: bytes::Bytes = conn.get("large").await.unwrap();
+        assert_eq!(bytes, large);
+        let (first, second): (Vec<u8>, String) = redis::pipe()
+            .get("large")
+            .get("small")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(first, large);
+        assert_eq!(second, "value");
+
+        // Nested and short bulk strings are still copied.
+        let values: Value = conn.mget(&["large", "small"]).await.unwrap();
+        assert_eq!(
+            values,
+            Value::Array(vec![
+                Value::BulkString(large),
+                Value::BulkString(b"value".to_vec())
+            ])
+        );
+        let value: Value = conn.get("small").await.unwrap();
+        assert_eq!(value, Value::BulkString(b"value".to_vec()));
+    }
+
+    #[async_test]
+    async fn test_streamed_bulk_strings() {
+        use tokio::io::AsyncReadExt;
+
+        let ctx = TestContext::new();
+        let mut conn = ctx.async_connection().await.unwrap();
+        let large: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+        let reply = conn
+            .send_command_with_reader(
+                redis::cmd("SET").arg("large"),
+                large.len() as u64,
+                std::io::Cursor::new(large.clone()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(reply, Value::Okay);
+
+        let mut reader = conn
+            .send_command_for_reader(redis::cmd("GET").arg("large"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reader.len(), large.len());
+        let mut value = Vec::new();
+        reader.read_to_end(&mut value).await.unwrap();
+        assert_eq!(value, large);
+
+        let mut reader = conn
+            .send_command_for_reader(redis::cmd("GETRANGE").arg("large").arg(10).arg(19))
+            .await
+            .unwrap()
+            .unwrap();
+        let mut range = Vec::new();
+        reader.read_to_end(&mut range).await.unwrap();
+        assert_eq!(range, large[10..20]);
+
+        let missing = conn
+            .send_command_for_reader(redis::cmd("GET").arg("missing"))
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        let err = conn
+            .send_command_for_reader(redis::cmd("INCR").arg("counter"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), redis::ErrorKind::UnexpectedReturnType);
+    }
+
     #[async_test]
     async fn test_can_authenticate_with_username_and_password() {
         let ctx = TestContext::new();
@@ -353,6 +791,23 @@ mod basic_async {
         assert_eq!(x, 42);
     }
 
+    #[async_test]
+    #[cfg(feature = "json")]
+    async fn test_module_json_get_as() {
+        let ctx = TestContext::with_modules(&[Module::Json]);
+        let mut con = ctx.async_connection().await.unwrap();
+        con.json_set::<_, _, _, ()>("x", "$", &serde_json::json!({"a": [1, 2]}))
+            .await
+            .unwrap();
+
+        let a: Option<Vec<i64>> = con.json_get_as("x", "$.a").await.unwrap();
+        assert_eq!(a, Some(vec![1, 2]));
+        let all: Vec<i64> = con.json_get_all_as("x", "$.a[*]").await.unwrap();
+        assert_eq!(all, [1, 2]);
+        let values: Vec<Option<i64>> = con.json_mget_as(&["x", "y"], ".a[0]").await.unwrap();
+        assert_eq!(values, [Some(1), None]);
+    }
+
     #[async_test]
     #[cfg(feature = "json")]
     async fn test_module_json_and_pipeline_transaction_with_ignore_errors() {
@@ -689,6 +1144,49 @@ mod basic_async {
         assert_eq!(hash, script.get_hash().to_string());
     }
 
+    #[async_test]
+    async fn test_function() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.async_connection().await.unwrap();
+
+        let library = redis::Library::new(
+            r"#!lua name=asynclib
+redis.register_function('set_and_get', function(keys, args)
+    redis.call('SET', keys[1], args[1])
+    return redis.call('GET', keys[1])
+end)
+redis.register_function{
+    function_name='get',
+    callback=function(keys, args) return redis.call('GET', keys[1]) end,
+    flags={'no-writes'}
+}
+",
+        );
+
+        // The library is loaded by the first invocation
+        let val: String = library
+            .function("set_and_get")
+            .key("key1")
+            .arg("foo")
+            .invoke_async(&mut con)
+            .await
+            .unwrap();
+        assert_eq!(val, "foo");
+        let val: String = library
+            .function("get")
+            .key("key1")
+            .invoke_ro_async(&mut con)
+            .await
+            .unwrap();
+        assert_eq!(val, "foo");
+
+        let name = library.load_replace_async(&mut con).await.unwrap();
+        assert_eq!(name, "asynclib");
+        let libraries: Vec<redis::function::LibraryInfo> = con.function_list().await.unwrap();
+        assert_eq!(libraries.len(), 1);
+        assert_eq!(libraries[0].functions.len(), 2);
+    }
+
     #[async_test]
     #[cfg(feature = "script")]
     async fn test_script_returning_complex_type(mut con: impl ConnectionLike) {
@@ -11,13 +11,17 @@ mod basic {
     use rand::prelude::IndexedRandom;
     use rand::{RngExt, rng};
 
+    use redis::migrate::{RestoreOptions, copy_keys};
     use redis::{
-        Client, Connection, ConnectionInfo, ConnectionLike, ControlFlow, CopyOptions, ErrorKind,
-        ExistenceCheck, ExpireOption, Expiry, FieldExistenceCheck, HashFieldExpirationOptions,
+        BitRangeUnit, BitfieldOffset, BitfieldOps, BitfieldOverflow, BitfieldRoOps, BitfieldType,
+        Client, Connection, ConnectionInfo, ConnectionLike, ControlFlow, CopyOptions, Durability,
+        DurabilityAck, ErrorKind, ExistenceCheck, ExpireOption, Expiry, FieldExistenceCheck,
+        HashFieldExpirationOptions,
         IntegerReplyOrNoOp::{ExistsButNotRelevant, IntegerReply},
-        MSetOptions, ProtocolVersion, PubSubCommands, PushInfo, PushKind, RedisConnectionInfo,
-        RedisResult, Role, ScanOptions, SetExpiry, SetOptions, SortedSetAddOptions, ToRedisArgs,
-        TypedCommands, UpdateCheck, Value, ValueComparison, ValueType, cmd,
+        LcsOptions, MSetOptions, ProtocolVersion, PubSubCommands, PushInfo, PushKind,
+        RedisConnectionInfo, RedisResult, Role, ScanOptions, ServerCommands, SetExpiry, SetOptions,
+        SortOptions, SortOrder, SortedSetAddOptions, ToRedisArgs, TypedCommands, UpdateCheck,
+        Value, ValueComparison, ValueType, ZAggregate, ZAggregateOptions, ZRangeOptions, cmd,
     };
     use redis::{RedisError, ServerErrorKind};
     use redis::{calculate_value_digest, is_valid_16_bytes_hex_digest};
@@ -32,7 +36,7 @@ mod basic {
     use redis_test::utils::get_listener_on_free_port;
 
     use assert_matches::assert_matches;
-    #[cfg(feature = "vector-sets")]
+    #[cfg(any(feature = "json", feature = "vector-sets"))]
     use serde_json::json;
     use std::collections::{BTreeMap, BTreeSet};
     use std::collections::{HashMap, HashSet};
@@ -154,6 +158,67 @@ mod basic {
         );
     }
 
+    #[test]
+    fn test_borrowed_replies() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        redis::cmd("HSET")
+            .arg("hash")
+            .arg(&["a", "1", "b"])
+            .arg(b"binary\xff")
+            .exec(&mut con)
+            .unwrap();
+        let reply: Value = redis::cmd("HGETALL").arg("hash").query(&mut con).unwrap();
+        let fields: HashMap<&str, &[u8]> = redis::from_redis_value_borrowed(&reply).unwrap();
+        assert_eq!(fields["a"], b"1");
+        assert_eq!(fields["b"], b"binary\xff");
+
+        let replies: Value = redis::pipe()
+            .get("missing")
+            .hget("hash", "a")
+            .hgetall("hash")
+            .query(&mut con)
+            .unwrap();
+        type Replies<'a> = (Option<&'a str>, i32, Vec<(&'a str, &'a [u8])>);
+        let (missing, a, pairs): Replies = redis::from_redis_value_borrowed(&replies).unwrap();
+        assert_eq!(missing, None);
+        assert_eq!(a, 1);
+        assert_eq!(pairs.len(), 2);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_codec() {
+        use redis::Commands;
+        use redis::codec::Json;
+
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+        let point = json!({"x": 1, "y": [2, 3]});
+
+        let encoded = Json::new(point.clone()).unwrap();
+        let _: () = Commands::set(&mut con, "point", &encoded).unwrap();
+        let _: () = Commands::hset(&mut con, "points", "a", &encoded).unwrap();
+        assert_eq!(
+            redis::cmd("GET").arg("point").query(&mut con),
+            Ok(r#"{"x":1,"y":[2,3]}"#.to_string())
+        );
+
+        let fetched: Json<serde_json::Value> = Commands::get(&mut con, "point").unwrap();
+        assert_eq!(fetched.into_inner(), point);
+        let fetched: Vec<Option<Json<serde_json::Value>>> =
+            Commands::mget(&mut con, &["point", "missing"]).unwrap();
+        assert_eq!(fetched, vec![Some(encoded), None]);
+        let fetched: HashMap<String, Json<serde_json::Value>> =
+            Commands::hgetall(&mut con, "points").unwrap();
+        assert_eq!(*fetched["a"], point);
+
+        let _: () = Commands::set(&mut con, "invalid", "{").unwrap();
+        let err = Commands::get::<_, Json<serde_json::Value>>(&mut con, "invalid").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+
     //unit test for key_type function
     #[test]
     fn test_key_type() {
@@ -1768,6 +1833,30 @@ mod basic {
         assert_eq!("foobar".to_string(), msg_payload);
     }
 
+    #[test]
+    fn test_keyspace_notifications() {
+        use redis::keyspace::{KeyspaceEvent, KeyspaceSubscription};
+
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+        let mut pubsub_con = ctx.connection();
+        let mut pubsub = pubsub_con.as_pubsub();
+        KeyspaceSubscription::new()
+            .db(0)
+            .events("del")
+            .notify_keyspace_events("Eg")
+            .subscribe(&mut pubsub)
+            .unwrap();
+
+        con.set("key", 1).unwrap();
+        con.del("key").unwrap();
+
+        let event = KeyspaceEvent::from_msg(&pubsub.get_message().unwrap()).unwrap();
+        assert_eq!(event.db, 0);
+        assert_eq!(event.key, b"key");
+        assert_eq!(event.event, "del");
+    }
+
     #[test]
     fn test_pubsub_unsubscribe() {
         let ctx = TestContext::new();
@@ -2204,6 +2293,84 @@ mod basic {
         assert_eq!(con.getbit("bitvec", 10), Ok(true));
     }
 
+    #[test]
+    fn test_bitfield_ops() {
+        assert_eq!(BitfieldOps::default().to_redis_args().len(), 0);
+        let ops = BitfieldOps::default()
+            .get(BitfieldType::Signed(5), BitfieldOffset::Bits(100))
+            .overflow(BitfieldOverflow::Fail)
+            .set(BitfieldType::Unsigned(8), BitfieldOffset::Index(2), 255)
+            .incr_by(BitfieldType::Signed(64), BitfieldOffset::Bits(0), -1);
+        assert_args!(
+            &ops, "GET", "i5", "100", "OVERFLOW", "FAIL", "SET", "u8", "#2", "255", "INCRBY",
+            "i64", "0", "-1"
+        );
+
+        assert_eq!(BitfieldRoOps::default().to_redis_args().len(), 0);
+        let ops = BitfieldRoOps::default()
+            .get(BitfieldType::Signed(5), BitfieldOffset::Bits(100))
+            .get(BitfieldType::Unsigned(8), BitfieldOffset::Index(2));
+        assert_args!(&ops, "GET", "i5", "100", "GET", "u8", "#2");
+    }
+
+    #[test]
+    fn test_bitfield() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        let counter = |index| (BitfieldType::Unsigned(4), BitfieldOffset::Index(index));
+        let (ty, offset) = counter(1);
+        let ops = BitfieldOps::default()
+            .set(ty, offset, 14)
+            .incr_by(ty, offset, 1)
+            .overflow(BitfieldOverflow::Fail)
+            .incr_by(ty, offset, 1)
+            .overflow(BitfieldOverflow::Sat)
+            .incr_by(ty, offset, 1)
+            .overflow(BitfieldOverflow::Wrap)
+            .incr_by(ty, offset, 1);
+        assert_eq!(
+            con.bitfield("counters", &ops),
+            Ok(vec![Some(0), Some(15), None, Some(15), Some(0)])
+        );
+
+        let (ty, offset) = counter(0);
+        let ops = BitfieldOps::default()
+            .set(ty, offset, 3)
+            .get(BitfieldType::Signed(4), offset);
+        assert_eq!(con.bitfield("counters", &ops), Ok(vec![Some(0), Some(3)]));
+
+        let ops = BitfieldRoOps::default()
+            .get(ty, offset)
+            .get(counter(1).0, counter(1).1);
+        assert_eq!(
+            con.bitfield_ro("counters", &ops),
+            Ok(vec![Some(3), Some(0)])
+        );
+    }
+
+    #[test]
+    fn test_bitpos() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        con.set("bits", b"\xff\xf0\x00".as_slice()).unwrap();
+        assert_eq!(con.bitpos("bits", false), Ok(12));
+        assert_eq!(con.bitpos("bits", true), Ok(0));
+        assert_eq!(
+            con.bitpos_range("bits", true, 2, -1, BitRangeUnit::Byte),
+            Ok(-1)
+        );
+        assert_eq!(
+            con.bitpos_range("bits", true, 3, 7, BitRangeUnit::Bit),
+            Ok(3)
+        );
+        assert_eq!(
+            con.bitpos_range("bits", false, 0, 11, BitRangeUnit::Bit),
+            Ok(-1)
+        );
+    }
+
     #[test]
     fn test_bit_operations() {
         let ctx = TestContext::new();
@@ -2438,6 +2605,103 @@ mod basic {
         );
     }
 
+    #[test]
+    fn test_zrange_options() {
+        assert_eq!(ZRangeOptions::default().to_redis_args().len(), 0);
+        let opts = ZRangeOptions::default().limit(1, 2).rev().by_score();
+        assert_args!(&opts, "BYSCORE", "REV", "LIMIT", "1", "2");
+        assert_args!(&ZRangeOptions::default().by_lex(), "BYLEX");
+
+        let opts = ZAggregateOptions::default()
+            .aggregate(ZAggregate::Min)
+            .weights([2.0, 0.5]);
+        assert_args!(&opts, "WEIGHTS", "2", "0.5", "AGGREGATE", "MIN");
+    }
+
+    #[test]
+    fn test_zrange_and_zrangestore() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        con.zadd_multiple("zset", &[(1, "a"), (2, "b"), (3, "c"), (4, "d")])
+            .unwrap();
+
+        let opts = ZRangeOptions::default().by_score().rev().limit(1, 2);
+        assert_eq!(
+            con.zrange_options("zset", "+inf", "(1", &opts),
+            Ok(vec!["c".to_string(), "b".to_string()])
+        );
+
+        assert_eq!(
+            con.zrange_options_withscores("zset", 0, 1, &ZRangeOptions::default()),
+            Ok(vec![("a".to_string(), 1.0), ("b".to_string(), 2.0)])
+        );
+
+        let opts = ZRangeOptions::default().by_lex();
+        assert_eq!(con.zrangestore("out", "zset", "[b", "[c", &opts), Ok(2));
+        assert_eq!(
+            con.zrange_withscores("out", 0, -1),
+            Ok(vec![("b".to_string(), 2.0), ("c".to_string(), 3.0)])
+        );
+    }
+
+    #[test]
+    fn test_zset_algebra() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        con.zadd_multiple("zset1", &[(1, "one"), (2, "two"), (4, "four")])
+            .unwrap();
+        con.zadd_multiple("zset2", &[(1, "one"), (2, "two"), (3, "three")])
+            .unwrap();
+        let keys = ["zset1", "zset2"];
+
+        assert_eq!(
+            con.zinter(&keys),
+            Ok(vec!["one".to_string(), "two".to_string()])
+        );
+        assert_eq!(
+            con.zinter_withscores(&keys),
+            Ok(vec![("one".to_string(), 2.0), ("two".to_string(), 4.0)])
+        );
+        let opts = ZAggregateOptions::default()
+            .weights([2.0, 3.0])
+            .aggregate(ZAggregate::Max);
+        assert_eq!(
+            con.zinter_options(&keys, &opts),
+            Ok(vec![("one".to_string(), 3.0), ("two".to_string(), 6.0)])
+        );
+        assert_eq!(con.zinterstore_options("out", &keys, &opts), Ok(2));
+        assert_eq!(con.zintercard(&keys, None), Ok(2));
+        assert_eq!(con.zintercard(&keys, Some(1)), Ok(1));
+
+        assert_eq!(con.zunion(&keys).unwrap().len(), 4);
+        let opts = ZAggregateOptions::default().aggregate(ZAggregate::Min);
+        assert_eq!(
+            con.zunion_options(&keys, &opts),
+            Ok(vec![
+                ("one".to_string(), 1.0),
+                ("two".to_string(), 2.0),
+                ("three".to_string(), 3.0),
+                ("four".to_string(), 4.0)
+            ])
+        );
+        assert_eq!(con.zunionstore_options("out", &keys, &opts), Ok(4));
+        assert_eq!(con.zunion_withscores(&keys).unwrap().len(), 4);
+
+        assert_eq!(con.zdiff(&keys), Ok(vec!["four".to_string()]));
+        assert_eq!(
+            con.zdiff_withscores(&keys),
+            Ok(vec![("four".to_string(), 4.0)])
+        );
+        assert_eq!(con.zdiffstore("out", &keys), Ok(1));
+
+        assert_eq!(
+            con.zscore_multiple("zset1", &["one", "four"]),
+            Ok(Some(vec![1.0, 4.0]))
+        );
+    }
+
     #[test]
     fn test_zrembylex() {
         let ctx = TestContext::new();
@@ -2547,6 +2811,76 @@ mod basic {
         assert_eq!(results, vec![false, true, true, true, false]);
     }
 
+    #[test]
+    fn test_sintercard() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        con.sadd("set1", &["a", "b", "c", "d"]).unwrap();
+        con.sadd("set2", &["b", "c", "d", "e"]).unwrap();
+        assert_eq!(con.sintercard(&["set1", "set2"], None), Ok(3));
+        assert_eq!(con.sintercard(&["set1", "set2"], Some(2)), Ok(2));
+        assert_eq!(con.sintercard(&["set1", "missing"], None), Ok(0));
+    }
+
+    #[test]
+    fn test_lcs() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        con.mset(&[("key1", "ohmytext"), ("key2", "mynewtext")])
+            .unwrap();
+        assert_eq!(con.lcs("key1", "key2"), Ok("mytext".to_string()));
+        assert_eq!(con.lcs_len("key1", "key2"), Ok(6));
+
+        let matches = con.lcs_idx("key1", "key2", &LcsOptions::default()).unwrap();
+        assert_eq!(matches.len, 6);
+        assert_eq!(
+            matches.matches,
+            vec![(4..8, 5..9, None), (2..4, 0..2, None)]
+        );
+
+        let opts = LcsOptions::default().min_match_len(4).with_match_len();
+        let matches = con.lcs_idx("key1", "key2", &opts).unwrap();
+        assert_eq!(matches.matches, vec![(4..8, 5..9, Some(4))]);
+    }
+
+    #[test]
+    fn test_wait() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_2);
+        let mut con = ctx.connection();
+
+        con.set("key", 1).unwrap();
+        assert_eq!(con.wait(0, 100), Ok(0));
+        assert_eq!(con.waitaof(0, 0, 100).unwrap().replicas, 0);
+    }
+
+    #[test]
+    fn test_pipeline_durability() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        let (value, ack): (i64, DurabilityAck) = redis::pipe()
+            .durability(Durability::replicas(0, 100))
+            .set("key", 1)
+            .ignore()
+            .incr("key", 1)
+            .query(&mut con)
+            .unwrap();
+        assert_eq!(value, 2);
+        assert_eq!(ack.replicas(), 0);
+        assert_eq!(ack, DurabilityAck::Replicas(0));
+
+        let (value, ack): (i64, DurabilityAck) = redis::pipe()
+            .atomic()
+            .durability(Durability::replicas(0, 100))
+            .incr("key", 1)
+            .query(&mut con)
+            .unwrap();
+        assert_eq!(value, 3);
+        assert_eq!(ack.replicas(), 0);
+    }
+
     #[test]
     fn test_object_freq_command() {
         let ctx = TestContext::new();
@@ -3387,6 +3721,156 @@ mod basic {
         assert_eq!(con.get("key4").unwrap(), Some("value1".to_string()));
     }
 
+    #[test]
+    fn test_server_commands() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        con.config_set("slowlog-log-slower-than", 0).unwrap();
+        assert_eq!(
+            con.config_get("slowlog-log-slower-than").unwrap()["slowlog-log-slower-than"],
+            "0"
+        );
+        con.slowlog_reset().unwrap();
+        con.set("slow_key", "value").unwrap();
+
+        let entries = con.slowlog_get(Some(10)).unwrap();
+        let entry = entries.iter().find(|entry| entry.args[0] == "SET").unwrap();
+        assert_eq!(entry.args, vec!["SET", "slow_key", "value"]);
+        assert!(entry.client_addr.is_some());
+        assert!(con.slowlog_len().unwrap() >= 1);
+        con.config_set("slowlog-log-slower-than", 10000).unwrap();
+
+        assert_eq!(con.dbsize(), Ok(1));
+        assert!(con.memory_usage("slow_key").unwrap().unwrap() > 0);
+        assert_eq!(con.memory_usage("missing_key"), Ok(None));
+        assert_eq!(con.memory_stats().unwrap().keys_count, 1);
+        assert!(!con.memory_doctor().unwrap().is_empty());
+
+        let info = con.info(&["server"]).unwrap();
+        assert!(info.contains_key(&"redis_version"));
+        assert!(!info.contains_key(&"used_memory"));
+        assert!(con.lastsave().unwrap() > 0);
+
+        con.latency_reset().unwrap();
+        assert_eq!(con.latency_latest(), Ok(vec![]));
+        assert_eq!(con.latency_history("command"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_sort_options() {
+        assert_eq!(SortOptions::default().to_redis_args().len(), 0);
+
+        let opts = SortOptions::default()
+            .alpha()
+            .order(SortOrder::Desc)
+            .get("#")
+            .get("name_*")
+            .limit(1, 2)
+            .by("weight_*");
+        assert_args!(
+            &opts, "BY", "weight_*", "LIMIT", "1", "2", "GET", "#", "GET", "name_*", "DESC",
+            "ALPHA"
+        );
+        assert_eq!(opts.num_of_args(), 11);
+    }
+
+    #[test]
+    fn test_sort() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        con.rpush("ids", &[3, 1, 2]).unwrap();
+        con.mset(&[("weight_1", 30), ("weight_2", 10), ("weight_3", 20)])
+            .unwrap();
+        con.mset(&[("name_1", "one"), ("name_3", "three")]).unwrap();
+
+        let sorted: Vec<u8> = con.sort("ids", &SortOptions::default()).unwrap();
+        assert_eq!(sorted, vec![1, 2, 3]);
+
+        let opts = SortOptions::default().order(SortOrder::Desc).limit(0, 2);
+        let sorted: Vec<u8> = con.sort_ro("ids", &opts).unwrap();
+        assert_eq!(sorted, vec![3, 2]);
+
+        let opts = SortOptions::default().by("weight_*").get("#").get("name_*");
+        let sorted: Vec<(u8, Option<String>)> = con.sort("ids", &opts).unwrap();
+        assert_eq!(
+            sorted,
+            vec![
+                (2, None),
+                (3, Some("three".to_string())),
+                (1, Some("one".to_string()))
+            ]
+        );
+
+        con.sadd("words", &["banana", "apple", "cherry"]).unwrap();
+        let opts = SortOptions::default().alpha();
+        assert_eq!(con.sort_store("words", "sorted_words", &opts), Ok(3));
+        assert_eq!(
+            con.lrange("sorted_words", 0, -1),
+            Ok(vec![
+                "apple".to_string(),
+                "banana".to_string(),
+                "cherry".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dump_restore() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+
+        con.rpush("list", &[1, 2, 3]).unwrap();
+        let payload = con.dump("list").unwrap().unwrap();
+        assert_eq!(con.dump("missing"), Ok(None));
+
+        con.restore("copy", 0, &payload).unwrap();
+        assert_eq!(
+            con.lrange("copy", 0, -1),
+            Ok(vec!["1".to_string(), "2".to_string(), "3".to_string()])
+        );
+        assert_eq!(con.pttl("copy"), Ok(ExistsButNotRelevant));
+
+        // RESTORE fails on existing keys unless REPLACE is given.
+        assert!(con.restore("copy", 0, &payload).is_err());
+        let opts = RestoreOptions::default().replace(true).idle_time(100);
+        con.restore_options("copy", 10_000, &payload, &opts)
+            .unwrap();
+        assert_matches!(con.pttl("copy"), Ok(IntegerReply(ttl)) if ttl > 0 && ttl <= 10_000);
+    }
+
+    #[test]
+    fn test_copy_keys() {
+        let ctx = TestContext::new();
+        let mut source = ctx.connection();
+        let mut destination = ctx.connection();
+        cmd("SELECT").arg(1).exec(&mut destination).unwrap();
+        destination.del(&["with_ttl", "without_ttl"]).unwrap();
+
+        source.set_ex("with_ttl", "a", 100).unwrap();
+        source.set("without_ttl", "b").unwrap();
+
+        let keys = ["with_ttl", "without_ttl", "missing"];
+        let opts = RestoreOptions::default();
+        assert_eq!(
+            copy_keys(&mut source, &mut destination, &keys, &opts),
+            Ok(2)
+        );
+        assert_eq!(destination.get("with_ttl"), Ok(Some("a".to_string())));
+        assert_eq!(destination.get("without_ttl"), Ok(Some("b".to_string())));
+        assert_matches!(destination.ttl("with_ttl"), Ok(IntegerReply(ttl)) if ttl > 0 && ttl <= 100);
+        assert_eq!(destination.ttl("without_ttl"), Ok(ExistsButNotRelevant));
+
+        // Without REPLACE, keys which exist on the destination make the copy fail.
+        assert!(copy_keys(&mut source, &mut destination, &keys, &opts).is_err());
+        let opts = RestoreOptions::default().replace(true);
+        assert_eq!(
+            copy_keys(&mut source, &mut destination, &keys, &opts),
+            Ok(2)
+        );
+    }
+
     #[test]
     fn test_expire_time() {
         let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
@@ -4466,6 +4950,43 @@ mod basic {
         assert_eq!(res, "connection-name");
     }
 
+    #[test]
+    fn test_client_info_and_list() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_2);
+        let mut con = ctx.connection();
+        let mut other = ctx.connection();
+        con.client_setname("admin").unwrap();
+        other.client_setname("worker").unwrap();
+
+        let info = con.client_info().unwrap();
+        assert_eq!(info.id, con.client_id().unwrap() as u64);
+        assert_eq!(info.name.as_deref(), Some("admin"));
+        assert_eq!(info.cmd, "client|info");
+
+        assert_eq!(con.client_no_evict(true), Ok(()));
+        assert_eq!(con.client_no_touch(true), Ok(()));
+        let flags = con.client_info().unwrap().flags;
+        assert!(flags.contains('e') && flags.contains('T'));
+        assert_eq!(con.client_no_touch(false), Ok(()));
+
+        let list = con.client_list().unwrap();
+        let worker = list
+            .clients
+            .iter()
+            .find(|client| client.name.as_deref() == Some("worker"))
+            .unwrap();
+        assert_eq!(worker.cmd, "client|setname");
+
+        let filter = redis::server::ClientKillFilter::default().id(worker.id);
+        assert_eq!(con.client_kill(filter), Ok(1));
+        assert!(other.client_id().is_err());
+
+        let mode = redis::server::ClientPauseMode::Write;
+        assert_eq!(con.client_pause(Duration::from_secs(10), mode), Ok(()));
+        assert_eq!(con.client_unpause(), Ok(()));
+        con.set("key", "value").unwrap();
+    }
+
     #[test]
     fn test_role_primary() {
         let ctx = TestContext::new();
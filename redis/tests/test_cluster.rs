@@ -13,8 +13,10 @@ mod cluster {
     use crate::support::*;
     use assert_matches::assert_matches;
     use redis::{
-        Commands, ConnectionLike, ErrorKind, RedisError, ServerErrorKind, Value,
+        Commands, ConnectionLike, Durability, DurabilityAck, ErrorKind, RedisError, ServerCommands,
+        ServerErrorKind, SortOptions, Value,
         cluster::{ClusterClient, ClusterConnection, cluster_pipe},
+        cluster_introspection::{ClusterInfo, ClusterNodes, ShardInfo},
         cluster_read_routing::{RandomReplicaStrategy, RoundRobinReplicaStrategy},
         cluster_routing::{MultipleNodeRoutingInfo, RoutingInfo, SingleNodeRoutingInfo},
         cmd, from_redis_value, parse_redis_value,
@@ -50,6 +52,99 @@ mod cluster {
         smoke_test_connection(cluster.connection());
     }
 
+    #[test]
+    fn test_cluster_server_commands() {
+        let cluster = TestClusterContext::new();
+        let mut con = cluster.connection();
+
+        let _: () = con.set("{x}key1", "value").unwrap();
+        let _: () = con.set("{y}key2", "value").unwrap();
+
+        let sizes = con.dbsize().unwrap();
+        assert_eq!(sizes.len(), cluster.cluster.servers.len());
+        assert!(sizes.values().sum::<usize>() >= 2);
+
+        let configs = con.config_get("cluster-enabled").unwrap();
+        assert_eq!(configs.len(), sizes.len());
+        for config in configs.values() {
+            assert_eq!(config["cluster-enabled"], "yes");
+        }
+
+        let lists = con.client_list().unwrap();
+        assert_eq!(lists.len(), sizes.len());
+        assert!(lists.values().all(|list| !list.clients.is_empty()));
+    }
+
+    #[test]
+    fn test_cluster_memory_usage() {
+        let cluster = TestClusterContext::new();
+        let mut con = cluster.connection();
+
+        // The keys live in different slots, so only key routing reaches the node owning each.
+        for key in ["{x}key", "{y}key", "{z}key"] {
+            let _: () = con.set(key, "value").unwrap();
+            let usage: Option<u64> = con.memory_usage(key).unwrap();
+            assert!(usage.unwrap() > 0);
+        }
+        let usage: Option<u64> = con.memory_usage("missing_key").unwrap();
+        assert_eq!(usage, None);
+    }
+
+    #[test]
+    fn test_cluster_introspection() {
+        let cluster = TestClusterContext::new();
+        let mut con = cluster.connection();
+
+        let info: ClusterInfo = con.cluster_info().unwrap();
+        assert!(info.state_ok);
+        assert_eq!(info.slots_assigned, 16384);
+        assert_eq!(info.known_nodes, cluster.cluster.servers.len());
+
+        let shards: Vec<ShardInfo> = con.cluster_shards().unwrap();
+        assert_eq!(shards.len(), info.size);
+        let slots: u32 = shards
+            .iter()
+            .flat_map(|shard| &shard.slot_ranges)
+            .map(|(start, end)| u32::from(end - start) + 1)
+            .sum();
+        assert_eq!(slots, 16384);
+        assert_eq!(ShardInfo::topology(&shards).shards().count(), shards.len());
+
+        let nodes = con.cluster_nodes::<ClusterNodes>().unwrap().nodes;
+        assert_eq!(nodes.len(), cluster.cluster.servers.len());
+        let myself = nodes.iter().find(|node| node.is_myself()).unwrap();
+        let myid: String = con.cluster_myid().unwrap();
+        assert!(!myid.is_empty());
+        assert!(!myself.id.is_empty());
+
+        let _: () = con.set("{introspection}key", "value").unwrap();
+        let slot: u16 = con.cluster_keyslot("{introspection}key").unwrap();
+        assert!(slot < 16384);
+        let count: usize = con.cluster_countkeysinslot(slot).unwrap();
+        assert_eq!(count, 1);
+        let keys: Vec<String> = con.cluster_getkeysinslot(slot, 10).unwrap();
+        assert_eq!(keys, vec!["{introspection}key".to_string()]);
+    }
+
+    #[test]
+    fn test_cluster_sort_patterns() {
+        let cluster = TestClusterContext::new();
+        let mut con = cluster.connection();
+
+        let _: () = con.rpush("{user}:ids", &[2, 1]).unwrap();
+        let _: () = con
+            .mset(&[("{user}:weight:1", 20), ("{user}:weight:2", 10)])
+            .unwrap();
+
+        let opts = SortOptions::default().by("{user}:weight:*").get("#");
+        let sorted: Vec<u8> = con.sort("{user}:ids", &opts).unwrap();
+        assert_eq!(sorted, vec![2, 1]);
+
+        let opts = SortOptions::default().by("weight:*");
+        let err = con.sort::<_, Vec<u8>>("{user}:ids", &opts).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Client);
+    }
+
     #[test]
     fn test_cluster_numbered_database() {
         run_test_if_version_supported!(VALKEY_9_0);
@@ -262,6 +357,41 @@ mod cluster {
         assert_eq!(rv, Ok(("1".to_string(), "2".to_string())));
     }
 
+    #[test]
+    fn test_cluster_function() {
+        let cluster = TestClusterContext::new();
+        skip_if_context_does_not_support!(cluster, REDIS_CE_7_0);
+        let mut con = cluster.connection();
+
+        let library = redis::Library::new(
+            r"#!lua name=clusterlib
+redis.register_function('set', function(keys, args) return redis.call('SET', keys[1], args[1]) end)
+redis.register_function{
+    function_name='get',
+    callback=function(keys, args) return redis.call('GET', keys[1]) end,
+    flags={'no-writes'}
+}
+",
+        );
+        assert_eq!(library.load(&mut con), Ok("clusterlib".to_string()));
+
+        // The library is available on every primary, whichever slot the keys hash to.
+        for key in ["foo", "bar", "baz", "qux"] {
+            let _: () = library
+                .function("set")
+                .key(key)
+                .arg(key)
+                .invoke(&mut con)
+                .unwrap();
+            let value: String = library
+                .function("get")
+                .key(key)
+                .invoke_ro(&mut con)
+                .unwrap();
+            assert_eq!(value, key);
+        }
+    }
+
     #[test]
     fn test_cluster_pipeline() {
         let cluster = TestClusterContext::new();
@@ -278,6 +408,26 @@ mod cluster {
         assert_eq!(resp, vec!["OK".to_string()]);
     }
 
+    #[test]
+    fn test_cluster_pipeline_durability() {
+        let cluster = TestClusterContext::new();
+        cluster.wait_for_cluster_up();
+        let mut con = cluster.connection();
+
+        // The keys are on different nodes, each node acknowledges its own writes.
+        let (a, b, ack): (i64, i64, DurabilityAck) = cluster_pipe()
+            .durability(Durability::replicas(0, 100))
+            .incr("{a}key", 1)
+            .incr("{b}key", 1)
+            .query(&mut con)
+            .unwrap();
+        assert_eq!((a, b), (1, 1));
+        assert_eq!(ack.replicas(), 0);
+
+        let replicas: usize = con.wait(0, 100).unwrap();
+        assert_eq!(replicas, 0);
+    }
+
     #[test]
     fn test_cluster_pipeline_multiple_keys() {
         use redis::FromRedisValue;
@@ -64,6 +64,21 @@ mod cluster_async {
         smoke_test_connection(connection).await;
     }
 
+    #[async_test]
+    async fn test_async_cluster_memory_usage() {
+        let cluster = TestClusterContext::new();
+        let mut con = cluster.async_connection().await;
+
+        // The keys live in different slots, so only key routing reaches the node owning each.
+        for key in ["{x}key", "{y}key", "{z}key"] {
+            let _: () = con.set(key, "value").await.unwrap();
+            let usage: Option<u64> = con.memory_usage(key).await.unwrap();
+            assert!(usage.unwrap() > 0);
+        }
+        let usage: Option<u64> = con.memory_usage("missing_key").await.unwrap();
+        assert_eq!(usage, None);
+    }
+
     #[async_test]
     async fn test_async_cluster_numbered_database() {
         run_test_if_version_supported!(VALKEY_9_0);
@@ -2946,6 +2961,116 @@ mod cluster_async {
             assert_eq!(res, ("foo".to_string(), 1));
         }
 
+        #[async_test]
+        async fn test_atomic_pipeline_durability_with_push_sender() {
+            use redis::{Durability, DurabilityAck};
+
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+            let ctx = TestClusterContext::new_with_cluster_client_builder(|builder| {
+                builder.push_sender(tx.clone())
+            });
+            let mut conn = ctx.async_connection().await;
+
+            let (value, ack): (i64, DurabilityAck) = pipe()
+                .atomic()
+                .durability(Durability::replicas(0, 100))
+                .set("{x}key", 1)
+                .ignore()
+                .incr("{x}key", 1)
+                .query_async(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(value, 2);
+            assert_eq!(ack.replicas(), 0);
+        }
+
+        #[async_test]
+        async fn test_keyspace_notifications_on_every_node() {
+            use redis::keyspace::{KeyspaceEvent, KeyspaceSubscription};
+
+            if !use_protocol().supports_resp3() {
+                return;
+            }
+
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let ctx = TestClusterContext::new_with_cluster_client_builder(|builder| {
+                builder.push_sender(tx.clone())
+            });
+
+            let (mut publish_conn, mut pubsub_conn) =
+                join!(ctx.async_connection(), ctx.async_connection());
+            KeyspaceSubscription::new()
+                .keys("*key")
+                .notify_keyspace_events("K$")
+                .subscribe_cluster(&mut pubsub_conn)
+                .await
+                .unwrap();
+
+            // The keys are in slots owned by different nodes.
+            let _: () = publish_conn.set("{a}key", "value").await.unwrap();
+            let _: () = publish_conn.set("{b}key", "value").await.unwrap();
+
+            let mut keys = Vec::new();
+            while keys.len() < 2 {
+                let push = get_push_with_timeout(&mut rx, Duration::from_secs(1))
+                    .await
+                    .unwrap();
+                if let Some(event) = redis::Msg::from_push_info(push)
+                    .as_ref()
+                    .and_then(KeyspaceEvent::from_msg)
+                {
+                    assert_eq!(event.event, "set");
+                    keys.push(event.key);
+                }
+            }
+            keys.sort();
+            assert_eq!(keys, [b"{a}key".to_vec(), b"{b}key".to_vec()]);
+        }
+
+        #[async_test]
+        async fn test_keyspace_notifications_with_replicas_arrive_once() {
+            use redis::keyspace::{KeyspaceEvent, KeyspaceSubscription};
+
+            if !use_protocol().supports_resp3() {
+                return;
+            }
+
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let ctx = TestClusterContext::new_with_config_and_builder(
+                RedisClusterConfiguration {
+                    num_nodes: 6,
+                    num_replicas: 1,
+                    ..Default::default()
+                },
+                |builder| builder.push_sender(tx.clone()),
+            );
+
+            let (mut publish_conn, mut pubsub_conn) =
+                join!(ctx.async_connection(), ctx.async_connection());
+            KeyspaceSubscription::new()
+                .keys("*key")
+                .notify_keyspace_events("K$")
+                .subscribe_cluster(&mut pubsub_conn)
+                .await
+                .unwrap();
+
+            let _: () = publish_conn.set("{a}key", "value").await.unwrap();
+            let _: () = publish_conn.set("{b}key", "value").await.unwrap();
+
+            // Replicas applying the writes would publish the same events again.
+            let mut keys = Vec::new();
+            while let Ok(push) = get_push_with_timeout(&mut rx, Duration::from_millis(500)).await {
+                if let Some(event) = redis::Msg::from_push_info(push)
+                    .as_ref()
+                    .and_then(KeyspaceEvent::from_msg)
+                {
+                    keys.push(event.key);
+                }
+            }
+            keys.sort();
+            assert_eq!(keys, [b"{a}key".to_vec(), b"{b}key".to_vec()]);
+        }
+
         #[async_test]
         async fn test_pub_sub_unsubscription() {
             if !use_protocol().supports_resp3() {
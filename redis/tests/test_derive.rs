@@ -0,0 +1,207 @@
+mod support;
+
+mod derive {
+    use std::collections::HashMap;
+
+    use redis::{Commands, FromRedisValue, ProtocolVersion, ToRedisArgs, ToSingleRedisArg, Value};
+
+    use crate::support::*;
+
+    #[derive(Debug, PartialEq, FromRedisValue, ToRedisArgs)]
+    enum Role {
+        Admin,
+        #[redis(rename = "member")]
+        Member,
+    }
+
+    #[derive(Debug, PartialEq, FromRedisValue, ToRedisArgs)]
+    struct UserId(u64);
+
+    #[derive(Debug, Default, PartialEq, FromRedisValue, ToRedisArgs)]
+    struct Address {
+        city: String,
+        #[redis(default)]
+        zip: String,
+    }
+
+    fn default_visits() -> u32 {
+        1
+    }
+
+    #[derive(Debug, PartialEq, FromRedisValue, ToRedisArgs)]
+    struct User {
+        id: UserId,
+        #[redis(rename = "user-name")]
+        name: String,
+        role: Role,
+        email: Option<String>,
+        #[redis(default = "default_visits")]
+        visits: u32,
+        #[redis(skip)]
+        cached: bool,
+        #[redis(flatten)]
+        address: Address,
+    }
+
+    #[derive(Debug, PartialEq, FromRedisValue, ToRedisArgs)]
+    struct Labeled<T> {
+        label: String,
+        value: T,
+    }
+
+    fn args(value: &impl ToRedisArgs) -> Vec<Vec<u8>> {
+        value.to_redis_args()
+    }
+
+    fn assert_single<T: ToSingleRedisArg>(_: &T) {}
+
+    #[test]
+    fn test_unit_enum() {
+        assert_eq!(Role::from_redis_value(bulk("Admin")), Ok(Role::Admin));
+        assert_eq!(Role::from_redis_value(bulk("member")), Ok(Role::Member));
+        assert!(Role::from_redis_value(bulk("Member")).is_err());
+        assert_eq!(args(&Role::Member), vec![b"member".to_vec()]);
+        assert_single(&Role::Admin);
+    }
+
+    #[test]
+    fn test_newtype() {
+        assert_eq!(UserId::from_redis_value(bulk("42")), Ok(UserId(42)));
+        assert_eq!(UserId::from_redis_value(Value::Int(42)), Ok(UserId(42)));
+        assert_eq!(args(&UserId(42)), vec![b"42".to_vec()]);
+        assert_single(&UserId(42));
+    }
+
+    #[test]
+    fn test_struct_from_resp2_and_resp3() {
+        let fields = [
+            ("id", "7"),
+            ("user-name", "alice"),
+            ("role", "Admin"),
+            ("city", "Paris"),
+        ];
+        let expected = User {
+            id: UserId(7),
+            name: "alice".to_string(),
+            role: Role::Admin,
+            email: None,
+            visits: 1,
+            cached: false,
+            address: Address {
+                city: "Paris".to_string(),
+                zip: String::new(),
+            },
+        };
+
+        let resp2 = Value::Array(
+            fields
+                .iter()
+                .flat_map(|(field, value)| [bulk(field), bulk(value)])
+                .collect(),
+        );
+        assert_eq!(User::from_redis_value(resp2), Ok(expected));
+
+        let resp3 = Value::Map(
+            fields
+                .iter()
+                .map(|(field, value)| (bulk(field), bulk(value)))
+                .collect(),
+        );
+        let user = User::from_redis_value(resp3).unwrap();
+        assert_eq!(user.address.city, "Paris");
+    }
+
+    #[test]
+    fn test_struct_missing_field() {
+        let value = Value::Map(vec![(bulk("user-name"), bulk("alice"))]);
+        let err = User::from_redis_value(value).unwrap_err();
+        assert!(err.to_string().contains("missing field `id` of User"));
+    }
+
+    #[test]
+    fn test_struct_to_args() {
+        let mut user = User {
+            id: UserId(7),
+            name: "alice".to_string(),
+            role: Role::Member,
+            email: None,
+            visits: 3,
+            cached: true,
+            address: Address {
+                city: "Paris".to_string(),
+                zip: "75001".to_string(),
+            },
+        };
+        let expected: Vec<Vec<u8>> = [
+            "id",
+            "7",
+            "user-name",
+            "alice",
+            "role",
+            "member",
+            "visits",
+            "3",
+            "city",
+            "Paris",
+            "zip",
+            "75001",
+        ]
+        .iter()
+        .map(|arg| arg.as_bytes().to_vec())
+        .collect();
+        assert_eq!(args(&user), expected);
+        assert_eq!(user.num_of_args(), expected.len());
+
+        user.email = Some("alice@example.com".to_string());
+        let with_email = args(&user);
+        assert_eq!(with_email.len(), expected.len() + 2);
+        assert_eq!(
+            with_email[6..8],
+            [b"email".to_vec(), b"alice@example.com".to_vec()]
+        );
+        assert_eq!(user.num_of_args(), with_email.len());
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        let labeled = Labeled {
+            label: "answer".to_string(),
+            value: 42u8,
+        };
+        let value = Value::Array(args(&labeled).into_iter().map(Value::BulkString).collect());
+        assert_eq!(Labeled::from_redis_value(value), Ok(labeled));
+    }
+
+    #[test]
+    fn test_struct_roundtrip() {
+        let ctx = TestContext::new();
+        let mut con = ctx.connection();
+        let user = User {
+            id: UserId(7),
+            name: "alice".to_string(),
+            role: Role::Member,
+            email: Some("alice@example.com".to_string()),
+            visits: 3,
+            cached: false,
+            address: Address {
+                city: "Paris".to_string(),
+                zip: "75001".to_string(),
+            },
+        };
+        redis::cmd("HSET")
+            .arg("user")
+            .arg(&user)
+            .exec(&mut con)
+            .unwrap();
+
+        let fetched: User = con.hgetall("user").unwrap();
+        assert_eq!(fetched, user);
+
+        let raw: HashMap<String, String> = con.hgetall("user").unwrap();
+        assert_eq!(raw["user-name"], "alice");
+        if ctx.protocol == ProtocolVersion::RESP3 {
+            let value: Value = con.hgetall("user").unwrap();
+            assert!(matches!(value, Value::Map(_)));
+        }
+    }
+}
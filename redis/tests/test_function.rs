@@ -0,0 +1,208 @@
+#[macro_use]
+mod support;
+
+mod function {
+    use redis::function::{FunctionListOptions, FunctionRestorePolicy};
+    use redis::{Commands, ErrorKind, Function, Library, ServerErrorKind};
+
+    use crate::support::*;
+
+    const LIBRARY: &str = r"#!lua name=testlib
+redis.register_function('set_and_get', function(keys, args)
+    redis.call('SET', keys[1], args[1])
+    return redis.call('GET', keys[1])
+end)
+redis.register_function{
+    function_name='add',
+    callback=function(keys, args) return tonumber(args[1]) + tonumber(args[2]) end,
+    flags={'no-writes'},
+    description='adds two numbers'
+}
+";
+
+    #[test]
+    fn test_function_load_and_invoke() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let library = Library::new(LIBRARY);
+        assert_eq!(library.name(), Some("testlib"));
+        assert_eq!(library.load(&mut con), Ok("testlib".to_string()));
+
+        let value: String = library
+            .function("set_and_get")
+            .key("my_key")
+            .arg("foo")
+            .invoke(&mut con)
+            .unwrap();
+        assert_eq!(value, "foo");
+
+        let sum: isize = library
+            .function("add")
+            .arg(1)
+            .arg(2)
+            .invoke_ro(&mut con)
+            .unwrap();
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn test_function_load_twice_requires_replace() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let library = Library::new(LIBRARY);
+        library.load(&mut con).unwrap();
+
+        let err = library.load(&mut con).unwrap_err();
+        assert_eq!(err.kind(), ServerErrorKind::ResponseError.into());
+
+        assert_eq!(library.load_replace(&mut con), Ok("testlib".to_string()));
+    }
+
+    #[test]
+    fn test_function_invocation_loads_library() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let library = Library::new(LIBRARY);
+        let sum: isize = library
+            .function("add")
+            .arg(2)
+            .arg(3)
+            .invoke(&mut con)
+            .unwrap();
+        assert_eq!(sum, 5);
+    }
+
+    #[test]
+    fn test_unknown_function_fails() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let err = Function::new("add")
+            .arg(1)
+            .arg(2)
+            .invoke::<isize>(&mut con)
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Server(ServerErrorKind::ResponseError)
+        );
+    }
+
+    #[test]
+    fn test_fcall_commands() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let name: String = con.function_load(LIBRARY).unwrap();
+        assert_eq!(name, "testlib");
+
+        let value: String = con.fcall("set_and_get", "my_key", "bar").unwrap();
+        assert_eq!(value, "bar");
+
+        let sum: isize = con.fcall_ro("add", &[] as &[&str], &[4, 5]).unwrap();
+        assert_eq!(sum, 9);
+
+        let (name, sum): (String, isize) = redis::pipe()
+            .function_load_replace(LIBRARY)
+            .fcall("add", &[] as &[&str], &[1, 1])
+            .query(&mut con)
+            .unwrap();
+        assert_eq!(name, "testlib");
+        assert_eq!(sum, 2);
+    }
+
+    #[test]
+    fn test_function_list() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        let libraries: Vec<redis::function::LibraryInfo> = con.function_list().unwrap();
+        assert!(libraries.is_empty());
+
+        Library::new(LIBRARY).load(&mut con).unwrap();
+
+        let libraries: Vec<redis::function::LibraryInfo> = con.function_list().unwrap();
+        assert_eq!(libraries.len(), 1);
+        let library = &libraries[0];
+        assert_eq!(library.name, "testlib");
+        assert_eq!(library.engine, "LUA");
+        assert_eq!(library.code, None);
+        assert_eq!(library.functions.len(), 2);
+
+        let add = library.functions.iter().find(|f| f.name == "add").unwrap();
+        assert_eq!(add.description.as_deref(), Some("adds two numbers"));
+        assert_eq!(add.flags, vec!["no-writes".to_string()]);
+
+        let set_and_get = library
+            .functions
+            .iter()
+            .find(|f| f.name == "set_and_get")
+            .unwrap();
+        assert_eq!(set_and_get.description, None);
+        assert!(set_and_get.flags.is_empty());
+
+        let libraries: Vec<redis::function::LibraryInfo> = con
+            .function_list_options(
+                FunctionListOptions::default()
+                    .library_name("test*")
+                    .with_code(),
+            )
+            .unwrap();
+        assert_eq!(libraries.len(), 1);
+        assert_eq!(libraries[0].code.as_deref(), Some(LIBRARY));
+
+        let libraries: Vec<redis::function::LibraryInfo> = con
+            .function_list_options(FunctionListOptions::default().library_name("other*"))
+            .unwrap();
+        assert!(libraries.is_empty());
+    }
+
+    #[test]
+    fn test_function_stats() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        Library::new(LIBRARY).load(&mut con).unwrap();
+
+        let stats: redis::function::FunctionStats = con.function_stats().unwrap();
+        assert_eq!(stats.running_script, None);
+        let lua = stats.engines.get("LUA").unwrap();
+        assert_eq!(lua.libraries_count, 1);
+        assert_eq!(lua.functions_count, 2);
+    }
+
+    #[test]
+    fn test_function_dump_and_restore() {
+        let ctx = run_test_if_version_supported!(REDIS_CE_7_0);
+        let mut con = ctx.connection();
+
+        Library::new(LIBRARY).load(&mut con).unwrap();
+        let payload: Vec<u8> = con.function_dump().unwrap();
+
+        let _: () = con.function_flush().unwrap();
+        let libraries: Vec<redis::function::LibraryInfo> = con.function_list().unwrap();
+        assert!(libraries.is_empty());
+
+        let _: () = con
+            .function_restore(&payload, FunctionRestorePolicy::Append)
+            .unwrap();
+        let sum: isize = Function::new("add").arg(1).arg(2).invoke(&mut con).unwrap();
+        assert_eq!(sum, 3);
+
+        let err = con
+            .function_restore::<()>(&payload, FunctionRestorePolicy::Append)
+            .unwrap_err();
+        assert_eq!(err.kind(), ServerErrorKind::ResponseError.into());
+
+        let _: () = con
+            .function_restore(&payload, FunctionRestorePolicy::Replace)
+            .unwrap();
+
+        let _: () = con.function_delete("testlib").unwrap();
+        let libraries: Vec<redis::function::LibraryInfo> = con.function_list().unwrap();
+        assert!(libraries.is_empty());
+    }
+}
@@ -2,7 +2,10 @@
 
 use assert_approx_eq::assert_approx_eq;
 
-use redis::geo::{Coord, RadiusOptions, RadiusOrder, RadiusSearchResult, Unit};
+use redis::geo::{
+    Coord, GeoSearchBy, GeoSearchFrom, GeoSearchOptions, GeoSearchStoreOptions, RadiusOptions,
+    RadiusOrder, RadiusSearchResult, Unit,
+};
 use redis::{RedisResult, TypedCommands};
 
 mod support;
@@ -195,3 +198,97 @@ fn test_georadius_by_member() {
 
     assert_eq!(names, vec!["Agrigento", "Palermo"]);
 }
+
+#[test]
+fn test_geosearch() {
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    assert_eq!(con.geo_add("my_gis", &[PALERMO, CATANIA]), Ok(2));
+
+    let from = || GeoSearchFrom::LonLat(Coord::lon_lat(15.0, 37.0));
+
+    // Simple request, without extra data
+    let mut result = con
+        .geo_search(
+            "my_gis",
+            from(),
+            GeoSearchBy::Radius(200.0, Unit::Kilometers),
+            GeoSearchOptions::default(),
+        )
+        .unwrap();
+    result.sort_by(|a, b| Ord::cmp(&a.name, &b.name));
+
+    let names: Vec<_> = result.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Catania", "Palermo"]);
+    assert_eq!(result[0].coord, None);
+    assert_eq!(result[0].dist, None);
+    assert_eq!(result[0].hash, None);
+
+    // Get data with all the fields
+    let result = con
+        .geo_search(
+            "my_gis",
+            from(),
+            GeoSearchBy::Box(400.0, 400.0, Unit::Kilometers),
+            GeoSearchOptions::default()
+                .with_coord()
+                .with_dist()
+                .with_hash()
+                .order(RadiusOrder::Desc)
+                .limit(1),
+        )
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name.as_str(), "Palermo");
+    assert_approx_eq!(result[0].dist.unwrap(), 190.4424, 0.001);
+    assert_eq!(result[0].hash, Some(3479099956230698));
+    assert_approx_eq!(result[0].coord.as_ref().unwrap().longitude, 13.361_389);
+    assert_approx_eq!(result[0].coord.as_ref().unwrap().latitude, 38.115_556);
+
+    // The box is too narrow to contain Palermo
+    let result = con
+        .geo_search(
+            "my_gis",
+            from(),
+            GeoSearchBy::Box(200.0, 400.0, Unit::Kilometers),
+            GeoSearchOptions::default().limit_any(5),
+        )
+        .unwrap();
+    let names: Vec<_> = result.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Catania"]);
+}
+
+#[test]
+fn test_geosearch_store() {
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    assert_eq!(con.geo_add("my_gis", &[PALERMO, CATANIA, AGRIGENTO]), Ok(3));
+
+    let stored = con.geo_search_store(
+        "near_agrigento",
+        "my_gis",
+        GeoSearchFrom::Member(AGRIGENTO.2.to_string()),
+        GeoSearchBy::Radius(100.0, Unit::Kilometers),
+        GeoSearchStoreOptions::default(),
+    );
+    assert_eq!(stored, Ok(2));
+    assert!(con.zscore("near_agrigento", PALERMO.2).unwrap().is_some());
+    assert_eq!(con.zscore("near_agrigento", CATANIA.2), Ok(None));
+
+    let stored = con.geo_search_store(
+        "dists",
+        "my_gis",
+        GeoSearchFrom::Member(AGRIGENTO.2.to_string()),
+        GeoSearchBy::Radius(100.0, Unit::Kilometers),
+        GeoSearchStoreOptions::default()
+            .order(RadiusOrder::Desc)
+            .limit(1)
+            .store_dist(),
+    );
+    assert_eq!(stored, Ok(1));
+    let score = con.zscore("dists", PALERMO.2).unwrap().unwrap();
+    assert_approx_eq!(score, 90.9778, 0.001);
+}
@@ -6,7 +6,9 @@ use crate::support::*;
 use assert_matches::assert_matches;
 use redis::bloom::{
     BloomFilterDumpChunk, BloomFilterDumpIterator, BloomFilterInfoType, BloomFilterInsertOptions,
-    BloomFilterScalingOptions,
+    BloomFilterScalingOptions, CountMinSketchMergeOptions, CuckooFilterDumpIterator,
+    CuckooFilterInsertOptions, CuckooFilterInsertResult, CuckooFilterReserveOptions,
+    TDigestCreateOptions, TDigestMergeOptions, TopKReserveOptions,
 };
 use redis::{TypedCommands, ValueType};
 use redis_test::server::Module;
@@ -347,3 +349,259 @@ fn test_module_bloom_dump_iterator() {
     let loaded_info = con.bf_info(KEY_2).unwrap();
     assert_eq!(loaded_info, original_info);
 }
+
+/// Tries to assure that Cuckoo filter updates and lookups work
+#[test]
+fn test_module_cuckoo_updates() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    // Cuckoo filters allow adding an item multiple times, unless using the `NX` variants
+    assert_eq!(con.cf_add(KEY_1, "foo"), Ok(true));
+    assert_eq!(con.cf_add(KEY_1, "foo"), Ok(true));
+    assert_eq!(con.cf_addnx(KEY_1, "foo"), Ok(false));
+    assert_eq!(con.cf_addnx(KEY_1, "bar"), Ok(true));
+    assert_eq!(con.key_type(KEY_1), Ok(ValueType::CuckooFilter));
+    assert_eq!(con.cf_count(KEY_1, "foo"), Ok(2));
+    assert_eq!(con.cf_exists(KEY_1, "bar"), Ok(true));
+    assert_eq!(
+        con.cf_mexists(KEY_1, &["foo", "bar", "baz"]),
+        Ok(vec![true, true, false])
+    );
+
+    // Deleting removes one occurrence at a time
+    assert_eq!(con.cf_del(KEY_1, "foo"), Ok(true));
+    assert_eq!(con.cf_count(KEY_1, "foo"), Ok(1));
+    assert_eq!(con.cf_del(KEY_1, "baz"), Ok(false));
+
+    assert_eq!(
+        con.cf_insert(KEY_1, &["baz", "baz"]),
+        Ok(vec![
+            CuckooFilterInsertResult::Added,
+            CuckooFilterInsertResult::Added
+        ])
+    );
+    assert_eq!(
+        con.cf_insertnx(KEY_1, &["baz", "quux"]),
+        Ok(vec![
+            CuckooFilterInsertResult::Exists,
+            CuckooFilterInsertResult::Added
+        ])
+    );
+
+    // Inserting with `NOCREATE` into a missing filter fails
+    let options = CuckooFilterInsertOptions::default().nocreate();
+    assert_matches!(
+        con.cf_insert_options(KEY_2, &["foo"], options).unwrap_err().detail(),
+        Some(d) if d.contains("not found")
+    );
+
+    let info = con.cf_info(KEY_1).unwrap();
+    assert_eq!(info.number_of_items_inserted, 5);
+    assert_eq!(info.number_of_items_deleted, 1);
+    assert_eq!(info.number_of_filters, 1);
+}
+
+/// Tries to assure that reserving Cuckoo filters is effective
+#[test]
+fn test_module_cuckoo_reserving() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    assert_eq!(con.cf_reserve(KEY_1, 1000), Ok(()));
+    assert_matches!(con.cf_reserve(KEY_1, 1000).unwrap_err().detail(), Some(d) if d.contains("exists"));
+
+    let options = CuckooFilterReserveOptions::default()
+        .bucket_size(4)
+        .max_iterations(42)
+        .expansion(2);
+    assert_eq!(con.cf_reserve_options(KEY_2, 1000, options), Ok(()));
+    let info = con.cf_info(KEY_2).unwrap();
+    assert_eq!(info.bucket_size, 4);
+    assert_eq!(info.max_iterations, 42);
+    assert_eq!(info.expansion_rate, 2);
+    assert_eq!(info.number_of_items_inserted, 0);
+
+    let options = CuckooFilterInsertOptions::default().capacity(64);
+    assert_eq!(
+        con.cf_insertnx_options(KEY_3, &["foo"], options),
+        Ok(vec![CuckooFilterInsertResult::Added])
+    );
+}
+
+/// Tries to assure that dumping a Cuckoo filter through an iterator and loading it works
+#[test]
+fn test_module_cuckoo_dump_iterator() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    assert_eq!(
+        con.cf_insert(KEY_1, &["foo", "bar"]),
+        Ok(vec![
+            CuckooFilterInsertResult::Added,
+            CuckooFilterInsertResult::Added
+        ])
+    );
+    let original_info = con.cf_info(KEY_1).unwrap();
+
+    let chunks = CuckooFilterDumpIterator::new(&mut con, KEY_1)
+        .map(|r| r.unwrap())
+        .collect::<Vec<_>>();
+    assert!(!chunks.is_empty());
+
+    for chunk in chunks {
+        con.cf_loadchunk(KEY_2, chunk).unwrap();
+    }
+
+    assert_eq!(
+        con.cf_mexists(KEY_2, &["foo", "bar", "baz"]),
+        Ok(vec![true, true, false])
+    );
+    assert_eq!(con.cf_info(KEY_2), Ok(original_info));
+}
+
+/// Tries to assure that Count-Min Sketch counting and merging work
+#[test]
+fn test_module_count_min_sketch() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    assert_eq!(con.cms_initbydim(KEY_1, 2000, 5), Ok(()));
+    assert_eq!(con.cms_initbyprob(KEY_2, 0.001, 0.01), Ok(()));
+    assert_eq!(con.key_type(KEY_1), Ok(ValueType::CountMin));
+
+    assert_eq!(
+        con.cms_incrby(KEY_1, &[("foo", 3), ("bar", 1), ("foo", 2)]),
+        Ok(vec![3, 1, 5])
+    );
+    assert_eq!(
+        con.cms_query(KEY_1, &["foo", "bar", "baz"]),
+        Ok(vec![5, 1, 0])
+    );
+
+    let info = con.cms_info(KEY_1).unwrap();
+    assert_eq!(info.width, 2000);
+    assert_eq!(info.depth, 5);
+    assert_eq!(info.count, 6);
+
+    // Merging requires sketches of the same dimensions
+    assert_eq!(con.cms_initbydim(KEY_3, 2000, 5), Ok(()));
+    assert_eq!(con.cms_merge(KEY_3, &[KEY_1]), Ok(()));
+    assert_eq!(con.cms_query(KEY_3, &["foo"]), Ok(vec![5]));
+    let options = CountMinSketchMergeOptions::default().weights([3]);
+    assert_eq!(con.cms_merge_options(KEY_3, &[KEY_1], options), Ok(()));
+    assert_eq!(con.cms_query(KEY_3, &["foo"]), Ok(vec![15]));
+}
+
+/// Tries to assure that Top-K tracking works
+#[test]
+fn test_module_top_k() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    assert_eq!(con.topk_reserve(KEY_1, 2), Ok(()));
+    assert_eq!(con.key_type(KEY_1), Ok(ValueType::TopK));
+    assert_eq!(con.topk_add(KEY_1, &["foo", "bar"]), Ok(vec![None, None]));
+    assert_eq!(
+        con.topk_incrby(KEY_1, &[("foo", 10), ("bar", 5)]),
+        Ok(vec![None, None])
+    );
+    // `baz` is not frequent enough to expel an item from the top list
+    assert_eq!(con.topk_add(KEY_1, &["baz"]), Ok(vec![None]));
+    assert_eq!(
+        con.topk_query(KEY_1, &["foo", "bar", "baz"]),
+        Ok(vec![true, true, false])
+    );
+    assert_eq!(
+        con.topk_list(KEY_1),
+        Ok(vec!["foo".to_string(), "bar".to_string()])
+    );
+    assert_eq!(
+        con.topk_list_with_count(KEY_1),
+        Ok(vec![("foo".to_string(), 11), ("bar".to_string(), 6)])
+    );
+
+    let options = TopKReserveOptions::new(50, 4, 0.8);
+    assert_eq!(con.topk_reserve_options(KEY_2, 3, options), Ok(()));
+    let info = con.topk_info(KEY_2).unwrap();
+    assert_eq!(info.k, 3);
+    assert_eq!(info.width, 50);
+    assert_eq!(info.depth, 4);
+    assert!((info.decay - 0.8).abs() < 1e-9);
+}
+
+/// Tries to assure that T-Digest observations and estimations work
+#[test]
+fn test_module_t_digest() {
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+    let mut con = ctx.connection();
+
+    assert_eq!(con.tdigest_create(KEY_1), Ok(()));
+    assert_eq!(con.key_type(KEY_1), Ok(ValueType::TDigest));
+    assert!(con.tdigest_min(KEY_1).unwrap().is_nan());
+
+    assert_eq!(con.tdigest_add(KEY_1, &[1., 2., 3., 4., 5.]), Ok(()));
+    assert_eq!(con.tdigest_min(KEY_1), Ok(1.));
+    assert_eq!(con.tdigest_max(KEY_1), Ok(5.));
+    assert_eq!(con.tdigest_quantile(KEY_1, &[0., 1.]), Ok(vec![1., 5.]));
+    assert_eq!(con.tdigest_cdf(KEY_1, &[0., 10.]), Ok(vec![0., 1.]));
+    assert_eq!(con.tdigest_rank(KEY_1, &[0., 1.]), Ok(vec![-1, 0]));
+    assert_eq!(con.tdigest_revrank(KEY_1, &[5.]), Ok(vec![0]));
+    assert_eq!(con.tdigest_byrank(KEY_1, &[0]), Ok(vec![1.]));
+    assert_eq!(con.tdigest_byrevrank(KEY_1, &[0]), Ok(vec![5.]));
+    assert_eq!(con.tdigest_trimmed_mean(KEY_1, 0., 1.), Ok(3.));
+
+    let info = con.tdigest_info(KEY_1).unwrap();
+    assert_eq!(info.compression, 100);
+    assert_eq!(info.observations, 5);
+
+    let options = TDigestCreateOptions::default().compression(200);
+    assert_eq!(con.tdigest_create_options(KEY_2, options), Ok(()));
+    assert_eq!(con.tdigest_add(KEY_2, &[10.]), Ok(()));
+    assert_eq!(con.tdigest_merge(KEY_3, &[KEY_1, KEY_2]), Ok(()));
+    assert_eq!(con.tdigest_max(KEY_3), Ok(10.));
+    assert_eq!(con.tdigest_info(KEY_3).unwrap().compression, 200);
+
+    let options = TDigestMergeOptions::default()
+        .compression(50)
+        .override_destination();
+    assert_eq!(con.tdigest_merge_options(KEY_3, &[KEY_1], options), Ok(()));
+    assert_eq!(con.tdigest_max(KEY_3), Ok(5.));
+    assert_eq!(con.tdigest_info(KEY_3).unwrap().compression, 50);
+
+    assert_eq!(con.tdigest_reset(KEY_3), Ok(()));
+    assert_eq!(con.tdigest_info(KEY_3).unwrap().observations, 0);
+}
+
+/// Tries to assure that the sketch commands are available on async connections
+#[cfg(feature = "tokio-comp")]
+#[test]
+fn test_module_sketches_async() {
+    use redis::AsyncTypedCommands;
+
+    let ctx = TestContext::with_modules(&[Module::Bloom]);
+    skip_if_context_does_not_support!(ctx, REDIS_BLOOM_ANY);
+
+    block_on_all(
+        async move {
+            let mut con = ctx.async_connection().await.unwrap();
+
+            con.cms_initbydim(KEY_1, 100, 3).await.unwrap();
+            assert_eq!(con.cms_incrby(KEY_1, &[("foo", 2)]).await, Ok(vec![2]));
+
+            con.topk_reserve(KEY_2, 1).await.unwrap();
+            assert_eq!(con.topk_add(KEY_2, &["foo"]).await, Ok(vec![None]));
+
+            con.tdigest_create(KEY_3).await.unwrap();
+            con.tdigest_add(KEY_3, &[1., 3.]).await.unwrap();
+            assert_eq!(con.tdigest_info(KEY_3).await.unwrap().observations, 2);
+        },
+        RuntimeType::Tokio,
+    );
+}
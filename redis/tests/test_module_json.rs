@@ -15,7 +15,7 @@ use redis_test::server::Module;
 use crate::support::*;
 mod support;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 // adds json! macro for quick json generation on the fly.
 use serde_json::json;
 
@@ -541,6 +541,110 @@ fn test_module_json_type() {
     assert_eq!(key_type, Ok(ValueType::JSON));
 }
 
+#[test]
+fn test_module_json_get_as() {
+    let ctx = TestContext::with_modules(&[Module::Json]);
+    let mut con = ctx.connection();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        a: i64,
+        nested: HashMap<String, String>,
+    }
+
+    let item = Item {
+        a: 2,
+        nested: HashMap::from([("a".to_string(), "x".to_string())]),
+    };
+    let _: () = con.json_set(TEST_KEY, "$", &item).unwrap();
+
+    assert_eq!(con.json_get_as(TEST_KEY, "$"), Ok(Some(item)));
+    assert_eq!(con.json_get_as(TEST_KEY, ".a"), Ok(Some(2i64)));
+    // Only the first match needs to be a `T`.
+    assert_eq!(con.json_get_as(TEST_KEY, "$..a"), Ok(Some(2i64)));
+    assert_eq!(con.json_get_as::<_, i64>(TEST_KEY, "$.missing"), Ok(None));
+    assert_eq!(con.json_get_as::<_, i64>("missing", "$.a"), Ok(None));
+
+    assert_eq!(
+        con.json_get_all_as(TEST_KEY, "$..a"),
+        Ok(vec![json!(2i64), json!("x")])
+    );
+    assert_eq!(con.json_get_all_as(TEST_KEY, ".a"), Ok(vec![2i64]));
+
+    assert_eq!(
+        con.json_mget_as(&[TEST_KEY, "missing"], "$.a"),
+        Ok(vec![Some(2i64), None])
+    );
+}
+
+#[test]
+fn test_module_json_merge() {
+    let ctx = TestContext::with_modules(&[Module::Json]);
+    let mut con = ctx.connection();
+
+    let _: () = con
+        .json_set(TEST_KEY, "$", &json!({"a": 1i64, "b": 2i64}))
+        .unwrap();
+    let _: () = con
+        .json_merge(TEST_KEY, "$", &json!({"b": null, "c": 3i64}))
+        .unwrap();
+
+    assert_eq!(
+        con.json_get_as(TEST_KEY, "$"),
+        Ok(Some(json!({"a": 1i64, "c": 3i64})))
+    );
+}
+
+#[test]
+fn test_module_json_debug_memory_and_forget() {
+    let ctx = TestContext::with_modules(&[Module::Json]);
+    let mut con = ctx.connection();
+
+    let _: () = con
+        .json_set(TEST_KEY, "$", &json!({"a": "value", "b": 2i64}))
+        .unwrap();
+
+    let memory: Vec<usize> = con.json_debug_memory(TEST_KEY, "$").unwrap();
+    assert_eq!(memory.len(), 1);
+    assert!(memory[0] > 0);
+
+    assert_eq!(con.json_forget(TEST_KEY, "$.a"), Ok(1));
+    assert_eq!(con.json_get_as(TEST_KEY, "$"), Ok(Some(json!({"b": 2i64}))));
+}
+
+#[test]
+fn test_module_json_resp() {
+    let ctx = TestContext::with_modules(&[Module::Json]);
+    let mut con = ctx.connection();
+
+    let _: () = con
+        .json_set(TEST_KEY, "$", &json!({"a": [1i64, "x"]}))
+        .unwrap();
+
+    let resp: Value = con.json_resp(TEST_KEY, ".a").unwrap();
+    assert_eq!(
+        resp,
+        Array(vec![
+            SimpleString("[".to_string()),
+            Int(1),
+            BulkString(b"x".to_vec())
+        ])
+    );
+}
+
+#[test]
+fn test_module_json_arr_index_start() {
+    let ctx = TestContext::with_modules(&[Module::Json]);
+    let mut con = ctx.connection();
+
+    let _: () = con
+        .json_set(TEST_KEY, "$", &json!({"a": [2i64, 1i64, 2i64]}))
+        .unwrap();
+
+    let index: Vec<i64> = con.json_arr_index_start(TEST_KEY, "$.a", &2i64, 1).unwrap();
+    assert_eq!(index, vec![2]);
+}
+
 #[test]
 fn test_module_json_set_options_json_value() {
     let ctx = TestContext::with_modules(&[Module::Json]);
@@ -0,0 +1,245 @@
+#![cfg(feature = "search")]
+
+mod support;
+
+use crate::support::*;
+use redis::search::{
+    AggregateCursor, AggregateQuery, AggregateResult, CreateIndexOptions, IndexInfo, NumericField,
+    Reducer, Schema, SearchQuery, SearchResult, SortOrder, TagField, TextField,
+};
+use redis::{Commands, Connection};
+use redis_test::server::Module;
+use std::collections::HashMap;
+
+const INDEX: &str = "test_search_idx";
+
+fn create_products_index(con: &mut Connection) {
+    let schema = Schema::new()
+        .field(TextField::new("title").weight(2.0))
+        .field(TagField::new("category"))
+        .field(NumericField::new("price").sortable());
+    let options = CreateIndexOptions::default().prefix("product:");
+    let _: () = con.ft_create_options(INDEX, &options, &schema).unwrap();
+
+    for (id, title, category, price) in [
+        (1, "red running shoe", "shoes", 80),
+        (2, "blue running shoe", "shoes", 120),
+        (3, "green hat", "hats", 20),
+        (4, "red hat", "hats", 25),
+        (5, "black boot", "shoes", 150),
+    ] {
+        let _: () = con
+            .hset_multiple(
+                format!("product:{id}"),
+                &[
+                    ("title", title.to_string()),
+                    ("category", category.to_string()),
+                    ("price", price.to_string()),
+                ],
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_module_search_create_and_search() {
+    let ctx = TestContext::with_modules(&[Module::Search]);
+    let mut con = ctx.connection();
+    create_products_index(&mut con);
+
+    let result: SearchResult = con
+        .ft_search(
+            INDEX,
+            &SearchQuery::new("@title:shoe").sort_by("price", SortOrder::Desc),
+        )
+        .unwrap();
+    assert_eq!(result.total, 2);
+    let ids: Vec<_> = result.documents.iter().map(|d| d.id.as_str()).collect();
+    assert_eq!(ids, vec!["product:2", "product:1"]);
+    assert_eq!(
+        result.documents[0].get::<String>("title"),
+        Some(Ok("blue running shoe".to_string()))
+    );
+    assert_eq!(result.documents[0].get::<u32>("price"), Some(Ok(120)));
+    assert_eq!(result.documents[0].score, None);
+    assert_eq!(result.documents[0].json, None);
+
+    let result: SearchResult = con
+        .ft_search(
+            INDEX,
+            &SearchQuery::new("@category:{hats}")
+                .with_scores()
+                .return_field("price"),
+        )
+        .unwrap();
+    assert_eq!(result.total, 2);
+    for document in &result.documents {
+        assert!(document.score.is_some());
+        assert_eq!(document.fields.len(), 1);
+        assert!(document.fields.contains_key("price"));
+    }
+
+    let result: SearchResult = con
+        .ft_search(
+            INDEX,
+            &SearchQuery::new("@price:[$min +inf]")
+                .param("min", 100)
+                .no_content()
+                .sort_by("price", SortOrder::Asc)
+                .limit(0, 1)
+                .dialect(2),
+        )
+        .unwrap();
+    assert_eq!(result.total, 2);
+    assert_eq!(result.documents.len(), 1);
+    assert_eq!(result.documents[0].id, "product:2");
+    assert!(result.documents[0].fields.is_empty());
+}
+
+#[test]
+fn test_module_search_aggregate() {
+    let ctx = TestContext::with_modules(&[Module::Search]);
+    let mut con = ctx.connection();
+    create_products_index(&mut con);
+
+    let query = AggregateQuery::new("*")
+        .group_by(
+            ["@category"],
+            [
+                Reducer::count().alias("count"),
+                Reducer::sum("@price").alias("total"),
+            ],
+        )
+        .sort_by([("@count", SortOrder::Desc)]);
+    let result: AggregateResult = con.ft_aggregate(INDEX, &query).unwrap();
+    assert_eq!(result.cursor_id, None);
+
+    let rows: Vec<(String, u32, u32)> = result
+        .rows
+        .iter()
+        .map(|row| {
+            (
+                redis::from_redis_value_ref(&row["category"]).unwrap(),
+                redis::from_redis_value_ref(&row["count"]).unwrap(),
+                redis::from_redis_value_ref(&row["total"]).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![("shoes".to_string(), 3, 350), ("hats".to_string(), 2, 45)]
+    );
+}
+
+#[test]
+fn test_module_search_aggregate_cursor() {
+    let ctx = TestContext::with_modules(&[Module::Search]);
+    let mut con = ctx.connection();
+    create_products_index(&mut con);
+
+    let query = AggregateQuery::new("*").load(["@title"]).cursor_count(2);
+    let mut cursor = AggregateCursor::new(INDEX, query);
+    let mut titles = Vec::new();
+    let mut batches = 0;
+    while let Some(rows) = cursor.next_batch(&mut con).unwrap() {
+        batches += 1;
+        for row in rows {
+            titles.push(redis::from_redis_value_ref::<String>(&row["title"]).unwrap());
+        }
+    }
+    assert!(batches >= 3);
+    assert_eq!(cursor.cursor_id(), None);
+    titles.sort();
+    assert_eq!(
+        titles,
+        vec![
+            "black boot",
+            "blue running shoe",
+            "green hat",
+            "red hat",
+            "red running shoe"
+        ]
+    );
+
+    // Deleting a cursor before it is exhausted
+    let query = AggregateQuery::new("*").load(["@title"]).cursor_count(1);
+    let mut cursor = AggregateCursor::new(INDEX, query);
+    assert_eq!(cursor.next_batch(&mut con).unwrap().unwrap().len(), 1);
+    let cursor_id = cursor.cursor_id().unwrap();
+    cursor.delete(&mut con).unwrap();
+    assert!(con.ft_cursor_read::<_, ()>(INDEX, cursor_id).is_err());
+}
+
+#[test]
+fn test_module_search_info_and_aliases() {
+    let ctx = TestContext::with_modules(&[Module::Search]);
+    let mut con = ctx.connection();
+    create_products_index(&mut con);
+
+    let info: IndexInfo = con.ft_info(INDEX).unwrap();
+    assert_eq!(info.index_name, INDEX);
+    assert_eq!(info.key_type, "HASH");
+    assert_eq!(info.prefixes, vec!["product:".to_string()]);
+    assert_eq!(info.num_docs, 5);
+    let attributes: HashMap<_, _> = info
+        .attributes
+        .iter()
+        .map(|attribute| (attribute.attribute.as_str(), attribute))
+        .collect();
+    assert_eq!(attributes["title"].field_type, "TEXT");
+    assert_eq!(attributes["category"].field_type, "TAG");
+    assert_eq!(attributes["price"].field_type, "NUMERIC");
+    assert!(attributes["price"].sortable);
+    assert!(!attributes["title"].sortable);
+
+    assert_eq!(con.ft_list(), Ok(vec![INDEX.to_string()]));
+    assert_eq!(
+        con.ft_tagvals(INDEX, "category"),
+        Ok(vec!["hats".to_string(), "shoes".to_string()])
+    );
+
+    let _: () = con.ft_aliasadd("products", INDEX).unwrap();
+    let result: SearchResult = con.ft_search("products", &SearchQuery::new("hat")).unwrap();
+    assert_eq!(result.total, 2);
+    let _: () = con.ft_aliasdel("products").unwrap();
+
+    let _: () = con
+        .ft_alter(INDEX, &TextField::new("description").into())
+        .unwrap();
+    let info: IndexInfo = con.ft_info(INDEX).unwrap();
+    assert_eq!(info.attributes.len(), 4);
+
+    let _: () = con.ft_dropindex_delete_documents(INDEX).unwrap();
+    assert_eq!(con.ft_list(), Ok(Vec::<String>::new()));
+    assert_eq!(con.exists("product:1"), Ok(false));
+}
+
+#[cfg(feature = "tokio-comp")]
+#[test]
+fn test_module_search_async() {
+    use redis::AsyncCommands;
+
+    let ctx = TestContext::with_modules(&[Module::Search]);
+    create_products_index(&mut ctx.connection());
+
+    block_on_all(
+        async move {
+            let mut con = ctx.async_connection().await.unwrap();
+
+            let result: SearchResult = con
+                .ft_search(INDEX, &SearchQuery::new("@category:{shoes}").no_content())
+                .await
+                .unwrap();
+            assert_eq!(result.total, 3);
+
+            let query = AggregateQuery::new("*").load(["@price"]).cursor_count(2);
+            let mut cursor = AggregateCursor::new(INDEX, query);
+            let mut rows = 0;
+            while let Some(batch) = cursor.next_batch_async(&mut con).await.unwrap() {
+                rows += batch.len();
+            }
+            assert_eq!(rows, 5);
+        },
+        RuntimeType::Tokio,
+    );
+}
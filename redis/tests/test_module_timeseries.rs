@@ -0,0 +1,193 @@
+#![cfg(feature = "timeseries")]
+
+mod support;
+
+use crate::support::*;
+use redis::timeseries::{
+    DuplicatePolicy, MGetSeries, MRangeSeries, Sample, TsAggregation, TsAggregationType,
+    TsAlterOptions, TsCreateOptions, TsFilter, TsIncrByOptions, TsInfo, TsMGetOptions,
+    TsMRangeOptions, TsRangeOptions,
+};
+use redis::{Commands, Connection};
+use redis_test::server::Module;
+
+fn sample(timestamp: u64, value: f64) -> Sample {
+    let mut sample = Sample::default();
+    sample.timestamp = timestamp;
+    sample.value = value;
+    sample
+}
+
+fn create_temperatures(con: &mut Connection) {
+    for (key, room) in [("temp:kitchen", "kitchen"), ("temp:attic", "attic")] {
+        let options = TsCreateOptions::default()
+            .duplicate_policy(DuplicatePolicy::Last)
+            .label("sensor", "temperature")
+            .label("room", room);
+        let _: () = con.ts_create_options(key, &options).unwrap();
+    }
+    let timestamps: Vec<u64> = con
+        .ts_madd(&[
+            ("temp:kitchen", 1000, 20.0),
+            ("temp:kitchen", 2000, 22.0),
+            ("temp:kitchen", 3000, 24.0),
+            ("temp:attic", 1000, 10.0),
+            ("temp:attic", 2000, 12.0),
+        ])
+        .unwrap();
+    assert_eq!(timestamps, vec![1000, 2000, 3000, 1000, 2000]);
+}
+
+#[test]
+fn test_module_timeseries_add_and_range() {
+    let ctx = TestContext::with_modules(&[Module::TimeSeries]);
+    let mut con = ctx.connection();
+    create_temperatures(&mut con);
+
+    assert_eq!(con.ts_add("temp:kitchen", 4000, 26.0), Ok(4000));
+    assert_eq!(
+        con.ts_range("temp:kitchen", "-", 2000),
+        Ok(vec![sample(1000, 20.0), sample(2000, 22.0)])
+    );
+    assert_eq!(
+        con.ts_revrange_options(
+            "temp:kitchen",
+            "-",
+            "+",
+            &TsRangeOptions::default().count(1)
+        ),
+        Ok(vec![sample(4000, 26.0)])
+    );
+    assert_eq!(
+        con.ts_range_options(
+            "temp:kitchen",
+            "-",
+            "+",
+            &TsRangeOptions::default()
+                .filter_by_value(21.0, 25.0)
+                .aggregation(TsAggregation::new(TsAggregationType::Avg, 10000))
+        ),
+        Ok(vec![sample(0, 23.0)])
+    );
+
+    let options = TsIncrByOptions::default().timestamp(10);
+    assert_eq!(con.ts_incrby_options("counter", 5, &options), Ok(10));
+    assert_eq!(con.ts_decrby_options("counter", 2, &options), Ok(10));
+    assert_eq!(con.ts_range("counter", "-", "+"), Ok(vec![sample(10, 3.0)]));
+}
+
+#[test]
+fn test_module_timeseries_multi_series() {
+    let ctx = TestContext::with_modules(&[Module::TimeSeries]);
+    let mut con = ctx.connection();
+    create_temperatures(&mut con);
+
+    let filter = TsFilter::new().equals("sensor", "temperature");
+    let mut series: Vec<MRangeSeries> = con.ts_mrange(0, 1500, &filter).unwrap();
+    series.sort_by(|a, b| a.key.cmp(&b.key));
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].key, "temp:attic");
+    assert!(series[0].labels.is_empty());
+    assert_eq!(series[0].samples, vec![sample(1000, 10.0)]);
+
+    let options = TsMRangeOptions::default()
+        .with_labels()
+        .group_by("sensor", TsAggregationType::Max);
+    let series: Vec<MRangeSeries> = con
+        .ts_mrevrange_options("-", "+", &options, &filter)
+        .unwrap();
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].key, "sensor=temperature");
+    assert_eq!(
+        series[0].samples,
+        vec![sample(3000, 24.0), sample(2000, 22.0), sample(1000, 20.0)]
+    );
+
+    let options = TsMGetOptions::default().selected_labels(["room"]);
+    let mut series: Vec<MGetSeries> = con
+        .ts_mget_options(
+            &options,
+            &TsFilter::new().is_in("room", ["attic", "kitchen"]),
+        )
+        .unwrap();
+    series.sort_by(|a, b| a.key.cmp(&b.key));
+    assert_eq!(series[0].labels["room"], "attic");
+    assert_eq!(series[0].sample, Some(sample(2000, 12.0)));
+    assert_eq!(series[1].sample, Some(sample(3000, 24.0)));
+
+    assert_eq!(
+        con.ts_queryindex(&TsFilter::new().equals("room", "kitchen")),
+        Ok(vec!["temp:kitchen".to_string()])
+    );
+}
+
+#[test]
+fn test_module_timeseries_alter_rules_and_info() {
+    let ctx = TestContext::with_modules(&[Module::TimeSeries]);
+    let mut con = ctx.connection();
+    create_temperatures(&mut con);
+
+    let _: () = con
+        .ts_alter(
+            "temp:kitchen",
+            &TsAlterOptions::default()
+                .retention(60000)
+                .label("room", "pantry"),
+        )
+        .unwrap();
+    let _: () = con.ts_create("temp:kitchen:avg").unwrap();
+    let _: () = con
+        .ts_createrule(
+            "temp:kitchen",
+            "temp:kitchen:avg",
+            TsAggregationType::Avg,
+            10000,
+        )
+        .unwrap();
+
+    let info: TsInfo = con.ts_info("temp:kitchen").unwrap();
+    assert_eq!(info.total_samples, 3);
+    assert_eq!(info.first_timestamp, 1000);
+    assert_eq!(info.last_timestamp, 3000);
+    assert_eq!(info.retention_time, 60000);
+    assert_eq!(info.labels.len(), 1);
+    assert_eq!(info.labels["room"], "pantry");
+    assert_eq!(info.duplicate_policy.as_deref(), Some("last"));
+    assert_eq!(info.rules.len(), 1);
+    assert_eq!(info.rules[0].dest_key, "temp:kitchen:avg");
+    assert_eq!(info.rules[0].bucket_duration, 10000);
+    assert_eq!(info.rules[0].aggregator, "AVG");
+
+    let info: TsInfo = con.ts_info("temp:kitchen:avg").unwrap();
+    assert_eq!(info.source_key.as_deref(), Some("temp:kitchen"));
+
+    let _: () = con
+        .ts_deleterule("temp:kitchen", "temp:kitchen:avg")
+        .unwrap();
+    let info: TsInfo = con.ts_info("temp:kitchen").unwrap();
+    assert!(info.rules.is_empty());
+}
+
+#[cfg(feature = "tokio-comp")]
+#[test]
+fn test_module_timeseries_async() {
+    use redis::AsyncCommands;
+
+    let ctx = TestContext::with_modules(&[Module::TimeSeries]);
+    create_temperatures(&mut ctx.connection());
+
+    block_on_all(
+        async move {
+            let mut con = ctx.async_connection().await.unwrap();
+
+            let _: u64 = con.ts_add("temp:attic", 3000, 14.0).await.unwrap();
+            let series: Vec<MGetSeries> = con
+                .ts_mget(&TsFilter::new().equals("room", "attic"))
+                .await
+                .unwrap();
+            assert_eq!(series.len(), 1);
+            assert_eq!(series[0].sample, Some(sample(3000, 14.0)));
+        },
+        RuntimeType::Tokio,
+    );
+}
@@ -629,6 +629,45 @@ fn test_xautoclaim() {
     assert!(reply.claimed[1].map.is_empty());
 }
 
+#[test]
+fn test_xinfo_stream_full() {
+    // Tests the following commands....
+    // xinfo_stream_full
+    // xinfo_stream_full_count
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let read = create_group_add_and_read(&mut con);
+    let first = &read.keys[0].ids[0];
+
+    let reply = con.xinfo_stream_full("k1").unwrap();
+    assert_eq!(reply.length, 10);
+    assert_eq!(reply.entries.len(), 10);
+    assert_eq!(reply.entries[0].id, first.id);
+    assert_eq!(reply.entries[0].get("h"), Some("w".to_string()));
+    assert_eq!(reply.groups.len(), 1);
+
+    let group = &reply.groups[0];
+    assert_eq!(group.base.name, "g1");
+    assert_eq!(group.base.pending, 10);
+    assert_eq!(group.base.consumers, 1);
+    assert_eq!(group.pel.len(), 10);
+    assert_eq!(group.pel[0].id, first.id);
+    assert_eq!(group.pel[0].consumer, "c1");
+    assert_eq!(group.pel[0].times_delivered, 1);
+
+    let consumer = &group.consumers[0];
+    assert_eq!(consumer.name, "c1");
+    assert_eq!(consumer.pending, 10);
+    assert_eq!(consumer.pel.len(), 10);
+    assert!(consumer.seen_time > 0);
+
+    let reply = con.xinfo_stream_full_count("k1", 2).unwrap();
+    assert_eq!(reply.entries.len(), 2);
+    assert_eq!(reply.groups[0].pel.len(), 2);
+    assert_eq!(reply.groups[0].consumers[0].pel.len(), 2);
+}
+
 #[test]
 fn test_xclaim() {
     // Tests the following commands....
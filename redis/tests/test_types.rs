@@ -608,6 +608,56 @@ mod types {
         }
     }
 
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_bulk_bytes() {
+        use bytes::Bytes;
+        use std::ffi::CString;
+
+        for parse_mode in [RedisParseMode::Owned, RedisParseMode::Ref] {
+            let value = || Value::BulkBytes(Bytes::from_static(b"42"));
+
+            let v: Result<Bytes, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(Bytes::from_static(b"42")));
+
+            let v: Result<String, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok("42".to_string()));
+
+            let v: Result<Vec<u8>, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(b"42".to_vec()));
+
+            let v: Result<Vec<i32>, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(vec![42]));
+
+            let v: Result<[u8; 2], _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(*b"42"));
+
+            let v: Result<i64, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(42));
+
+            let v: Result<f64, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(42.0));
+
+            let v: Result<CString, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(CString::new("42").unwrap()));
+
+            let v: Result<Option<String>, _> = parse_mode.parse_redis_value(value());
+            assert_eq!(v, Ok(Some("42".to_string())));
+
+            let v: Result<bool, _> =
+                parse_mode.parse_redis_value(Value::BulkBytes(Bytes::from_static(b"1")));
+            assert_eq!(v, Ok(true));
+
+            let v: Result<bool, _> = parse_mode.parse_redis_value(value());
+            assert_matches!(v, Err(_));
+        }
+
+        // Converting an owned value into `Bytes` keeps sharing the same buffer.
+        let bytes = Bytes::from(vec![0u8; 1024]);
+        let converted: Bytes = redis::from_redis_value(Value::BulkBytes(bytes.clone())).unwrap();
+        assert_eq!(converted.as_ptr(), bytes.as_ptr());
+    }
+
     #[cfg(feature = "uuid")]
     #[test]
     fn test_uuid() {
@@ -983,4 +1033,124 @@ mod types {
             );
         }
     }
+
+    #[test]
+    fn test_lcs_matches() {
+        let resp2 = redis_value!([
+            "matches",
+            [[[4, 7], [5, 8], 4], [[2, 3], [0, 1], 2]],
+            "len",
+            6
+        ]);
+        let resp3 = redis_value!({
+            "matches": [[[4, 7], [5, 8]], [[2, 3], [0, 1]]],
+            "len": 6
+        });
+
+        let matches = redis::LcsMatches::from_redis_value(resp2).unwrap();
+        assert_eq!(matches.len, 6);
+        assert_eq!(
+            matches.matches,
+            vec![(4..8, 5..9, Some(4)), (2..4, 0..2, Some(2))]
+        );
+
+        let matches = redis::LcsMatches::from_redis_value(resp3).unwrap();
+        assert_eq!(
+            matches.matches,
+            vec![(4..8, 5..9, None), (2..4, 0..2, None)]
+        );
+
+        assert!(
+            redis::LcsMatches::from_redis_value(redis_value!({
+                "matches": [[[4, 7]]],
+                "len": 6
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_borrowed_strings() {
+        use redis::from_redis_value_borrowed;
+        use std::borrow::Cow;
+
+        let value = Value::BulkString(b"hello".to_vec());
+        let borrowed: &str = from_redis_value_borrowed(&value).unwrap();
+        assert_eq!(borrowed, "hello");
+        let Value::BulkString(bytes) = &value else {
+            unreachable!()
+        };
+        assert_eq!(borrowed.as_ptr(), bytes.as_ptr());
+
+        let borrowed: &[u8] = from_redis_value_borrowed(&value).unwrap();
+        assert_eq!(borrowed, b"hello");
+        let simple = redis_value!(simple:"OK");
+        let borrowed: &str = from_redis_value_borrowed(&simple).unwrap();
+        assert_eq!(borrowed, "OK");
+        let borrowed: &str = from_redis_value_borrowed(&Value::Okay).unwrap();
+        assert_eq!(borrowed, "OK");
+
+        let cow: Cow<str> = from_redis_value_borrowed(&value).unwrap();
+        assert_matches!(cow, Cow::Borrowed("hello"));
+        let cow: Cow<str> = from_redis_value_borrowed(&Value::Int(42)).unwrap();
+        assert_matches!(cow, Cow::Owned(ref val) if val == "42");
+        let cow: Cow<[u8]> = from_redis_value_borrowed(&value).unwrap();
+        assert_matches!(cow, Cow::Borrowed(b"hello"));
+
+        let invalid = Value::BulkString(vec![0xff]);
+        assert_matches!(from_redis_value_borrowed::<&str>(&invalid), Err(_));
+        assert_matches!(from_redis_value_borrowed::<&[u8]>(&Value::Int(1)), Err(_));
+        assert_matches!(from_redis_value_borrowed::<&str>(&Value::Nil), Err(_));
+
+        let optional: Option<&str> = from_redis_value_borrowed(&Value::Nil).unwrap();
+        assert_eq!(optional, None);
+        let optional: Option<&str> = from_redis_value_borrowed(&value).unwrap();
+        assert_eq!(optional, Some("hello"));
+    }
+
+    #[test]
+    fn test_borrowed_collections() {
+        use redis::from_redis_value_borrowed;
+        use std::collections::BTreeMap;
+
+        let resp2 = redis_value!(["a", "1", "b", "2"]);
+        let resp3 = redis_value!({"a": "1", "b": "2"});
+        for value in [&resp2, &resp3] {
+            let map: HashMap<&str, &[u8]> = from_redis_value_borrowed(value).unwrap();
+            assert_eq!(map, HashMap::from([("a", &b"1"[..]), ("b", &b"2"[..])]));
+
+            let map: BTreeMap<&str, i64> = from_redis_value_borrowed(value).unwrap();
+            assert_eq!(map, BTreeMap::from([("a", 1), ("b", 2)]));
+
+            let pairs: Vec<(&str, u8)> = from_redis_value_borrowed(value).unwrap();
+            assert_eq!(pairs, vec![("a", 1), ("b", 2)]);
+        }
+        assert_matches!(from_redis_value_borrowed::<Vec<&str>>(&resp3), Err(_));
+        assert_matches!(
+            from_redis_value_borrowed::<Vec<(&str, &str, &str)>>(&resp3),
+            Err(_)
+        );
+
+        let list: Vec<&str> = from_redis_value_borrowed(&resp2).unwrap();
+        assert_eq!(list, vec!["a", "1", "b", "2"]);
+        let list: Vec<&str> = from_redis_value_borrowed(&Value::Nil).unwrap();
+        assert!(list.is_empty());
+        let map: HashMap<&str, &str> = from_redis_value_borrowed(&Value::Nil).unwrap();
+        assert!(map.is_empty());
+
+        let nested = redis_value!([["a", 1], ["b", 2]]);
+        let pairs: Vec<(&str, i32)> = from_redis_value_borrowed(&nested).unwrap();
+        assert_eq!(pairs, vec![("a", 1), ("b", 2)]);
+
+        let replies = redis_value!([nil, ["x", "y"], 3]);
+        let (first, list, count): (Option<&str>, Vec<&[u8]>, usize) =
+            from_redis_value_borrowed(&replies).unwrap();
+        assert_eq!(first, None);
+        assert_eq!(list, vec![b"x", b"y"]);
+        assert_eq!(count, 3);
+        assert_matches!(from_redis_value_borrowed::<(&str, &str)>(&replies), Err(_));
+        let pair = redis_value!([1, 2]);
+        let (value, _): (&Value, Value) = from_redis_value_borrowed(&pair).unwrap();
+        assert_eq!(value, &Value::Int(1));
+    }
 }